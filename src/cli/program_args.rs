@@ -2,6 +2,7 @@ use std::fmt::{Display, Formatter};
 use std::str::FromStr;
 use clap::Parser;
 use crate::core::code_generator::target_os::TargetOS;
+use crate::core::semantics::warnings::{Lint, LintConfig, LintLevel};
 
 #[derive(Parser, Debug)]
 #[command(author, version, about = crate::cli::main_screen::print_help_screen(), long_about = None)]
@@ -21,6 +22,33 @@ pub struct ProgramArgs {
     #[arg(short = 'o', long, default_value_t = OptimizationLevel::O1)]
     /// Describes the level of provided optimization
     pub optimization_level: OptimizationLevel,
+    #[arg(short = 'I', long = "module-path")]
+    /// Additional directories to search for imported modules
+    pub module_paths: Vec<String>,
+    #[arg(short = 'A', long = "allow")]
+    /// Don't report the given lint, by name or code (e.g. unused_mut, W0002)
+    pub allow: Vec<Lint>,
+    #[arg(short = 'W', long = "warn")]
+    /// Report the given lint as a warning
+    pub warn: Vec<Lint>,
+    #[arg(short = 'D', long = "deny")]
+    /// Report the given lint as an error, which stops the build
+    pub deny: Vec<Lint>,
+}
+
+impl ProgramArgs {
+    /// The lint levels of the command line. `deny` takes precedence over `warn` and `warn` over `allow`
+    pub fn lint_config(&self) -> LintConfig {
+        let mut lint_config = LintConfig::default();
+
+        for (lints, level) in [(&self.allow, LintLevel::Allow), (&self.warn, LintLevel::Warn), (&self.deny, LintLevel::Deny)] {
+            for lint in lints {
+                lint_config.set(*lint, level);
+            }
+        }
+
+        lint_config
+    }
 }
 
 
@@ -1,8 +1,11 @@
+use crate::core::code_generator::asm_builder::ASMBuilder;
 use crate::core::code_generator::asm_options::ASMOptions;
-use crate::core::code_generator::asm_result::ASMResult;
+use crate::core::code_generator::asm_result::{ASMResult, ASMResultError, ASMResultVariance};
 use crate::core::code_generator::generator::Stack;
+use crate::core::code_generator::registers::{Bit64, GeneralPurposeRegister};
 use crate::core::code_generator::{ASMGenerateError, MetaInfo, ToASM};
 use crate::core::model::abstract_syntax_tree_nodes::assignable::Assignable;
+use crate::core::model::abstract_syntax_tree_nodes::assignables::equation_parser::prefix_arithmetic::{PointerArithmetic, PrefixArithmetic};
 
 
 impl ToASM for Assignable {
@@ -18,7 +21,9 @@ impl ToASM for Assignable {
             Assignable::Boolean(boolean) => Ok(boolean.to_asm(stack, meta, options)?),
             Assignable::Array(array) => Ok(array.to_asm(stack, meta, options)?),
             Assignable::Object(object) => Ok(object.to_asm(stack, meta, options)?),
-            Assignable::Parameter(_) => Err(ASMGenerateError::AssignmentNotImplemented { assignable: self.clone() })
+            Assignable::EnumPath(enum_path) => Ok(enum_path.to_asm(stack, meta, options)?),
+            Assignable::FieldAccess(field_access) => Ok(field_access.to_asm(stack, meta, options)?),
+            Assignable::Parameter(_) | Assignable::Uninitialized => Err(ASMGenerateError::AssignmentNotImplemented { assignable: Box::new(self.clone()) })
         }
     }
 
@@ -32,9 +37,12 @@ impl ToASM for Assignable {
             Assignable::MethodCall(s) => s.is_stack_look_up(stack, meta),
             Assignable::Identifier(s) => s.is_stack_look_up(stack, meta),
             Assignable::Object(s) => s.is_stack_look_up(stack, meta),
+            Assignable::EnumPath(s) => s.is_stack_look_up(stack, meta),
+            Assignable::FieldAccess(s) => s.is_stack_look_up(stack, meta),
             Assignable::Expression(a) => a.is_stack_look_up(stack, meta),
             Assignable::Parameter(r) => r.is_stack_look_up(stack, meta),
-            Assignable::Array(s) => s.is_stack_look_up(stack, meta)
+            Assignable::Array(s) => s.is_stack_look_up(stack, meta),
+            Assignable::Uninitialized => false,
         }
     }
 
@@ -47,9 +55,12 @@ impl ToASM for Assignable {
             Assignable::MethodCall(a) => a.byte_size(meta),
             Assignable::Identifier(a) => a.byte_size(meta),
             Assignable::Object(a) => a.byte_size(meta),
+            Assignable::EnumPath(a) => a.byte_size(meta),
+            Assignable::FieldAccess(a) => a.byte_size(meta),
             Assignable::Expression(a) => a.byte_size(meta),
             Assignable::Parameter(r) => r.ty.byte_size(),
             Assignable::Array(r) => r.byte_size(meta),
+            Assignable::Uninitialized => 0,
         }
     }
 
@@ -62,9 +73,63 @@ impl ToASM for Assignable {
             Assignable::MethodCall(v) => v.data_section(stack, meta),
             Assignable::Identifier(v) => v.data_section(stack, meta),
             Assignable::Object(v) => v.data_section(stack, meta),
+            Assignable::EnumPath(v) => v.data_section(stack, meta),
+            Assignable::FieldAccess(v) => v.data_section(stack, meta),
             Assignable::Expression(v) => v.data_section(stack, meta),
             Assignable::Parameter(r) => r.data_section(stack, meta),
             Assignable::Array(r) => r.data_section(stack, meta),
+            Assignable::Uninitialized => false,
         }
     }
-}
\ No newline at end of file
+}
+
+impl Assignable {
+    /// Generates the address of a struct. Returns the instructions computing the address and the address itself, like `rbp - 16`.
+    /// Pointers to structs are dereferenced. Structs without an address, like objects, are written into temporary memory first
+    pub fn struct_address(&self, stack: &mut Stack, meta: &mut MetaInfo) -> Result<(String, String), ASMGenerateError> {
+        match self {
+            Assignable::Identifier(identifier) => {
+                let stack_location = stack.variables.resolve(identifier).ok_or(ASMGenerateError::UnresolvedReference {
+                    name: identifier.name.clone(),
+                    file_position: meta.file_position.clone(),
+                })?;
+                let address = format!("rbp - {}", stack_location.position + stack_location.size);
+
+                if identifier.get_type(&meta.static_type_information).is_some_and(|ty| ty.is_pointer()) {
+                    let register = Self::address_register(stack);
+                    return Ok((ASMBuilder::mov_ident_line(&register, format!("QWORD [{address}]")), register.to_string()));
+                }
+
+                Ok((String::new(), address))
+            }
+            Assignable::FieldAccess(field_access) => field_access.struct_address(stack, meta),
+            Assignable::Expression(expression) if expression.index_operator.is_none() => match (&expression.value, &expression.prefix_arithmetic) {
+                (Some(value), None | Some(PrefixArithmetic::PointerArithmetic(PointerArithmetic::Asterics))) => value.struct_address(stack, meta),
+                _ => Err(ASMGenerateError::InternalError(format!("`{}` has no address", self), meta.file_position.clone())),
+            },
+            Assignable::Object(object) => {
+                let offset = stack.reserve_temporary(object.byte_size(meta));
+                let address = format!("rbp - {offset}");
+
+                Ok((object.write_to(&address, stack, meta)?, address))
+            }
+            Assignable::MethodCall(method_call) => match method_call.to_asm(stack, meta, None)? {
+                ASMResult::MultilineResulted(target, register) => Ok((target, register.to_64_bit_register().to_string())),
+                _ => Err(ASMGenerateError::ASMResult(ASMResultError::UnexpectedVariance {
+                    expected: vec![ASMResultVariance::MultilineResulted],
+                    actual: ASMResultVariance::Multiline,
+                    ast_node: "Method call".to_string(),
+                })),
+            },
+            _ => Err(ASMGenerateError::InternalError(format!("`{}` has no address", self), meta.file_position.clone())),
+        }
+    }
+
+    /// the register holding addresses, which are loaded from memory
+    pub fn address_register(stack: &Stack) -> GeneralPurposeRegister {
+        match stack.register_to_use.last() {
+            Some(register) if !register.is_float_register() => register.to_64_bit_register(),
+            _ => GeneralPurposeRegister::Bit64(Bit64::Rax),
+        }
+    }
+}
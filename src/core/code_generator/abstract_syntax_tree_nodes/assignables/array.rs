@@ -16,7 +16,7 @@ impl ToASM for Array {
 
         let initial_position = match options {
             Some(ASMOptions::IdentifierPresent(concrete_type)) => {
-                let stack_variable = stack.variables.iter().rfind(|v| v.name == concrete_type.identifier).ok_or(ASMGenerateError::InternalError("Cannot find variable".to_string(), meta.file_position.clone()))?;
+                let stack_variable = stack.variables.resolve_l_value(&concrete_type.identifier).ok_or(ASMGenerateError::InternalError("Cannot find variable".to_string(), meta.file_position.clone()))?;
                 stack_variable.position
             },
             _ => {
@@ -0,0 +1,24 @@
+use crate::core::code_generator::asm_options::ASMOptions;
+use crate::core::code_generator::asm_result::ASMResult;
+use crate::core::code_generator::generator::Stack;
+use crate::core::code_generator::{ASMGenerateError, MetaInfo, ToASM};
+use crate::core::model::abstract_syntax_tree_nodes::assignables::enum_path::EnumPath;
+
+/// enum values are represented by their discriminant and use the size of a custom type
+impl ToASM for EnumPath {
+    fn to_asm(&self, _stack: &mut Stack, meta: &mut MetaInfo, _options: Option<ASMOptions>) -> Result<ASMResult, ASMGenerateError> {
+        let discriminant = meta.static_type_information.enum_definition(&self.ty)
+            .and_then(|enum_definition| enum_definition.discriminant(&self.variant.name))
+            .ok_or(ASMGenerateError::InternalError(format!("Cannot find the discriminant of `{}`", self), meta.file_position.clone()))?;
+
+        Ok(ASMResult::Inline(discriminant.to_string()))
+    }
+
+    fn is_stack_look_up(&self, _stack: &mut Stack, _meta: &MetaInfo) -> bool {
+        false
+    }
+
+    fn byte_size(&self, _meta: &MetaInfo) -> usize {
+        self.ty.byte_size()
+    }
+}
@@ -22,7 +22,7 @@ impl Expression {
     fn iterator_from_type(&self, meta: &mut MetaInfo, lhs_size: usize) -> Result<(GeneralPurposeRegisterIterator, Option<FloatType>), ASMGenerateError> {
         if let Some(lhs) = &self.lhs {
             let ty = &lhs.get_type(&meta.static_type_information).ok_or(Box::new(InferTypeError::NoTypePresent(
-                LValue::Identifier(Identifier { name: "Expression".to_string() }), meta.file_position.clone()
+                LValue::Identifier(Identifier { name: "Expression".to_string(), symbol: None }), meta.file_position.clone()
             )))?;
 
             return Ok(if let Type::Float(f, _) = ty {
@@ -124,7 +124,7 @@ impl Expression {
                 stack.register_to_use.pop();
 
                 let ty = &rhs.get_type(&meta.static_type_information).ok_or(Box::new(InferTypeError::NoTypePresent(
-                    LValue::Identifier(Identifier { name: "Expression".to_string() }), meta.file_position.clone()
+                    LValue::Identifier(Identifier { name: "Expression".to_string(), symbol: None }), meta.file_position.clone()
                 )))?;
                 let operation = self.operator.specific_operation(ty, &[&destination_register, &target_register], stack, meta)?.inject_registers();
                 target += &ASMBuilder::ident_line(&operation.0);
@@ -160,7 +160,7 @@ impl Expression {
             ASMResult::MultilineResulted(s, new_register) => {
                 target += &s;
                 let final_ty = self.get_type(&meta.static_type_information).ok_or(Box::new(InferTypeError::NoTypePresent(
-                    LValue::Identifier(Identifier { name: "Expression".to_string() }), meta.file_position.clone()
+                    LValue::Identifier(Identifier { name: "Expression".to_string(), symbol: None }), meta.file_position.clone()
                 )))?;
                 let maybe_new_register = if final_ty.is_float() { new_register.to_float_register() } else { new_register.clone() };
 
@@ -185,7 +185,7 @@ impl Expression {
         })))? {
             ASMResult::Inline(inline) => {
                 let ty = rhs.get_type(&meta.static_type_information).ok_or(Box::new(InferTypeError::NoTypePresent(
-                    LValue::Identifier(Identifier { name: "Expression".to_string() }), meta.file_position.clone()
+                    LValue::Identifier(Identifier { name: "Expression".to_string(), symbol: None }), meta.file_position.clone()
                 )))?;
                 let operation = self.operator.specific_operation(&ty, &[destination_register.to_string(), inline.to_string()], stack, meta)?.inject_registers();
                 target += &ASMBuilder::ident_line(&operation.0);
@@ -194,7 +194,7 @@ impl Expression {
             ASMResult::MultilineResulted(s, mut new_register) => {
                 target += &s;
                 let final_ty = self.get_type(&meta.static_type_information).ok_or(Box::new(InferTypeError::NoTypePresent(
-                    LValue::Identifier(Identifier { name: "Expression".to_string() }), meta.file_position.clone()
+                    LValue::Identifier(Identifier { name: "Expression".to_string(), symbol: None }), meta.file_position.clone()
                 )))?;
                 let maybe_new_register = if final_ty.is_float() { new_register.to_float_register() } else { new_register.clone() };
 
@@ -204,7 +204,7 @@ impl Expression {
 
 
                 let ty = rhs.get_type(&meta.static_type_information).ok_or(Box::new(InferTypeError::NoTypePresent(
-                    LValue::Identifier(Identifier { name: "Expression".to_string() }), meta.file_position.clone()
+                    LValue::Identifier(Identifier { name: "Expression".to_string(), symbol: None }), meta.file_position.clone()
                 )))?;
                 let operation = self.operator.specific_operation(&ty, &[destination_register.to_string(), new_register.to_string()], stack, meta)?.inject_registers();
                 target += &ASMBuilder::ident_line(&operation.0);
@@ -248,7 +248,7 @@ impl Expression {
             ASMResult::MultilineResulted(s, new_register) => {
                 target += &s;
                 let final_ty = self.get_type(&meta.static_type_information).ok_or(Box::new(InferTypeError::NoTypePresent(
-                    LValue::Identifier(Identifier { name: "Expression".to_string() }), meta.file_position.clone()
+                    LValue::Identifier(Identifier { name: "Expression".to_string(), symbol: None }), meta.file_position.clone()
                 )))?;
                 let maybe_new_register = if final_ty.is_float() { new_register.to_float_register() } else { new_register.clone() };
 
@@ -279,7 +279,7 @@ impl Expression {
             ASMResult::MultilineResulted(s, mut new_register) => {
                 target += &s;
                 let final_ty = self.get_type(&meta.static_type_information).ok_or(Box::new(InferTypeError::NoTypePresent(
-                    LValue::Identifier(Identifier { name: "Expression".to_string() }), meta.file_position.clone()
+                    LValue::Identifier(Identifier { name: "Expression".to_string(), symbol: None }), meta.file_position.clone()
                 )))?;
                 let maybe_new_register = if final_ty.is_float() { new_register.to_float_register() } else { new_register.clone() };
 
@@ -355,7 +355,7 @@ impl Expression {
             ASMResult::MultilineResulted(s, mut new_register) => {
                 target += &s;
                 let final_ty = self.get_type(&meta.static_type_information).ok_or(Box::new(InferTypeError::NoTypePresent(
-                    LValue::Identifier(Identifier { name: "Expression".to_string() }), meta.file_position.clone()
+                    LValue::Identifier(Identifier { name: "Expression".to_string(), symbol: None }), meta.file_position.clone()
                 )))?;
                 let maybe_new_register = if final_ty.is_float() { new_register.to_float_register() } else { new_register.clone() };
 
@@ -415,6 +415,7 @@ impl Expression {
 
         target += &ASMBuilder::ident_line(&format!("push {}", pushing_register.to_64_bit_register()));
         target += &ASMBuilder::ident_line(&format!("xor {}, {}", pushing_register.to_64_bit_register(), pushing_register.to_64_bit_register()));
+        stack.pushed_bytes += 8;
 
         stack.register_to_use.push(register_a.clone());
         let mut target_register = stack.register_to_use.last(&meta.file_position)?;
@@ -445,6 +446,7 @@ impl Expression {
 
         Self::pop_to_register(&mut target, &float_type, &register_b)?;
         Self::pop_to_register(&mut target, &float_type, &register_a)?;
+        stack.pushed_bytes -= 8;
 
         if target_register.is_float_register() {
             target += &ASMBuilder::mov_x_ident_line(&target_register, &register_a, Some(register_a.size() as usize));
@@ -471,7 +473,7 @@ impl ToASM for Expression {
         if let Some(value) = &self.value { // no lhs and rhs
             if stack.register_to_use.is_empty() {
                 let assignable_type = self.get_type(&meta.static_type_information).ok_or(Box::new(InferTypeError::NoTypePresent(
-                    LValue::Identifier(Identifier { name: value.identifier().unwrap_or("Expression".to_string() )}), meta.file_position.clone()
+                    LValue::Identifier(Identifier { name: value.identifier().unwrap_or("Expression".to_string() ), symbol: None }), meta.file_position.clone()
                 )))?;
                 let iterator = GeneralPurposeRegister::iter_from_byte_size(assignable_type.byte_size())?;
                 stack.register_to_use.push(iterator.current());
@@ -483,7 +485,10 @@ impl ToASM for Expression {
             }
 
 
-            let s = if let Some(prefix_arithmetic) = &self.prefix_arithmetic {
+            // a cast to the same type doesn't change the value
+            let prefix_arithmetic = self.prefix_arithmetic.as_ref().filter(|_| !self.is_redundant_cast(&meta.static_type_information));
+
+            let s = if let Some(prefix_arithmetic) = prefix_arithmetic {
                 Self::prefix_arithmetic_to_asm(prefix_arithmetic, value, &stack.register_to_use.last(&meta.file_position)?, stack, meta, options)
             } else if matches!(value.as_ref(), Assignable::MethodCall(_)) {
                 value.to_asm(stack, meta, Some(ASMOptions::InExpressionMethodCall(InExpressionMethodCall)))
@@ -38,6 +38,8 @@ impl ToASM for Operator {
             Operator::LogicalAnd => "je",
             Operator::LogicalOr => "jne",
             Operator::Mod => "div",
+            Operator::LogicalNot => "xor",
+            Operator::BitwiseNot => "not",
         }.to_string()))
     }
 
@@ -26,7 +26,7 @@ impl ToASM for PrefixArithmetic {
                             // you must write to an anonymous stack position and dereference that one
                             if GeneralPurposeRegister::from_str(&options.register_or_stack_address).is_ok() {
                                 let byte_size = options.value.infer_type(&mut meta.static_type_information)?.byte_size();
-                                stack.variables.push(StackLocation::new_anonymous_stack_location(stack.stack_position, byte_size));
+                                stack.variables.declare(StackLocation::new_anonymous_stack_location(stack.stack_position, byte_size));
                                 stack.stack_position += byte_size;
 
                                 let offset = stack.stack_position;
@@ -76,7 +76,11 @@ impl ToASM for PrefixArithmetic {
                         (Type::Bool(_), Type::Integer(i2, _)) => Boolean::cast_from_to(&Boolean::True, i2, &options.register_or_stack_address, stack, meta)?,
                         (Type::Float(f1, _), Type::Integer(i2, _)) => FloatType::cast_from_to(f1, i2, &options.register_or_stack_address, stack, meta)?,
                         (Type::Integer(i1, _), Type::Integer(i2, _)) => IntegerType::cast_from_to(i1, i2, &options.register_or_stack_address, stack, meta)?,
-                        _ => return Err(ASMGenerateError::CastUnsupported(CastToError::CastUnsupported(cast_to.clone()), meta.file_position.clone()))
+                        // enums are stored as 64 bit discriminants, the smaller integer register is taken from the result
+                        (Type::Custom(_, _), Type::Integer(_, _)) if meta.static_type_information.enum_definition(&cast_to.from).is_some() => {
+                            ASMResult::Multiline(ASMBuilder::mov_ident_line(&options.register_64, &options.register_or_stack_address))
+                        }
+                        _ => return Err(ASMGenerateError::CastUnsupported(Box::new(CastToError::CastUnsupported(cast_to.clone())), meta.file_position.clone()))
                     };
 
                     result.apply_with(&mut options.target)
@@ -99,7 +103,36 @@ impl ToASM for PrefixArithmetic {
                 PrefixArithmetic::Operation(Operator::Noop) => {
                     return Ok(ASMResult::MultilineResulted(options.target.clone(), options.register_64.clone()));
                 }
-                PrefixArithmetic::Operation(_) => unimplemented!("Not finished yet"),
+                PrefixArithmetic::Operation(operator) => {
+                    let value_type = options.value.infer_type(&mut meta.static_type_information)?;
+                    let register = options.register_64.to_size_register_ignore_float(&ByteSize::try_from(value_type.byte_size())?);
+
+                    let source = match GeneralPurposeRegister::from_str(&options.register_or_stack_address) {
+                        Ok(source) if !source.is_float_register() => source.to_size_register(&register.size()).to_string(),
+                        _ => options.register_or_stack_address.clone(),
+                    };
+                    options.target.push_str(&ASMBuilder::mov_x_ident_line(&register, source, Some(value_type.byte_size())));
+
+                    let instruction = match (operator, &value_type) {
+                        (Operator::Sub, Type::Integer(_, _)) => format!("neg {register}"),
+                        (Operator::BitwiseNot, Type::Integer(_, _)) => format!("not {register}"),
+                        (Operator::LogicalNot, Type::Bool(_)) => format!("xor {register}, 1"),
+                        // flipping the sign bit
+                        (Operator::Sub, Type::Float(FloatType::Float32, _)) => format!("xor {register}, 0x80000000"),
+                        (Operator::Sub, Type::Float(FloatType::Float64, _)) => format!("btc {register}, 63"),
+                        _ => return Err(ASMGenerateError::InternalError(format!("Unary `{operator}` is not supported on `{value_type}`"), meta.file_position.clone())),
+                    };
+
+                    options.target.push_str(&ASMBuilder::ident_line(&instruction));
+
+                    return if let Type::Float(_, _) = &value_type {
+                        let float_register = options.register_64.to_float_register();
+                        options.target.push_str(&ASMBuilder::mov_x_ident_line(&float_register, &register, Some(value_type.byte_size())));
+                        Ok(ASMResult::MultilineResulted(options.target.clone(), float_register))
+                    } else {
+                        Ok(ASMResult::MultilineResulted(options.target.clone(), register))
+                    }
+                }
             }
         }
 
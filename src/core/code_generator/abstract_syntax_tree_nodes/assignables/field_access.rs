@@ -0,0 +1,84 @@
+use crate::core::code_generator::asm_builder::ASMBuilder;
+use crate::core::code_generator::asm_options::ASMOptions;
+use crate::core::code_generator::asm_result::ASMResult;
+use crate::core::code_generator::generator::Stack;
+use crate::core::code_generator::register_destination::word_from_byte_size;
+use crate::core::code_generator::registers::ByteSize;
+use crate::core::code_generator::{ASMGenerateError, MetaInfo, ToASM};
+use crate::core::model::abstract_syntax_tree_nodes::assignable::Assignable;
+use crate::core::model::abstract_syntax_tree_nodes::assignables::field_access::FieldAccess;
+use crate::core::model::types::ty::Type;
+
+impl ToASM for FieldAccess {
+    fn to_asm(&self, stack: &mut Stack, meta: &mut MetaInfo, options: Option<ASMOptions>) -> Result<ASMResult, ASMGenerateError> {
+        let ty = self.field_type(meta)?;
+
+        if let (Some(ASMOptions::PrepareRegisterOption(prepare_register)), Type::Float(_, _)) = (&options, &ty) {
+            if prepare_register.assignable.is_some() {
+                return prepare_register.transform(stack, meta);
+            }
+        }
+
+        let (mut target, address) = self.field_address(stack, meta)?;
+        let is_struct = meta.static_type_information.struct_definition(&ty).is_some();
+        let operand = format!("{} [{address}]", word_from_byte_size(ty.byte_size()));
+
+        if target.is_empty() && !is_struct {
+            return Ok(ASMResult::Inline(operand));
+        }
+
+        let register = Assignable::address_register(stack);
+
+        // l_values and structs are represented by their address
+        if is_struct || matches!(options, Some(ASMOptions::LValueExpressionOption)) {
+            target += &ASMBuilder::ident_line(&format!("lea {register}, [{address}]"));
+            return Ok(ASMResult::MultilineResulted(target, register));
+        }
+
+        let register = register.to_size_register(&ByteSize::try_from(ty.byte_size())?);
+        target += &ASMBuilder::mov_ident_line(&register, operand);
+
+        Ok(ASMResult::MultilineResulted(target, register))
+    }
+
+    fn is_stack_look_up(&self, _stack: &mut Stack, _meta: &MetaInfo) -> bool {
+        true
+    }
+
+    fn byte_size(&self, meta: &MetaInfo) -> usize {
+        self.get_type(&meta.static_type_information).map_or(0, |ty| ty.byte_size())
+    }
+}
+
+impl FieldAccess {
+    /// the address of a struct stored in the field. Pointers stored in the field are dereferenced
+    pub fn struct_address(&self, stack: &mut Stack, meta: &mut MetaInfo) -> Result<(String, String), ASMGenerateError> {
+        let (mut target, address) = self.field_address(stack, meta)?;
+
+        if self.field_type(meta)?.is_pointer() {
+            let register = Assignable::address_register(stack);
+            target += &ASMBuilder::mov_ident_line(&register, format!("QWORD [{address}]"));
+
+            return Ok((target, register.to_string()));
+        }
+
+        Ok((target, address))
+    }
+
+    /// the address of the field. The offset of the field is taken from the `struc` of the struct
+    fn field_address(&self, stack: &mut Stack, meta: &mut MetaInfo) -> Result<(String, String), ASMGenerateError> {
+        let value_type = self.value.get_type(&meta.static_type_information).ok_or(ASMGenerateError::InternalError(format!("Cannot infer the type of `{}`", self.value), meta.file_position.clone()))?;
+        let struct_type = value_type.pop_pointer().unwrap_or(value_type);
+        let label = meta.static_type_information.struct_definition(&struct_type)
+            .ok_or(ASMGenerateError::InternalError(format!("Struct definition for type {} not found", struct_type), meta.file_position.clone()))?
+            .label();
+
+        let (target, address) = self.value.struct_address(stack, meta)?;
+
+        Ok((target, format!("{address} + {label}.{}", self.field)))
+    }
+
+    fn field_type(&self, meta: &MetaInfo) -> Result<Type, ASMGenerateError> {
+        self.get_type(&meta.static_type_information).ok_or(ASMGenerateError::InternalError(format!("Cannot infer the type of `{}`", self), meta.file_position.clone()))
+    }
+}
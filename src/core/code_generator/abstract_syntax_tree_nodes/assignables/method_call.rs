@@ -6,15 +6,18 @@ use crate::core::code_generator::conventions::CallingRegister;
 use crate::core::code_generator::generator::Stack;
 use crate::core::code_generator::register_destination::byte_size_from_word;
 use crate::core::code_generator::registers::{Bit64, ByteSize, GeneralPurposeRegister};
+use crate::core::code_generator::target_os::TargetOS;
 use crate::core::code_generator::ToASM;
 use crate::core::code_generator::{conventions, ASMGenerateError, MetaInfo};
 use crate::core::model::abstract_syntax_tree_nodes::assignable::Assignable;
 use crate::core::model::abstract_syntax_tree_nodes::assignables::method_call::MethodCall;
 use crate::core::model::abstract_syntax_tree_nodes::identifier::Identifier;
 use crate::core::model::abstract_syntax_tree_nodes::l_value::LValue;
+use crate::core::model::types::integer::IntegerAST;
 use crate::core::model::types::ty::Type;
 use crate::core::parser::types::r#type::InferTypeError;
 use std::fmt::Debug;
+use std::str::FromStr;
 
 impl ToASM for MethodCall {
     fn to_asm(&self, stack: &mut Stack, meta: &mut MetaInfo, options: Option<ASMOptions>) -> Result<ASMResult, ASMGenerateError> {
@@ -61,6 +64,7 @@ impl ToASM for MethodCall {
 
         if !is_direct_method_call {
             target += &ASMBuilder::push_registers(&registers_push_ignore);
+            stack.pushed_bytes += ASMBuilder::push_registers_size(&registers_push_ignore);
         }
 
         #[derive(Debug)]
@@ -69,12 +73,71 @@ impl ToASM for MethodCall {
             Stack,
         }
 
+        // arguments, which don't fit into registers are pushed from right to left before any register is assigned
+        let stack_arguments = calling_convention.iter()
+            .zip(self.arguments.iter().rev())
+            .filter(|(conventions, _)| conventions.contains(&CallingRegister::Stack))
+            .map(|(_, argument)| argument)
+            .collect::<Vec<_>>();
+
+        let mut stack_cleanup = 0;
+        let shadow_space = if !stack_arguments.is_empty() && meta.target_os == TargetOS::Windows { 32 } else { 0 };
+
+        // the stack must be 16 byte aligned at the point of the call. The frame of the method is aligned,
+        // but saved registers and interim results of a surrounding expression may be pushed on top of it
+        if !(stack.pushed_bytes + stack_arguments.len() * 8 + shadow_space).is_multiple_of(16) {
+            target += &ASMBuilder::ident_line("sub rsp, 8");
+            stack_cleanup += 8;
+            stack.pushed_bytes += 8;
+        }
+
+        if !stack_arguments.is_empty() {
+            for argument in &stack_arguments {
+                let provided_type = argument.get_type(&meta.static_type_information).ok_or(Box::new(InferTypeError::NoTypePresent(
+                    LValue::Identifier(Identifier { name: self.identifier.identifier(), symbol: None },), self.file_position.clone()
+                )))?;
+                let scratch_register = GeneralPurposeRegister::Bit64(Bit64::Rax)
+                    .to_size_register(&ByteSize::try_from(provided_type.byte_size())?);
+
+                match Self::argument_to_asm(argument, stack, meta, Some(ASMOptions::InterimResultOption(InterimResultOption::from(&scratch_register))))? {
+                    ASMResult::Inline(source) => {
+                        target += &ASMBuilder::mov_x_ident_line(&scratch_register, source, Some(provided_type.byte_size()));
+                    }
+                    ASMResult::MultilineResulted(source, r) => {
+                        target += &source;
+                        target += &ASMBuilder::mov_x_ident_line(&scratch_register, &r, Some(provided_type.byte_size()));
+                    }
+                    ASMResult::Multiline(_) => return Err(ASMGenerateError::ASMResult(ASMResultError::UnexpectedVariance {
+                        expected: vec![ASMResultVariance::Inline, ASMResultVariance::MultilineResulted],
+                        actual: ASMResultVariance::Multiline,
+                        ast_node: "Method call".to_string(),
+                    }))
+                }
+
+                target += &ASMBuilder::ident_line("push rax");
+                stack_cleanup += 8;
+                stack.pushed_bytes += 8;
+            }
+
+            if shadow_space > 0 {
+                target += &ASMBuilder::ident_line(&format!("sub rsp, {shadow_space}"));
+                stack_cleanup += shadow_space;
+                stack.pushed_bytes += shadow_space;
+            }
+        }
+
         let zipped = calling_convention.iter().zip(self.arguments.iter().rev().collect::<Vec<_>>());
         let mut parameters = vec![];
+        // the evaluated arguments are kept on the stack, until every argument is evaluated
+        let mut pushed_arguments = 0;
 
         for (conventions, argument) in zipped {
+            if conventions.contains(&CallingRegister::Stack) {
+                continue;
+            }
+
             let provided_type = argument.get_type(&meta.static_type_information).ok_or(Box::new(InferTypeError::NoTypePresent(
-                LValue::Identifier(Identifier { name: self.identifier.identifier() },), self.file_position.clone()
+                LValue::Identifier(Identifier { name: self.identifier.identifier(), symbol: None },), self.file_position.clone()
             )))?;
             let result_from_eval = GeneralPurposeRegister::Bit64(Bit64::Rax)
                 .to_size_register(&ByteSize::try_from(provided_type.byte_size())?);
@@ -82,7 +145,7 @@ impl ToASM for MethodCall {
             let mut inline = false;
             let mut assign = String::new();
 
-            match argument.to_asm(stack, meta, Some(ASMOptions::InterimResultOption(InterimResultOption::from(&result_from_eval))))? {
+            match Self::argument_to_asm(argument, stack, meta, Some(ASMOptions::InterimResultOption(InterimResultOption::from(&result_from_eval))))? {
                 ASMResult::Inline(source) => {
                     inline = true;
                     assign = source;
@@ -90,30 +153,29 @@ impl ToASM for MethodCall {
                 ASMResult::MultilineResulted(source, r) => {
                     target += &source;
 
-                    if let Assignable::Float(_) = argument {
-                        inline = true;
-                        assign = r.to_string();
-                    } else {
-                        if r.is_float_register() {
-                            target += &ASMBuilder::mov_x_ident_line(r.to_64_bit_register(), &r, Some(r.size() as usize));
-                        }
+                    // the result register is reused by the next argument, so it has to be saved on the stack
+                    if r.is_float_register() {
+                        target += &ASMBuilder::mov_x_ident_line(r.to_64_bit_register(), &r, Some(r.size() as usize));
+                    }
 
-                        if let GeneralPurposeRegister::Memory(stack_position) = &r {
-                            let inline_stack_word_size = byte_size_from_word(stack_position.split(" ")
-                                .next()
-                                .ok_or(ASMGenerateError::InternalError(format!("Could not parse {stack_position} as a byte size"), self.file_position.clone()))?);
+                    if let GeneralPurposeRegister::Memory(stack_position) = &r {
+                        let inline_stack_word_size = byte_size_from_word(stack_position.split(" ")
+                            .next()
+                            .ok_or(ASMGenerateError::InternalError(format!("Could not parse {stack_position} as a byte size"), self.file_position.clone()))?);
 
-                            let destination_register = stack
-                                .register_to_use
-                                .last()
-                                .unwrap_or(&GeneralPurposeRegister::Bit64(Bit64::Rax)).to_size_register(&ByteSize::try_from(inline_stack_word_size)?);
+                        let destination_register = stack
+                            .register_to_use
+                            .last()
+                            .unwrap_or(&GeneralPurposeRegister::Bit64(Bit64::Rax)).to_size_register(&ByteSize::try_from(inline_stack_word_size)?);
 
-                            target += &ASMBuilder::mov_ident_line(&destination_register, &r);
-                            target += &ASMBuilder::ident_line(&format!("push {}", destination_register.to_64_bit_register()));
-                        } else {
-                            target += &ASMBuilder::ident_line(&format!("push {}", r.to_64_bit_register()));
-                        }
+                        target += &ASMBuilder::mov_ident_line(&destination_register, &r);
+                        target += &ASMBuilder::ident_line(&format!("push {}", destination_register.to_64_bit_register()));
+                    } else {
+                        target += &ASMBuilder::ident_line(&format!("push {}", r.to_64_bit_register()));
                     }
+
+                    pushed_arguments += 1;
+                    stack.pushed_bytes += 8;
                 }
                 ASMResult::Multiline(_) => return Err(ASMGenerateError::ASMResult(ASMResultError::UnexpectedVariance {
                     expected: vec![ASMResultVariance::Inline, ASMResultVariance::MultilineResulted],
@@ -123,12 +185,18 @@ impl ToASM for MethodCall {
             }
 
 
+            // integers smaller than 32 bit are extended, since the callee (especially variadic c functions) reads the whole register
+            let extension = match &provided_type {
+                Type::Integer(integer_type, _) if integer_type.byte_size() < 4 => Some(if integer_type.signed() { "movsx" } else { "movzx" }),
+                _ => None
+            };
+
             let mut variadic_parameters = vec![];
             for convention in conventions {
                 match convention {
                     CallingRegister::Register(register_convention) => {
                         let register_convention_sized = register_convention.to_size_register_ignore_float(&ByteSize::try_from(provided_type.byte_size())?);
-                        variadic_parameters.push((register_convention_sized, if inline { RegisterResult::Assign(assign.clone()) } else { RegisterResult::Stack }, Some(provided_type.byte_size())));
+                        variadic_parameters.push((register_convention_sized, if inline { RegisterResult::Assign(assign.clone()) } else { RegisterResult::Stack }, Some(provided_type.byte_size()), extension));
                     }
                     CallingRegister::Stack => {}
                 }
@@ -143,17 +211,49 @@ impl ToASM for MethodCall {
         // since multiple pops result in unexpected or even crashing behavior. just one pop is needed
         let mut popped_into = GeneralPurposeRegister::Bit64(Bit64::Rax);
         for all_conventions in parameters.iter().rev() {
-            for (index, (register_convention_sized, assign, size)) in all_conventions.iter().enumerate() {
+            for (index, (register_convention_sized, assign, size, extension)) in all_conventions.iter().enumerate() {
+                if let (Some(extension), false) = (extension, register_convention_sized.is_float_register()) {
+                    let extended_register = register_convention_sized.to_size_register(&ByteSize::_4);
+
+                    match assign {
+                        RegisterResult::Assign(assign) if IntegerAST::from_str(assign).is_ok() => {
+                            target += &ASMBuilder::mov_ident_line(&extended_register, assign);
+                        }
+                        RegisterResult::Assign(assign) => {
+                            target += &ASMBuilder::ident_line(&format!("{extension} {extended_register}, {assign}"));
+                        }
+                        RegisterResult::Stack => {
+                            let source = if index == 0 {
+                                target += &ASMBuilder::ident_line(&format!("pop {}", register_convention_sized.to_64_bit_register()));
+                                popped_into = register_convention_sized.to_64_bit_register();
+                                register_convention_sized.clone()
+                            } else {
+                                popped_into.to_size_register(&register_convention_sized.size())
+                            };
+
+                            target += &ASMBuilder::ident_line(&format!("{extension} {extended_register}, {source}"));
+                        }
+                    }
+
+                    continue;
+                }
+
                 if index == 0 {
                     match assign {
                         RegisterResult::Assign(assign) => {
                             target += &ASMBuilder::mov_x_ident_line(register_convention_sized, assign, *size)
                         }
                         RegisterResult::Stack => {
-                            target += &ASMBuilder::ident_line(&format!("pop {}", register_convention_sized.to_64_bit_register()));
+                            let pop_register = match (&meta.target_os, register_convention_sized) {
+                                // the general purpose counterpart of a xmm register might already hold an integer argument
+                                (TargetOS::Linux | TargetOS::WindowsSubsystemLinux, GeneralPurposeRegister::Float(_)) => GeneralPurposeRegister::Bit64(Bit64::Rax),
+                                _ => register_convention_sized.to_64_bit_register()
+                            };
+
+                            target += &ASMBuilder::ident_line(&format!("pop {}", pop_register));
 
                             if let GeneralPurposeRegister::Float(float_register) = register_convention_sized {
-                                target += &ASMBuilder::mov_x_ident_line(float_register, register_convention_sized.to_64_bit_register(), Some(register_convention_sized.size() as usize));
+                                target += &ASMBuilder::mov_x_ident_line(float_register, &pop_register, Some(register_convention_sized.size() as usize));
                                 popped_into = GeneralPurposeRegister::Float(float_register.clone());
                             } else {
                                 popped_into = register_convention_sized.to_64_bit_register();
@@ -173,19 +273,41 @@ impl ToASM for MethodCall {
             }
         }
 
+        stack.pushed_bytes -= pushed_arguments * 8;
+
+        if conventions::returns_in_memory(method_def, &meta.static_type_information) {
+            let offset = stack.reserve_temporary(method_def.return_type.byte_size_with_meta(meta)?);
+            target += &ASMBuilder::ident_line(&format!("lea {}, [rbp - {offset}]", conventions::return_pointer_register(&meta.target_os)));
+        }
+
         target += &ASMBuilder::ident(&ASMBuilder::comment_line(&self.to_string()));
-        target += &ASMBuilder::ident_line(&format!("call {}", if method_def.is_extern { method_def.identifier.identifier() } else { method_def.method_label_name() }));
+
+        if method_def.is_extern && matches!(meta.target_os, TargetOS::Linux | TargetOS::WindowsSubsystemLinux) {
+            // variadic functions expect the amount of used vector registers in al
+            target += &ASMBuilder::mov_ident_line("eax", conventions::vector_register_count(&calling_convention));
+        }
+
+        target += &ASMBuilder::ident_line(&format!("call {}", if method_def.is_extern { method_def.symbol_name() } else { method_def.method_label_name() }));
+
+        if stack_cleanup > 0 {
+            target += &ASMBuilder::ident_line(&format!("add rsp, {stack_cleanup}"));
+            stack.pushed_bytes -= stack_cleanup;
+        }
 
         if method_def.return_type != Type::Void {
+            let return_size = ByteSize::try_from(method_def.return_type.byte_size())?;
+            let return_register = conventions::return_calling_convention_from(&method_def.return_type, &meta.target_os);
+
             target += &ASMBuilder::mov_x_ident_line(
-                &register_to_move_result,
-                GeneralPurposeRegister::Bit64(Bit64::Rax).to_size_register(&ByteSize::try_from(method_def.return_type.byte_size())?),
+                if return_register.is_float_register() { register_to_move_result.to_size_register_ignore_float(&return_size) } else { register_to_move_result.clone() },
+                return_register.to_size_register_ignore_float(&return_size),
                 Some(method_def.return_type.byte_size()),
             );
         }
 
         if !is_direct_method_call {
             target += &ASMBuilder::pop_registers(&registers_push_ignore);
+            stack.pushed_bytes -= ASMBuilder::push_registers_size(&registers_push_ignore);
         }
 
 
@@ -223,4 +345,22 @@ impl ToASM for MethodCall {
         stack.label_count = count_before;
         has_before_label_asm
     }
+}
+
+impl MethodCall {
+    /// structs are passed by their address. The called method copies the struct
+    fn argument_to_asm(argument: &Assignable, stack: &mut Stack, meta: &mut MetaInfo, options: Option<ASMOptions>) -> Result<ASMResult, ASMGenerateError> {
+        let is_struct = argument.get_type(&meta.static_type_information)
+            .is_some_and(|ty| meta.static_type_information.struct_definition(&ty).is_some());
+
+        if !is_struct {
+            return argument.to_asm(stack, meta, options);
+        }
+
+        let (mut target, address) = argument.struct_address(stack, meta)?;
+        let register = GeneralPurposeRegister::Bit64(Bit64::Rax);
+        target += &ASMBuilder::ident_line(&format!("lea {register}, [{address}]"));
+
+        Ok(ASMResult::MultilineResulted(target, register))
+    }
 }
\ No newline at end of file
@@ -3,6 +3,8 @@ pub mod string;
 pub mod float;
 pub mod method_call;
 pub mod object;
+pub mod enum_path;
 pub mod equation_parser;
 pub mod boolean;
-pub mod array;
\ No newline at end of file
+pub mod array;
+pub mod field_access;
@@ -1,20 +1,45 @@
 use crate::core::code_generator::asm_options::ASMOptions;
-use crate::core::code_generator::asm_result::ASMResult;
+use crate::core::code_generator::asm_result::{ASMResult, ASMResultError, ASMResultVariance};
 use crate::core::code_generator::generator::Stack;
 use crate::core::code_generator::{ASMGenerateError, MetaInfo, ToASM};
 use crate::core::code_generator::asm_builder::ASMBuilder;
 use crate::core::code_generator::asm_options::interim_result::InterimResultOption;
 use crate::core::code_generator::register_destination::word_from_byte_size;
-use crate::core::code_generator::registers::GeneralPurposeRegister;
+use crate::core::code_generator::registers::{Bit64, ByteSize, GeneralPurposeRegister};
 use crate::core::model::abstract_syntax_tree_nodes::assignable::Assignable;
 use crate::core::model::abstract_syntax_tree_nodes::assignables::object::Object;
 
 impl ToASM for Object {
+    /// objects are written into temporary memory. The result is the address of the memory
     fn to_asm(&self, stack: &mut Stack, meta: &mut MetaInfo, _options: Option<ASMOptions>) -> Result<ASMResult, ASMGenerateError> {
+        let (mut target, address) = Assignable::Object(self.clone()).struct_address(stack, meta)?;
+        let register = Assignable::address_register(stack);
+
+        target += &ASMBuilder::ident_line(&format!("lea {register}, [{address}]"));
+
+        Ok(ASMResult::MultilineResulted(target, register))
+    }
+
+    fn data_section(&self, _stack: &mut Stack, _meta: &mut MetaInfo) -> bool {
+        self.fields.iter().any(|f| f.assignable.data_section(_stack, _meta))
+    }
+
+    fn is_stack_look_up(&self, _stack: &mut Stack, _meta: &MetaInfo) -> bool {
+        self.fields.iter().any(|f| f.assignable.is_stack_look_up(_stack, _meta))
+    }
+
+    fn byte_size(&self, meta: &MetaInfo) -> usize {
+        self.ty.byte_size_with_meta(meta).unwrap_or(0)
+    }
+}
+
+impl Object {
+    /// writes every field of the object into the memory at the provided address, e.g. `rbp - 16`
+    pub fn write_to(&self, address: &str, stack: &mut Stack, meta: &mut MetaInfo) -> Result<String, ASMGenerateError> {
         let mut target = String::new();
         target += &ASMBuilder::ident_comment_line(&format!("{}", self).replace("\n", "").replace("    ", " ").replace("}", " }"));
-        let struct_def = meta.static_type_information.custom_defined_types
-            .get(&self.ty)
+        let struct_def = meta.static_type_information
+            .struct_definition(&self.ty)
             .cloned()
             .ok_or(ASMGenerateError::InternalError(
             format!("Struct definition for type {} not found", self.ty),
@@ -22,71 +47,45 @@ impl ToASM for Object {
         ))?;
 
         for field in &self.fields {
-            let field_size = field.assignable.byte_size(meta);
-            let options: Option<ASMOptions> = if field_size >= 0 && field_size <= 8 {
-                Some(ASMOptions::InterimResultOption(InterimResultOption {
-                    general_purpose_register: GeneralPurposeRegister::iter_from_byte_size(field.assignable.byte_size(meta))?.current().clone(),
-                }))
-            } else {
-                None
-            };
+            let field_address = format!("{address} + {}.{}", struct_def.label(), field.l_value.identifier());
+            let field_type = struct_def.fields.iter()
+                .find(|f| f.name.name == field.l_value.identifier())
+                .map(|f| f.ty.clone())
+                .ok_or(ASMGenerateError::InternalError(format!("Struct {} has no field {}", self.ty, field.l_value), meta.file_position.clone()))?;
 
             target += &ASMBuilder::ident_comment_line(&format!("{}.{}", self.ty, field.l_value.identifier()));
 
-            let field_asm = field.assignable.to_asm(stack, meta, options)?;
-            let mut current_stack_position = stack.stack_position;
+            if meta.static_type_information.struct_definition(&field_type).is_some() {
+                if let Assignable::Object(object) = &field.assignable {
+                    target += &object.write_to(&field_address, stack, meta)?;
+                } else {
+                    let (source, source_address) = field.assignable.struct_address(stack, meta)?;
+                    target += &source;
+                    target += &ASMBuilder::copy_memory(&field_address, &source_address, field_type.byte_size_with_meta(meta)?);
+                }
 
-            match field_asm {
-                ASMResult::Inline(source) => {
-                    if field.assignable.is_stack_look_up(stack, meta) {
-                        let destination_register = GeneralPurposeRegister::iter_from_byte_size(field.assignable.byte_size(meta))?.current();
-                        let current_word = word_from_byte_size(field.assignable.byte_size(meta));
-                        let struct_offset = format!("{}.{}", struct_def.ty, field.l_value.identifier());
-                        let destination = format!("{current_word} [rsp - {current_stack_position} - {struct_offset}]");
-
-                        target += &ASMBuilder::mov_x_ident_line(&destination_register, source, Some(destination_register.size() as usize));
-                        target += &ASMBuilder::mov_ident_line(destination, &destination_register);
-                    } else {
-                        let current_word = word_from_byte_size(field.assignable.byte_size(meta));
-                        let struct_offset = format!("{}.{}", struct_def.ty, field.l_value.identifier());
-                        let destination = format!("{current_word} [rsp - {current_stack_position} - {struct_offset}]");
+                continue;
+            }
 
-                        target += &ASMBuilder::mov_ident_line(destination, source);
-                    }
-                },
-                ASMResult::MultilineResulted(t, r) => {
-                    let current_word = word_from_byte_size(field.assignable.byte_size(meta));
-                    let struct_offset = format!("{}.{}", struct_def.ty, field.l_value.identifier());
-                    let destination = format!("{current_word} [rsp - {current_stack_position} - {struct_offset}]");
+            let field_size = field_type.byte_size();
+            let register = GeneralPurposeRegister::Bit64(Bit64::Rax).to_size_register(&ByteSize::try_from(field_size)?);
 
-                    target += &t;
-                    target += &ASMBuilder::mov_ident_line(destination, r);
-                },
-                ASMResult::Multiline(t) => {
-                    target += &t;
-                },
+            match field.assignable.to_asm(stack, meta, Some(ASMOptions::InterimResultOption(InterimResultOption::from(&register))))? {
+                ASMResult::Inline(source) => target += &ASMBuilder::mov_x_ident_line(&register, source, Some(field_size)),
+                ASMResult::MultilineResulted(source, result) => {
+                    target += &source;
+                    target += &ASMBuilder::mov_x_ident_line(&register, result, Some(field_size));
+                }
+                ASMResult::Multiline(_) => return Err(ASMGenerateError::ASMResult(ASMResultError::UnexpectedVariance {
+                    expected: vec![ASMResultVariance::Inline, ASMResultVariance::MultilineResulted],
+                    actual: ASMResultVariance::Multiline,
+                    ast_node: "Object".to_string(),
+                }))
             }
 
-            stack.stack_position += field_size;
+            target += &ASMBuilder::mov_ident_line(format!("{} [{field_address}]", word_from_byte_size(field_size)), &register);
         }
-        
-        stack.stack_position -= struct_def.byte_size(meta);
-
-        Ok(ASMResult::Multiline(target))
-    }
 
-    fn data_section(&self, _stack: &mut Stack, _meta: &mut MetaInfo) -> bool {
-        self.fields.iter().any(|f| f.assignable.data_section(_stack, _meta))
-    }
-
-    fn is_stack_look_up(&self, _stack: &mut Stack, _meta: &MetaInfo) -> bool {
-        self.fields.iter().any(|f| f.assignable.is_stack_look_up(_stack, _meta))
-    }
-
-    fn byte_size(&self, meta: &MetaInfo) -> usize {
-        meta.static_type_information.custom_defined_types.get(&self.ty)
-            .cloned()
-            .map(|struct_def| struct_def.byte_size(meta))
-            .unwrap_or(0)
+        Ok(target)
     }
-}
\ No newline at end of file
+}
@@ -8,17 +8,21 @@ use crate::core::code_generator::{ASMGenerateError,
 use crate::core::model::types::static_string::StaticString;
 
 impl ToASM for StaticString {
-    fn to_asm(&self, stack: &mut Stack, _meta: &mut MetaInfo, options: Option<ASMOptions>) -> Result<ASMResult, ASMGenerateError> {
-        let label = if let Some(key) = stack.data_section.str_key(&self.value) {
+    fn to_asm(&self, stack: &mut Stack, meta: &mut MetaInfo, options: Option<ASMOptions>) -> Result<ASMResult, ASMGenerateError> {
+        let content = self.content().map_err(|err| ASMGenerateError::InternalError(err.to_string(), meta.file_position.clone()))?;
+
+        let label = if let Some(key) = stack.data_section.str_key(&content) {
             key.to_string()
         } else {
-            stack.create_label()
+            let label = stack.create_label();
+            stack.data_section.push_str(&label, &content);
+            label
         };
 
         let destination_register = if let Some(ASMOptions::InterimResultOption(concrete_type)) = options {
             concrete_type.general_purpose_register.clone()
         } else {
-            GeneralPurposeRegister::iter_from_byte_size(self.byte_size(_meta))?.current()
+            GeneralPurposeRegister::iter_from_byte_size(self.byte_size(meta))?.current()
         };
 
         let target = ASMBuilder::mov_ident_line(&destination_register, label);
@@ -36,25 +40,11 @@ impl ToASM for StaticString {
     }
 
     fn data_section(&self, stack: &mut Stack, _meta: &mut MetaInfo) -> bool {
-        let new_line_included = replace_add_quote(&self.value, "\\n", 10);
-        let tab_included = replace_add_quote(&new_line_included, "\\t", 9);
-
+        // the label got registered while generating the instructions, the content is encoded by the data section
+        if let Ok(content) = self.content() {
+            return stack.data_section.str_key(&content).is_some();
+        }
 
-        let key = if let Some(k) = stack.data_section.str_key(&tab_included) {
-            k.to_string()
-        } else {
-            stack.get_latest_label()
-        };
-
-        stack.data_section.push_str(&key, &tab_included);
-        true
+        false
     }
 }
-
-/// replaces the occurrence with the provided number and sets quotes
-/// ## Example
-/// replace_add_quote("\"Hallo \n Welt\"") returns
-/// \"Hallo\", 10, \"Welt\"
-fn replace_add_quote(value: &str, occurrence: &str, replace_value: usize) -> String {
-    format!("\"{}\"", value[1..value.len() - 1].replace(occurrence, &format!("\", {}, \"", replace_value)))
-}
\ No newline at end of file
@@ -0,0 +1,27 @@
+use crate::core::code_generator::asm_builder::ASMBuilder;
+use crate::core::code_generator::asm_options::ASMOptions;
+use crate::core::code_generator::asm_result::ASMResult;
+use crate::core::code_generator::generator::Stack;
+use crate::core::code_generator::{ASMGenerateError, MetaInfo, ToASM};
+use crate::core::model::abstract_syntax_tree_nodes::break_::Break;
+
+impl ToASM for Break {
+    fn to_asm(&self, stack: &mut Stack, _meta: &mut MetaInfo, _options: Option<ASMOptions>) -> Result<ASMResult, ASMGenerateError> {
+        let jump_target = stack.break_label(&self.label)
+            .ok_or_else(|| ASMGenerateError::InternalError(format!("`{}` is not inside of a matching loop", self), self.file_position.clone()))?;
+
+        let mut target = String::new();
+        target += &ASMBuilder::ident(&ASMBuilder::comment_line(&format!("{}", self)));
+        target += &ASMBuilder::ident_line(&format!("jmp {jump_target}"));
+
+        Ok(ASMResult::Multiline(target))
+    }
+
+    fn is_stack_look_up(&self, _stack: &mut Stack, _meta: &MetaInfo) -> bool {
+        false
+    }
+
+    fn byte_size(&self, _meta: &MetaInfo) -> usize {
+        0
+    }
+}
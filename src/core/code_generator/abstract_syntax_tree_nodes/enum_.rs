@@ -0,0 +1,19 @@
+use crate::core::code_generator::generator::Stack;
+use crate::core::code_generator::{MetaInfo, ToASM, ASMGenerateError};
+use crate::core::code_generator::asm_options::ASMOptions;
+use crate::core::code_generator::asm_result::ASMResult;
+use crate::core::model::abstract_syntax_tree_nodes::enum_::Enum;
+
+impl ToASM for Enum {
+    fn to_asm(&self, _: &mut Stack, _: &mut MetaInfo, _: Option<ASMOptions>) -> Result<ASMResult, ASMGenerateError> {
+        Ok(ASMResult::Inline(String::new()))
+    }
+
+    fn is_stack_look_up(&self, _stack: &mut Stack, _meta: &MetaInfo) -> bool {
+        false
+    }
+
+    fn byte_size(&self, _meta: &MetaInfo) -> usize {
+        self.ty.byte_size()
+    }
+}
@@ -2,7 +2,7 @@ use crate::core::code_generator::{ASMGenerateError, MetaInfo, ToASM};
 use crate::core::code_generator::asm_builder::ASMBuilder;
 use crate::core::code_generator::asm_options::ASMOptions;
 use crate::core::code_generator::asm_result::{ASMResult, ASMResultError, ASMResultVariance};
-use crate::core::code_generator::generator::Stack;
+use crate::core::code_generator::generator::{LoopLabels, Stack};
 use crate::core::model::abstract_syntax_tree_nodes::for_::{For};
 
 
@@ -13,6 +13,12 @@ impl ToASM for For {
         let mut target = String::new();
 
         target += &ASMBuilder::ident(&ASMBuilder::comment_line(&format!("for ({}; {}; {})", self.initialization, self.condition, self.update)));
+
+        // the variables of the header are only visible inside of the loop
+        stack.begin_scope();
+        meta.static_type_information.push_scope();
+        meta.static_type_information.declare(self.initialization.clone());
+
         let _ = self.initialization.to_asm(stack, meta, options.clone())?
             .apply_with(&mut target)
             .allow(ASMResultVariance::Inline)
@@ -26,7 +32,15 @@ impl ToASM for For {
 
         target += &ASMBuilder::line(&format!("{label2}:"));
 
-        target += &stack.generate_scope(&self.stack, meta, options.clone())?;
+        stack.loops.push(LoopLabels { label: self.label.clone(), continue_label: None, break_label: None });
+        let scope = stack.generate_scope(&self.stack, meta, options.clone());
+        let loop_labels = stack.loops.pop().unwrap_or_default();
+        target += &scope?;
+
+        // `continue` jumps to the update
+        if let Some(continue_label) = loop_labels.continue_label {
+            target += &ASMBuilder::line(&format!("{continue_label}:"));
+        }
 
         let _ = self.update.to_asm(stack, meta, options.clone())?
             .apply_with(&mut target)
@@ -46,6 +60,10 @@ impl ToASM for For {
         if let Some(general_purpose_register) = general_purpose_register {
             target += &ASMBuilder::ident_line(&format!("cmp {general_purpose_register}, 0"));
             target += &ASMBuilder::ident_line(&format!("jne {label2}"));
+
+            if let Some(break_label) = loop_labels.break_label {
+                target += &ASMBuilder::line(&format!("{break_label}:"));
+            }
         } else {
             return Err(ASMGenerateError::ASMResult(ASMResultError::UnexpectedVariance {
                 expected: vec![ASMResultVariance::MultilineResulted],
@@ -54,6 +72,8 @@ impl ToASM for For {
             }));
         }
 
+        let _ = meta.static_type_information.pop_scope();
+        stack.end_scope();
 
         Ok(ASMResult::Multiline(target))
     }
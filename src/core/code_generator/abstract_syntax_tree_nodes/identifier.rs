@@ -16,9 +16,7 @@ impl ToASM for Identifier {
             if let Type::Float(_, _) =
                 self.get_type(&meta.static_type_information)
                     .ok_or(Box::new(InferTypeError::NoTypePresent(
-                        LValue::Identifier(Identifier {
-                            name: self.name.clone(),
-                        }),
+                        LValue::Identifier(self.clone()),
                         meta.file_position.clone(),
                     )))?
             {
@@ -26,14 +24,8 @@ impl ToASM for Identifier {
             }
         }
 
-        if let Some(stack_location) = stack.variables.iter().rfind(|&variable| variable.name.identifier() == self.name.as_str()) {
-            if let Some(found_variable) = meta.static_type_information.context.iter().rfind(|v| {
-                if let LValue::Identifier(n) = &v.l_value {
-                    n.name == *self.name
-                } else {
-                    false
-                }
-            }) {
+        if let Some(stack_location) = stack.variables.resolve(self) {
+            if let Some(found_variable) = meta.static_type_information.resolve(self) {
                 if let Some(ty) = &found_variable.ty {
                     let operand_hint = word_from_byte_size(ty.byte_size());
                     let amount_elements = stack_location.elements;
@@ -120,13 +112,7 @@ impl ToASM for Identifier {
     }
 
     fn byte_size(&self, meta: &MetaInfo) -> usize {
-        if let Some(v) = meta.static_type_information.iter().rfind(|v| {
-            if let LValue::Identifier(n) = &v.l_value {
-                n.name == *self.name
-            } else {
-                false
-            }
-        }) {
+        if let Some(v) = meta.static_type_information.resolve(self) {
             if let Some(ty) = &v.ty {
                 return ty.byte_size();
             }
@@ -0,0 +1,19 @@
+use crate::core::code_generator::asm_options::ASMOptions;
+use crate::core::code_generator::asm_result::ASMResult;
+use crate::core::code_generator::generator::Stack;
+use crate::core::code_generator::{ASMGenerateError, MetaInfo, ToASM};
+use crate::core::model::abstract_syntax_tree_nodes::impl_::Impl;
+
+impl ToASM for Impl {
+    fn to_asm(&self, _stack: &mut Stack, _meta: &mut MetaInfo, _options: Option<ASMOptions>) -> Result<ASMResult, ASMGenerateError> {
+        Err(ASMGenerateError::InternalError(format!("The methods of `{}` must be lowered before generating code", self.ty), self.file_position.clone()))
+    }
+
+    fn is_stack_look_up(&self, _stack: &mut Stack, _meta: &MetaInfo) -> bool {
+        false
+    }
+
+    fn byte_size(&self, _meta: &MetaInfo) -> usize {
+        0
+    }
+}
@@ -0,0 +1,194 @@
+use crate::core::code_generator::asm_builder::ASMBuilder;
+use crate::core::code_generator::asm_options::ASMOptions;
+use crate::core::code_generator::asm_result::{ASMResult, ASMResultError, ASMResultVariance};
+use crate::core::code_generator::generator::Stack;
+use crate::core::code_generator::registers::{Bit64, ByteSize, GeneralPurposeRegister};
+use crate::core::code_generator::{ASMGenerateError, MetaInfo, ToASM};
+use crate::core::model::abstract_syntax_tree_nodes::match_::{Match, MatchPattern};
+use crate::core::model::types::ty::Type;
+
+/// A jump table is only worth it for at least this many matched values
+const JUMP_TABLE_MIN_VALUES: i128 = 4;
+/// The maximum amount of entries of a jump table
+const JUMP_TABLE_MAX_ENTRIES: i128 = 256;
+
+impl ToASM for Match {
+    fn to_asm(&self, stack: &mut Stack, meta: &mut MetaInfo, options: Option<ASMOptions>) -> Result<ASMResult, ASMGenerateError> {
+        let mut target = String::new();
+
+        target.push_str(&format!("    ; match ({})\n", self.value));
+
+        let value_type = self.value.get_type(&meta.static_type_information)
+            .ok_or(ASMGenerateError::InternalError("Cannot infer type".to_string(), meta.file_position.clone()))?;
+        let value_register = GeneralPurposeRegister::Bit64(Bit64::Rax).to_size_register(&ByteSize::try_from(value_type.byte_size())?);
+
+        match &self.value.to_asm(stack, meta, options.clone())? {
+            ASMResult::Inline(source) => target += &ASMBuilder::mov_ident_line(&value_register, source),
+            ASMResult::MultilineResulted(source, register) => {
+                target += source;
+                target += &ASMBuilder::mov_ident_line(&value_register, register);
+            }
+            ASMResult::Multiline(_) => return Err(ASMResultError::UnexpectedVariance {
+                expected: vec![ASMResultVariance::Inline, ASMResultVariance::MultilineResulted],
+                actual: ASMResultVariance::Multiline,
+                ast_node: "match node".to_string(),
+            }.into())
+        }
+
+        let continue_label = stack.create_label();
+        let arm_labels = self.arms.iter().map(|_| stack.create_label()).collect::<Vec<_>>();
+        let wildcard_arm = self.arms.iter().position(|arm| arm.patterns.contains(&MatchPattern::Wildcard));
+        let default_label = wildcard_arm.map_or(&continue_label, |index| &arm_labels[index]).clone();
+
+        let arm_intervals = self.arms.iter()
+            .map(|arm| arm.patterns.iter().filter_map(|pattern| pattern.interval(&meta.static_type_information)).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+
+        if let Some((min, max)) = self.jump_table_bounds(&value_type, &arm_intervals) {
+            let table_label = stack.create_label();
+            let index_register = GeneralPurposeRegister::Bit64(Bit64::Rax);
+            let table_register = GeneralPurposeRegister::Bit64(Bit64::Rdx);
+
+            target.push_str(&ASMBuilder::ident_comment_line("jump table"));
+            target.push_str(&extend_to_64_bit(&value_register, &value_type));
+            target.push_str(&ASMBuilder::ident_line(&format!("sub {index_register}, {min}")));
+            target.push_str(&ASMBuilder::ident_line(&format!("cmp {index_register}, {}", max - min)));
+            target.push_str(&ASMBuilder::ident_line(&format!("ja {default_label}")));
+            target.push_str(&ASMBuilder::mov_ident_line(&table_register, &table_label));
+            target.push_str(&ASMBuilder::ident_line(&format!("jmp QWORD [{table_register} + {index_register} * 8]")));
+            target.push_str(&format!("{table_label}:\n"));
+
+            for value in min..=max {
+                // the first arm, which matches the value, wins
+                let arm = self.arms.iter().enumerate().position(|(index, arm)| {
+                    arm.patterns.contains(&MatchPattern::Wildcard) || arm_intervals[index].iter().any(|(start, end)| (*start..=*end).contains(&value))
+                });
+
+                target.push_str(&ASMBuilder::ident_line(&format!("dq {}", arm.map_or(&continue_label, |index| &arm_labels[index]))));
+            }
+        } else {
+            let signed = match &value_type {
+                Type::Integer(integer_type, _) => integer_type.signed(),
+                _ => true,
+            };
+
+            'arms: for (index, arm) in self.arms.iter().enumerate() {
+                for pattern in &arm.patterns {
+                    match (pattern, pattern.interval(&meta.static_type_information)) {
+                        (MatchPattern::Wildcard, _) => break 'arms,
+                        (_, Some((start, end))) if start == end => {
+                            target.push_str(&compare(&value_register, start));
+                            target.push_str(&ASMBuilder::ident_line(&format!("je {}", arm_labels[index])));
+                        }
+                        (_, Some((start, end))) => {
+                            let next_pattern_label = stack.create_label();
+
+                            target.push_str(&compare(&value_register, start));
+                            target.push_str(&ASMBuilder::ident_line(&format!("{} {next_pattern_label}", if signed { "jl" } else { "jb" })));
+                            target.push_str(&compare(&value_register, end));
+                            target.push_str(&ASMBuilder::ident_line(&format!("{} {}", if signed { "jle" } else { "jbe" }, arm_labels[index])));
+                            target.push_str(&format!("{next_pattern_label}:\n"));
+                        }
+                        (_, None) => return Err(ASMGenerateError::InternalError(format!("Cannot generate the pattern `{pattern}`"), meta.file_position.clone())),
+                    }
+                }
+            }
+
+            target.push_str(&ASMBuilder::ident_line(&format!("jmp {default_label}")));
+        }
+
+        for (arm, arm_label) in self.arms.iter().zip(&arm_labels) {
+            target.push_str(&format!("{arm_label}:\n"));
+            target.push_str(&ASMBuilder::ident_comment_line(&format!("match arm {}", arm.patterns.iter().map(|pattern| pattern.to_string()).collect::<Vec<_>>().join(" | "))));
+            target.push_str(&stack.generate_scope(&arm.stack, meta, options.clone())?);
+            target.push_str(&format!("    jmp {}\n", continue_label));
+        }
+
+        target.push_str(&format!("{}:\n", continue_label));
+        target.push_str("    ; Continue after match \n");
+        Ok(ASMResult::Multiline(target))
+    }
+
+    fn is_stack_look_up(&self, _stack: &mut Stack, _meta: &MetaInfo) -> bool {
+        true
+    }
+
+    fn byte_size(&self, _meta: &MetaInfo) -> usize {
+        0
+    }
+
+    fn data_section(&self, stack: &mut Stack, meta: &mut MetaInfo) -> bool {
+        let mut has_before_label_asm = false;
+        let count_before = stack.label_count;
+
+        if self.value.data_section(stack, meta) {
+            has_before_label_asm = true;
+            stack.label_count -= 1;
+        }
+
+        for arm in &self.arms {
+            for node in &arm.stack {
+                if node.data_section(stack, meta) {
+                    has_before_label_asm = true;
+                    stack.label_count -= 1;
+                }
+            }
+        }
+
+        stack.label_count = count_before;
+        has_before_label_asm
+    }
+}
+
+impl Match {
+    /// Returns the smallest and the biggest matched value, if the matched values are dense enough for a jump table.
+    /// Booleans are always compared
+    fn jump_table_bounds(&self, value_type: &Type, arm_intervals: &[Vec<(i128, i128)>]) -> Option<(i128, i128)> {
+        if matches!(value_type, Type::Bool(_)) {
+            return None;
+        }
+
+        let intervals = arm_intervals.iter().flatten().filter(|(start, end)| start <= end);
+        let min = intervals.clone().map(|(start, _)| *start).min()?;
+        let max = intervals.clone().map(|(_, end)| *end).max()?;
+        let matched_values = intervals.map(|(start, end)| end - start + 1).sum::<i128>();
+        let entries = max - min + 1;
+
+        // the offset is subtracted as an immediate value
+        let fits_immediate = i32::try_from(min).is_ok() && i32::try_from(max - min).is_ok();
+
+        if fits_immediate && matched_values >= JUMP_TABLE_MIN_VALUES && entries <= JUMP_TABLE_MAX_ENTRIES && entries <= matched_values * 2 {
+            Some((min, max))
+        } else {
+            None
+        }
+    }
+}
+
+/// Compares the value with a constant. 64 bit registers can only be compared with 32 bit immediate values
+fn compare(value_register: &GeneralPurposeRegister, constant: i128) -> String {
+    if value_register.size() == 8 && i32::try_from(constant).is_err() {
+        let constant_register = GeneralPurposeRegister::Bit64(Bit64::Rdx);
+
+        return ASMBuilder::mov_ident_line(&constant_register, constant) + &ASMBuilder::ident_line(&format!("cmp {value_register}, {constant_register}"));
+    }
+
+    ASMBuilder::ident_line(&format!("cmp {value_register}, {constant}"))
+}
+
+/// Extends the value to `rax`, so it can be used as an index
+fn extend_to_64_bit(value_register: &GeneralPurposeRegister, value_type: &Type) -> String {
+    let signed = match value_type {
+        Type::Integer(integer_type, _) => integer_type.signed(),
+        _ => true,
+    };
+
+    match (value_register.size(), signed) {
+        (ByteSize::_8, _) => String::new(),
+        (ByteSize::_4, true) => ASMBuilder::ident_line(&format!("movsxd rax, {value_register}")),
+        // writing a 32 bit register clears the upper half
+        (ByteSize::_4, false) => ASMBuilder::ident_line(&format!("mov {value_register}, {value_register}")),
+        (_, true) => ASMBuilder::ident_line(&format!("movsx rax, {value_register}")),
+        (_, false) => ASMBuilder::ident_line(&format!("movzx rax, {value_register}")),
+    }
+}
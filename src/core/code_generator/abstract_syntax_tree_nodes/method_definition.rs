@@ -1,15 +1,16 @@
-use crate::core::code_generator::conventions::calling_convention_from;
+use crate::core::code_generator::conventions::{calling_convention_from, return_pointer_register, returns_in_memory, stack_argument_offset, RETURN_POINTER};
 
 use crate::core::code_generator::asm_builder::ASMBuilder;
 use crate::core::code_generator::asm_options::ASMOptions;
 use crate::core::code_generator::asm_result::{ASMResult, ASMResultVariance};
 use crate::core::code_generator::conventions::CallingRegister;
 use crate::core::code_generator::generator::Stack;
-use crate::core::code_generator::registers::ByteSize;
+use crate::core::code_generator::register_destination::word_from_byte_size;
+use crate::core::code_generator::registers::{Bit64, ByteSize, GeneralPurposeRegister};
 use crate::core::code_generator::{ASMGenerateError, MetaInfo, ToASM};
 use crate::core::model::abstract_syntax_tree_nodes::method_definition::{MethodDefinition};
 use crate::core::model::types::ty::Type;
-use crate::core::parser::static_type_context::{CurrentMethodInfo, StaticTypeContext};
+use crate::core::parser::static_type_context::CurrentMethodInfo;
 use crate::utils::math;
 
 impl MethodDefinition {
@@ -27,7 +28,24 @@ impl MethodDefinition {
 
         let return_type = self.return_type.to_string().replace('*', "ptr");
 
-        format!(".{}_{}~{}", self.identifier, parameters, return_type)
+        Self::label_name(&self.identifier.identifier(), &parameters, &return_type)
+    }
+
+    /// `::` of module paths isn't allowed inside of labels, neither are the brackets of instances of generic methods like `max<i32>` or of array types.
+    /// They are replaced by `$`, which can't be part of an identifier, so the labels of instances never collide with the labels of overloaded methods
+    pub fn label_name(identifier: &str, parameters: &str, return_type: &str) -> String {
+        format!(".{identifier}_{parameters}~{return_type}")
+            .replace("::", ".")
+            .replace(", ", "$")
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '~' | '$') { c } else { '$' })
+            .collect()
+    }
+
+    /// The name of the symbol an extern method is linked against. Modules only qualify the name inside of the program
+    pub fn symbol_name(&self) -> String {
+        let identifier = self.identifier.identifier();
+        identifier.rsplit("::").next().unwrap_or(&identifier).to_string()
     }
 }
 
@@ -44,10 +62,36 @@ impl ToASM for MethodDefinition {
         let mut stack_allocation = 32; // per default microsoft convention requires 32 byte as a shadow stack
         let mut method_scope: String = String::new();
 
-        let calling_convention = calling_convention_from(self, &meta.target_os);
+        let calling_convention = calling_convention_from(self, &meta.target_os, &meta.static_type_information);
+
+        if returns_in_memory(self, &meta.static_type_information) {
+            if let Some(stack_location) = stack.variables.lookup(RETURN_POINTER) {
+                stack_allocation += stack_location.size;
+                method_scope.push_str(&ASMBuilder::mov_ident_line(format!("QWORD [rbp - {}]", stack_location.position + stack_location.size), return_pointer_register(&meta.target_os)));
+            }
+        }
 
         for (index, argument) in self.arguments.iter().enumerate() {
-            if let Some(stack_location) = stack.variables.iter().rfind(|v| v.name.identifier() == argument.identifier.identifier()) {
+            if let Some(stack_location) = stack.variables.resolve_l_value(&argument.identifier) {
+                // structs are passed by their address and copied into the stack of the method
+                if meta.static_type_information.struct_definition(&argument.ty).is_some() {
+                    stack_allocation += stack_location.size;
+                    let destination = format!("rbp - {}", stack_location.position + stack_location.size);
+                    let size = stack_location.size;
+
+                    let source = match &calling_convention[index][0] {
+                        CallingRegister::Register(r) => r.to_string(),
+                        CallingRegister::Stack => {
+                            let nth_stack_argument = calling_convention[..index].iter().filter(|c| c.contains(&CallingRegister::Stack)).count();
+                            method_scope.push_str(&ASMBuilder::mov_ident_line(GeneralPurposeRegister::Bit64(Bit64::Rax), format!("QWORD [rbp + {}]", stack_argument_offset(nth_stack_argument, &meta.target_os))));
+                            GeneralPurposeRegister::Bit64(Bit64::Rax).to_string()
+                        }
+                    };
+
+                    method_scope.push_str(&ASMBuilder::copy_memory(&destination, &source, size));
+                    continue;
+                }
+
                 let destination = stack_location.name.clone().to_asm(stack, meta, options.clone())?;
                 let source = match &calling_convention[index][0] {
                     CallingRegister::Register(r) => {
@@ -57,7 +101,14 @@ impl ToASM for MethodDefinition {
                             r.to_size_register(&ByteSize::try_from(argument.ty.byte_size())?).to_string()
                         }
                     }
-                    CallingRegister::Stack => "popppp".to_string()
+                    CallingRegister::Stack => {
+                        let nth_stack_argument = calling_convention[..index].iter().filter(|c| c.contains(&CallingRegister::Stack)).count();
+                        let byte_size = argument.ty.byte_size();
+                        let scratch_register = GeneralPurposeRegister::Bit64(Bit64::Rax).to_size_register(&ByteSize::try_from(byte_size)?);
+
+                        method_scope.push_str(&ASMBuilder::mov_ident_line(&scratch_register, format!("{} [rbp + {}]", word_from_byte_size(byte_size), stack_argument_offset(nth_stack_argument, &meta.target_os))));
+                        scratch_register.to_string()
+                    }
                 };
 
                 method_scope.push_str(&ASMBuilder::mov_x_ident_line(destination, source, if let Type::Float(f, _) = &argument.ty {
@@ -80,15 +131,6 @@ impl ToASM for MethodDefinition {
             meta.file_position = node.file_position();
             stack_allocation += node.byte_size(meta);
 
-
-            let variables_len = meta.static_type_information.len();
-
-            if let Some(scope_stacks) = node.scope() {
-                for scope_stack in scope_stacks {
-                    meta.static_type_information.merge(StaticTypeContext::new(scope_stack));
-                }
-            }
-
             let _ = node.to_asm(stack, meta, None)?
                 .apply_with(&mut method_scope)
                 .allow(ASMResultVariance::Inline)
@@ -98,17 +140,13 @@ impl ToASM for MethodDefinition {
                 .finish()?;
 
             node.data_section(stack, meta);
-
-            let amount_pop = meta.static_type_information.len() - variables_len;
-
-            for _ in 0..amount_pop {
-                let _ = meta.static_type_information.pop();
-            }
         }
 
         meta.static_type_information.expected_return_type = None;
+        stack_allocation += stack.temporary_size;
 
-        let stack_allocation_asm = ASMBuilder::ident_line(&format!("sub rsp, {}", math::lowest_power_of_2_gt_n(stack_allocation)));
+        // the frame keeps the stack 16 byte aligned for calls
+        let stack_allocation_asm = ASMBuilder::ident_line(&format!("sub rsp, {}", math::lowest_power_of_2_gt_n(stack_allocation).max(16)));
         let leave_statement = if self.return_type == Type::Void { "    leave\n    ret\n".to_string() } else { String::new() };
 
         Ok(ASMResult::Multiline(format!("{}{}{}{}", label_header, stack_allocation_asm, method_scope, leave_statement)))
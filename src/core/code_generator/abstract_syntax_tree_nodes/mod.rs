@@ -4,6 +4,7 @@ pub mod assignable;
 pub mod assignables;
 pub mod method_definition;
 pub mod r#if;
+pub mod r#match;
 
 pub mod import;
 pub mod r#return;
@@ -12,3 +13,9 @@ pub mod r#for;
 pub mod r#while;
 pub mod l_value;
 mod struct_;
+mod enum_;
+mod syntax_error;
+mod r#break;
+mod r#continue;
+
+mod impl_;
\ No newline at end of file
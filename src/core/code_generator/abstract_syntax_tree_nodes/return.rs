@@ -2,9 +2,9 @@ use crate::core::code_generator::asm_builder::ASMBuilder;
 use crate::core::code_generator::asm_options::interim_result::InterimResultOption;
 use crate::core::code_generator::asm_options::ASMOptions;
 use crate::core::code_generator::asm_result::{ASMResult, ASMResultError, ASMResultVariance};
-use crate::core::code_generator::conventions::return_calling_convention;
+use crate::core::code_generator::conventions::{return_calling_convention, RETURN_POINTER};
 use crate::core::code_generator::generator::Stack;
-use crate::core::code_generator::registers::{ByteSize, GeneralPurposeRegister};
+use crate::core::code_generator::registers::{Bit64, ByteSize, GeneralPurposeRegister};
 use crate::core::code_generator::{ASMGenerateError, MetaInfo, ToASM};
 use crate::core::model::abstract_syntax_tree_nodes::ret::{Return};
 
@@ -14,22 +14,33 @@ impl ToASM for Return {
         let mut target = String::new();
         target += &ASMBuilder::ident(&ASMBuilder::comment_line(&format!("{}", self)));
 
-        if let Some(assignable) = &self.assignable {
-            let destination_register = return_calling_convention(stack, meta)?.to_size_register_ignore_float(
-                &ByteSize::try_from(meta.static_type_information.expected_return_type.as_ref().map_or(8, |t| t.return_type.byte_size()))?
-            );
+        if let (Some(assignable), Some(return_pointer)) = (&self.assignable, stack.variables.lookup(RETURN_POINTER)) {
+            // structs are copied into the memory provided by the caller
+            let return_pointer = format!("QWORD [rbp - {}]", return_pointer.position + return_pointer.size);
+            let return_size = meta.static_type_information.expected_return_type.as_ref().map_or(Ok(0), |t| t.return_type.byte_size_with_meta(meta))?;
+            let (source, source_address) = assignable.struct_address(stack, meta)?;
+
+            target += &source;
+            target += &ASMBuilder::ident_line(&format!("lea r10, [{source_address}]"));
+            target += &ASMBuilder::mov_ident_line(GeneralPurposeRegister::Bit64(Bit64::Rax), return_pointer);
+            target += &ASMBuilder::copy_memory("rax", "r10", return_size);
+        } else if let Some(assignable) = &self.assignable {
+            let return_size = meta.static_type_information.expected_return_type.as_ref().map_or(8, |t| t.return_type.byte_size());
+            let return_register = return_calling_convention(stack, meta)?;
+            // the value is always computed in a general purpose register and moved to the float register afterward, if needed
+            let destination_register = return_register.to_size_register(&ByteSize::try_from(return_size)?);
 
             let source = assignable.to_asm(stack, meta, Some(ASMOptions::InterimResultOption(InterimResultOption {
                 general_purpose_register: destination_register.clone(),
             })))?;
 
             match source {
-                ASMResult::Inline(source) => target += &ASMBuilder::mov_ident_line(destination_register, source),
+                ASMResult::Inline(source) => target += &ASMBuilder::mov_ident_line(&destination_register, source),
                 ASMResult::MultilineResulted(source, r) => {
                     target += &source;
 
                     if let GeneralPurposeRegister::Float(f) = r {
-                        target += &ASMBuilder::mov_x_ident_line(destination_register, f, Some(assignable.byte_size(meta)));
+                        target += &ASMBuilder::mov_x_ident_line(&destination_register, f, Some(assignable.byte_size(meta)));
                     }
                 }
                 ASMResult::Multiline(_) => return Err(ASMGenerateError::ASMResult(ASMResultError::UnexpectedVariance {
@@ -39,6 +50,10 @@ impl ToASM for Return {
                 }))
 
             }
+
+            if return_register.is_float_register() {
+                target += &ASMBuilder::mov_x_ident_line(&return_register, &destination_register, Some(return_size));
+            }
         }
 
         target += &ASMBuilder::ident_line("leave");
@@ -15,25 +15,22 @@ impl ToASM for Struct {
     }
 
     fn byte_size(&self, meta_info: &MetaInfo) -> usize {
-        self.fields.iter().fold(0, |acc, field| acc + field.ty.byte_size_with_meta(meta_info))
+        // unknown field types are reported by the variables holding the struct
+        self.byte_size_with_meta(meta_info).unwrap_or(0)
     }
 
     fn data_section(&self, _stack: &mut Stack, meta: &mut MetaInfo) -> bool {
         let mut target = String::new();
         target += &ASMBuilder::comment_line(&format!("{}", self).replace("\n", "").replace("    ", " ").replace("}", " }"));
 
-        target += &ASMBuilder::line(&format!("struc {}", self.ty));
+        target += &ASMBuilder::line(&format!("struc {}", self.label()));
 
         let longest_field_name_length = self.fields.iter().map(|f| f.name.name.chars().count()).max().unwrap_or(0);
 
         for field in &self.fields {
             let padding = " ".repeat(longest_field_name_length - field.name.name.chars().count());
 
-            let field_type_byte_size = if let Some(struct_def) = meta.static_type_information.custom_defined_types.get(&field.ty).cloned() {
-                struct_def.byte_size(meta)
-            } else {
-                field.ty.byte_size()
-            };
+            let field_type_byte_size = field.ty.byte_size_with_meta(meta).unwrap_or(0);
 
             target += &ASMBuilder::ident_line(&format!(".{}{}\tresb {}", field.name, padding, field_type_byte_size));
         }
@@ -43,4 +40,16 @@ impl ToASM for Struct {
         _stack.data_section.push_struct_definition(target);
         true
     }
+}
+
+impl Struct {
+    /// the sum of the sizes of all fields
+    pub fn byte_size_with_meta(&self, meta: &MetaInfo) -> Result<usize, ASMGenerateError> {
+        self.fields.iter().map(|field| field.ty.byte_size_with_meta(meta)).sum()
+    }
+
+    /// The name of the NASM structure. Labels can't contain `::`, so the namespaces are separated by dots
+    pub fn label(&self) -> String {
+        self.ty.to_string().replace("::", ".")
+    }
 }
\ No newline at end of file
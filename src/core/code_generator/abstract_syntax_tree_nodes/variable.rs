@@ -8,7 +8,11 @@ use crate::core::code_generator::register_destination::word_from_byte_size;
 use crate::core::code_generator::registers::{Bit64, ByteSize, GeneralPurposeRegister};
 use crate::core::code_generator::{register_destination, ASMGenerateError, MetaInfo, ToASM};
 use crate::core::model::abstract_syntax_tree_nodes::assignable::Assignable;
+use crate::core::model::abstract_syntax_tree_nodes::l_value::LValue;
+use crate::core::model::abstract_syntax_tree_nodes::assignables::equation_parser::operator::Operator;
 use crate::core::model::abstract_syntax_tree_nodes::variable::Variable;
+use crate::core::model::types::float::FloatType;
+use crate::core::model::types::integer::IntegerType;
 use crate::core::model::types::ty::Type;
 
 impl ToASM for Variable<'=', ';'> {
@@ -16,6 +20,25 @@ impl ToASM for Variable<'=', ';'> {
         let mut target = String::new();
         target += &ASMBuilder::ident(&ASMBuilder::comment_line(&format!("{}", self)));
 
+        if let Some(operator) = &self.compound_operator {
+            target += &self.compound_assignment_to_asm(operator, stack, meta, options)?;
+            return Ok(ASMResult::Multiline(target));
+        }
+
+        // a declaration without a value only reserves the memory of the variable
+        if self.assignable == Assignable::Uninitialized {
+            let byte_size = self.ty.as_ref().map_or(Ok(0), |ty| ty.byte_size_with_meta(meta))?;
+            stack.variables.declare(StackLocation { position: stack.stack_position, size: byte_size, name: self.l_value.clone(), elements: 1 });
+            stack.stack_position += byte_size;
+
+            return Ok(ASMResult::Multiline(target));
+        }
+
+        if let Some(struct_size) = self.struct_size(meta)? {
+            target += &self.struct_assignment_to_asm(struct_size, stack, meta)?;
+            return Ok(ASMResult::Multiline(target));
+        }
+
         let result = match &self.assignable {
             Assignable::Array(_) => {
                 let i = IdentifierPresent {
@@ -42,7 +65,7 @@ impl ToASM for Variable<'=', ';'> {
                 _ => 1
             };
 
-            stack.variables.push(StackLocation { position: stack.stack_position, size: byte_size, name: self.l_value.clone(), elements });
+            stack.variables.declare(StackLocation { position: stack.stack_position, size: byte_size, name: self.l_value.clone(), elements });
 
             stack.stack_position += byte_size;
 
@@ -81,6 +104,10 @@ impl ToASM for Variable<'=', ';'> {
                     let destination_register = GeneralPurposeRegister::iter_from_byte_size(self.assignable.byte_size(meta))?.current();
                     target += &ASMBuilder::mov_x_ident_line(&destination_register, source, Some(destination_register.size() as usize));
                     target += &ASMBuilder::mov_ident_line(destination, &destination_register);
+                } else if matches!(&self.assignable, Assignable::Integer(integer) if integer.value.parse::<i32>().is_err() && self.assignable.byte_size(meta) == 8) {
+                    // there is no instruction moving a 64 bit immediate into memory directly
+                    target += &ASMBuilder::mov_ident_line(GeneralPurposeRegister::Bit64(Bit64::Rax), source);
+                    target += &ASMBuilder::mov_ident_line(destination, GeneralPurposeRegister::Bit64(Bit64::Rax));
                 } else {
                     target += &ASMBuilder::mov_ident_line(destination, source);
                 }
@@ -125,10 +152,228 @@ impl ToASM for Variable<'=', ';'> {
             return obj.byte_size(meta);
         }
         
-        self.ty.as_ref().map_or(0, |ty| ty.byte_size())
+        // unknown types are reported by `to_asm`
+        self.ty.as_ref().map_or(0, |ty| ty.byte_size_with_meta(meta).unwrap_or(0))
     }
 
     fn data_section(&self, stack: &mut Stack, meta: &mut MetaInfo) -> bool {
         self.assignable.data_section(stack, meta)
     }
-}
\ No newline at end of file
+}
+
+impl Variable<'=', ';'> {
+    /// the size of the assigned struct, if a struct is assigned
+    fn struct_size(&self, meta: &MetaInfo) -> Result<Option<usize>, ASMGenerateError> {
+        let Some(ty) = self.ty.as_ref() else {
+            return Ok(None);
+        };
+        let byte_size = ty.byte_size_with_meta(meta)?;
+
+        Ok(meta.static_type_information.struct_definition(ty).map(|_| byte_size))
+    }
+
+    /// structs don't fit into registers, so they are copied from memory to memory
+    fn struct_assignment_to_asm(&self, struct_size: usize, stack: &mut Stack, meta: &mut MetaInfo) -> Result<String, ASMGenerateError> {
+        let mut target = String::new();
+
+        if self.define {
+            // the memory is reserved first, so temporary memory used by the assignable doesn't overlap with the variable
+            let position = stack.stack_position;
+            stack.stack_position += struct_size;
+            let address = format!("rbp - {}", stack.stack_position);
+
+            if let Assignable::Object(object) = &self.assignable {
+                target += &object.write_to(&address, stack, meta)?;
+            } else {
+                let (source, source_address) = self.assignable.struct_address(stack, meta)?;
+                target += &source;
+                target += &ASMBuilder::copy_memory(&address, &source_address, struct_size);
+            }
+
+            stack.variables.declare(StackLocation { position, size: struct_size, name: self.l_value.clone(), elements: 1 });
+            return Ok(target);
+        }
+
+        // the source address is kept in r10, since computing the destination address might need registers as well
+        let (source, source_address) = self.assignable.struct_address(stack, meta)?;
+        target += &source;
+        target += &ASMBuilder::ident_line(&format!("lea r10, [{source_address}]"));
+
+        let l_value = match &self.l_value {
+            LValue::Identifier(identifier) => Assignable::Identifier(identifier.clone()),
+            LValue::Expression(expression) => Assignable::Expression(expression.clone()),
+        };
+
+        stack.register_to_use.push(GeneralPurposeRegister::Bit64(Bit64::Rdx));
+        let (destination, destination_address) = l_value.struct_address(stack, meta)?;
+        stack.register_to_use.pop();
+
+        target += &destination;
+        target += &ASMBuilder::copy_memory(&destination_address, "r10", struct_size);
+
+        Ok(target)
+    }
+
+    /// generates `l_value <operator>= assignable` directly on the memory of the l_value.
+    /// the value is evaluated first and kept in rcx, so the address of the l_value is only computed once
+    fn compound_assignment_to_asm(&self, operator: &Operator, stack: &mut Stack, meta: &mut MetaInfo, options: Option<ASMOptions>) -> Result<String, ASMGenerateError> {
+        let mut target = String::new();
+
+        let ty = self.compound_expression()
+            .and_then(|expression| expression.lhs)
+            .and_then(|l_value| l_value.get_type(&meta.static_type_information))
+            .ok_or(ASMGenerateError::InternalError("Cannot infer type".to_string(), meta.file_position.clone()))?;
+        let size = ByteSize::try_from(ty.byte_size())?;
+
+        let rax = GeneralPurposeRegister::Bit64(Bit64::Rax).to_size_register(&size);
+        let rcx = GeneralPurposeRegister::Bit64(Bit64::Rcx).to_size_register(&size);
+
+        let interim_options = InterimResultOption {
+            general_purpose_register: rax.clone(),
+        };
+
+        match self.assignable.to_asm(stack, meta, Some(ASMOptions::InterimResultOption(interim_options)))? {
+            ASMResult::Inline(source) => target += &ASMBuilder::mov_ident_line(&rcx, source),
+            ASMResult::MultilineResulted(source, register) => {
+                target += &source;
+                target += &match register {
+                    GeneralPurposeRegister::Memory(memory) => ASMBuilder::mov_ident_line(&rcx, memory),
+                    register if register.is_float_register() => ASMBuilder::mov_x_ident_line(&rcx, register, Some(ty.byte_size())),
+                    register => ASMBuilder::mov_ident_line(&rcx, register.to_size_register(&size)),
+                };
+            }
+            ASMResult::Multiline(_) => {
+                return Err(ASMGenerateError::ASMResult(ASMResultError::UnexpectedVariance {
+                    expected: vec![ASMResultVariance::MultilineResulted, ASMResultVariance::Inline],
+                    actual: ASMResultVariance::Multiline,
+                    ast_node: "variable node".to_string(),
+                }))
+            }
+        }
+
+        stack.register_to_use.push(GeneralPurposeRegister::Bit64(Bit64::Rdx));
+        // rcx is pushed around the computation of the address, if it isn't inline
+        stack.pushed_bytes += 8;
+        let l_value = self.l_value.to_asm(stack, meta, options);
+        stack.pushed_bytes -= 8;
+
+        let destination = match l_value? {
+            ASMResult::Inline(destination) => destination,
+            ASMResult::MultilineResulted(source, register) => {
+                // computing the address of the l_value may use rcx as well
+                target += &ASMBuilder::ident_line("push rcx");
+                target += &source;
+                target += &ASMBuilder::ident_line("pop rcx");
+
+                match register {
+                    GeneralPurposeRegister::Memory(memory) => memory,
+                    register => format!("{} [{}]", word_from_byte_size(ty.byte_size()), register),
+                }
+            }
+            ASMResult::Multiline(_) => {
+                return Err(ASMGenerateError::ASMResult(ASMResultError::UnexpectedVariance {
+                    expected: vec![ASMResultVariance::MultilineResulted, ASMResultVariance::Inline],
+                    actual: ASMResultVariance::Multiline,
+                    ast_node: "variable node".to_string(),
+                }))
+            }
+        };
+        stack.register_to_use.pop();
+
+        match &ty {
+            Type::Integer(integer_type, _) => {
+                target += &Self::compound_integer_operation(operator, integer_type, &destination, &rax, &rcx, meta)?;
+            }
+            Type::Float(float_type, _) => {
+                let postfix = match float_type {
+                    FloatType::Float32 => "ss",
+                    FloatType::Float64 => "sd",
+                };
+                let instruction = match operator {
+                    Operator::Add => "add",
+                    Operator::Sub => "sub",
+                    Operator::Mul => "mul",
+                    Operator::Div => "div",
+                    _ => return Err(ASMGenerateError::InternalError(format!("`{}=` is not supported for floats", operator), meta.file_position.clone())),
+                };
+
+                target += &ASMBuilder::mov_x_ident_line("xmm1", &rcx, Some(ty.byte_size()));
+                target += &ASMBuilder::ident_line(&format!("mov{postfix} xmm0, {destination}"));
+                target += &ASMBuilder::ident_line(&format!("{instruction}{postfix} xmm0, xmm1"));
+                target += &ASMBuilder::ident_line(&format!("mov{postfix} {destination}, xmm0"));
+            }
+            _ => return Err(ASMGenerateError::InternalError(format!("`{}=` is not supported for `{}`", operator, ty), meta.file_position.clone())),
+        }
+
+        Ok(target)
+    }
+
+    fn compound_integer_operation(operator: &Operator, integer_type: &IntegerType, destination: &str, rax: &GeneralPurposeRegister, rcx: &GeneralPurposeRegister, meta: &MetaInfo) -> Result<String, ASMGenerateError> {
+        let mut target = String::new();
+        let signed = integer_type.signed();
+
+        // multiplications and divisions are not encodable for every size, so the smaller integers are computed with 32 bit
+        let (wide_rax, wide_rcx) = match integer_type.byte_size() {
+            1 | 2 => (GeneralPurposeRegister::Bit64(Bit64::Rax).to_size_register(&ByteSize::_4), GeneralPurposeRegister::Bit64(Bit64::Rcx).to_size_register(&ByteSize::_4)),
+            _ => (rax.clone(), rcx.clone()),
+        };
+        let widen = |target: &mut String, register: &GeneralPurposeRegister, source: &dyn std::fmt::Display| {
+            if register == rax || register == rcx {
+                *target += &ASMBuilder::mov_ident_line(register, source);
+            } else if signed {
+                *target += &ASMBuilder::ident_line(&format!("movsx {register}, {source}"));
+            } else {
+                *target += &ASMBuilder::ident_line(&format!("movzx {register}, {source}"));
+            }
+        };
+
+        match operator {
+            Operator::Add | Operator::Sub | Operator::BitwiseAnd | Operator::BitwiseOr | Operator::BitwiseXor => {
+                let instruction = match operator {
+                    Operator::Add => "add",
+                    Operator::Sub => "sub",
+                    Operator::BitwiseAnd => "and",
+                    Operator::BitwiseOr => "or",
+                    _ => "xor",
+                };
+                target += &ASMBuilder::ident_line(&format!("{instruction} {destination}, {rcx}"));
+            }
+            Operator::LeftShift => target += &ASMBuilder::ident_line(&format!("shl {destination}, cl")),
+            Operator::RightShift if signed => target += &ASMBuilder::ident_line(&format!("sar {destination}, cl")),
+            Operator::RightShift => target += &ASMBuilder::ident_line(&format!("shr {destination}, cl")),
+            Operator::Mul => {
+                widen(&mut target, &wide_rax, &destination);
+                widen(&mut target, &wide_rcx, rcx);
+                target += &ASMBuilder::ident_line(&format!("imul {wide_rax}, {wide_rcx}"));
+                target += &ASMBuilder::mov_ident_line(destination, rax);
+            }
+            Operator::Div | Operator::Mod => {
+                // rdx is part of the result of the division, but might be part of the address of the l_value as well
+                let r14 = GeneralPurposeRegister::Bit64(Bit64::R14);
+                let rdx = GeneralPurposeRegister::Bit64(Bit64::Rdx);
+                target += &ASMBuilder::mov_ident_line(&r14, &rdx);
+                widen(&mut target, &wide_rax, &destination);
+                widen(&mut target, &wide_rcx, rcx);
+
+                if signed {
+                    target += &ASMBuilder::ident_line(if integer_type.byte_size() == 8 { "cqo" } else { "cdq" });
+                    target += &ASMBuilder::ident_line(&format!("idiv {wide_rcx}"));
+                } else {
+                    let edx = rdx.to_size_register(&ByteSize::_4);
+                    target += &ASMBuilder::ident_line(&format!("xor {edx}, {edx}"));
+                    target += &ASMBuilder::ident_line(&format!("div {wide_rcx}"));
+                }
+
+                if *operator == Operator::Mod {
+                    target += &ASMBuilder::mov_ident_line(&wide_rax, rdx.to_size_register(&wide_rax.size()));
+                }
+
+                target += &ASMBuilder::mov_ident_line(&rdx, &r14);
+                target += &ASMBuilder::mov_ident_line(destination, rax);
+            }
+            _ => return Err(ASMGenerateError::InternalError(format!("`{}=` is not supported for integers", operator), meta.file_position.clone())),
+        }
+
+        Ok(target)
+    }
+}
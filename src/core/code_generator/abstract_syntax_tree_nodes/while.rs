@@ -1,8 +1,9 @@
 use crate::core::code_generator::asm_result::{ASMResult, ASMResultError, ASMResultVariance};
-use crate::core::code_generator::generator::Stack;
+use crate::core::code_generator::generator::{LoopLabels, Stack};
 use crate::core::code_generator::{ASMGenerateError, MetaInfo, ToASM};
 use crate::core::code_generator::asm_builder::ASMBuilder;
 use crate::core::code_generator::asm_options::ASMOptions;
+use crate::core::code_generator::registers::{Bit8, GeneralPurposeRegister, NibbleRegister};
 use crate::core::model::abstract_syntax_tree_nodes::while_::{While};
 
 impl ToASM for While {
@@ -15,24 +16,37 @@ impl ToASM for While {
 
         target += &ASMBuilder::ident_line(&format!("jmp {label1}"));
         target += &ASMBuilder::line(&format!("{label2}:"));
-        target += &stack.generate_scope(&self.stack, meta, options.clone())?;
+
+        // `continue` jumps to the condition
+        stack.loops.push(LoopLabels { label: self.label.clone(), continue_label: Some(label1.clone()), break_label: None });
+        let scope = stack.generate_scope(&self.stack, meta, options.clone());
+        let loop_labels = stack.loops.pop().unwrap_or_default();
+        target += &scope?;
 
         target += &ASMBuilder::line(&format!("{label1}:"));
-        let general_purpose_register = self.condition.to_asm(stack, meta, options.clone())?
-            .apply_with(&mut target)
-            .allow(ASMResultVariance::MultilineResulted)
-            .ast_node("while")
-            .finish()?;
-
-        if let Some(general_purpose_register) = general_purpose_register {
-            target += &ASMBuilder::ident_line(&format!("cmp {general_purpose_register}, 0"));
-            target += &ASMBuilder::ident_line(&format!("jne {label2}"));
-        } else {
-            return Err(ASMGenerateError::ASMResult(ASMResultError::UnexpectedVariance {
-                expected: vec![ASMResultVariance::MultilineResulted],
-                actual: ASMResultVariance::from(&self.condition.to_asm(stack, meta, options)?),
+        let condition = match self.condition.to_asm(stack, meta, options)? {
+            // constants like `true` and variables are compared through a register
+            ASMResult::Inline(value) => {
+                let register = GeneralPurposeRegister::Bit8(Bit8::Single(NibbleRegister::AL));
+                target += &ASMBuilder::mov_ident_line(&register, value);
+                register
+            }
+            ASMResult::MultilineResulted(condition_target, general_purpose_register) => {
+                target += &condition_target;
+                general_purpose_register
+            }
+            ASMResult::Multiline(_) => return Err(ASMGenerateError::ASMResult(ASMResultError::UnexpectedVariance {
+                expected: vec![ASMResultVariance::Inline, ASMResultVariance::MultilineResulted],
+                actual: ASMResultVariance::Multiline,
                 ast_node: "while".to_string(),
-            }));
+            })),
+        };
+
+        target += &ASMBuilder::ident_line(&format!("cmp {condition}, 0"));
+        target += &ASMBuilder::ident_line(&format!("jne {label2}"));
+
+        if let Some(break_label) = loop_labels.break_label {
+            target += &ASMBuilder::line(&format!("{break_label}:"));
         }
 
         Ok(ASMResult::Multiline(target))
@@ -1,6 +1,7 @@
 use std::fmt::{Display, Formatter};
 use std::str::FromStr;
 
+use crate::core::code_generator::register_destination::word_from_byte_size;
 use crate::core::code_generator::registers::{Bit64, GeneralPurposeRegister, GeneralPurposeRegisterIterator};
 
 /// A utility struct for construction asm related strings
@@ -37,18 +38,10 @@ impl ASMBuilder {
     }
 
     pub fn push_registers(ignore_registers: &[&GeneralPurposeRegister]) -> String {
-        let mut general_purpose_registers = GeneralPurposeRegisterIterator::new(GeneralPurposeRegister::Bit64(Bit64::Rax))
-            .collect::<Vec<_>>();
-
-        general_purpose_registers.insert(0, GeneralPurposeRegister::Bit64(Bit64::Rax));
-
         let mut target = String::new();
         target += &Self::ident(&Self::comment_line("PushQ"));
 
-        for register in general_purpose_registers {
-            if ignore_registers.iter().any(|a| &&register.to_size_register(&a.size()) == a) {
-                continue;
-            }
+        for register in Self::saved_registers(ignore_registers) {
             target += &Self::ident_line(&format!("push {}", register));
         }
 
@@ -56,25 +49,32 @@ impl ASMBuilder {
     }
 
     pub fn pop_registers(ignore_registers: &[&GeneralPurposeRegister]) -> String {
-        let mut general_purpose_registers = GeneralPurposeRegisterIterator::new(GeneralPurposeRegister::Bit64(Bit64::Rax))
-            .collect::<Vec<GeneralPurposeRegister>>();
-        general_purpose_registers.insert(0, GeneralPurposeRegister::Bit64(Bit64::Rax));
-        general_purpose_registers.reverse();
-
         let mut target = String::new();
         target += &Self::ident(&Self::comment_line("PopQ"));
 
-        for register in &general_purpose_registers {
-            if ignore_registers.iter().any(|a| &&register.to_size_register(&a.size()) == a) {
-                continue;
-            }
-
+        for register in Self::saved_registers(ignore_registers).iter().rev() {
             target += &Self::ident_line(&format!("pop {register}"));
         }
 
         target
     }
 
+    /// the amount of bytes [`ASMBuilder::push_registers`] pushes onto the stack
+    pub fn push_registers_size(ignore_registers: &[&GeneralPurposeRegister]) -> usize {
+        Self::saved_registers(ignore_registers).len() * 8
+    }
+
+    /// the general purpose registers saved by [`ASMBuilder::push_registers`] in the order they are pushed
+    fn saved_registers(ignore_registers: &[&GeneralPurposeRegister]) -> Vec<GeneralPurposeRegister> {
+        let mut general_purpose_registers = GeneralPurposeRegisterIterator::new(GeneralPurposeRegister::Bit64(Bit64::Rax))
+            .collect::<Vec<_>>();
+
+        general_purpose_registers.insert(0, GeneralPurposeRegister::Bit64(Bit64::Rax));
+        general_purpose_registers.retain(|register| !ignore_registers.iter().any(|a| &&register.to_size_register(&a.size()) == a));
+
+        general_purpose_registers
+    }
+
     pub fn mov_line<T: Display, P: Display>(destination: T, source: P) -> String {
         let source = source.to_string();
         let destination = destination.to_string();
@@ -137,6 +137,28 @@ impl ASMBuilder {
         Self::mov_x_ident_line::<T, P>(destination, source, None)
     }
 
+    /// copies the provided amount of bytes from the source address to the destination address, e.g. `rbp - 16`. `r11` is used as scratch register
+    pub fn copy_memory(destination: &str, source: &str, byte_size: usize) -> String {
+        let mut target = String::new();
+        let mut offset = 0;
+
+        while offset < byte_size {
+            let (chunk, register) = match byte_size - offset {
+                8.. => (8, "r11"),
+                4.. => (4, "r11d"),
+                2.. => (2, "r11w"),
+                _ => (1, "r11b"),
+            };
+            let word = word_from_byte_size(chunk);
+
+            target += &Self::mov_ident_line(register, format!("{word} [{source} + {offset}]"));
+            target += &Self::mov_ident_line(format!("{word} [{destination} + {offset}]"), register);
+            offset += chunk;
+        }
+
+        target
+    }
+
     pub fn comma_seperated(destination: &str, source: &str) -> String {
         format!("{}, {}", destination, source)
     }
@@ -45,6 +45,20 @@ impl PrepareRegisterOption {
             return Ok(ASMResult::MultilineResulted(target, float_register.clone()));
         }
 
+        if let Some(Assignable::FieldAccess(field_access)) = &self.assignable {
+            let size = field_access.byte_size(meta);
+            let general_purpose_register_sized = self.general_purpose_register.to_size_register(&ByteSize::try_from(size)?);
+            let float_register = &self.general_purpose_register.to_float_register();
+
+            let mut target = match field_access.to_asm(stack, meta, None)? {
+                ASMResult::Inline(t) | ASMResult::Multiline(t) => ASMBuilder::mov_ident_line(&general_purpose_register_sized, t),
+                ASMResult::MultilineResulted(t, register) => t + &ASMBuilder::mov_ident_line(&general_purpose_register_sized, register),
+            };
+
+            target += &ASMBuilder::mov_x_ident_line(float_register, &general_purpose_register_sized, Some(size));
+            return Ok(ASMResult::MultilineResulted(target, float_register.clone()));
+        }
+
         Err(ASMGenerateError::ASMResult(ASMResultError::NoOptionProvided("Wrong assignable in Float calculation".to_string())))
     }
 }
\ No newline at end of file
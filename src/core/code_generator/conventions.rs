@@ -31,27 +31,75 @@ impl Display for CallingRegister {
 pub fn calling_convention(stack: &mut Stack, meta: &mut MetaInfo, calling_arguments: &[Assignable], method_name: &str) -> Result<Vec<Vec<CallingRegister>>, Box<InferTypeError>> {
     match meta.target_os {
         TargetOS::Windows => windows_calling_convention(stack, meta, calling_arguments, method_name),
-        TargetOS::Linux | TargetOS::WindowsSubsystemLinux => {
-            unimplemented!("Linux calling convention not implemented yet");
-        }
+        TargetOS::Linux | TargetOS::WindowsSubsystemLinux => system_v_calling_convention(stack, meta, calling_arguments, method_name),
     }
 }
 
-pub fn calling_convention_from(method_definition: &MethodDefinition, target_os: &TargetOS) -> Vec<Vec<CallingRegister>> {
+pub fn calling_convention_from(method_definition: &MethodDefinition, target_os: &TargetOS, type_context: &StaticTypeContext) -> Vec<Vec<CallingRegister>> {
+    let return_pointer = returns_in_memory(method_definition, type_context);
+
     match target_os {
-        TargetOS::Windows => windows_calling_convention_from(method_definition),
-        TargetOS::Linux | TargetOS::WindowsSubsystemLinux => {
-            unimplemented!("Linux calling convention not implemented yet");
-        }
+        TargetOS::Windows => windows_calling_convention_from(method_definition, return_pointer),
+        TargetOS::Linux | TargetOS::WindowsSubsystemLinux => system_v_calling_convention_from(method_definition, return_pointer),
+    }
+}
+
+/// The name of the stack location inside a method, which holds the address a returned struct is written to.
+/// `return` is a keyword, so it never collides with a variable
+pub const RETURN_POINTER: &str = "return";
+
+/// Structs are returned in memory provided by the caller. The address of the memory is passed as a hidden first argument
+/// and returned in `rax`. Struct arguments are passed as the address of the struct, the callee copies the struct into its own stack.
+/// Small structs are never passed in registers and are never classified into INTEGER and SSE eightbytes like System V requires,
+/// so extern methods taking or returning structs by value are rejected by the static type check on every target
+pub fn returns_in_memory(method_definition: &MethodDefinition, type_context: &StaticTypeContext) -> bool {
+    !method_definition.is_extern && type_context.struct_definition(&method_definition.return_type).is_some()
+}
+
+/// Returns the register holding the address of the memory, where a returned struct is written to
+pub fn return_pointer_register(target_os: &TargetOS) -> GeneralPurposeRegister {
+    match target_os {
+        TargetOS::Windows => GeneralPurposeRegister::Bit64(Bit64::Rcx),
+        TargetOS::Linux | TargetOS::WindowsSubsystemLinux => GeneralPurposeRegister::Bit64(Bit64::Rdi),
     }
 }
 
 pub fn return_calling_convention(_stack: &mut Stack, meta: &MetaInfo) -> Result<GeneralPurposeRegister, Box<InferTypeError>> {
-    match meta.target_os {
-        TargetOS::Windows => Ok(GeneralPurposeRegister::Bit64(Bit64::Rax)),
-        TargetOS::Linux | TargetOS::WindowsSubsystemLinux => {
-            unimplemented!("Linux returning convention not implemented yet");
-        }
+    let return_type = meta.static_type_information.expected_return_type
+        .as_ref()
+        .map_or(Type::Void, |t| t.return_type.clone());
+
+    Ok(return_calling_convention_from(&return_type, &meta.target_os))
+}
+
+/// Returns the register, where a value of the provided type is returned from a method call
+/// The value is always computed in `rax`. System V additionally expects floats in `xmm0`
+pub fn return_calling_convention_from(return_type: &Type, target_os: &TargetOS) -> GeneralPurposeRegister {
+    match (target_os, return_type) {
+        (TargetOS::Linux | TargetOS::WindowsSubsystemLinux, Type::Float(_, _)) => GeneralPurposeRegister::Float(FloatRegister::Xmm0),
+        _ => GeneralPurposeRegister::Bit64(Bit64::Rax),
+    }
+}
+
+/// Returns the amount of vector registers used by the provided calling convention.
+/// System V expects this amount in `al` when calling variadic functions like `printf`
+pub fn vector_register_count(calling_convention: &[Vec<CallingRegister>]) -> usize {
+    calling_convention
+        .iter()
+        .flatten()
+        .filter(|c| matches!(c, CallingRegister::Register(r) if r.is_float_register()))
+        .count()
+}
+
+/// Returns the byte offset relative to `rbp` inside the callee, where the nth stack argument is located
+pub fn stack_argument_offset(nth_stack_argument: usize, target_os: &TargetOS) -> usize {
+    // return address and the pushed rbp
+    let offset = 16 + nth_stack_argument * 8;
+
+    match target_os {
+        // the caller reserves 32 byte shadow space between the return address and the stack arguments
+        TargetOS::Windows => offset + 32,
+        TargetOS::Linux | TargetOS::WindowsSubsystemLinux => offset,
     }
 }
 
@@ -84,7 +132,7 @@ fn windows_calling_convention(_stack: &mut Stack, meta: &mut MetaInfo, calling_a
                 .iter().filter(|m| m.identifier.identifier() == method_name)
                 .map(|m| m.arguments.iter().map(|a| a.ty.clone()).collect::<Vec<_>>())
                 .collect::<Vec<_>>(),
-            method_name: LValue::Identifier(Identifier { name: method_name.to_string() }),
+            method_name: LValue::Identifier(Identifier { name: method_name.to_string(), symbol: None }),
             file_position: meta.file_position.clone(),
             provided: calling_arguments.iter().filter_map(|a| a.get_type(&meta.static_type_information)).collect::<Vec<_>>(),
         }))
@@ -96,9 +144,13 @@ fn windows_calling_convention(_stack: &mut Stack, meta: &mut MetaInfo, calling_a
         return Err(Box::new(InferTypeError::UnresolvedReference(method_name.to_string(), meta.file_position.clone())));
     };
 
+    // the hidden return pointer occupies the first register
+    let first_index = usize::from(returns_in_memory(&method_defs[0], &meta.static_type_information));
+
     for (index, calling_argument) in calling_arguments.iter().enumerate() {
+        let index = index + first_index;
         let calling_ty: Type = calling_argument.get_type(&meta.static_type_information).ok_or(InferTypeError::NoTypePresent(
-            LValue::Identifier(Identifier { name: "Argument".to_string() }), meta.file_position.clone()
+            LValue::Identifier(Identifier { name: "Argument".to_string(), symbol: None }), meta.file_position.clone()
         ))?;
 
         match calling_ty {
@@ -131,6 +183,93 @@ fn windows_calling_convention(_stack: &mut Stack, meta: &mut MetaInfo, calling_a
     Ok(result)
 }
 
+fn system_v_calling_convention(_stack: &mut Stack, meta: &mut MetaInfo, calling_arguments: &[Assignable], method_name: &str) -> Result<Vec<Vec<CallingRegister>>, Box<InferTypeError>> {
+    let method_defs = method_definitions(&meta.static_type_information, calling_arguments, method_name)?;
+
+    if method_defs.is_empty() {
+        return Err(Box::new(InferTypeError::UnresolvedReference(method_name.to_string(), meta.file_position.clone())))
+    }
+
+    if method_defs.len() > 1 {
+        return Err(Box::new(InferTypeError::MethodCallSignatureMismatch {
+            signatures: meta.static_type_information.methods
+                .iter().filter(|m| m.identifier.identifier() == method_name)
+                .map(|m| m.arguments.iter().map(|a| a.ty.clone()).collect::<Vec<_>>())
+                .collect::<Vec<_>>(),
+            method_name: LValue::Identifier(Identifier { name: method_name.to_string(), symbol: None }),
+            file_position: meta.file_position.clone(),
+            provided: calling_arguments.iter().filter_map(|a| a.get_type(&meta.static_type_information)).collect::<Vec<_>>(),
+        }))
+    }
+
+    let return_pointer = returns_in_memory(&method_defs[0], &meta.static_type_information);
+    let mut argument_types = vec![];
+
+    for calling_argument in calling_arguments {
+        argument_types.push(calling_argument.get_type(&meta.static_type_information).ok_or(InferTypeError::NoTypePresent(
+            LValue::Identifier(Identifier { name: "Argument".to_string(), symbol: None }), meta.file_position.clone()
+        ))?);
+    }
+
+    Ok(system_v_calling_convention_from_types(&argument_types, return_pointer))
+}
+
+fn system_v_calling_convention_from(method_definition: &MethodDefinition, return_pointer: bool) -> Vec<Vec<CallingRegister>> {
+    system_v_calling_convention_from_types(&method_definition.arguments.iter().map(|a| a.ty.clone()).collect::<Vec<_>>(), return_pointer)
+}
+
+/// System V AMD64 assigns integer and float arguments independently from each other.
+/// Variadic functions expect floats in the xmm registers only, so extern methods don't need special treatment
+fn system_v_calling_convention_from_types(argument_types: &[Type], return_pointer: bool) -> Vec<Vec<CallingRegister>> {
+    static FLOAT_ORDER: [CallingRegister; 8] = [
+        CallingRegister::Register(GeneralPurposeRegister::Float(FloatRegister::Xmm0)),
+        CallingRegister::Register(GeneralPurposeRegister::Float(FloatRegister::Xmm1)),
+        CallingRegister::Register(GeneralPurposeRegister::Float(FloatRegister::Xmm2)),
+        CallingRegister::Register(GeneralPurposeRegister::Float(FloatRegister::Xmm3)),
+        CallingRegister::Register(GeneralPurposeRegister::Float(FloatRegister::Xmm4)),
+        CallingRegister::Register(GeneralPurposeRegister::Float(FloatRegister::Xmm5)),
+        CallingRegister::Register(GeneralPurposeRegister::Float(FloatRegister::Xmm6)),
+        CallingRegister::Register(GeneralPurposeRegister::Float(FloatRegister::Xmm7)),
+    ];
+    static POINTER_ORDER: [CallingRegister; 6] = [
+        CallingRegister::Register(GeneralPurposeRegister::Bit64(Bit64::Rdi)),
+        CallingRegister::Register(GeneralPurposeRegister::Bit64(Bit64::Rsi)),
+        CallingRegister::Register(GeneralPurposeRegister::Bit64(Bit64::Rdx)),
+        CallingRegister::Register(GeneralPurposeRegister::Bit64(Bit64::Rcx)),
+        CallingRegister::Register(GeneralPurposeRegister::Bit64(Bit64::R8)),
+        CallingRegister::Register(GeneralPurposeRegister::Bit64(Bit64::R9))
+    ];
+
+    let mut result = vec![];
+    // the hidden return pointer occupies the first integer register
+    let mut pointer_index = usize::from(return_pointer);
+    let mut float_index = 0;
+
+    for ty in argument_types {
+        match ty {
+            Type::Integer(_, _) | Type::Bool(_) | Type::Custom(_, _) | Type::Array(_, _, _) => {
+                if let Some(register) = POINTER_ORDER.get(pointer_index) {
+                    result.push(vec![register.clone()]);
+                    pointer_index += 1;
+                } else {
+                    result.push(vec![CallingRegister::Stack]);
+                }
+            }
+            Type::Float(_, _) => {
+                if let Some(register) = FLOAT_ORDER.get(float_index) {
+                    result.push(vec![register.clone()]);
+                    float_index += 1;
+                } else {
+                    result.push(vec![CallingRegister::Stack]);
+                }
+            }
+            Type::Void | Type::Statement => {}
+        }
+    }
+
+    result
+}
+
 /// Returns every possible method definition based on the argument signature and method name
 pub fn method_definitions(type_context: &StaticTypeContext, arguments: &[Assignable], method_name: &str) -> Result<Vec<MethodDefinition>, Box<InferTypeError>> {
     let mut method_definitions = vec![];
@@ -159,7 +298,7 @@ pub fn method_definitions(type_context: &StaticTypeContext, arguments: &[Assigna
 }
 
 
-fn windows_calling_convention_from(method_definition: &MethodDefinition) -> Vec<Vec<CallingRegister>> {
+fn windows_calling_convention_from(method_definition: &MethodDefinition, return_pointer: bool) -> Vec<Vec<CallingRegister>> {
     static FLOAT_ORDER: [CallingRegister; 4] = [
         CallingRegister::Register(GeneralPurposeRegister::Float(FloatRegister::Xmm0)),
         CallingRegister::Register(GeneralPurposeRegister::Float(FloatRegister::Xmm1)),
@@ -176,6 +315,8 @@ fn windows_calling_convention_from(method_definition: &MethodDefinition) -> Vec<
     let mut result = vec![];
 
     for (index, argument) in method_definition.arguments.iter().enumerate() {
+        let index = index + usize::from(return_pointer);
+
         match argument.ty {
             Type::Integer(_, _) | Type::Bool(_) | Type::Custom(_, _) | Type::Array(_, _, _) => {
                 if index < 4 {
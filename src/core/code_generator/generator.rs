@@ -1,6 +1,6 @@
 use crate::core::code_generator::asm_builder::ASMBuilder;
 use crate::core::code_generator::asm_options::ASMOptions;
-use crate::core::code_generator::conventions::calling_convention_from;
+use crate::core::code_generator::conventions::{calling_convention_from, returns_in_memory, RETURN_POINTER};
 use crate::core::code_generator::registers::GeneralPurposeRegister;
 use crate::core::code_generator::target_os::TargetOS;
 use crate::core::code_generator::ASMGenerateError;
@@ -15,6 +15,7 @@ use crate::core::model::abstract_syntax_tree_nodes::parameter::Parameter;
 use crate::core::model::abstract_syntax_tree_nodes::ret::Return;
 use crate::core::model::abstract_syntax_tree_nodes::variable::Variable;
 use crate::core::model::data_section::DataSection;
+use crate::core::model::symbol_table::{Symbol, SymbolId, SymbolTable};
 use crate::core::model::types::integer::IntegerType;
 use crate::core::model::types::mutability::Mutability;
 use crate::core::model::types::ty::Type;
@@ -28,6 +29,16 @@ pub struct StackLocation {
     pub name: LValue,
 }
 
+impl Symbol for StackLocation {
+    fn symbol_name(&self) -> String {
+        self.name.identifier()
+    }
+
+    fn symbol_id(&self) -> Option<SymbolId> {
+        self.name.symbol()
+    }
+}
+
 impl StackLocation {
     pub fn new_anonymous_stack_location(position: usize, size: usize) -> StackLocation {
         Self {
@@ -40,6 +51,17 @@ impl StackLocation {
 }
 
 
+/// The jump targets of a loop. The targets are created on first use, so loops without `break` or `continue` don't get additional labels
+#[derive(Default, Debug, Clone)]
+pub struct LoopLabels {
+    /// the label of the loop in the source code, e.g. `outer` for `'outer: while`
+    pub label: Option<String>,
+    /// the jump target of `continue`
+    pub continue_label: Option<String>,
+    /// the jump target of `break`
+    pub break_label: Option<String>,
+}
+
 #[derive(Default, Debug)]
 /// a struct representing the current stack pointer and variables in the stack
 pub struct Stack {
@@ -47,8 +69,8 @@ pub struct Stack {
     pub stack_position: usize,
     /// represents a list of all defined scopes and the position on the stack where it starts
     scopes: Vec<usize>,
-    /// represents a list of all available variables in the current scopes and above
-    pub variables: Vec<StackLocation>,
+    /// represents all available variables in the current scopes and above
+    pub variables: SymbolTable<StackLocation>,
     /// represents the current state, if an indexing is required
     pub indexing: Option<ASMResult>,
     /// represents the data section in the assembly language
@@ -56,12 +78,30 @@ pub struct Stack {
     /// to create labels and avoid collisions in naming, a label count is used
     pub label_count: usize,
     pub register_to_use: Vec<GeneralPurposeRegister>,
+    /// represents all loops surrounding the currently generated node. The innermost loop is the last one
+    pub loops: Vec<LoopLabels>,
+    /// the amount of bytes reserved for intermediate values of the current method, like structs returned from a method call
+    pub temporary_size: usize,
+    /// the amount of bytes pushed on top of the frame of the current method at the current point, like saved registers or arguments.
+    /// A call needs it to align the stack to 16 bytes
+    pub pushed_bytes: usize,
 }
 
 impl Stack {
     pub fn clear_stack(&mut self) {
         self.stack_position = 0;
+        self.temporary_size = 0;
+        self.pushed_bytes = 0;
         self.variables.clear();
+        self.register_to_use.clear();
+    }
+
+    /// reserves stack memory for an intermediate value. The memory is valid until the method returns
+    /// returns the offset of the memory relative to `rbp`
+    pub fn reserve_temporary(&mut self, size: usize) -> usize {
+        self.stack_position += size;
+        self.temporary_size += size;
+        self.stack_position
     }
 }
 
@@ -94,11 +134,42 @@ impl Stack {
         format!(".label{}", self.label_count - 1)
     }
 
+    /// Returns the jump target of `break` for the loop with the provided label or the innermost loop
+    pub fn break_label(&mut self, label: &Option<String>) -> Option<String> {
+        let index = self.find_loop(label)?;
+
+        if self.loops[index].break_label.is_none() {
+            self.loops[index].break_label = Some(self.create_label());
+        }
+
+        self.loops[index].break_label.clone()
+    }
+
+    /// Returns the jump target of `continue` for the loop with the provided label or the innermost loop
+    pub fn continue_label(&mut self, label: &Option<String>) -> Option<String> {
+        let index = self.find_loop(label)?;
+
+        if self.loops[index].continue_label.is_none() {
+            self.loops[index].continue_label = Some(self.create_label());
+        }
+
+        self.loops[index].continue_label.clone()
+    }
+
+    fn find_loop(&self, label: &Option<String>) -> Option<usize> {
+        match label {
+            Some(label) => self.loops.iter().rposition(|loop_labels| loop_labels.label.as_ref() == Some(label)),
+            None => self.loops.len().checked_sub(1),
+        }
+    }
+
 
     pub fn generate_scope(&mut self, nodes: &Vec<AbstractSyntaxTreeNode>, meta: &mut MetaInfo, options: Option<ASMOptions>) -> Result<String, ASMGenerateError> {
         let mut target = String::new();
 
         self.begin_scope();
+        meta.static_type_information.push_scope();
+        meta.static_type_information.merge(StaticTypeContext::new(nodes));
 
         for node in nodes {
             meta.file_position = node.file_position().clone();
@@ -112,24 +183,21 @@ impl Stack {
             node.data_section(self, meta);
         }
 
+        let _ = meta.static_type_information.pop_scope();
         self.end_scope();
         Ok(target)
     }
 
-    fn begin_scope(&mut self) {
-        self.scopes.push(self.variables.len());
+    pub fn begin_scope(&mut self) {
+        self.scopes.push(self.stack_position);
+        self.variables.push_scope();
     }
 
-    fn end_scope(&mut self) {
-        if let Some(last_element) = self.scopes.last() {
-            let pop_count = self.variables.len() - *last_element;
-            self.stack_position -= pop_count;
-
-            for _ in 0..pop_count {
-                let _ = self.variables.pop();
-            }
-
-            let _ = self.scopes.pop();
+    /// the variables of the scope are released. Their memory is reused by the following variables
+    pub fn end_scope(&mut self) {
+        if let Some(scope_position) = self.scopes.pop() {
+            self.stack_position = scope_position;
+            let _ = self.variables.pop_scope();
         }
     }
 }
@@ -148,12 +216,13 @@ impl ASMGenerator {
         let compile_comment = &ASMBuilder::line(&format!("; This assembly is targeted for the {} Operating System", self.target_os));
         let method_definitions = self.generate_method_definitions()?;
 
-        let entry_point_label = if self.target_os == TargetOS::Windows {
-            boiler_plate += &ASMBuilder::line("segment .text");
-            String::from("main")
-        } else {
-            String::from("_start")
-        };
+        // the program is linked against the c runtime, which calls `main` on every target
+        let entry_point_label = String::from("main");
+
+        boiler_plate += &ASMBuilder::line(match self.target_os {
+            TargetOS::Windows => "segment .text",
+            TargetOS::Linux | TargetOS::WindowsSubsystemLinux => "section .text",
+        });
 
         boiler_plate += &ASMBuilder::line(&format!("global {}", entry_point_label));
         boiler_plate += &ASMBuilder::line("");
@@ -161,9 +230,9 @@ impl ASMGenerator {
         let mut added_extern_methods: Vec<String> = vec![];
         self.top_level_scope.iter().for_each(|a|
             if let AbstractSyntaxTreeNode::MethodDefinition(method_def) = a {
-                if method_def.is_extern && !added_extern_methods.contains(&method_def.identifier.identifier()) {
-                    boiler_plate += &ASMBuilder::line(&format!("extern {}", &method_def.identifier.identifier()));
-                    added_extern_methods.push(method_def.identifier.identifier());
+                if method_def.is_extern && !added_extern_methods.contains(&method_def.symbol_name()) {
+                    boiler_plate += &ASMBuilder::line(&format!("extern {}", &method_def.symbol_name()));
+                    added_extern_methods.push(method_def.symbol_name());
                 }
             }
         );
@@ -192,11 +261,25 @@ impl ASMGenerator {
                         meta.static_type_information.merge(StaticTypeContext::new(&main.stack));
 
                         let main_function_asm = main.to_asm(&mut self.stack, &mut meta, None)?;
+
+                        // the offsets of the fields are taken from the `struc` of the struct
+                        for node in &self.top_level_scope {
+                            if let AbstractSyntaxTreeNode::StructDefinition(struct_definition) = node {
+                                struct_definition.data_section(&mut self.stack, &mut meta);
+                            }
+                        }
+
                         let data_section = self.stack.data_section
                             .clone()
                             .to_asm(&mut self.stack, &mut meta, None)?;
 
-                        Ok(format!("{}{}{}{}{}", compile_comment, data_section, boiler_plate, method_definitions, main_function_asm))
+                        let stack_note = match self.target_os {
+                            TargetOS::Windows => String::new(),
+                            // marks the stack as non-executable, otherwise the linker complains about an executable stack
+                            TargetOS::Linux | TargetOS::WindowsSubsystemLinux => ASMBuilder::line("section .note.GNU-stack noalloc noexec nowrite progbits"),
+                        };
+
+                        Ok(format!("{}{}{}{}{}{}", compile_comment, data_section, boiler_plate, method_definitions, main_function_asm, stack_note))
                     } else {
                         return Err(ASMGenerateError::EntryPointNotFound)
                     }
@@ -208,8 +291,10 @@ impl ASMGenerator {
         } else {
             self.require_main = true;
 
-            let method_definitions = self.top_level_scope.iter().filter(|t| matches!(t, AbstractSyntaxTreeNode::MethodDefinition(_))).cloned().collect::<Vec<_>>();
-            let mut main_stack = self.top_level_scope.iter().filter(|t| !matches!(t, AbstractSyntaxTreeNode::Import(_) | AbstractSyntaxTreeNode::MethodDefinition(_))).cloned().collect::<Vec<AbstractSyntaxTreeNode>>();
+            // definitions stay on the top level, so the methods still see the structs and enums
+            let is_definition = |t: &AbstractSyntaxTreeNode| matches!(t, AbstractSyntaxTreeNode::Import(_) | AbstractSyntaxTreeNode::MethodDefinition(_) | AbstractSyntaxTreeNode::StructDefinition(_) | AbstractSyntaxTreeNode::EnumDefinition(_));
+            let definitions = self.top_level_scope.iter().filter(|t| is_definition(t)).cloned().collect::<Vec<_>>();
+            let mut main_stack = self.top_level_scope.iter().filter(|t| !is_definition(t)).cloned().collect::<Vec<AbstractSyntaxTreeNode>>();
             // last element of main stack via pattern matching
             if let [.., last] = &main_stack[..] {
                 if !matches!(last, AbstractSyntaxTreeNode::Return(_)) {
@@ -222,20 +307,17 @@ impl ASMGenerator {
             }
 
             let main_function = AbstractSyntaxTreeNode::MethodDefinition(MethodDefinition {
-                identifier: LValue::Identifier(Identifier { name: "main".to_string() }),
+                identifier: LValue::Identifier(Identifier { name: "main".to_string(), symbol: None }),
+                generic_parameters: vec![],
                 return_type: Type::Integer(IntegerType::I32, Mutability::Immutable),
                 arguments: vec![],
                 stack: main_stack,
                 is_extern: false,
+                is_public: false,
                 file_position: FilePosition::default(),
             });
 
-            self.top_level_scope.clear();
-            let imports = self.top_level_scope.iter().filter(|t| matches!(t, AbstractSyntaxTreeNode::Import(_))).cloned().collect::<Vec<AbstractSyntaxTreeNode>>();
-
-            method_definitions.iter().for_each(|t| self.top_level_scope.push(t.clone()));
-            imports.iter().for_each(|t| self.top_level_scope.push(t.clone()));
-
+            self.top_level_scope = definitions;
             self.top_level_scope.push(main_function);
             self.generate()
         }
@@ -256,31 +338,47 @@ impl ASMGenerator {
                 if !method_definition.is_extern && method_definition.identifier.identifier() != "main" {
                     self.stack.clear_stack();
 
-                    let calling_convention = calling_convention_from(method_definition, &self.target_os);
+                    let calling_convention = calling_convention_from(method_definition, &self.target_os, &meta.static_type_information);
+
+                    // the address of the memory, where a returned struct is written to
+                    if returns_in_memory(method_definition, &meta.static_type_information) {
+                        self.stack.variables.declare(StackLocation {
+                            position: self.stack.stack_position,
+                            size: 8,
+                            elements: 1,
+                            name: LValue::Identifier(Identifier { name: RETURN_POINTER.to_string(), symbol: None }),
+                        });
+
+                        self.stack.stack_position += 8;
+                    }
 
                     for (index, argument) in method_definition.arguments.iter().enumerate() {
                         let parameter = Parameter {
-                            identifier: Identifier { name: argument.identifier.identifier() },
+                            identifier: Identifier { name: argument.identifier.identifier(), symbol: argument.identifier.symbol() },
                             ty: argument.ty.clone(),
                             register: calling_convention[index][0].clone(),
                             mutability: argument.ty.mutable(),
                             file_position: method_definition.file_position.clone(),
                         };
 
-                        self.stack.variables.push(StackLocation {
+                        // structs are copied into the stack of the method
+                        let size = argument.ty.byte_size_with_meta(&meta)?;
+
+                        self.stack.variables.declare(StackLocation {
                             position: self.stack.stack_position,
-                            size: argument.ty.byte_size(),
+                            size,
                             elements: 1,
                             name: argument.identifier.clone(),
                         });
 
-                        self.stack.stack_position += argument.ty.byte_size();
+                        self.stack.stack_position += size;
 
-                        meta.static_type_information.context.push(Variable {
+                        meta.static_type_information.declare(Variable {
                             l_value: argument.identifier.clone(),
                             mutability: parameter.mutability,
                             ty: Some(argument.ty.clone()),
                             define: true,
+                            compound_operator: None,
                             assignable: Assignable::Parameter(parameter),
                             file_position: method_definition.file_position.clone(),
                         });
@@ -25,14 +25,14 @@ pub mod abstract_syntax_tree_nodes;
 pub enum ASMGenerateError {
     _VariableAlreadyUsed { name: String, file_position: FilePosition },
     UnresolvedReference { name: String, file_position: FilePosition },
-    CastUnsupported(CastToError, FilePosition),
+    CastUnsupported(Box<CastToError>, FilePosition),
     EntryPointNotFound,
-    LValueAssignment(LValue, FilePosition),
+    LValueAssignment(Box<LValue>, FilePosition),
     MultipleEntryPointsFound(Vec<FilePosition>),
     TypeNotInferrable(Box<InferTypeError>),
     InternalError(String, FilePosition),
     ASMResult(ASMResultError),
-    AssignmentNotImplemented { assignable: Assignable, },
+    AssignmentNotImplemented { assignable: Box<Assignable>, },
     NotImplemented { ast_node: String, },
 }
 
@@ -9,6 +9,7 @@ pub enum Bit64 {
     Rax,
     Rcx,
     Rdi,
+    Rsi,
     Rdx,
     R15,
     R8,
@@ -26,6 +27,7 @@ pub enum Bit32 {
     Eax,
     Ecx,
     Edi,
+    Esi,
     Edx,
     R15d,
     R8d,
@@ -40,6 +42,7 @@ pub enum Bit16 {
     Ax,
     Cx,
     Di,
+    Si,
     Dx,
     R15w,
     R14w,
@@ -63,6 +66,7 @@ pub enum NibbleRegister {
     CH,
     CL,
     DIL,
+    SIL,
     DH,
     DL,
     R15b,
@@ -123,6 +127,7 @@ impl FromStr for GeneralPurposeRegister {
             "rax" => Ok(GeneralPurposeRegister::Bit64(Bit64::Rax)),
             "rcx" => Ok(GeneralPurposeRegister::Bit64(Bit64::Rcx)),
             "rdi" => Ok(GeneralPurposeRegister::Bit64(Bit64::Rdi)),
+            "rsi" => Ok(GeneralPurposeRegister::Bit64(Bit64::Rsi)),
             "rdx" => Ok(GeneralPurposeRegister::Bit64(Bit64::Rdx)),
             "r15" => Ok(GeneralPurposeRegister::Bit64(Bit64::R15)),
             "r9" => Ok(GeneralPurposeRegister::Bit64(Bit64::R9)),
@@ -130,6 +135,7 @@ impl FromStr for GeneralPurposeRegister {
             "eax" => Ok(GeneralPurposeRegister::Bit32(Bit32::Eax)),
             "ecx" => Ok(GeneralPurposeRegister::Bit32(Bit32::Ecx)),
             "edi" => Ok(GeneralPurposeRegister::Bit32(Bit32::Edi)),
+            "esi" => Ok(GeneralPurposeRegister::Bit32(Bit32::Esi)),
             "edx" => Ok(GeneralPurposeRegister::Bit32(Bit32::Edx)),
             "r15d" => Ok(GeneralPurposeRegister::Bit32(Bit32::R15d)),
             "r9d" => Ok(GeneralPurposeRegister::Bit32(Bit32::R9d)),
@@ -137,6 +143,7 @@ impl FromStr for GeneralPurposeRegister {
             "ax" => Ok(GeneralPurposeRegister::Bit16(Bit16::Ax)),
             "cx" => Ok(GeneralPurposeRegister::Bit16(Bit16::Cx)),
             "di" => Ok(GeneralPurposeRegister::Bit16(Bit16::Di)),
+            "si" => Ok(GeneralPurposeRegister::Bit16(Bit16::Si)),
             "dx" => Ok(GeneralPurposeRegister::Bit16(Bit16::Dx)),
             "r15w" => Ok(GeneralPurposeRegister::Bit16(Bit16::R15w)),
             "r14w" => Ok(GeneralPurposeRegister::Bit16(Bit16::R14w)),
@@ -157,6 +164,7 @@ impl FromStr for GeneralPurposeRegister {
             "dh" => Ok(GeneralPurposeRegister::Bit8(Bit8::Single(NibbleRegister::DH))),
             "dl" => Ok(GeneralPurposeRegister::Bit8(Bit8::Single(NibbleRegister::DL))),
             "dil" => Ok(GeneralPurposeRegister::Bit8(Bit8::Single(NibbleRegister::DIL))),
+            "sil" => Ok(GeneralPurposeRegister::Bit8(Bit8::Single(NibbleRegister::SIL))),
             "xmm0" => Ok(GeneralPurposeRegister::Float(FloatRegister::Xmm0)),
             "xmm1" => Ok(GeneralPurposeRegister::Float(FloatRegister::Xmm1)),
             "xmm2" => Ok(GeneralPurposeRegister::Float(FloatRegister::Xmm2)),
@@ -353,6 +361,7 @@ impl GeneralPurposeRegister {
                     Bit64::Rax => GeneralPurposeRegister::Bit16(Bit16::Ax),
                     Bit64::Rcx => GeneralPurposeRegister::Bit16(Bit16::Cx),
                     Bit64::Rdi => GeneralPurposeRegister::Bit16(Bit16::Di),
+                    Bit64::Rsi => GeneralPurposeRegister::Bit16(Bit16::Si),
                     Bit64::Rdx => GeneralPurposeRegister::Bit16(Bit16::Dx),
                     Bit64::R15 => GeneralPurposeRegister::Bit16(Bit16::R15w),
                     Bit64::R14 => GeneralPurposeRegister::Bit16(Bit16::R14w),
@@ -367,6 +376,7 @@ impl GeneralPurposeRegister {
                     Bit32::Eax => GeneralPurposeRegister::Bit16(Bit16::Ax),
                     Bit32::Ecx => GeneralPurposeRegister::Bit16(Bit16::Cx),
                     Bit32::Edi => GeneralPurposeRegister::Bit16(Bit16::Di),
+                    Bit32::Esi => GeneralPurposeRegister::Bit16(Bit16::Si),
                     Bit32::Edx => GeneralPurposeRegister::Bit16(Bit16::Dx),
                     Bit32::R15d => GeneralPurposeRegister::Bit16(Bit16::R15w),
                     Bit32::R14d => GeneralPurposeRegister::Bit16(Bit16::R14w),
@@ -384,6 +394,7 @@ impl GeneralPurposeRegister {
                     Bit8::Single(NibbleRegister::CH) => GeneralPurposeRegister::Bit16(Bit16::Cx),
                     Bit8::Single(NibbleRegister::CL) => GeneralPurposeRegister::Bit16(Bit16::Cx),
                     Bit8::Single(NibbleRegister::DIL) => GeneralPurposeRegister::Bit16(Bit16::Di),
+                    Bit8::Single(NibbleRegister::SIL) => GeneralPurposeRegister::Bit16(Bit16::Si),
                     Bit8::Single(NibbleRegister::DH) => GeneralPurposeRegister::Bit16(Bit16::Dx),
                     Bit8::Single(NibbleRegister::DL) => GeneralPurposeRegister::Bit16(Bit16::Dx),
                     _ => unimplemented!("Not needed yet")
@@ -401,6 +412,7 @@ impl GeneralPurposeRegister {
                     Bit64::Rax => GeneralPurposeRegister::Bit8(Bit8::Single(NibbleRegister::AL)),
                     Bit64::Rcx => GeneralPurposeRegister::Bit8(Bit8::Single(NibbleRegister::CL)),
                     Bit64::Rdi => GeneralPurposeRegister::Bit8(Bit8::Single(NibbleRegister::DIL)),
+                    Bit64::Rsi => GeneralPurposeRegister::Bit8(Bit8::Single(NibbleRegister::SIL)),
                     Bit64::Rdx => GeneralPurposeRegister::Bit8(Bit8::Single(NibbleRegister::DL)),
                     Bit64::R15 => GeneralPurposeRegister::Bit8(Bit8::Single(NibbleRegister::R15b)),
                     Bit64::R14 => GeneralPurposeRegister::Bit8(Bit8::Single(NibbleRegister::R14b)),
@@ -415,6 +427,7 @@ impl GeneralPurposeRegister {
                     Bit32::Eax => GeneralPurposeRegister::Bit8(Bit8::Single(NibbleRegister::AL)),
                     Bit32::Ecx => GeneralPurposeRegister::Bit8(Bit8::Single(NibbleRegister::CL)),
                     Bit32::Edi => GeneralPurposeRegister::Bit8(Bit8::Single(NibbleRegister::DIL)),
+                    Bit32::Esi => GeneralPurposeRegister::Bit8(Bit8::Single(NibbleRegister::SIL)),
                     Bit32::Edx => GeneralPurposeRegister::Bit8(Bit8::Single(NibbleRegister::DL)),
                     Bit32::R15d => GeneralPurposeRegister::Bit8(Bit8::Single(NibbleRegister::R15b)),
                     Bit32::R14d => GeneralPurposeRegister::Bit8(Bit8::Single(NibbleRegister::R14b)),
@@ -429,6 +442,7 @@ impl GeneralPurposeRegister {
                     Bit16::Ax => GeneralPurposeRegister::Bit8(Bit8::Single(NibbleRegister::AL)),
                     Bit16::Cx => GeneralPurposeRegister::Bit8(Bit8::Single(NibbleRegister::CL)),
                     Bit16::Di => GeneralPurposeRegister::Bit8(Bit8::Single(NibbleRegister::DIL)),
+                    Bit16::Si => GeneralPurposeRegister::Bit8(Bit8::Single(NibbleRegister::SIL)),
                     Bit16::Dx => GeneralPurposeRegister::Bit8(Bit8::Single(NibbleRegister::DL)),
                     Bit16::R15w => GeneralPurposeRegister::Bit8(Bit8::Single(NibbleRegister::R15b)),
                     Bit16::R14w => GeneralPurposeRegister::Bit8(Bit8::Single(NibbleRegister::R14b)),
@@ -461,6 +475,7 @@ impl GeneralPurposeRegister {
                     Bit64::Rax => GeneralPurposeRegister::Bit32(Bit32::Eax),
                     Bit64::Rcx => GeneralPurposeRegister::Bit32(Bit32::Ecx),
                     Bit64::Rdi => GeneralPurposeRegister::Bit32(Bit32::Edi),
+                    Bit64::Rsi => GeneralPurposeRegister::Bit32(Bit32::Esi),
                     Bit64::Rdx => GeneralPurposeRegister::Bit32(Bit32::Edx),
                     Bit64::R15 => GeneralPurposeRegister::Bit32(Bit32::R15d),
                     Bit64::R8 => GeneralPurposeRegister::Bit32(Bit32::R8d),
@@ -476,6 +491,7 @@ impl GeneralPurposeRegister {
                     Bit16::Ax => GeneralPurposeRegister::Bit32(Bit32::Eax),
                     Bit16::Cx => GeneralPurposeRegister::Bit32(Bit32::Ecx),
                     Bit16::Di => GeneralPurposeRegister::Bit32(Bit32::Edi),
+                    Bit16::Si => GeneralPurposeRegister::Bit32(Bit32::Esi),
                     Bit16::Dx => GeneralPurposeRegister::Bit32(Bit32::Edx),
                     Bit16::R15w => GeneralPurposeRegister::Bit32(Bit32::R15d),
                     Bit16::R14w => GeneralPurposeRegister::Bit32(Bit32::R14d),
@@ -514,6 +530,7 @@ impl GeneralPurposeRegister {
                     Bit32::Eax => GeneralPurposeRegister::Bit64(Bit64::Rax),
                     Bit32::Ecx => GeneralPurposeRegister::Bit64(Bit64::Rcx),
                     Bit32::Edi => GeneralPurposeRegister::Bit64(Bit64::Rdi),
+                    Bit32::Esi => GeneralPurposeRegister::Bit64(Bit64::Rsi),
                     Bit32::Edx => GeneralPurposeRegister::Bit64(Bit64::Rdx),
                     Bit32::R15d => GeneralPurposeRegister::Bit64(Bit64::R15),
                     Bit32::R8d => GeneralPurposeRegister::Bit64(Bit64::R8),
@@ -528,6 +545,7 @@ impl GeneralPurposeRegister {
                     Bit16::Ax => GeneralPurposeRegister::Bit64(Bit64::Rax),
                     Bit16::Cx => GeneralPurposeRegister::Bit64(Bit64::Rcx),
                     Bit16::Di => GeneralPurposeRegister::Bit64(Bit64::Rdi),
+                    Bit16::Si => GeneralPurposeRegister::Bit64(Bit64::Rsi),
                     Bit16::Dx => GeneralPurposeRegister::Bit64(Bit64::Rdx),
                     Bit16::R15w => GeneralPurposeRegister::Bit64(Bit64::R15),
                     Bit16::R14w => GeneralPurposeRegister::Bit64(Bit64::R14),
@@ -634,6 +652,7 @@ impl NibbleRegister {
             NibbleRegister::CH => Bit64::Rcx,
             NibbleRegister::CL => Bit64::Rcx,
             NibbleRegister::DIL => Bit64::Rdi,
+            NibbleRegister::SIL => Bit64::Rsi,
             NibbleRegister::DH => Bit64::Rdx,
             NibbleRegister::DL => Bit64::Rdx,
             NibbleRegister::R15b => Bit64::R15,
@@ -659,7 +678,8 @@ impl NibbleRegister {
             NibbleRegister::R12b => Bit32::R12d,
             NibbleRegister::R8b => Bit32::R8d,
             NibbleRegister::R9b => Bit32::R9d,
-            NibbleRegister::DIL => Bit32::Edi
+            NibbleRegister::DIL => Bit32::Edi,
+            NibbleRegister::SIL => Bit32::Esi,
         }
     }
 }
@@ -688,6 +708,7 @@ impl Display for NibbleRegister {
             NibbleRegister::CH => "ch",
             NibbleRegister::CL => "cl",
             NibbleRegister::DIL => "dil",
+            NibbleRegister::SIL => "sil",
             NibbleRegister::DH => "dh",
             NibbleRegister::DL => "dl",
             NibbleRegister::R15b => "r15b",
@@ -730,6 +751,7 @@ impl Display for Bit16 {
             Bit16::Ax => "ax",
             Bit16::Cx => "cx",
             Bit16::Di => "di",
+            Bit16::Si => "si",
             Bit16::Dx => "dx",
             Bit16::R15w => "r15w",
             Bit16::R14w => "r14w",
@@ -748,6 +770,7 @@ impl Display for Bit32 {
             Bit32::Eax => "eax",
             Bit32::Ecx => "ecx",
             Bit32::Edi => "edi",
+            Bit32::Esi => "esi",
             Bit32::Edx => "edx",
             Bit32::R15d => "r15d",
             Bit32::R8d => "r8d",
@@ -765,6 +788,7 @@ impl Display for Bit64 {
             Bit64::Rax => "rax",
             Bit64::Rcx => "rcx",
             Bit64::Rdi => "rdi",
+            Bit64::Rsi => "rsi",
             Bit64::Rdx => "rdx",
             Bit64::R9 => "r9",
             Bit64::R8 => "r8",
@@ -58,17 +58,22 @@ impl TargetOS {
                     println!("{}", stdout);
                 }
 
-                let status = Some(output.status);
-
-
                 if !output.stderr.is_empty() && !suppress_error {
                     let stderr = String::from_utf8_lossy(&output.stderr);
                     eprintln!("Error: \n{}", stderr);
                 }
 
-                if let Some(status) = status {
-                    if let Some(code) = status.code() {
-                        return code;
+                if let Some(code) = output.status.code() {
+                    return code;
+                }
+
+                // a process killed by a signal has no exit code. report it like a shell does
+                #[cfg(unix)]
+                {
+                    use std::os::unix::process::ExitStatusExt;
+
+                    if let Some(signal) = output.status.signal() {
+                        return 128 + signal;
                     }
                 }
 
@@ -85,6 +90,19 @@ impl TargetOS {
         }
     }
     
+    /// Runs every build step in order and stops at the first failing one
+    fn run_build_steps(steps: Vec<(&str, Vec<&str>)>) -> i32 {
+        for (program, args) in steps {
+            let status = TargetOS::run_generic_commands(program, args, false);
+
+            if status != 0 {
+                return status;
+            }
+        }
+
+        0
+    }
+
     fn compile_and_execute(&self, target_creator: &TargetCreator, build: bool, execute: bool) -> i32 {
         if build {
             println!("Compiling...");
@@ -104,8 +122,10 @@ impl TargetOS {
             TargetOS::Windows => {
                 // nasm -f win64 main.asm ; gcc main.obj -o main ; .\main.exe ; echo $LASTEXITCODE
                 if build {
-                    TargetOS::run_generic_commands("nasm", vec!["-gcv8", "-f", "win64", "main.asm"], false);
-                    TargetOS::run_generic_commands("gcc", vec!["main.obj", "-o", "main"], false);
+                    return TargetOS::run_build_steps(vec![
+                        ("nasm", vec!["-gcv8", "-f", "win64", "main.asm"]),
+                        ("gcc", vec!["main.obj", "-o", "main"]),
+                    ]);
                 }
                 if execute {
                     TargetOS::run_generic_commands("./main.exe", vec![], false)
@@ -114,9 +134,13 @@ impl TargetOS {
                 }
             }
             TargetOS::Linux => {
+                // nasm -felf64 main.asm -o main.o ; cc -no-pie main.o -o main ; ./main ; echo $?
+                // linking through the c compiler provides `_start`, calls `main` and resolves libc functions like `printf`
                 if build {
-                    TargetOS::run_generic_commands("nasm", vec!["-felf64", "main.asm"], false);
-                    TargetOS::run_generic_commands("ld", vec!["-o", "main", "main.o"], true);
+                    return TargetOS::run_build_steps(vec![
+                        ("nasm", vec!["-felf64", "main.asm", "-o", "main.o"]),
+                        ("cc", vec!["-no-pie", "main.o", "-o", "main"]),
+                    ]);
                 }
                 if execute {
                     TargetOS::run_generic_commands("./main", vec![], false)
@@ -126,10 +150,13 @@ impl TargetOS {
             }
             TargetOS::WindowsSubsystemLinux => {
                 if build {
-                    TargetOS::run_generic_commands("wsl", vec!["nasm", "-felf64", "main.asm", "&&", "ld", "-o", "main", "main.o"], false);
+                    return TargetOS::run_build_steps(vec![
+                        ("wsl", vec!["nasm", "-felf64", "main.asm", "-o", "main.o"]),
+                        ("wsl", vec!["cc", "-no-pie", "main.o", "-o", "main"]),
+                    ]);
                 }
                 if execute {
-                    TargetOS::run_generic_commands("./main", vec![], false)
+                    TargetOS::run_generic_commands("wsl", vec!["./main"], false)
                 } else {
                     0
                 }
@@ -1 +1,2 @@
 pub mod monkey_file;
+pub mod source_map;
@@ -3,18 +3,26 @@ use std::io::Read;
 use std::path::{Path, PathBuf};
 
 use anyhow::Context;
-use crate::core::lexer::tokenizer::tokenize;
+use crate::core::io::source_map::{FileId, SourceMap};
+use crate::core::lexer::error::Error;
+use crate::core::lexer::tokenizer::tokenize_lossless;
 use crate::core::lexer::token_with_span::TokenWithSpan;
 
 #[derive(Debug, PartialEq, Clone)]
 pub struct MonkeyFile {
     pub path: PathBuf,
+    /// The tokens keep the whitespace and comments around them, so tools can rebuild the file from them
     pub tokens: Vec<TokenWithSpan>,
-    pub size: usize
+    pub size: usize,
+    /// The id of the file inside the [`SourceMap`]
+    pub file_id: FileId,
+    /// Every lexical error of a read file. The invalid source code is kept as a [`crate::core::lexer::token::Token::Error`] in `tokens`
+    pub errors: Vec<Error>,
 }
 
 impl MonkeyFile {
-    pub fn read<P: AsRef<Path>>(path: P) -> anyhow::Result<Self> {
+    /// Reads the file and registers it in the provided source map
+    pub fn read<P: AsRef<Path>>(path: P, source_map: &mut SourceMap) -> anyhow::Result<Self> {
         let path_buffer = PathBuf::from(path.as_ref());
 
         let mut file: File = File::open(path)
@@ -23,21 +31,15 @@ impl MonkeyFile {
         let mut buffer = String::new();
 
         let size = file.read_to_string(&mut buffer)?;
-        let tokens = tokenize(&buffer)?;
+        let file_id = source_map.add(&path_buffer, &buffer);
+        let (tokens, errors) = tokenize_lossless(&buffer, file_id);
 
         Ok(Self {
             path: path_buffer,
             tokens,
             size,
-        })
-    }
-
-    pub fn read_from_str(buffer: &str) -> anyhow::Result<Self> {
-        let tokens = tokenize(buffer)?;
-        Ok(Self {
-            path: PathBuf::new(),
-            tokens,
-            size: buffer.chars().count(),
+            file_id,
+            errors,
         })
     }
 }
\ No newline at end of file
@@ -0,0 +1,33 @@
+use std::path::{Path, PathBuf};
+
+/// Identifies a source file inside the [`SourceMap`]. The default id belongs to source code,
+/// which isn't backed by a file
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct FileId(pub usize);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SourceFile {
+    pub path: PathBuf,
+    pub source: String,
+}
+
+/// Knows every file loaded during one compilation, so spans can be resolved back into the file they belong to
+#[derive(Debug, Default)]
+pub struct SourceMap {
+    files: Vec<SourceFile>,
+}
+
+impl SourceMap {
+    pub fn add<P: AsRef<Path>>(&mut self, path: P, source: &str) -> FileId {
+        self.files.push(SourceFile { path: PathBuf::from(path.as_ref()), source: source.to_string() });
+        FileId(self.files.len())
+    }
+
+    pub fn get(&self, file_id: FileId) -> Option<&SourceFile> {
+        file_id.0.checked_sub(1).and_then(|index| self.files.get(index))
+    }
+
+    pub fn path_of(&self, file_id: FileId) -> Option<&Path> {
+        self.get(file_id).map(|file| file.path.as_path())
+    }
+}
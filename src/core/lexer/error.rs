@@ -1,10 +1,10 @@
 use std::fmt::{Debug, Display, Formatter};
 use crate::core::lexer::token::Token;
-use crate::core::lexer::token_with_span::TokenWithSpan;
+use crate::core::lexer::token_with_span::{FilePosition, TokenWithSpan};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum Error {
-    InvalidCharacter(char),
+    InvalidCharacter(char, FilePosition),
     UnexpectedToken(TokenWithSpan),
     Callstack(Box<Error>),
     ExpectedToken(Token),
@@ -13,6 +13,9 @@ pub enum Error {
         context: TokenWithSpan,
     },
     UnexpectedEOF,
+    UnterminatedComment(FilePosition),
+    InvalidEscapeSequence(String, FilePosition),
+    InvalidCharLiteral(String, FilePosition),
 }
 
 impl Error {
@@ -22,6 +25,19 @@ impl Error {
             context: start.clone(),
         }
     }
+
+    pub fn file_position(&self) -> Option<&FilePosition> {
+        match self {
+            Error::InvalidCharacter(_, file_position) |
+            Error::UnterminatedComment(file_position) |
+            Error::InvalidEscapeSequence(_, file_position) |
+            Error::InvalidCharLiteral(_, file_position) => Some(file_position),
+            Error::UnexpectedToken(token) => Some(&token.span),
+            Error::Callstack(error) => error.file_position(),
+            Error::WithContext { error, context } => error.file_position().or(Some(&context.span)),
+            Error::ExpectedToken(_) | Error::UnexpectedEOF => None,
+        }
+    }
 }
 
 pub enum ErrorMatch {
@@ -64,10 +80,13 @@ impl From<Token> for ErrorMatch {
 impl Display for Error {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", match self {
-            Error::InvalidCharacter(character) => format!("Invalid character: `{}`", character),
+            Error::InvalidCharacter(character, position) => format!("Line: {}: \tInvalid character: `{}`", position, character),
             Error::UnexpectedToken(token) => format!("Unexpected token: {}", token),
             Error::ExpectedToken(f) => format!("Expected token: `{}`", f), 
             Error::UnexpectedEOF => "Unexpected EOF".to_string(),
+            Error::InvalidEscapeSequence(sequence, position) => format!("Line: {}: \tInvalid escape sequence: `{}`", position, sequence),
+            Error::InvalidCharLiteral(literal, position) => format!("Line: {}: \tA character literal must contain exactly one ascii character: `{}`", position, literal),
+            Error::UnterminatedComment(position) => format!("Line: {}: \tUnterminated block comment", position),
             Error::Callstack(trace) => trace.to_string(),
             Error::WithContext { error, context } => {
                 format!("{}\n\tInside: {}", error, context)
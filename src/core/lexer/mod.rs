@@ -2,7 +2,6 @@ pub mod tokenizer;
 pub mod token;
 pub mod error;
 mod token_information;
-pub mod semantic_token_merge;
 pub mod token_with_span;
 pub mod parse;
 pub mod macros;
@@ -2,8 +2,13 @@ use crate::core::lexer::error::Error;
 use crate::core::lexer::token_with_span::TokenWithSpan;
 use crate::core::model::abstract_syntax_tree_node::AbstractSyntaxTreeNode;
 use crate::core::model::abstract_syntax_tree_nodes::assignables::method_call::MethodCall;
+use crate::core::model::abstract_syntax_tree_nodes::break_::Break;
+use crate::core::model::abstract_syntax_tree_nodes::continue_::Continue;
+use crate::core::model::abstract_syntax_tree_nodes::enum_::Enum;
 use crate::core::model::abstract_syntax_tree_nodes::for_::For;
 use crate::core::model::abstract_syntax_tree_nodes::if_::If;
+use crate::core::model::abstract_syntax_tree_nodes::impl_::Impl;
+use crate::core::model::abstract_syntax_tree_nodes::match_::Match;
 use crate::core::model::abstract_syntax_tree_nodes::import::Import;
 use crate::core::model::abstract_syntax_tree_nodes::method_definition::MethodDefinition;
 use crate::core::model::abstract_syntax_tree_nodes::ret::Return;
@@ -102,14 +107,14 @@ pub trait Parse: Default + Clone + Sized {
     /// use monkey_language::core::lexer::token::Token;
     /// use monkey_language::core::lexer::error::Error;
     /// let tokens = vec![
-    ///     TokenWithSpan::new(Token::If, 1, 3),
-    ///     TokenWithSpan::new(Token::ParenthesisOpen, 4, 5),
-    ///     TokenWithSpan::new(Token::Literal("x".to_string()), 6, 7),
-    ///     TokenWithSpan::new(Token::GreaterThan, 8, 9),
-    ///     TokenWithSpan::new(Token::Numbers("10".to_string()), 10, 12),
-    ///     TokenWithSpan::new(Token::ParenthesisClose, 13, 14),
-    ///     TokenWithSpan::new(Token::CurlyBraceOpen, 15, 16),
-    ///     TokenWithSpan::new(Token::CurlyBraceClose, 17, 18),
+    ///     TokenWithSpan::new(Token::If, 1, 1..=2),
+    ///     TokenWithSpan::new(Token::ParenthesisOpen, 1, 4..=4),
+    ///     TokenWithSpan::new(Token::Literal("x".to_string()), 1, 5..=5),
+    ///     TokenWithSpan::new(Token::GreaterThan, 1, 7..=7),
+    ///     TokenWithSpan::new(Token::Numbers("10".to_string()), 1, 9..=10),
+    ///     TokenWithSpan::new(Token::ParenthesisClose, 1, 11..=11),
+    ///     TokenWithSpan::new(Token::CurlyBraceOpen, 1, 13..=13),
+    ///     TokenWithSpan::new(Token::CurlyBraceClose, 1, 14..=14),
     /// ];
     /// let parse_result: Result<ParseResult<If>, Error> = If::parse(&tokens, ParseOptions::default());
     /// assert!(parse_result.is_ok());
@@ -135,6 +140,15 @@ impl From<ParseResult<Struct>> for Result<ParseResult<AbstractSyntaxTreeNode>, E
     }
 }
 
+impl From<ParseResult<Enum>> for Result<ParseResult<AbstractSyntaxTreeNode>, Error> {
+    fn from(value: ParseResult<Enum>) -> Self {
+        Ok(ParseResult {
+            result: AbstractSyntaxTreeNode::EnumDefinition(value.result),
+            consumed: value.consumed,
+        })
+    }
+}
+
 impl From<ParseResult<MethodDefinition>> for Result<ParseResult<AbstractSyntaxTreeNode>, Error> {
     fn from(value: ParseResult<MethodDefinition>) -> Self {
         Ok(ParseResult {
@@ -153,6 +167,24 @@ impl From<ParseResult<While>> for Result<ParseResult<AbstractSyntaxTreeNode>, Er
     }
 }
 
+impl From<ParseResult<Break>> for Result<ParseResult<AbstractSyntaxTreeNode>, Error> {
+    fn from(value: ParseResult<Break>) -> Self {
+        Ok(ParseResult {
+            result: AbstractSyntaxTreeNode::Break(value.result),
+            consumed: value.consumed,
+        })
+    }
+}
+
+impl From<ParseResult<Continue>> for Result<ParseResult<AbstractSyntaxTreeNode>, Error> {
+    fn from(value: ParseResult<Continue>) -> Self {
+        Ok(ParseResult {
+            result: AbstractSyntaxTreeNode::Continue(value.result),
+            consumed: value.consumed,
+        })
+    }
+}
+
 impl From<ParseResult<Return>> for Result<ParseResult<AbstractSyntaxTreeNode>, Error> {
     fn from(value: ParseResult<Return>) -> Self {
         Ok(ParseResult {
@@ -162,6 +194,15 @@ impl From<ParseResult<Return>> for Result<ParseResult<AbstractSyntaxTreeNode>, E
     }
 }
 
+impl From<ParseResult<Impl>> for Result<ParseResult<AbstractSyntaxTreeNode>, Error> {
+    fn from(value: ParseResult<Impl>) -> Self {
+        Ok(ParseResult {
+            result: AbstractSyntaxTreeNode::Impl(value.result),
+            consumed: value.consumed,
+        })
+    }
+}
+
 impl From<ParseResult<Import>> for Result<ParseResult<AbstractSyntaxTreeNode>, Error> {
     fn from(value: ParseResult<Import>) -> Self {
         Ok(ParseResult {
@@ -198,4 +239,13 @@ impl From<ParseResult<If>> for Result<ParseResult<AbstractSyntaxTreeNode>, Error
             consumed: value.consumed,
         })
     }
+}
+
+impl From<ParseResult<Match>> for Result<ParseResult<AbstractSyntaxTreeNode>, Error> {
+    fn from(value: ParseResult<Match>) -> Self {
+        Ok(ParseResult {
+            result: AbstractSyntaxTreeNode::Match(value.result),
+            consumed: value.consumed,
+        })
+    }
 }
\ No newline at end of file
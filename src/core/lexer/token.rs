@@ -6,12 +6,16 @@ pub enum Token {
     #[default]
     If,
     Else,
+    Match,
     Let,
     Mut,
     Module,
+    Pub,
     Numbers(String),
     Literal(String),
     Struct,
+    Enum,
+    Impl,
     True,
     False,
     While,
@@ -19,6 +23,10 @@ pub enum Token {
     Extern,
     Fn,
     Return,
+    Break,
+    Continue,
+    /// A loop label like `'outer`. The name is stored without the leading `'`
+    Label(String),
     Equals,                 // =
     Plus,                   // +
     Minus,                  // -
@@ -33,12 +41,23 @@ pub enum Token {
     LogicalAnd,             // &&
     LogicalOr,              // ||
     LogicalNot,             // !
+    Tilde,                  // ~
     GreaterThanEquals,      // >=
     LessThanEquals,         // <=
     EqualsEquals,           // ==
     NotEquals,              // !=
     LeftShift,              // <<
     RightShift,             // >>
+    PlusEquals,             // +=
+    MinusEquals,            // -=
+    MultiplyEquals,         // *=
+    DivideEquals,           // /=
+    ModuloEquals,           // %=
+    LeftShiftEquals,        // <<=
+    RightShiftEquals,       // >>=
+    AmpersandEquals,        // &=
+    PipeEquals,             // |=
+    XorEquals,              // ^=
     ParenthesisOpen,        // (
     ParenthesisClose,       // )
     CurlyBraceOpen,         // {
@@ -48,9 +67,15 @@ pub enum Token {
     SemiColon,              // ;
     Comma,                  // ,
     Colon,                  // :
+    DoubleColon,            // ::
+    FatArrow,               // =>
+    DotDot,                 // ..
+    DotDotEquals,           // ..=
     Function,               // fn
     Dot,                    // .
     Underscore,             // _
+    /// Source code, which isn't a valid token. The lexer reports it and keeps going
+    Error(String),
 }
 
 impl From<char> for Token {
@@ -74,6 +99,7 @@ impl From<char> for Token {
             '>' => Token::GreaterThan,
             '&' => Token::Ampersand,
             '!' => Token::LogicalNot,
+            '~' => Token::Tilde,
             ';' => Token::SemiColon,
             ',' => Token::Comma,
             ':' => Token::Colon,
@@ -92,6 +118,8 @@ impl Display for Token {
             match self {
                 Token::Numbers(value) => write!(f, "{}", value),
                 Token::Literal(value) => write!(f, "{}", value),
+                Token::Error(value) => write!(f, "{}", value),
+                Token::Label(name) => write!(f, "'{}", name),
                 _ => unreachable!("Token not implemented for Display")
             }
         }
@@ -107,6 +135,7 @@ impl Token {
         match self {
             Token::If => Some("if"),
             Token::Else => Some("else"),
+            Token::Match => Some("match"),
             Token::Let => Some("let"),
             Token::Mut => Some("mut"),
             Token::While => Some("while"),
@@ -114,8 +143,13 @@ impl Token {
             Token::Extern => Some("extern"),
             Token::Fn => Some("fn"),
             Token::Return => Some("return"),
+            Token::Break => Some("break"),
+            Token::Continue => Some("continue"),
             Token::Module => Some("module"),
+            Token::Pub => Some("pub"),
             Token::Struct => Some("struct"),
+            Token::Enum => Some("enum"),
+            Token::Impl => Some("impl"),
             Token::ParenthesisOpen => Some("("),
             Token::ParenthesisClose => Some(")"),
             Token::CurlyBraceOpen => Some("{"),
@@ -138,19 +172,34 @@ impl Token {
             Token::LogicalAnd => Some("&&"),
             Token::LogicalOr => Some("||"),
             Token::LogicalNot => Some("!"),
+            Token::Tilde => Some("~"),
             Token::GreaterThanEquals => Some(">="),
             Token::LessThanEquals => Some("<="),
             Token::EqualsEquals => Some("=="),
+            Token::FatArrow => Some("=>"),
             Token::NotEquals => Some("!="),
             Token::LeftShift => Some("<<"),
             Token::RightShift => Some(">>"),
+            Token::PlusEquals => Some("+="),
+            Token::MinusEquals => Some("-="),
+            Token::MultiplyEquals => Some("*="),
+            Token::DivideEquals => Some("/="),
+            Token::ModuloEquals => Some("%="),
+            Token::LeftShiftEquals => Some("<<="),
+            Token::RightShiftEquals => Some(">>="),
+            Token::AmpersandEquals => Some("&="),
+            Token::PipeEquals => Some("|="),
+            Token::XorEquals => Some("^="),
             Token::SemiColon => Some(";"),
             Token::Comma => Some(","),
             Token::Colon => Some(":"),
+            Token::DoubleColon => Some("::"),
             Token::Function => Some("fn"),
+            Token::DotDot => Some(".."),
+            Token::DotDotEquals => Some("..="),
             Token::Dot => Some("."),
             Token::Underscore => Some("_"),
-            Token::Numbers(_) | Token::Literal(_) => None,
+            Token::Numbers(_) | Token::Literal(_) | Token::Error(_) | Token::Label(_) => None,
         }
     }
 }
\ No newline at end of file
@@ -46,16 +46,22 @@ impl Iterator for TokenInformationIterator {
 
         let next_token = match self.index {
             Token::If => Token::Else,
-            Token::Else => Token::Let,
+            Token::Else => Token::Match,
+            Token::Match => Token::Let,
             Token::Let => Token::Mut,
             Token::Mut => Token::Module,
-            Token::Module => Token::While,
+            Token::Module => Token::Pub,
+            Token::Pub => Token::While,
             Token::While => Token::Return,
-            Token::Return => Token::Extern,
+            Token::Return => Token::Break,
+            Token::Break => Token::Continue,
+            Token::Continue => Token::Extern,
             Token::Extern => Token::True,
             Token::True => Token::False,
             Token::False => Token::Struct,
-            Token::Struct => Token::Fn,
+            Token::Struct => Token::Enum,
+            Token::Enum => Token::Impl,
+            Token::Impl => Token::Fn,
             Token::Fn => Token::For,
             Token::For => Token::ParenthesisOpen,
             Token::ParenthesisOpen => Token::ParenthesisClose,
@@ -65,9 +71,24 @@ impl Iterator for TokenInformationIterator {
             Token::SquareBracketOpen => Token::SquareBracketClose,
             Token::SquareBracketClose => Token::SemiColon,
             Token::SemiColon => Token::Comma,
-            Token::Comma => Token::Colon,
-            Token::Colon => Token::Dot,
-            Token::Dot => Token::Plus,
+            Token::Comma => Token::DoubleColon,
+            Token::DoubleColon => Token::Colon,
+            // ranges are tried before `.`, so `1..5` isn't lexed as two dots
+            Token::Colon => Token::DotDotEquals,
+            Token::DotDotEquals => Token::DotDot,
+            Token::DotDot => Token::Dot,
+            // compound assignments are tried before their operators, so `+=` isn't lexed as `+` and `=`
+            Token::Dot => Token::LeftShiftEquals,
+            Token::LeftShiftEquals => Token::RightShiftEquals,
+            Token::RightShiftEquals => Token::PlusEquals,
+            Token::PlusEquals => Token::MinusEquals,
+            Token::MinusEquals => Token::MultiplyEquals,
+            Token::MultiplyEquals => Token::DivideEquals,
+            Token::DivideEquals => Token::ModuloEquals,
+            Token::ModuloEquals => Token::AmpersandEquals,
+            Token::AmpersandEquals => Token::PipeEquals,
+            Token::PipeEquals => Token::XorEquals,
+            Token::XorEquals => Token::Plus,
             Token::Plus => Token::Minus,
             Token::Minus => Token::Multiply,
             Token::Multiply => Token::Divide,
@@ -76,7 +97,8 @@ impl Iterator for TokenInformationIterator {
             Token::NotEquals => Token::LogicalAnd,
             Token::LogicalAnd => Token::LogicalOr,
             Token::LogicalOr => Token::LogicalNot,
-            Token::LogicalNot => Token::Pipe,
+            Token::LogicalNot => Token::Tilde,
+            Token::Tilde => Token::Pipe,
             Token::Pipe => Token::Xor,
             Token::Xor => Token::GreaterThanEquals,
             Token::GreaterThanEquals => Token::LessThanEquals,
@@ -86,12 +108,13 @@ impl Iterator for TokenInformationIterator {
             Token::LessThan => Token::GreaterThan,
             Token::GreaterThan => Token::Underscore,
             Token::Underscore => Token::Ampersand,
-            Token::Ampersand => Token::EqualsEquals,
+            Token::Ampersand => Token::FatArrow,
+            Token::FatArrow => Token::EqualsEquals,
             Token::EqualsEquals => Token::Equals,
             Token::Equals => Token::Function,
             Token::Function => Token::Numbers("".to_string()),
             Token::Numbers(_) => Token::Literal("".to_string()),
-            Token::Literal(_) => Token::If,
+            Token::Literal(_) | Token::Error(_) | Token::Label(_) => Token::If,
         };
 
         self.index = next_token.clone();
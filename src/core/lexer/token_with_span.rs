@@ -1,12 +1,16 @@
 use std::fmt::{Display, Formatter};
-use std::ops::RangeInclusive;
+use std::ops::{Range, RangeInclusive};
+use crate::core::io::source_map::FileId;
 use crate::core::lexer::token::Token;
 
 /// A struct representing a span in a file.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct FilePosition {
     pub line: RangeInclusive<i32>,
-    pub column: RangeInclusive<i32>
+    pub column: RangeInclusive<i32>,
+    /// The byte offsets of the span inside the source code
+    pub offset: Range<usize>,
+    pub file_id: FileId,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -14,12 +18,32 @@ pub struct TokenWithSpan {
     /// The token.
     pub token: Token,
     /// The span of the token.
-    pub span: FilePosition
+    pub span: FilePosition,
+    /// The whitespace and comments around the token. Only collected by the lossless tokenizer
+    pub trivia: Option<Box<Trivia>>,
+}
+
+/// Whitespace and comments surrounding a token
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Trivia {
+    /// Everything between the previous token and this token, which isn't trailing trivia of the previous token
+    pub leading: String,
+    /// Everything after the token up to the end of its line
+    pub trailing: String,
 }
 
 impl TokenWithSpan {
-    pub fn new(token: Token, min: i32, max: i32) -> TokenWithSpan {
-        TokenWithSpan { token, span: FilePosition { line: min..=max, column: min..=max } }
+    /// Creates a token, which is placed in a single line
+    pub fn new(token: Token, line: i32, column: RangeInclusive<i32>) -> TokenWithSpan {
+        TokenWithSpan { token, span: FilePosition { line: line..=line, column, ..Default::default() }, trivia: None }
+    }
+
+    /// Returns the source code of the token including its trivia
+    pub fn to_source(&self) -> String {
+        match &self.trivia {
+            Some(trivia) => format!("{}{}{}", trivia.leading, self.token, trivia.trailing),
+            None => self.token.to_string(),
+        }
     }
 }
 
@@ -27,7 +51,9 @@ impl FilePosition {
     pub fn from_min_max(min: &TokenWithSpan, max: &TokenWithSpan) -> FilePosition {
         FilePosition {
             line: *min.span.line.start()..=*max.span.line.end(),
-            column: *min.span.column.start()..=*max.span.column.end()
+            column: *min.span.column.start()..=*max.span.column.end(),
+            offset: min.span.offset.start..max.span.offset.end.max(min.span.offset.start),
+            file_id: min.span.file_id,
         }
     }
 }
@@ -36,7 +62,9 @@ impl Default for FilePosition {
     fn default() -> Self {
         Self {
             line: 0..=0,
-            column: 0..=0
+            column: 0..=0,
+            offset: 0..0,
+            file_id: FileId::default(),
         }
     }
 }
@@ -55,7 +83,6 @@ impl Display for FilePosition {
             format!("{} ({} chars{})", self.column.start(), (self.column.end() - self.column.start()) + 1, line_break_information)
         };
 
-
         write!(f, "{}:{}", self.line.start(), column)
     }
 }
@@ -65,4 +92,3 @@ impl Display for TokenWithSpan {
         write!(f, "`{}` at line {}", self.token, self.span)
     }
 }
-
@@ -1,41 +1,126 @@
+use crate::core::io::source_map::FileId;
 use crate::core::lexer::error::Error;
-use crate::core::lexer::semantic_token_merge::semantic_token_merge;
 use crate::core::lexer::token::Token;
-use crate::core::lexer::token_with_span::{FilePosition, TokenWithSpan};
+use crate::core::lexer::token_with_span::{FilePosition, TokenWithSpan, Trivia};
 
-pub fn tokenize(string: &str) -> Result<Vec<TokenWithSpan>, Error> {
-    let token = semantic_token_merge(&collect_greedy(string)?)?;
-    Ok(token)
-}
-
-/// Collects tokens from a string greedily.
-///
-/// # Arguments
+/// Tokenizes the source code of a file without losing anything and without stopping at the first error. Every span points into the file
+/// with the provided id. Whitespace and comments are kept as [`Trivia`] of the surrounding tokens and invalid characters as [`Token::Error`],
+/// so the parser can continue and concatenating [`TokenWithSpan::to_source`] of every token gives back the source code byte for byte.
 ///
-/// * `string`: &str - The string to collect tokens from.
+/// Source code without any token has nothing to attach its trivia to and results in an empty token stream
 ///
-/// returns: Result<Vec<Token>, Error>
+/// returns: the tokens and all lexical errors in the order of their appearance
+pub fn tokenize_lossless(string: &str, file_id: FileId) -> (Vec<TokenWithSpan>, Vec<Error>) {
+    let (mut tokens, errors) = collect_greedy_recovering(string, file_id);
+    let mut previous_end = 0;
+
+    for index in 0..tokens.len() {
+        let between = &string[previous_end..tokens[index].span.offset.start];
+        let (trailing, leading) = if index == 0 {
+            ("", between)
+        } else {
+            between.split_at(trailing_trivia_len(between))
+        };
+
+        if index > 0 {
+            tokens[index - 1].trivia.get_or_insert_with(Default::default).trailing = trailing.to_string();
+        }
+
+        tokens[index].trivia = Some(Box::new(Trivia { leading: leading.to_string(), trailing: String::new() }));
+        previous_end = tokens[index].span.offset.end;
+    }
+
+    if let Some(last) = tokens.last_mut() {
+        last.trivia.get_or_insert_with(Default::default).trailing = string[previous_end..].to_string();
+    }
+
+    (tokens, errors)
+}
+
+/// The trailing trivia of a token ends before the next line break, which isn't part of a comment
+fn trailing_trivia_len(trivia: &str) -> usize {
+    let mut length = 0;
+
+    while length < trivia.len() {
+        let rest = &trivia[length..];
+
+        if rest.starts_with('\n') {
+            break;
+        }
+
+        if rest.starts_with("//") {
+            return length + rest.find('\n').unwrap_or(rest.len());
+        }
+
+        if rest.starts_with("/*") {
+            // same rules as `skip_comment`. `/` and `*` are ascii, so stepping over bytes is fine
+            let bytes = rest.as_bytes();
+            let mut depth = 0;
+            let mut offset = 0;
+            let mut comment_length = rest.len();
+
+            while offset < bytes.len() {
+                match (bytes[offset], bytes.get(offset + 1)) {
+                    (b'/', Some(b'*')) => {
+                        depth += 1;
+                        offset += 2;
+                    }
+                    (b'*', Some(b'/')) => {
+                        depth -= 1;
+                        offset += 2;
+
+                        if depth == 0 {
+                            comment_length = offset;
+                            break;
+                        }
+                    }
+                    _ => offset += 1,
+                }
+            }
+
+            // a multi line comment starts the leading trivia of the next token
+            if rest[..comment_length].contains('\n') {
+                break;
+            }
+
+            length += comment_length;
+            continue;
+        }
+
+        length += rest.chars().next().map_or(1, char::len_utf8);
+    }
+
+    length
+}
+
+/// Collects tokens from a string greedily and keeps going after an invalid character.
+/// An unterminated block comment swallows the rest of the source code and therefore ends the collection
 ///
 /// # Examples
 ///
 /// ```
-/// use monkey_language::core::lexer::error::Error;
-/// use monkey_language::core::lexer::tokenizer::collect_greedy;
+/// use monkey_language::core::io::source_map::FileId;
+/// use monkey_language::core::lexer::tokenizer::collect_greedy_recovering;
 /// use monkey_language::core::lexer::token::Token;
 /// use monkey_language::core::lexer::token_with_span::FilePosition;
 ///
-/// let tokens = collect_greedy("let a = 10;")?.iter().map(|token| token.token.clone()).collect::<Vec<_>>();
-/// assert_eq!(tokens, vec![Token::Let, Token::Literal("a".to_string()), Token::Equals, Token::Numbers("10".to_string()), Token::SemiColon]);
-/// let span = collect_greedy("let a = 10;")?.iter().map(|token| token.span.clone()).collect::<Vec<_>>();
-/// assert_eq!(span[0], FilePosition { line: 1..=1, column: 1..=3 });
-/// # Ok::<(), Error>(())
+/// let (tokens, errors) = collect_greedy_recovering("let a = 10;", FileId::default());
+/// assert!(errors.is_empty());
+/// assert_eq!(tokens.iter().map(|token| token.token.clone()).collect::<Vec<_>>(), vec![Token::Let, Token::Literal("a".to_string()), Token::Equals, Token::Numbers("10".to_string()), Token::SemiColon]);
+/// assert_eq!(tokens[0].span, FilePosition { line: 1..=1, column: 1..=3, offset: 0..3, ..Default::default() });
 /// ```
-pub fn collect_greedy(string: &str) -> Result<Vec<TokenWithSpan>, Error> {
+pub fn collect_greedy_recovering(string: &str, file_id: FileId) -> (Vec<TokenWithSpan>, Vec<Error>) {
     let mut tokens = vec![];
+    let mut errors = vec![];
     let mut index = 0;
     let mut line = 1;
     let mut column = 1;
     let chars = string.chars().collect::<Vec<_>>();
+    // byte offset of every char, including the end of the source code
+    let byte_offsets = string.char_indices()
+        .map(|(offset, _)| offset)
+        .chain(std::iter::once(string.len()))
+        .collect::<Vec<_>>();
 
     while index < chars.len() {
         if chars[index] == '\n' {
@@ -51,7 +136,26 @@ pub fn collect_greedy(string: &str) -> Result<Vec<TokenWithSpan>, Error> {
             continue;
         }
 
+        let comment_start = index;
+        match skip_comment(&chars, &mut index, &mut line, &mut column) {
+            Ok(true) => continue,
+            Ok(false) => {}
+            Err(Error::UnterminatedComment(position)) => {
+                errors.push(Error::UnterminatedComment(FilePosition {
+                    offset: byte_offsets[comment_start]..byte_offsets[comment_start + 2],
+                    file_id,
+                    ..position
+                }));
+                break;
+            }
+            Err(error) => {
+                errors.push(error);
+                break;
+            }
+        }
+
         let start_token = column;
+        let start_line = line;
         let token_target = Token::iter();
         let mut found = false;
 
@@ -59,6 +163,8 @@ pub fn collect_greedy(string: &str) -> Result<Vec<TokenWithSpan>, Error> {
             let mut collected = String::new();
             let before_collect_index = index;
             let before_collect_column = column;
+            let before_collect_line = line;
+            let mut is_label = false;
 
             // check if the next char is a normal letter
             // if in literal mode -> literal mode, if first char is a letter
@@ -71,19 +177,20 @@ pub fn collect_greedy(string: &str) -> Result<Vec<TokenWithSpan>, Error> {
             // token length is not always known. for expected tokens like if, let, mut, etc. we know the length
             if let Some(token_length) = token_information.token_length {
                 while collected.len() < token_length && index < chars.len() {
+                    // keywords and operators never contain whitespace
                     if chars[index].is_whitespace() {
-                        if collected.is_empty() {
-                            return Err(Error::InvalidCharacter(chars[index]));
-                        }
-
-                        index += 1;
-                        continue;
+                        break;
                     }
 
                     collected.push(chars[index]);
                     index += 1;
                     column += 1;
                 }
+            } else if let Some(label_length) = label_length(&chars[index..]) {
+                collected.extend(&chars[index..index + label_length]);
+                index += label_length;
+                column += label_length as i32;
+                is_label = true;
             } else {
                 // but for literals we don't know the length; so we need to collect until we find a single character token or whitespace
                 'outer: while 
@@ -95,16 +202,31 @@ pub fn collect_greedy(string: &str) -> Result<Vec<TokenWithSpan>, Error> {
                     index += 1;
                     column += 1;
 
-                    if collected.starts_with("\"") {
+                    // raw strings (r"...") don't know escape sequences, so the first quote closes them
+                    let is_raw_string = collected == "r\"";
+                    let closing_quote = if collected.starts_with('\'') { '\'' } else { '"' };
+                    if collected.starts_with(['"', '\'']) || is_raw_string {
                         while index < chars.len() {
                             collected.push(chars[index]);
-                            if collected.ends_with("\"") {
+                            index += 1;
+                            column += 1;
+
+                            // strings can span multiple lines
+                            if chars[index - 1] == '\n' {
+                                line += 1;
+                                column = 1;
+                            }
+
+                            if collected.ends_with(closing_quote) {
+                                break 'outer;
+                            }
+
+                            // keep the escaped character, so an escaped quote doesn't end the string
+                            if !is_raw_string && chars[index - 1] == '\\' && index < chars.len() {
+                                collected.push(chars[index]);
                                 index += 1;
                                 column += 1;
-                                break 'outer;
                             }
-                            index += 1;
-                            column += 1;
                         }
                     }
                 }
@@ -125,8 +247,9 @@ pub fn collect_greedy(string: &str) -> Result<Vec<TokenWithSpan>, Error> {
                 true
             };
 
-            if token_information.matches(&collected) && whole_literal_captured {
+            if !collected.is_empty() && token_information.matches(&collected) && whole_literal_captured {
                 let token = match token_information.token {
+                    Token::Literal(_) if is_label => Token::Label(collected[1..].to_string()),
                     Token::Literal(_) => Token::Literal(collected),
                     Token::Numbers(_) => Token::Numbers(collected),
                     _ => token_information.token,
@@ -135,9 +258,12 @@ pub fn collect_greedy(string: &str) -> Result<Vec<TokenWithSpan>, Error> {
                 tokens.push(TokenWithSpan {
                     token,
                     span: FilePosition {
-                        line: line..=line,
+                        line: start_line..=line,
                         column: start_token..=end_column,
+                        offset: byte_offsets[before_collect_index]..byte_offsets[index],
+                        file_id,
                     },
+                    trivia: None,
                 });
 
                 found = true;
@@ -145,15 +271,95 @@ pub fn collect_greedy(string: &str) -> Result<Vec<TokenWithSpan>, Error> {
             } else {
                 index = before_collect_index;
                 column = before_collect_column;
+                line = before_collect_line;
             }
         }
 
         if !found {
-            return Err(Error::InvalidCharacter(chars[index]));
+            let span = FilePosition {
+                line: line..=line,
+                column: column..=column,
+                offset: byte_offsets[index]..byte_offsets[index + 1],
+                file_id,
+            };
+
+            errors.push(Error::InvalidCharacter(chars[index], span.clone()));
+            tokens.push(TokenWithSpan { token: Token::Error(chars[index].to_string()), span, trivia: None });
+
+            index += 1;
+            column += 1;
         }
     }
 
-    Ok(tokens)
+    (tokens, errors)
+}
+
+/// Skips a `//` line comment or a (nestable) `/* */` block comment starting at `index`.
+/// The newline ending a line comment is left untouched, so the line counting stays in one place.
+///
+/// returns: Ok(true), if a comment was skipped
+fn skip_comment(chars: &[char], index: &mut usize, line: &mut i32, column: &mut i32) -> Result<bool, Error> {
+    match (chars.get(*index), chars.get(*index + 1)) {
+        (Some('/'), Some('/')) => {
+            while *index < chars.len() && chars[*index] != '\n' {
+                *index += 1;
+                *column += 1;
+            }
+
+            Ok(true)
+        }
+        (Some('/'), Some('*')) => {
+            let start = FilePosition { line: *line..=*line, column: *column..=*column + 1, ..Default::default() };
+            let mut depth = 0;
+
+            while *index < chars.len() {
+                match (chars[*index], chars.get(*index + 1)) {
+                    ('/', Some('*')) => {
+                        depth += 1;
+                        *index += 2;
+                        *column += 2;
+                    }
+                    ('*', Some('/')) => {
+                        depth -= 1;
+                        *index += 2;
+                        *column += 2;
+
+                        if depth == 0 {
+                            return Ok(true);
+                        }
+                    }
+                    ('\n', _) => {
+                        *line += 1;
+                        *column = 1;
+                        *index += 1;
+                    }
+                    _ => {
+                        *index += 1;
+                        *column += 1;
+                    }
+                }
+            }
+
+            Err(Error::UnterminatedComment(start))
+        }
+        _ => Ok(false)
+    }
+}
+
+/// Returns the amount of chars of a loop label like `'outer`, if one starts at the beginning of `chars`.
+/// A label is a quote followed by an identifier, which isn't closed by another quote like a char literal (`'a'`)
+fn label_length(chars: &[char]) -> Option<usize> {
+    if chars.first() != Some(&'\'') || !chars.get(1).is_some_and(|c| c.is_alphabetic() || *c == '_') {
+        return None;
+    }
+
+    let length = 1 + chars[1..].iter().take_while(|c| c.is_alphanumeric() || **c == '_').count();
+
+    if chars.get(length) == Some(&'\'') {
+        None
+    } else {
+        Some(length)
+    }
 }
 
 fn done_collecting_literal(target_char: Option<&char>) -> bool {
@@ -162,7 +368,7 @@ fn done_collecting_literal(target_char: Option<&char>) -> bool {
         if next_char.is_whitespace() {
             true
         } else {
-            !(next_char.is_alphanumeric() || *next_char == '_' || *next_char == '$' || *next_char == '"')
+            !(next_char.is_alphanumeric() || *next_char == '_' || *next_char == '$' || *next_char == '"' || *next_char == '\'')
         }
     } else {
         true
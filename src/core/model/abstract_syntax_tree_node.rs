@@ -1,12 +1,18 @@
 use std::fmt::{Display, Formatter};
 use crate::core::lexer::token_with_span::FilePosition;
 use crate::core::model::abstract_syntax_tree_nodes::assignables::method_call::MethodCall;
+use crate::core::model::abstract_syntax_tree_nodes::break_::Break;
+use crate::core::model::abstract_syntax_tree_nodes::continue_::Continue;
+use crate::core::model::abstract_syntax_tree_nodes::enum_::Enum;
 use crate::core::model::abstract_syntax_tree_nodes::for_::For;
 use crate::core::model::abstract_syntax_tree_nodes::if_::If;
+use crate::core::model::abstract_syntax_tree_nodes::impl_::Impl;
 use crate::core::model::abstract_syntax_tree_nodes::import::Import;
+use crate::core::model::abstract_syntax_tree_nodes::match_::Match;
 use crate::core::model::abstract_syntax_tree_nodes::method_definition::MethodDefinition;
 use crate::core::model::abstract_syntax_tree_nodes::ret::Return;
 use crate::core::model::abstract_syntax_tree_nodes::struct_::Struct;
+use crate::core::model::abstract_syntax_tree_nodes::syntax_error::SyntaxError;
 use crate::core::model::abstract_syntax_tree_nodes::variable::Variable;
 use crate::core::model::abstract_syntax_tree_nodes::while_::While;
 
@@ -16,13 +22,20 @@ use crate::core::model::abstract_syntax_tree_nodes::while_::While;
 pub enum AbstractSyntaxTreeNode {
     Variable(Variable<'=', ';'>),
     StructDefinition(Struct),
+    EnumDefinition(Enum),
+    /// only exists while parsing, the methods are lowered into method definitions by the [crate::core::parser::ast_parser::ASTParser]
+    Impl(Impl),
     MethodCall(MethodCall),
     MethodDefinition(MethodDefinition),
     Import(Import),
     Return(Return),
     If(If),
+    Match(Match),
     For(For),
     While(While),
+    Break(Break),
+    Continue(Continue),
+    SyntaxError(SyntaxError),
 }
 
 impl AbstractSyntaxTreeNode {
@@ -31,12 +44,18 @@ impl AbstractSyntaxTreeNode {
             AbstractSyntaxTreeNode::Variable(node) => node.file_position.clone(),
             AbstractSyntaxTreeNode::MethodCall(node) => node.file_position.clone(),
             AbstractSyntaxTreeNode::StructDefinition(node) => node.file_position.clone(),
+            AbstractSyntaxTreeNode::EnumDefinition(node) => node.file_position.clone(),
+            AbstractSyntaxTreeNode::Impl(node) => node.file_position.clone(),
             AbstractSyntaxTreeNode::MethodDefinition(node) => node.file_position.clone(),
             AbstractSyntaxTreeNode::If(node) => node.file_position.clone(),
+            AbstractSyntaxTreeNode::Match(node) => node.file_position.clone(),
             AbstractSyntaxTreeNode::Import(node) => node.file_position.clone(),
             AbstractSyntaxTreeNode::Return(node) => node.file_position.clone(),
             AbstractSyntaxTreeNode::While(node) => node.file_position.clone(),
             AbstractSyntaxTreeNode::For(node) => node.file_position.clone(),
+            AbstractSyntaxTreeNode::Break(node) => node.file_position.clone(),
+            AbstractSyntaxTreeNode::Continue(node) => node.file_position.clone(),
+            AbstractSyntaxTreeNode::SyntaxError(node) => node.file_position.clone(),
         }
     }
 }
@@ -56,11 +75,17 @@ impl Display for AbstractSyntaxTreeNode {
             AbstractSyntaxTreeNode::MethodCall(node) => write!(f, "{:width$}", node, width = ident),
             AbstractSyntaxTreeNode::MethodDefinition(node) => write!(f, "{:width$}", node, width = ident),
             AbstractSyntaxTreeNode::If(node) => write!(f, "{:width$}", node, width = ident),
+            AbstractSyntaxTreeNode::Match(node) => write!(f, "{:width$}", node, width = ident),
             AbstractSyntaxTreeNode::Import(node) => write!(f, "{:width$}", node, width = ident),
             AbstractSyntaxTreeNode::Return(node) => write!(f, "{:width$}", node, width = ident),
             AbstractSyntaxTreeNode::While(node) => write!(f, "{:width$}", node, width = ident),
             AbstractSyntaxTreeNode::For(node) => write!(f, "{:width$}", node, width = ident),
+            AbstractSyntaxTreeNode::Break(node) => write!(f, "{:width$}", node, width = ident),
+            AbstractSyntaxTreeNode::Continue(node) => write!(f, "{:width$}", node, width = ident),
             AbstractSyntaxTreeNode::StructDefinition(node) => write!(f, "{:width$}", node, width = ident),
+            AbstractSyntaxTreeNode::EnumDefinition(node) => write!(f, "{:width$}", node, width = ident),
+            AbstractSyntaxTreeNode::Impl(node) => write!(f, "{:width$}", node, width = ident),
+            AbstractSyntaxTreeNode::SyntaxError(node) => write!(f, "{:width$}", node, width = ident),
         }
     }
 }
\ No newline at end of file
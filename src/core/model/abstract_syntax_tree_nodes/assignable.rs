@@ -1,4 +1,6 @@
+use crate::core::model::abstract_syntax_tree_nodes::assignables::enum_path::EnumPath;
 use crate::core::model::abstract_syntax_tree_nodes::assignables::equation_parser::expression::Expression;
+use crate::core::model::abstract_syntax_tree_nodes::assignables::field_access::FieldAccess;
 use crate::core::model::abstract_syntax_tree_nodes::assignables::method_call::MethodCall;
 use crate::core::model::abstract_syntax_tree_nodes::assignables::object::Object;
 use crate::core::model::abstract_syntax_tree_nodes::identifier::Identifier;
@@ -10,7 +12,8 @@ use crate::core::model::types::integer::IntegerAST;
 use crate::core::model::types::static_string::StaticString;
 use std::fmt::{Display, Formatter};
 
-/// AST node for assignable abstract_syntax_tree_nodes. Numbers, strings, method calls, other variables, objects, and arithmetic / boolean equations.
+/// AST node for assignable abstract_syntax_tree_nodes. Numbers, strings, method calls, other variables, objects, fields of structs, enum variants, and arithmetic / boolean equations.
+/// `Uninitialized` is the missing value of a declaration like `let mut a: i32;`
 #[derive(Debug, PartialEq, Clone)]
 pub enum Assignable {
     String(StaticString),
@@ -21,8 +24,11 @@ pub enum Assignable {
     MethodCall(MethodCall),
     Identifier(Identifier),
     Object(Object),
+    EnumPath(EnumPath),
+    FieldAccess(FieldAccess),
     Array(Array),
     Expression(Expression),
+    Uninitialized,
 }
 
 impl Display for Assignable {
@@ -37,9 +43,12 @@ impl Display for Assignable {
             Assignable::MethodCall(node) => format!("{:width$}", node),
             Assignable::Identifier(node) => format!("{:width$}", node),
             Assignable::Object(node) => format!("{:width$}", node),
+            Assignable::EnumPath(node) => format!("{:width$}", node),
+            Assignable::FieldAccess(node) => format!("{:width$}", node),
             Assignable::Expression(node) => format!("{:width$}", node),
             Assignable::Parameter(node) => format!("{:width$}", node),
             Assignable::Array(node) => format!("{:width$}", node),
+            Assignable::Uninitialized => String::new(),
         })
     }
 }
@@ -0,0 +1,18 @@
+use std::fmt::{Display, Formatter};
+use crate::core::model::abstract_syntax_tree_nodes::identifier::Identifier;
+use crate::core::model::types::ty::Type;
+
+/// AST node for a variant of an enum used as a value
+/// # Pattern
+/// - `EnumName::VariantName`
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct EnumPath {
+    pub ty: Type,
+    pub variant: Identifier,
+}
+
+impl Display for EnumPath {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}::{}", self.ty, self.variant)
+    }
+}
@@ -22,6 +22,10 @@ pub enum Operator {
     BitwiseOr,
     LogicalAnd,
     LogicalOr,
+    /// unary `!`
+    LogicalNot,
+    /// unary `~`
+    BitwiseNot,
 }
 
 impl Display for Operator {
@@ -46,6 +50,8 @@ impl Display for Operator {
             Operator::LogicalAnd => "&&",
             Operator::LogicalOr => "||",
             Operator::Mod => "%",
+            Operator::LogicalNot => "!",
+            Operator::BitwiseNot => "~",
         })
     }
 }
\ No newline at end of file
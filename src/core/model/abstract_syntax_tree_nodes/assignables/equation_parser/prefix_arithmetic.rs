@@ -4,9 +4,8 @@ use crate::core::model::types::ty::Type;
 
 #[derive(Clone, PartialEq, Debug)]
 pub enum PrefixArithmetic {
-    #[allow(unused)]
+    /// A unary operator like `-a`, `!a` or `~a`
     Operation(Operator),
-    // For example the "-" like let a = -5;
     PointerArithmetic(PointerArithmetic),
     Cast(Type),
 }
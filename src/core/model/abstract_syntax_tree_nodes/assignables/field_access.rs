@@ -0,0 +1,19 @@
+use std::fmt::{Display, Formatter};
+use crate::core::model::abstract_syntax_tree_nodes::assignable::Assignable;
+use crate::core::model::abstract_syntax_tree_nodes::identifier::Identifier;
+
+/// AST node for reading or writing a field of a struct. Pointers to structs are dereferenced automatically
+/// # Pattern
+/// - `person.age`
+/// - `person.mother.height`
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct FieldAccess {
+    pub value: Box<Assignable>,
+    pub field: Identifier,
+}
+
+impl Display for FieldAccess {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}", self.value, self.field)
+    }
+}
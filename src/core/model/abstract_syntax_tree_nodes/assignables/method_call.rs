@@ -1,20 +1,31 @@
 use crate::core::lexer::token_with_span::FilePosition;
 use crate::core::model::abstract_syntax_tree_nodes::assignable::{Assignable};
 use crate::core::model::abstract_syntax_tree_nodes::l_value::LValue;
+use crate::core::model::types::ty::Type;
 use std::fmt::{Display, Formatter};
 
 #[derive(Debug, PartialEq, Clone, Default)]
 pub struct MethodCall {
+    /// The value before the dot of a call like `person.greet()`. After the type inference it is passed as the first argument
+    pub receiver: Option<Box<Assignable>>,
     pub identifier: LValue,
+    /// the explicit type arguments of a generic call like `max::<i32>(a, b)`. Without them, they are inferred from the arguments
+    pub type_arguments: Vec<Type>,
     pub arguments: Vec<Assignable>,
     pub file_position: FilePosition,
 }
 
 impl Display for MethodCall {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}{}({})",
-               " ".repeat(f.width().unwrap_or(0)), 
-               self.identifier, 
+        write!(f, "{}{}{}{}({})",
+               " ".repeat(f.width().unwrap_or(0)),
+               self.receiver.as_ref().map(|receiver| format!("{receiver}.")).unwrap_or_default(),
+               self.identifier,
+               if self.type_arguments.is_empty() {
+                   String::new()
+               } else {
+                   format!("::<{}>", self.type_arguments.iter().map(|ty| ty.to_string()).collect::<Vec<String>>().join(", "))
+               },
                self.arguments
                    .iter()
                    .map(|ass| format!("{}", ass))
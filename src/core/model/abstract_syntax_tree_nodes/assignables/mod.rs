@@ -1,3 +1,5 @@
 pub mod equation_parser;
 pub mod method_call;
 pub mod object;
+pub mod enum_path;
+pub mod field_access;
@@ -0,0 +1,15 @@
+use std::fmt::{Display, Formatter};
+use crate::core::lexer::token_with_span::FilePosition;
+
+/// Jumps to the next iteration of the innermost loop or the loop with the provided label
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct Continue {
+    pub label: Option<String>,
+    pub file_position: FilePosition,
+}
+
+impl Display for Continue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}continue{}", " ".repeat(f.width().unwrap_or(0)), self.label.as_ref().map_or(String::new(), |label| format!(" '{label}")))
+    }
+}
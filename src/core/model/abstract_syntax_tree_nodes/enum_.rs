@@ -0,0 +1,83 @@
+use std::fmt::{Display, Formatter};
+use crate::core::lexer::token_with_span::FilePosition;
+use crate::core::model::abstract_syntax_tree_nodes::identifier::Identifier;
+use crate::core::model::types::integer::IntegerAST;
+use crate::core::model::types::ty::Type;
+
+
+/// AST node for a variant of an enum
+/// # Pattern
+/// - `VariantName`
+/// - `VariantName = 5`
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct EnumVariant {
+    pub name: Identifier,
+    pub discriminant: Option<IntegerAST>,
+}
+
+
+/// AST node for enum definition
+/// # Pattern
+/// - `enum EnumName { Variant1, Variant2 = 5, ... }`
+/// - `pub enum EnumName { ... }`
+///   where variant is represented by [EnumVariant] struct
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct Enum {
+    pub ty: Type,
+    pub variants: Vec<EnumVariant>,
+    /// public enums are accessible from other modules
+    pub is_public: bool,
+    pub file_position: FilePosition,
+}
+
+impl Enum {
+    /// Returns the discriminant of the variant. A variant without an explicit discriminant
+    /// continues counting from the previous variant. The first variant starts at 0
+    pub fn discriminant(&self, variant_name: &str) -> Option<i64> {
+        let mut next_discriminant = 0;
+
+        for variant in &self.variants {
+            let discriminant = match &variant.discriminant {
+                Some(integer) => integer.value.parse::<i64>().ok()?,
+                None => next_discriminant,
+            };
+
+            if variant.name.name == variant_name {
+                return Some(discriminant);
+            }
+
+            next_discriminant = discriminant.checked_add(1)?;
+        }
+
+        None
+    }
+}
+
+impl Display for Enum {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let ident: usize = f.width().unwrap_or(0);
+
+        writeln!(f, "{}{}enum {} {{", " ".repeat(ident), if self.is_public { "pub " } else { "" }, self.ty)?;
+
+        for (i, variant) in self.variants.iter().enumerate() {
+            if i < self.variants.len() - 1 {
+                writeln!(f, "{:width$}{},", "", variant, width = ident + 4)?;
+            } else {
+                writeln!(f, "{:width$}{}", "", variant, width = ident + 4)?;
+            }
+        }
+
+        write!(f, "{}}}", " ".repeat(ident))?;
+
+        Ok(())
+    }
+}
+
+impl Display for EnumVariant {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.discriminant {
+            Some(discriminant) => write!(f, "{} = {}", self.name, discriminant),
+            None => write!(f, "{}", self.name),
+        }
+    }
+}
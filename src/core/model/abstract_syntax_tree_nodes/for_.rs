@@ -6,6 +6,8 @@ use std::fmt::{Display, Formatter};
 
 #[derive(Debug, PartialEq, Clone, Default)]
 pub struct For {
+    /// The optional label of the loop, e.g. `outer` for `'outer: for`
+    pub label: Option<String>,
     pub initialization: Variable<'=', ';'>,
     pub condition: Assignable,
     pub update: Variable<'=', ';'>,
@@ -16,7 +18,8 @@ pub struct For {
 impl Display for For {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         let ident: usize = f.width().unwrap_or(0);
-        writeln!(f, "{}for ({} {}; {}) {{", " ".repeat(ident), self.initialization, self.condition, self.update)?;
+        let label = self.label.as_ref().map_or(String::new(), |label| format!("'{label}: "));
+        writeln!(f, "{}{label}for ({} {}; {}) {{", " ".repeat(ident), self.initialization, self.condition, self.update)?;
 
         for node in &self.stack {
             writeln!(f, "{:width$}", node, width = ident + 4)?;
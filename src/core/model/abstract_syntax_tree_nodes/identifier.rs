@@ -1,10 +1,36 @@
+use std::cmp::Ordering;
 use std::fmt::{Display, Formatter};
+use std::hash::{Hash, Hasher};
+use crate::core::model::symbol_table::SymbolId;
 
 /// AST node for a name. Basically a string that can be used as a variable name.
 /// Everything is allowed except for reserved keywords and special characters in the beginning
-#[derive(Debug, Eq, PartialOrd, PartialEq, Default, Hash, Clone)]
+#[derive(Debug, Default, Clone)]
 pub struct Identifier {
     pub name: String,
+    /// the declaration the name refers to. It's resolved by the type inference, so later phases don't need to look up the name again
+    pub symbol: Option<SymbolId>,
+}
+
+/// Identifiers are compared by their name. The symbol is only resolved from the name
+impl PartialEq for Identifier {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+    }
+}
+
+impl Eq for Identifier {}
+
+impl PartialOrd for Identifier {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.name.partial_cmp(&other.name)
+    }
+}
+
+impl Hash for Identifier {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.name.hash(state);
+    }
 }
 
 impl Display for Identifier {
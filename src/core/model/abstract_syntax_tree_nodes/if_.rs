@@ -7,6 +7,7 @@ use std::fmt::{Display, Formatter};
 /// # Pattern
 /// - `if (condition) {Body}`
 /// - `if (condition) {Body} else {Body}`
+/// - `if (condition) {Body} else if (condition) {Body} else {Body}`, where the `else if` is the only node of the else stack
 #[derive(Debug, PartialEq, Clone, Default)]
 pub struct If {
     pub condition: Assignable,
@@ -24,7 +25,10 @@ impl Display for If {
             writeln!(f, "{:width$}{}", "", a, width = ident + 4)?;
         }
         write!(f, "{}}}", " ".repeat(ident))?;
-        if let Some(else_stack) = &self.else_stack {
+        if let Some([AbstractSyntaxTreeNode::If(else_if)]) = self.else_stack.as_deref() {
+            let else_if = format!("{:width$}", else_if, width = ident);
+            write!(f, " else {}", else_if.trim_start())?;
+        } else if let Some(else_stack) = &self.else_stack {
             writeln!(f, " else {{")?;
             for a in else_stack {
                 writeln!(f, "{:width$}{}", "", a, width = ident + 4)?;
@@ -0,0 +1,51 @@
+use std::fmt::{Display, Formatter};
+use crate::core::lexer::token_with_span::FilePosition;
+use crate::core::model::abstract_syntax_tree_nodes::identifier::Identifier;
+use crate::core::model::abstract_syntax_tree_nodes::l_value::LValue;
+use crate::core::model::abstract_syntax_tree_nodes::method_definition::MethodDefinition;
+use crate::core::model::types::ty::Type;
+
+
+/// AST node for methods of a struct
+/// # Pattern
+/// - `impl StructName { fn method(self: *StructName): void { ... } ... }`
+///
+/// The methods are lowered into free methods called `StructName::method`, see [Impl::mangled_methods].
+/// The receiver of a call like `person.greet()` is passed as the first argument
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct Impl {
+    pub ty: Type,
+    pub methods: Vec<MethodDefinition>,
+    pub file_position: FilePosition,
+}
+
+impl Impl {
+    /// The name of the method `method_name` defined for the type
+    pub fn mangled_name(ty: &Type, method_name: &str) -> String {
+        format!("{}::{method_name}", ty)
+    }
+
+    /// Returns the methods renamed into the namespace of the type
+    pub fn mangled_methods(self) -> Vec<MethodDefinition> {
+        let ty = self.ty;
+
+        self.methods.into_iter().map(|mut method_definition| {
+            method_definition.identifier = LValue::Identifier(Identifier { name: Self::mangled_name(&ty, &method_definition.identifier.identifier()), symbol: None });
+            method_definition
+        }).collect()
+    }
+}
+
+impl Display for Impl {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let ident: usize = f.width().unwrap_or(0);
+
+        writeln!(f, "{}impl {} {{", " ".repeat(ident), self.ty)?;
+
+        for method_definition in &self.methods {
+            writeln!(f, "{:width$}", method_definition, width = ident + 4)?;
+        }
+
+        write!(f, "{}}}", " ".repeat(ident))
+    }
+}
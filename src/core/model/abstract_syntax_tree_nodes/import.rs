@@ -1,29 +1,34 @@
 use std::fmt::{Display, Formatter};
-use crate::core::io::monkey_file::MonkeyFile;
+use std::path::PathBuf;
 use crate::core::lexer::token_with_span::FilePosition;
+use crate::core::model::abstract_syntax_tree_nodes::identifier::Identifier;
 
-#[derive(Debug, PartialEq, Clone)]
+/// AST node for an import of a module. The definitions of the module are accessible inside of its namespace.
+/// # Pattern
+/// - `module std;`
+/// - `module collections::list;`
+#[derive(Debug, PartialEq, Clone, Default)]
 pub struct Import {
-    pub monkey_file: MonkeyFile,
+    pub path: Vec<Identifier>,
     pub file_position: FilePosition
 }
 
+impl Import {
+    /// The namespace of the imported definitions, for example `collections::list`
+    pub fn namespace(&self) -> String {
+        self.path.iter().map(|segment| segment.name.as_str()).collect::<Vec<_>>().join("::")
+    }
 
-impl Display for Import {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        writeln!(f, "{}module {};", " ".repeat(f.width().unwrap_or(0)), self.monkey_file.path.display())
+    /// The path of the module file relative to the importing file or a search path, for example `collections/list.monkey`
+    pub fn relative_path(&self) -> PathBuf {
+        let mut path = self.path.iter().map(|segment| segment.name.as_str()).collect::<PathBuf>();
+        path.set_extension("monkey");
+        path
     }
 }
 
-impl Default for Import {
-    fn default() -> Self {
-        Import {
-            monkey_file: MonkeyFile {
-                path: Default::default(),
-                tokens: vec![],
-                size: 0,
-            },
-            file_position: Default::default(),
-        }
+impl Display for Import {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}module {};", " ".repeat(f.width().unwrap_or(0)), self.namespace())
     }
-}
\ No newline at end of file
+}
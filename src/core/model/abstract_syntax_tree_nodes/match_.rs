@@ -0,0 +1,80 @@
+use crate::core::lexer::token_with_span::FilePosition;
+use crate::core::model::abstract_syntax_tree_node::AbstractSyntaxTreeNode;
+use crate::core::model::abstract_syntax_tree_nodes::assignable::Assignable;
+use std::fmt::{Display, Formatter};
+
+/// AST node for match statement.
+/// # Pattern
+/// - `match (value) { pattern => {Body} pattern | pattern => {Body} _ => {Body} }`
+///   where each arm is represented by [MatchArm] struct
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct Match {
+    pub value: Assignable,
+    pub arms: Vec<MatchArm>,
+    pub file_position: FilePosition,
+}
+
+/// An arm of a match statement. The body is executed, if any of the patterns matches
+/// # Pattern
+/// - `pattern => {Body}`
+/// - `pattern | pattern => {Body}`
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct MatchArm {
+    pub patterns: Vec<MatchPattern>,
+    pub stack: Vec<AbstractSyntaxTreeNode>,
+    pub file_position: FilePosition,
+}
+
+/// A pattern of a match arm
+/// # Pattern
+/// - `5`, `true`, `Color::Red`
+/// - `1..5`, `1..=5`
+/// - `_`
+#[derive(Debug, PartialEq, Clone, Default)]
+pub enum MatchPattern {
+    /// an integer, boolean or enum variant literal
+    Literal(Assignable),
+    /// a range of integers. The end is only part of the range, if the range is inclusive
+    Range { start: Assignable, end: Assignable, inclusive: bool },
+    /// `_` matches every value
+    #[default]
+    Wildcard,
+}
+
+impl Display for Match {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let ident: usize = f.width().unwrap_or(0);
+        writeln!(f, "{}match ({}) {{", " ".repeat(ident), self.value)?;
+
+        for arm in &self.arms {
+            writeln!(f, "{:width$}", arm, width = ident + 4)?;
+        }
+
+        write!(f, "{}}}", " ".repeat(ident))
+    }
+}
+
+impl Display for MatchArm {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let ident: usize = f.width().unwrap_or(0);
+        let patterns = self.patterns.iter().map(|pattern| pattern.to_string()).collect::<Vec<_>>().join(" | ");
+        writeln!(f, "{}{patterns} => {{", " ".repeat(ident))?;
+
+        for a in &self.stack {
+            writeln!(f, "{:width$}{}", "", a, width = ident + 4)?;
+        }
+
+        write!(f, "{}}}", " ".repeat(ident))
+    }
+}
+
+impl Display for MatchPattern {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MatchPattern::Literal(literal) => write!(f, "{literal}"),
+            MatchPattern::Range { start, end, inclusive: true } => write!(f, "{start}..={end}"),
+            MatchPattern::Range { start, end, inclusive: false } => write!(f, "{start}..{end}"),
+            MatchPattern::Wildcard => write!(f, "_"),
+        }
+    }
+}
@@ -3,15 +3,21 @@ use crate::core::model::abstract_syntax_tree_node::AbstractSyntaxTreeNode;
 use crate::core::model::abstract_syntax_tree_nodes::l_value::LValue;
 use crate::core::model::types::ty::Type;
 use std::fmt::{Display, Formatter};
+use std::str::FromStr;
 
-/// AST node for method definition. Pattern is `fn function_name(argument1, ..., argumentN): returnType { }`
+/// AST node for method definition. Pattern is `fn function_name(argument1, ..., argumentN): returnType { }`, optionally prefixed by `pub`.
+/// Generic methods declare their type parameters after the name like `fn max<T: Numeric>(a: T, b: T): T { }`
 #[derive(Debug, PartialEq, Clone, Default)]
 pub struct MethodDefinition {
     pub identifier: LValue,
+    /// the type parameters of a generic method. Generic methods aren't compiled themselves, only their instances for the used type arguments
+    pub generic_parameters: Vec<GenericParameter>,
     pub return_type: Type,
     pub arguments: Vec<MethodArgument>,
     pub stack: Vec<AbstractSyntaxTreeNode>,
     pub is_extern: bool,
+    /// public methods are accessible from other modules
+    pub is_public: bool,
     pub file_position: FilePosition
 }
 
@@ -21,15 +27,85 @@ pub struct MethodArgument {
     pub ty: Type,
 }
 
+/// A type parameter of a generic method like `T` or `T: Numeric`
+#[derive(Debug, PartialEq, Clone)]
+pub struct GenericParameter {
+    pub name: String,
+    pub bound: Option<TypeBound>,
+}
+
+/// Restricts the types a type parameter can be replaced with, so the operations of the bound can be used on values of the type parameter
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum TypeBound {
+    /// integers and floats
+    Numeric,
+    Integer,
+    Float,
+}
+
+impl MethodDefinition {
+    pub fn is_generic(&self) -> bool {
+        !self.generic_parameters.is_empty()
+    }
+}
+
+impl TypeBound {
+    pub fn is_satisfied_by(&self, ty: &Type) -> bool {
+        match self {
+            TypeBound::Numeric => matches!(ty, Type::Integer(_, _) | Type::Float(_, _)),
+            TypeBound::Integer => matches!(ty, Type::Integer(_, _)),
+            TypeBound::Float => matches!(ty, Type::Float(_, _)),
+        }
+    }
+}
+
+impl FromStr for TypeBound {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Numeric" => Ok(TypeBound::Numeric),
+            "Integer" => Ok(TypeBound::Integer),
+            "Float" => Ok(TypeBound::Float),
+            _ => Err(format!("Unknown bound: {s}")),
+        }
+    }
+}
+
+impl Display for TypeBound {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", match self {
+            TypeBound::Numeric => "Numeric",
+            TypeBound::Integer => "Integer",
+            TypeBound::Float => "Float",
+        })
+    }
+}
+
+impl Display for GenericParameter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.bound {
+            Some(bound) => write!(f, "{}: {bound}", self.name),
+            None => write!(f, "{}", self.name),
+        }
+    }
+}
+
 
 impl Display for MethodDefinition {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         let ident = f.width().unwrap_or(0);
 
-        write!(f, "{}{}fn {}({}): {}{}",
+        write!(f, "{}{}{}fn {}{}({}): {}{}",
                " ".repeat(ident),
+               if self.is_public { "pub " } else { "" },
                if self.is_extern { "extern " } else { "" },
                self.identifier,
+               if self.is_generic() {
+                   format!("<{}>", self.generic_parameters.iter().map(|parameter| parameter.to_string()).collect::<Vec<String>>().join(", "))
+               } else {
+                   String::new()
+               },
                self.arguments
                    .iter()
                    .map(|argument| format!("{}: {}{}", argument.identifier, if argument.ty.mutable() { "mut" } else { "" }, argument.ty))
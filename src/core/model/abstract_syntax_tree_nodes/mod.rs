@@ -8,6 +8,12 @@ pub mod ret;
 pub mod method_definition;
 pub mod import;
 pub mod if_;
+pub mod match_;
 pub mod for_;
 pub mod while_;
-pub mod struct_;
\ No newline at end of file
+pub mod struct_;
+pub mod enum_;
+pub mod impl_;
+pub mod syntax_error;
+pub mod break_;
+pub mod continue_;
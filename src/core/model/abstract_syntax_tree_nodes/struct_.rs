@@ -17,11 +17,14 @@ pub struct Field {
 /// AST node for struct definition
 /// # Pattern
 /// - `struct StructName { field1: Type1, field2: Type2, ... }`
+/// - `pub struct StructName { ... }`
 ///   where field is represented by [Field] struct
 #[derive(Debug, PartialEq, Clone, Default)]
 pub struct Struct {
     pub ty: Type,
     pub fields: Vec<Field>,
+    /// public structs are accessible from other modules
+    pub is_public: bool,
     pub file_position: FilePosition,
 }
 
@@ -29,7 +32,7 @@ impl Display for Struct {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         let ident: usize = f.width().unwrap_or(0);
 
-        writeln!(f, "{}struct {} {{", " ".repeat(ident), self.ty)?;
+        writeln!(f, "{}{}struct {} {{", " ".repeat(ident), if self.is_public { "pub " } else { "" }, self.ty)?;
 
         for (i, field) in self.fields.iter().enumerate() {
             if i < self.fields.len() - 1 {
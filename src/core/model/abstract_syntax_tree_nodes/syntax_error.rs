@@ -0,0 +1,16 @@
+use std::fmt::{Display, Formatter};
+use crate::core::lexer::error::Error;
+use crate::core::lexer::token_with_span::FilePosition;
+
+/// A statement, which couldn't be parsed. The parser skips its tokens and continues with the next statement
+#[derive(Debug, PartialEq, Clone)]
+pub struct SyntaxError {
+    pub error: Error,
+    pub file_position: FilePosition,
+}
+
+impl Display for SyntaxError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}<syntax error>", " ".repeat(f.width().unwrap_or(0)))
+    }
+}
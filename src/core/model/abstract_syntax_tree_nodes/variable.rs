@@ -1,13 +1,16 @@
 use std::fmt::{Display, Formatter};
 use crate::core::lexer::token_with_span::FilePosition;
 use crate::core::model::abstract_syntax_tree_nodes::assignable::{Assignable};
+use crate::core::model::abstract_syntax_tree_nodes::assignables::equation_parser::operator::Operator;
 use crate::core::model::abstract_syntax_tree_nodes::l_value::LValue;
+use crate::core::model::symbol_table::{Symbol, SymbolId};
 use crate::core::model::types::ty::Type;
 
 /// AST node for a variable. Pattern is defined as: name <Assignment> assignment <Separator>
 /// # Examples
 /// - `name = assignment;`
 /// - `name: assignment,`
+/// - `name += assignment;`
 #[derive(Debug, PartialEq, Clone, Default)]
 pub struct Variable<const ASSIGNMENT: char, const SEPARATOR: char> {
     pub l_value: LValue,
@@ -17,15 +20,35 @@ pub struct Variable<const ASSIGNMENT: char, const SEPARATOR: char> {
     pub ty: Option<Type>,
     /// flag defining if the variable is a new definition or a re-assignment
     pub define: bool,
+    /// operator of a compound assignment like `name += assignment;`. It's None for a plain assignment
+    pub compound_operator: Option<Operator>,
     pub assignable: Assignable,
     pub file_position: FilePosition,
 }
 
+impl<const ASSIGNMENT: char, const SEPARATOR: char> Symbol for Variable<ASSIGNMENT, SEPARATOR> {
+    fn symbol_name(&self) -> String {
+        self.l_value.identifier()
+    }
+
+    fn symbol_id(&self) -> Option<SymbolId> {
+        self.l_value.symbol()
+    }
+}
+
 impl Display for Variable<'=', ';'> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         let t = self.ty.as_ref().map_or(String::new(), |ty| format!(": {ty}"));
         let ident: usize = f.width().unwrap_or(0);
 
+        if let Some(operator) = &self.compound_operator {
+            return write!(f, "{}{} {}= {:width$}", " ".repeat(ident), self.l_value, operator, self.assignable, width = ident);
+        }
+
+        if self.assignable == Assignable::Uninitialized {
+            return write!(f, "{}let {}{}{}", " ".repeat(ident), if self.mutability { "mut " } else { "" }, self.l_value, &t);
+        }
+
         write!(
             f,
             "{}{}{}{}{} = {:width$}",
@@ -5,6 +5,8 @@ use std::fmt::{Display, Formatter};
 
 #[derive(Debug, PartialEq, Clone, Default)]
 pub struct While {
+    /// The optional label of the loop, e.g. `outer` for `'outer: while`
+    pub label: Option<String>,
     pub condition: Assignable,
     pub stack: Vec<AbstractSyntaxTreeNode>,
     pub file_position: FilePosition
@@ -12,7 +14,8 @@ pub struct While {
 
 impl Display for While {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}while ({}) {{", " ".repeat(f.width().unwrap_or(0)), self.condition)?;
+        let label = self.label.as_ref().map_or(String::new(), |label| format!("'{label}: "));
+        write!(f, "{}{label}while ({}) {{", " ".repeat(f.width().unwrap_or(0)), self.condition)?;
 
         for a in &self.stack {
             write!(f, "\n{:width$}{}", "", a, width = f.width().unwrap_or(0) + 4)?;
@@ -12,6 +12,7 @@ pub struct DataSection {
 }
 
 impl DataSection {
+    /// Registers the already decoded content of a string under the provided label
     pub fn push_str(&mut self, key: &str, value: &str) -> bool {
         self.data.insert(key.to_string(), value.to_string()).is_some()
     }
@@ -33,18 +34,21 @@ impl DataSection {
 
 impl ToASM for DataSection {
     fn to_asm(&self, _stack: &mut Stack, _meta: &mut MetaInfo, _options: Option<ASMOptions>) -> Result<ASMResult, ASMGenerateError> {
-        if self.data.is_empty() {
+        if self.data.is_empty() && self.struct_definitions.is_empty() {
             return Ok(ASMResult::Inline("".to_string()))
         }
         let mut target = String::new();
-        target += &ASMBuilder::line("section .data");
 
-        for (key, value) in &self.data {
-            target += &ASMBuilder::ident_line(&format!("{key}: db {value}, 0"))
-        }
+        if !self.data.is_empty() {
+            target += &ASMBuilder::line("section .data");
 
-        target += &ASMBuilder::line("");
-        target += &ASMBuilder::line("");
+            for (key, value) in &self.data {
+                target += &ASMBuilder::ident_line(&format!("{key}: db {}, 0", encode_nasm_bytes(value)))
+            }
+
+            target += &ASMBuilder::line("");
+            target += &ASMBuilder::line("");
+        }
 
         for struct_definition in &self.struct_definitions {
             target += struct_definition;
@@ -63,4 +67,34 @@ impl ToASM for DataSection {
     fn byte_size(&self, _meta: &MetaInfo) -> usize {
         0
     }
-}
\ No newline at end of file
+}
+
+/// Encodes the content as nasm operands for `db`. NASM strings don't know escape sequences,
+/// so every byte, which can't be part of a quoted string, is written as a number
+/// ## Example
+/// encode_nasm_bytes("Hallo\n\"Welt\"") returns
+/// "Hallo", 10, 34, "Welt", 34
+fn encode_nasm_bytes(value: &str) -> String {
+    let mut operands = vec![];
+    let mut quoted = String::new();
+
+    for byte in value.bytes() {
+        if (0x20..0x7F).contains(&byte) && byte != b'"' {
+            quoted.push(byte as char);
+            continue;
+        }
+
+        if !quoted.is_empty() {
+            operands.push(format!("\"{quoted}\""));
+            quoted.clear();
+        }
+
+        operands.push(byte.to_string());
+    }
+
+    if !quoted.is_empty() || operands.is_empty() {
+        operands.push(format!("\"{quoted}\""));
+    }
+
+    operands.join(", ")
+}
@@ -1,5 +1,6 @@
 pub mod scope_type;
 pub mod data_section;
+pub mod symbol_table;
 pub mod abstract_syntax_tree_nodes;
 pub mod scope;
 pub mod abstract_syntax_tree_node;
@@ -0,0 +1,120 @@
+use std::fmt::{Display, Formatter};
+use crate::core::model::abstract_syntax_tree_nodes::identifier::Identifier;
+use crate::core::model::abstract_syntax_tree_nodes::l_value::LValue;
+
+/// Identifies a declared symbol. Ids are handed out in declaration order and are never reused, so an id stays valid after the scope of its symbol was closed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct SymbolId(pub usize);
+
+impl Display for SymbolId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "#{}", self.0)
+    }
+}
+
+/// Everything that can be declared inside of a scope and looked up by its name
+pub trait Symbol {
+    fn symbol_name(&self) -> String;
+
+    /// the id the type inference recorded on the declaration of the symbol
+    fn symbol_id(&self) -> Option<SymbolId> {
+        None
+    }
+}
+
+/// A lexically scoped symbol table
+///
+/// Symbols are visible from their declaration until the scope they were declared in is popped.
+/// Inner declarations shadow outer declarations with the same name
+#[derive(Debug, Clone)]
+pub struct SymbolTable<T> {
+    /// every symbol ever declared, indexed by its id
+    symbols: Vec<T>,
+    /// all symbols in the current scope and above. The innermost declaration is the last one
+    visible: Vec<SymbolId>,
+    /// the amount of visible symbols at the start of each open scope
+    scopes: Vec<usize>,
+}
+
+impl<T> Default for SymbolTable<T> {
+    fn default() -> Self {
+        Self {
+            symbols: vec![],
+            visible: vec![],
+            scopes: vec![],
+        }
+    }
+}
+
+impl<T: Symbol> SymbolTable<T> {
+    pub fn push_scope(&mut self) {
+        self.scopes.push(self.visible.len());
+    }
+
+    /// closes the innermost scope and returns the ids of all symbols declared inside of it
+    pub fn pop_scope(&mut self) -> Vec<SymbolId> {
+        match self.scopes.pop() {
+            Some(start) => self.visible.split_off(start),
+            None => vec![],
+        }
+    }
+
+    /// the amount of open scopes
+    pub fn depth(&self) -> usize {
+        self.scopes.len()
+    }
+
+    /// declares the symbol in the innermost scope
+    pub fn declare(&mut self, symbol: T) -> SymbolId {
+        let id = SymbolId(self.symbols.len());
+        self.symbols.push(symbol);
+        self.visible.push(id);
+
+        id
+    }
+
+    /// finds the innermost visible symbol with the provided name
+    pub fn lookup(&self, name: &str) -> Option<&T> {
+        self.lookup_id(name).and_then(|id| self.get(id))
+    }
+
+    pub fn lookup_id(&self, name: &str) -> Option<SymbolId> {
+        self.visible.iter().rev().find(|id| self.symbols[id.0].symbol_name() == name).copied()
+    }
+
+    /// finds the visible symbol the identifier was resolved to by the type inference.
+    /// Identifiers created after the inference and symbols declared without an id are looked up by their name
+    pub fn resolve(&self, identifier: &Identifier) -> Option<&T> {
+        identifier.symbol
+            .and_then(|symbol| self.iter().rev().find(|declared| declared.symbol_id() == Some(symbol)))
+            .or_else(|| self.lookup(&identifier.name))
+    }
+
+    /// finds the variable the l_value writes to. For `a[0]` or `person.age` it's the variable `a` or `person`
+    pub fn resolve_l_value(&self, l_value: &LValue) -> Option<&T> {
+        match l_value {
+            LValue::Identifier(identifier) => self.resolve(identifier),
+            LValue::Expression(_) => self.lookup(&l_value.identifier()),
+        }
+    }
+
+    /// returns the symbol with the provided id, even if it isn't visible anymore
+    pub fn get(&self, id: SymbolId) -> Option<&T> {
+        self.symbols.get(id.0)
+    }
+
+    pub fn get_mut(&mut self, id: SymbolId) -> Option<&mut T> {
+        self.symbols.get_mut(id.0)
+    }
+
+    /// all visible symbols. The outermost declaration comes first
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &T> {
+        self.visible.iter().map(|id| &self.symbols[id.0])
+    }
+
+    /// hides all symbols and closes all scopes. The symbols stay accessible by their id
+    pub fn clear(&mut self) {
+        self.visible.clear();
+        self.scopes.clear();
+    }
+}
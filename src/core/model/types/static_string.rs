@@ -2,12 +2,14 @@ use std::fmt::{Display, Formatter};
 
 #[derive(Debug, Eq, PartialOrd, PartialEq, Clone, Default)]
 pub struct StaticString {
+    // the literal like it's written in the source code, including quotes and escape sequences
     pub value: String,
 }
 
 #[derive(Debug)]
 pub enum StaticStringError {
     UnmatchedRegex,
+    InvalidEscapeSequence(String, usize),
 }
 
 impl std::error::Error for StaticStringError {}
@@ -15,7 +17,8 @@ impl std::error::Error for StaticStringError {}
 impl Display for StaticStringError {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", match self {
-            StaticStringError::UnmatchedRegex => "Name must match: ^\".*\"$ ",
+            StaticStringError::UnmatchedRegex => "Name must match: ^\".*\"$ ".to_string(),
+            StaticStringError::InvalidEscapeSequence(sequence, _) => format!("Invalid escape sequence: `{sequence}`"),
         })
     }
 }
@@ -29,9 +29,11 @@ impl ConstFoldable for AbstractSyntaxTreeNode {
             AbstractSyntaxTreeNode::Import(_) => true,
             AbstractSyntaxTreeNode::Return(node) => node.is_const(),
             AbstractSyntaxTreeNode::If(node) => node.is_const(),
+            AbstractSyntaxTreeNode::Match(node) => node.is_const(),
             AbstractSyntaxTreeNode::For(node) => node.is_const(),
             AbstractSyntaxTreeNode::While(node) => node.is_const(),
-            AbstractSyntaxTreeNode::StructDefinition(_) => false
+            AbstractSyntaxTreeNode::StructDefinition(_) | AbstractSyntaxTreeNode::EnumDefinition(_) | AbstractSyntaxTreeNode::Impl(_) | AbstractSyntaxTreeNode::SyntaxError(_) => false,
+            AbstractSyntaxTreeNode::Break(_) | AbstractSyntaxTreeNode::Continue(_) => true,
         }
     }
 
@@ -43,9 +45,11 @@ impl ConstFoldable for AbstractSyntaxTreeNode {
             AbstractSyntaxTreeNode::Import(_) => None,
             AbstractSyntaxTreeNode::Return(node) => node.const_fold(static_type_context, optimization_context).map(AbstractSyntaxTreeNode::Return),
             AbstractSyntaxTreeNode::If(node) => node.const_fold(static_type_context, optimization_context).map(AbstractSyntaxTreeNode::If),
+            AbstractSyntaxTreeNode::Match(node) => node.const_fold(static_type_context, optimization_context).map(AbstractSyntaxTreeNode::Match),
             AbstractSyntaxTreeNode::For(node) => node.const_fold(static_type_context, optimization_context).map(AbstractSyntaxTreeNode::For),
             AbstractSyntaxTreeNode::While(node) => node.const_fold(static_type_context, optimization_context).map(AbstractSyntaxTreeNode::While),
-            AbstractSyntaxTreeNode::StructDefinition(_) => None,
+            AbstractSyntaxTreeNode::StructDefinition(_) | AbstractSyntaxTreeNode::EnumDefinition(_) | AbstractSyntaxTreeNode::Impl(_) | AbstractSyntaxTreeNode::SyntaxError(_) => None,
+            AbstractSyntaxTreeNode::Break(_) | AbstractSyntaxTreeNode::Continue(_) => None,
         }
     }
 }
\ No newline at end of file
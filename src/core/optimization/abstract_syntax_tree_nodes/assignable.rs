@@ -1,4 +1,5 @@
 use crate::core::model::abstract_syntax_tree_nodes::assignable::Assignable;
+use crate::core::model::types::boolean::Boolean;
 use crate::core::optimization::optimization_trait::{AssignmentConstFoldable, ConstFoldable, Optimization, OptimizationContext};
 use crate::core::parser::static_type_context::StaticTypeContext;
 
@@ -75,4 +76,26 @@ impl Assignable {
             _ => None,
         }
     }
+
+    pub fn negate(&self, static_type_context: &StaticTypeContext) -> Option<Assignable> {
+        match self {
+            Assignable::Float(value) => Some(Assignable::Float(value.negate(static_type_context)?)),
+            Assignable::Integer(value) => Some(Assignable::Integer(value.negate(static_type_context)?)),
+            _ => None,
+        }
+    }
+
+    pub fn logical_not(&self, _static_type_context: &StaticTypeContext) -> Option<Assignable> {
+        match self {
+            Assignable::Boolean(value) => Some(Assignable::Boolean(Boolean { value: !value.value })),
+            _ => None,
+        }
+    }
+
+    pub fn bitwise_not(&self, static_type_context: &StaticTypeContext) -> Option<Assignable> {
+        match self {
+            Assignable::Integer(value) => Some(Assignable::Integer(value.bitwise_not(static_type_context)?)),
+            _ => None,
+        }
+    }
 }
\ No newline at end of file
@@ -2,6 +2,7 @@ use std::collections::HashMap;
 use crate::core::model::abstract_syntax_tree_nodes::assignable::Assignable;
 use crate::core::model::abstract_syntax_tree_nodes::assignables::equation_parser::expression::Expression;
 use crate::core::model::abstract_syntax_tree_nodes::assignables::equation_parser::operator::Operator;
+use crate::core::model::abstract_syntax_tree_nodes::assignables::equation_parser::prefix_arithmetic::PrefixArithmetic;
 use crate::core::model::abstract_syntax_tree_nodes::identifier::Identifier;
 use crate::core::model::types::float::FloatType;
 use crate::core::model::types::integer::IntegerType;
@@ -36,6 +37,10 @@ impl ConstFoldable for Expression {
             return value.is_const();
         }
 
+        if let (Some(value), None, None, Some(PrefixArithmetic::Operation(_))) = (&self.value, &self.lhs, &self.rhs, &self.prefix_arithmetic) {
+            return value.is_const();
+        }
+
         if let (Some(left), Some(right), _) = (&self.lhs, &self.rhs, self.operator) {
             return left.is_const() && right.is_const();
         }
@@ -66,45 +71,27 @@ impl Expression {
             return Some(*value.clone());
         }
 
+        if let (Some(value), None, None, Some(PrefixArithmetic::Operation(operator))) = (&self.value, &self.lhs, &self.rhs, &self.prefix_arithmetic) {
+            let value = match value.as_ref() {
+                Assignable::Expression(expression) => expression.const_fold_assignment(static_type_context, optimization_context),
+                value => Some(value.clone()),
+            };
+            let value = Self::constant_value(value, static_type_context, optimization_context)?;
+
+            return match operator {
+                Operator::Sub => value.negate(static_type_context),
+                Operator::LogicalNot => value.logical_not(static_type_context),
+                Operator::BitwiseNot => value.bitwise_not(static_type_context),
+                _ => None,
+            };
+        }
+
         if let (Some(left), Some(right), operation) = (&self.lhs, &self.rhs, self.operator) {
             let left = left.const_fold_assignment(static_type_context, optimization_context);
             let right = right.const_fold_assignment(static_type_context, optimization_context);
 
-            let left = match &left {
-                Some(Assignable::Identifier(identifier)) => {
-                    if let Some(const_variable_assignment) = optimization_context.constant_variables.get(&identifier.name) {
-                        Some(const_variable_assignment.clone())
-                    } else {
-                        left
-                    }
-                }
-                Some(Assignable::MethodCall(method_call)) => {
-                    if let Some(const_method_assignment) = optimization_context.const_method_definitions.get(&method_call.method_label_name(static_type_context)) {
-                        Some(const_method_assignment.clone())
-                    } else {
-                        left
-                    }
-                }
-                _ => left,
-            };
-
-            let right = match &right {
-                Some(Assignable::Identifier(identifier)) => {
-                    if let Some(const_variable_assignment) = optimization_context.constant_variables.get(&identifier.name) {
-                        Some(const_variable_assignment.clone())
-                    } else {
-                        right
-                    }
-                }
-                Some(Assignable::MethodCall(method_call)) => {
-                    if let Some(const_method_assignment) = optimization_context.const_method_definitions.get(&method_call.method_label_name(static_type_context)) {
-                        Some(const_method_assignment.clone())
-                    } else {
-                        right
-                    }
-                }
-                _ => right,
-            };
+            let left = Self::constant_value(left, static_type_context, optimization_context);
+            let right = Self::constant_value(right, static_type_context, optimization_context);
 
 
 
@@ -118,7 +105,7 @@ impl Expression {
 
                 let mut base_type_matrix: HashMap<(Type, Operator, Type), Type> = HashMap::new();
 
-                base_type_matrix.insert((Type::Custom(Identifier { name: "string".to_string() }, Mutability::Immutable), Operator::Add, Type::Custom(Identifier { name: "string".to_string() }, Mutability::Immutable)), Type::Custom(Identifier { name: "*string".to_string() }, Mutability::Immutable));
+                base_type_matrix.insert((Type::Custom(Identifier { name: "string".to_string(), symbol: None }, Mutability::Immutable), Operator::Add, Type::Custom(Identifier { name: "string".to_string(), symbol: None }, Mutability::Immutable)), Type::Custom(Identifier { name: "*string".to_string(), symbol: None }, Mutability::Immutable));
 
                 IntegerType::operation_matrix(&mut base_type_matrix);
                 FloatType::operation_matrix(&mut base_type_matrix);
@@ -137,4 +124,25 @@ impl Expression {
         }
         None
     }
+
+    /// replaces identifiers and method calls with their constant value, if there is one
+    fn constant_value(assignable: Option<Assignable>, static_type_context: &StaticTypeContext, optimization_context: &OptimizationContext) -> Option<Assignable> {
+        match &assignable {
+            Some(Assignable::Identifier(identifier)) => {
+                if let Some(const_variable_assignment) = optimization_context.constant_variables.get(&identifier.name) {
+                    Some(const_variable_assignment.clone())
+                } else {
+                    assignable
+                }
+            }
+            Some(Assignable::MethodCall(method_call)) => {
+                if let Some(const_method_assignment) = optimization_context.const_method_definitions.get(&method_call.method_label_name(static_type_context)) {
+                    Some(const_method_assignment.clone())
+                } else {
+                    assignable
+                }
+            }
+            _ => assignable,
+        }
+    }
 }
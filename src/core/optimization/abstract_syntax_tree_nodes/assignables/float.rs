@@ -54,4 +54,11 @@ impl FloatAST {
             ty: self.ty.clone(),
         })
     }
+
+    pub fn negate(&self, _static_type_context: &crate::core::parser::static_type_context::StaticTypeContext) -> Option<FloatAST> {
+        Some(FloatAST {
+            value: -self.value,
+            ty: self.ty.clone(),
+        })
+    }
 }
\ No newline at end of file
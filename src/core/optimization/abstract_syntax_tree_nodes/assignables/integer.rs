@@ -86,4 +86,16 @@ impl IntegerAST {
     pub fn div(&self, right: &IntegerAST, _static_type_context: &crate::core::parser::static_type_context::StaticTypeContext) -> Option<IntegerAST> {
         self.apply_bin_op(right, |a, b| a / b, |a, b| a / b)
     }
+
+    pub fn negate(&self, _static_type_context: &crate::core::parser::static_type_context::StaticTypeContext) -> Option<IntegerAST> {
+        if !self.ty.signed() {
+            return None;
+        }
+
+        self.apply_bin_op(self, |a, _| -a, |a, _| a)
+    }
+
+    pub fn bitwise_not(&self, _static_type_context: &crate::core::parser::static_type_context::StaticTypeContext) -> Option<IntegerAST> {
+        self.apply_bin_op(self, |a, _| !a, |a, _| !a)
+    }
 }
\ No newline at end of file
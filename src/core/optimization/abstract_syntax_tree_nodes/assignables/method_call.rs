@@ -1,4 +1,5 @@
 use crate::core::model::abstract_syntax_tree_nodes::assignables::method_call::MethodCall;
+use crate::core::model::abstract_syntax_tree_nodes::method_definition::MethodDefinition;
 use crate::core::model::types::ty::Type;
 use crate::core::optimization::optimization_trait::{AssignmentConstFoldable, ConstFoldable, Optimization, OptimizationContext};
 use crate::core::parser::static_type_context::StaticTypeContext;
@@ -60,6 +61,6 @@ impl MethodCall {
         }.replace('*', "ptr");
 
         let return_type = self.get_type(static_type_context).unwrap_or(Type::Void).to_string().replace('*', "ptr");
-        format!(".{}_{}~{}", self.identifier.identifier(), parameters, return_type)
+        MethodDefinition::label_name(&self.identifier.identifier(), &parameters, &return_type)
     }
 }
\ No newline at end of file
@@ -11,8 +11,6 @@ impl AssignmentConstFoldable for StaticString {
 
 impl StaticString {
     pub fn add(&self, right: &StaticString, _static_type_context: &StaticTypeContext) -> Option<StaticString> {
-        Some(StaticString {
-            value: format!("{}{}", self.value, right.value),
-        })
+        Some(StaticString::from_content(&format!("{}{}", self.content().ok()?, right.content().ok()?)))
     }
 }
\ No newline at end of file
@@ -0,0 +1,29 @@
+use crate::core::model::abstract_syntax_tree_nodes::match_::Match;
+use crate::core::optimization::optimization_trait::{ConstFoldable, Optimization, OptimizationContext};
+use crate::core::parser::static_type_context::StaticTypeContext;
+
+impl Optimization for Match {
+    fn o1(&mut self, static_type_context: &mut StaticTypeContext, optimization: OptimizationContext) -> OptimizationContext {
+        let mut current_optimization_context = optimization;
+
+        current_optimization_context = self.value.o1(static_type_context, current_optimization_context);
+
+        for arm in &mut self.arms {
+            for node in &mut arm.stack {
+                current_optimization_context = node.o1(static_type_context, current_optimization_context);
+            }
+        }
+
+        current_optimization_context
+    }
+}
+
+impl ConstFoldable for Match {
+    fn is_const(&self) -> bool {
+        false
+    }
+
+    fn const_fold(&self, _static_type_context: &StaticTypeContext, _optimization_context: &OptimizationContext) -> Option<Self> {
+        None
+    }
+}
@@ -59,9 +59,11 @@ impl ConstFoldable for MethodDefinition {
 
         Some(MethodDefinition {
             identifier: self.identifier.clone(),
+            generic_parameters: self.generic_parameters.clone(),
             arguments: self.arguments.clone(),
             return_type: self.return_type.clone(),
             is_extern: self.is_extern,
+            is_public: self.is_public,
             stack: folded,
             file_position: self.file_position.clone(),
         })
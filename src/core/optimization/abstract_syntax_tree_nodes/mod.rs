@@ -4,5 +4,6 @@ mod assignable;
 mod variable;
 mod return_;
 mod if_;
+mod match_;
 mod for_;
 mod while_;
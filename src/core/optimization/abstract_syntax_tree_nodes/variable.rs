@@ -8,7 +8,10 @@ impl Optimization for Variable<'=', ';'> {
         
         optimization = self.assignable.o1(static_type_context, optimization.clone());
 
-        if self.is_const() {
+        if self.compound_operator.is_some() {
+            // `a += 1` depends on the previous value of `a`, which is not known at this point anymore
+            optimization.constant_variables.remove(&self.l_value.identifier());
+        } else if self.is_const() {
             if let Some(assignable_const) = self.assignable.const_fold(static_type_context, &optimization) {
                 optimization.constant_variables.insert(self.l_value.identifier(), assignable_const);
             }
@@ -20,7 +23,7 @@ impl Optimization for Variable<'=', ';'> {
 
 impl ConstFoldable for Variable<'=', ';'> {
     fn is_const(&self) -> bool {
-        self.assignable.is_const()
+        self.compound_operator.is_none() && self.assignable.is_const()
     }
 
     fn const_fold(&self, static_type_context: &StaticTypeContext, optimization_context: &OptimizationContext) -> Option<Self> {
@@ -29,6 +32,7 @@ impl ConstFoldable for Variable<'=', ';'> {
             mutability: self.mutability,
             ty: self.ty.clone(),
             define: self.define,
+            compound_operator: self.compound_operator,
             assignable: self.assignable.const_fold(static_type_context, optimization_context)?,
             file_position: self.file_position.clone(),
         })
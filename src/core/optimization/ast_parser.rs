@@ -93,9 +93,32 @@ impl ASTParser {
             &self.program
         };
 
-        let static_type_context = &mut StaticTypeContext::new(scope);
+        // methods, structs and enums are only defined on the top level
+        let static_type_context = &mut StaticTypeContext::new(&self.program);
+        static_type_context.merge(StaticTypeContext::new(scope));
         let finder = FindASTNode::<MethodCall>::from(Box::new(find_method_call_in_assignable));
-        let called_methods = finder.find_ast_node_in_stack(scope, static_type_context).iter().map(|m| m.method_label_name(static_type_context)).collect::<Vec<_>>();
+        let mut called_methods = finder.find_ast_node_in_stack(scope, static_type_context).iter().map(|m| m.method_label_name(static_type_context)).collect::<Vec<_>>();
+
+        // methods called by called methods are needed as well. The arguments of these calls are only known inside of the calling method,
+        // so every overload with the called name is kept
+        let mut visited_methods = vec![];
+
+        while let Some(method_definition) = self.program.iter().find_map(|token| match token {
+            AbstractSyntaxTreeNode::MethodDefinition(method_definition) if called_methods.contains(&method_definition.method_label_name()) && !visited_methods.contains(&method_definition.method_label_name()) => Some(method_definition),
+            _ => None,
+        }) {
+            visited_methods.push(method_definition.method_label_name());
+
+            for method_call in finder.find_ast_node_in_stack(&method_definition.stack, static_type_context) {
+                for token in &self.program {
+                    if let AbstractSyntaxTreeNode::MethodDefinition(called_method) = token {
+                        if called_method.identifier.identifier() == method_call.identifier.identifier() && !called_methods.contains(&called_method.method_label_name()) {
+                            called_methods.push(called_method.method_label_name());
+                        }
+                    }
+                }
+            }
+        }
 
         let mut uncalled_methods = vec![];
 
@@ -144,6 +167,10 @@ fn find_variable_use_in_assignable(v: &FindASTNode<Variable<'=', ';'>>, assignab
             let func = &v.expression_match;
             func(v, a, static_type_context)
         }
+        Assignable::FieldAccess(field_access) => {
+            let func = &v.assignable_match;
+            func(v, &field_access.value, static_type_context)
+        }
         Assignable::Identifier(identifier) => {
             Some(vec![Variable {
                 l_value: LValue::Identifier(identifier.clone()),
@@ -152,12 +179,13 @@ fn find_variable_use_in_assignable(v: &FindASTNode<Variable<'=', ';'>>, assignab
                 assignable: Assignable::Identifier(identifier.clone()),
                 file_position: FilePosition::default(),
                 define: false,
+                compound_operator: None,
             }])
         }
         Assignable::String(_) | Assignable::Integer(_) |
         Assignable::Float(_) | Assignable::Parameter(_) |
-        Assignable::Boolean(_) |
-        Assignable::Object(_) => None,
+        Assignable::Boolean(_) | Assignable::Uninitialized |
+        Assignable::Object(_) | Assignable::EnumPath(_) => None,
         Assignable::Array(array) => {
             let mut elements = vec![];
             let func = &v.assignable_match;
@@ -183,14 +211,18 @@ fn find_method_call_in_assignable(v: &FindASTNode<MethodCall>, assignable: &Assi
         Assignable::MethodCall(method_call) => {
             Some(vec![method_call.clone()])
         }
+        Assignable::FieldAccess(field_access) => {
+            let func = &v.assignable_match;
+            func(v, &field_access.value, static_type_context)
+        }
         Assignable::Expression(a) => {
             let func = &v.expression_match;
             func(v, a, static_type_context)
         }
         Assignable::String(_) | Assignable::Integer(_) |
         Assignable::Float(_) | Assignable::Parameter(_) |
-        Assignable::Boolean(_) | Assignable::Identifier(_) |
-        Assignable::Object(_) => None,
+        Assignable::Boolean(_) | Assignable::Identifier(_) | Assignable::Uninitialized |
+        Assignable::Object(_) | Assignable::EnumPath(_) => None,
         Assignable::Array(array) => {
             let mut elements = vec![];
             let func = &v.assignable_match;
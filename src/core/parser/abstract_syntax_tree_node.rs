@@ -12,8 +12,10 @@ impl AbstractSyntaxTreeNode {
         match self {
             AbstractSyntaxTreeNode::Variable(_) | AbstractSyntaxTreeNode::MethodCall(_) |
             AbstractSyntaxTreeNode::Import(_) | AbstractSyntaxTreeNode::Return(_) |
-            AbstractSyntaxTreeNode::StructDefinition(_) => None,
+            AbstractSyntaxTreeNode::Break(_) | AbstractSyntaxTreeNode::Continue(_) |
+            AbstractSyntaxTreeNode::StructDefinition(_) | AbstractSyntaxTreeNode::EnumDefinition(_) | AbstractSyntaxTreeNode::SyntaxError(_) => None,
             AbstractSyntaxTreeNode::MethodDefinition(t) => Some(vec![&t.stack]),
+            AbstractSyntaxTreeNode::Impl(t) => Some(t.methods.iter().map(|method_definition| &method_definition.stack).collect()),
             AbstractSyntaxTreeNode::If(t) => {
                 let mut res = vec![&t.if_stack];
                 if let Some(else_stack) = &t.else_stack {
@@ -22,6 +24,7 @@ impl AbstractSyntaxTreeNode {
 
                 Some(res)
             }
+            AbstractSyntaxTreeNode::Match(t) => Some(t.arms.iter().map(|arm| &arm.stack).collect()),
             AbstractSyntaxTreeNode::For(t) => Some(vec![&t.stack]),
             AbstractSyntaxTreeNode::While(t) => Some(vec![&t.stack]),
         }
@@ -37,52 +40,21 @@ impl StaticTypeCheck for AbstractSyntaxTreeNode {
             AbstractSyntaxTreeNode::Import(node) => node.static_type_check(type_context),
             AbstractSyntaxTreeNode::Return(node) => node.static_type_check(type_context),
             AbstractSyntaxTreeNode::If(node) => node.static_type_check(type_context),
+            AbstractSyntaxTreeNode::Match(node) => node.static_type_check(type_context),
             AbstractSyntaxTreeNode::For(node) => node.static_type_check(type_context),
             AbstractSyntaxTreeNode::While(node) => node.static_type_check(type_context),
             AbstractSyntaxTreeNode::StructDefinition(node) => node.static_type_check(type_context),
+            AbstractSyntaxTreeNode::EnumDefinition(node) => node.static_type_check(type_context),
+            AbstractSyntaxTreeNode::Impl(node) => node.static_type_check(type_context),
+            AbstractSyntaxTreeNode::SyntaxError(node) => node.static_type_check(type_context),
+            AbstractSyntaxTreeNode::Break(node) => node.static_type_check(type_context),
+            AbstractSyntaxTreeNode::Continue(node) => node.static_type_check(type_context),
         }
     }
 }
 
 impl ToASM for AbstractSyntaxTreeNode {
     fn to_asm(&self, stack: &mut Stack, meta: &mut MetaInfo, options: Option<ASMOptions>) -> Result<ASMResult, ASMGenerateError> {
-        let variables_len = meta.static_type_information.len();
-
-        let scopes = match self {
-            AbstractSyntaxTreeNode::If(if_def) => {
-                let mut res = vec![&if_def.if_stack];
-                if let Some(else_stack) = &if_def.else_stack {
-                    res.push(else_stack)
-                }
-
-                res
-            }
-            AbstractSyntaxTreeNode::For(for_node) => {
-                vec![&for_node.stack]
-            }
-            AbstractSyntaxTreeNode::While(while_node) => {
-                vec![&while_node.stack]
-            }
-            AbstractSyntaxTreeNode::MethodDefinition(method_def) => {
-                vec![&method_def.stack]
-            }
-            AbstractSyntaxTreeNode::Variable(_) | AbstractSyntaxTreeNode::MethodCall(_) |
-            AbstractSyntaxTreeNode::Import(_) | AbstractSyntaxTreeNode::Return(_) |
-            AbstractSyntaxTreeNode::StructDefinition(_)
-            => vec![]
-        };
-
-        for scope in scopes {
-            let scoped_checker = StaticTypeContext::new(scope);
-            meta.static_type_information.merge(scoped_checker);
-
-            let amount_pop = meta.static_type_information.len() - variables_len;
-
-            for _ in 0..amount_pop {
-                let _ = meta.static_type_information.pop();
-            }
-        }
-
         match self {
             AbstractSyntaxTreeNode::Variable(node) => node.to_asm(stack, meta, options),
             AbstractSyntaxTreeNode::MethodCall(node) => node.to_asm(stack, meta, options),
@@ -90,9 +62,15 @@ impl ToASM for AbstractSyntaxTreeNode {
             AbstractSyntaxTreeNode::MethodDefinition(node) => node.to_asm(stack, meta, options),
             AbstractSyntaxTreeNode::Import(node) => node.to_asm(stack, meta, options),
             AbstractSyntaxTreeNode::If(node) => node.to_asm(stack, meta, options),
+            AbstractSyntaxTreeNode::Match(node) => node.to_asm(stack, meta, options),
             AbstractSyntaxTreeNode::For(node) => node.to_asm(stack, meta, options),
             AbstractSyntaxTreeNode::While(node) => node.to_asm(stack, meta, options),
-            AbstractSyntaxTreeNode::StructDefinition(node) => node.to_asm(stack, meta, options)
+            AbstractSyntaxTreeNode::StructDefinition(node) => node.to_asm(stack, meta, options),
+            AbstractSyntaxTreeNode::EnumDefinition(node) => node.to_asm(stack, meta, options),
+            AbstractSyntaxTreeNode::Impl(node) => node.to_asm(stack, meta, options),
+            AbstractSyntaxTreeNode::SyntaxError(node) => node.to_asm(stack, meta, options),
+            AbstractSyntaxTreeNode::Break(node) => node.to_asm(stack, meta, options),
+            AbstractSyntaxTreeNode::Continue(node) => node.to_asm(stack, meta, options),
         }
     }
 
@@ -102,12 +80,18 @@ impl ToASM for AbstractSyntaxTreeNode {
             AbstractSyntaxTreeNode::Variable(node) => node.is_stack_look_up(stack, meta),
             AbstractSyntaxTreeNode::MethodCall(node) => node.is_stack_look_up(stack, meta),
             AbstractSyntaxTreeNode::If(node) => node.is_stack_look_up(stack, meta),
+            AbstractSyntaxTreeNode::Match(node) => node.is_stack_look_up(stack, meta),
             AbstractSyntaxTreeNode::Import(node) => node.is_stack_look_up(stack, meta),
             AbstractSyntaxTreeNode::For(node) => node.is_stack_look_up(stack, meta),
             AbstractSyntaxTreeNode::While(node) => node.is_stack_look_up(stack, meta),
             AbstractSyntaxTreeNode::MethodDefinition(node) => node.is_stack_look_up(stack, meta),
             AbstractSyntaxTreeNode::Return(node) => node.is_stack_look_up(stack, meta),
             AbstractSyntaxTreeNode::StructDefinition(node) => node.is_stack_look_up(stack, meta),
+            AbstractSyntaxTreeNode::EnumDefinition(node) => node.is_stack_look_up(stack, meta),
+            AbstractSyntaxTreeNode::Impl(node) => node.is_stack_look_up(stack, meta),
+            AbstractSyntaxTreeNode::SyntaxError(node) => node.is_stack_look_up(stack, meta),
+            AbstractSyntaxTreeNode::Break(node) => node.is_stack_look_up(stack, meta),
+            AbstractSyntaxTreeNode::Continue(node) => node.is_stack_look_up(stack, meta),
         }
     }
 
@@ -120,8 +104,14 @@ impl ToASM for AbstractSyntaxTreeNode {
             AbstractSyntaxTreeNode::For(node) => node.byte_size(meta),
             AbstractSyntaxTreeNode::While(node) => node.byte_size(meta),
             AbstractSyntaxTreeNode::If(node) => node.byte_size(meta),
+            AbstractSyntaxTreeNode::Match(node) => node.byte_size(meta),
             AbstractSyntaxTreeNode::Return(node) => node.byte_size(meta),
             AbstractSyntaxTreeNode::StructDefinition(node) => node.byte_size(meta),
+            AbstractSyntaxTreeNode::EnumDefinition(node) => node.byte_size(meta),
+            AbstractSyntaxTreeNode::Impl(node) => node.byte_size(meta),
+            AbstractSyntaxTreeNode::SyntaxError(node) => node.byte_size(meta),
+            AbstractSyntaxTreeNode::Break(node) => node.byte_size(meta),
+            AbstractSyntaxTreeNode::Continue(node) => node.byte_size(meta),
         }
     }
 
@@ -135,8 +125,14 @@ impl ToASM for AbstractSyntaxTreeNode {
             AbstractSyntaxTreeNode::For(node) => node.data_section(stack, meta),
             AbstractSyntaxTreeNode::While(node) => node.data_section(stack, meta),
             AbstractSyntaxTreeNode::If(node) => node.data_section(stack, meta),
+            AbstractSyntaxTreeNode::Match(node) => node.data_section(stack, meta),
             AbstractSyntaxTreeNode::Return(node) => node.data_section(stack, meta),
             AbstractSyntaxTreeNode::StructDefinition(node) => node.data_section(stack, meta),
+            AbstractSyntaxTreeNode::EnumDefinition(node) => node.data_section(stack, meta),
+            AbstractSyntaxTreeNode::Impl(node) => node.data_section(stack, meta),
+            AbstractSyntaxTreeNode::SyntaxError(node) => node.data_section(stack, meta),
+            AbstractSyntaxTreeNode::Break(node) => node.data_section(stack, meta),
+            AbstractSyntaxTreeNode::Continue(node) => node.data_section(stack, meta),
         }
     }
 }
@@ -2,6 +2,8 @@ use crate::core::lexer::error::Error;
 use crate::core::lexer::parse::{Parse, ParseOptions, ParseResult};
 use crate::core::lexer::token_with_span::TokenWithSpan;
 use crate::core::model::abstract_syntax_tree_nodes::assignable::Assignable;
+use crate::core::model::abstract_syntax_tree_nodes::assignables::enum_path::EnumPath;
+use crate::core::model::abstract_syntax_tree_nodes::assignables::field_access::FieldAccess;
 use crate::core::model::abstract_syntax_tree_nodes::assignables::equation_parser::expression::Expression;
 use crate::core::model::abstract_syntax_tree_nodes::assignables::equation_parser::prefix_arithmetic::PrefixArithmetic;
 use crate::core::model::abstract_syntax_tree_nodes::assignables::method_call::MethodCall;
@@ -34,7 +36,9 @@ impl Parse for Assignable {
             |tokens: &[TokenWithSpan]| StaticString::parse(tokens, ParseOptions::default()).map(|r| ParseResult { result: Assignable::String(r.result), consumed: r.consumed }),
             |tokens: &[TokenWithSpan]| FloatAST::parse(tokens, ParseOptions::default()).map(|r| ParseResult { result: Assignable::Float(r.result), consumed: r.consumed }),
             |tokens: &[TokenWithSpan]| IntegerAST::parse(tokens, ParseOptions::default()).map(|r| ParseResult { result: Assignable::Integer(r.result), consumed: r.consumed }),
+            |tokens: &[TokenWithSpan]| EnumPath::parse(tokens, ParseOptions::default()).map(|r| ParseResult { result: Assignable::EnumPath(r.result), consumed: r.consumed }),
             |tokens: &[TokenWithSpan]| MethodCall::parse(tokens, ParseOptions::default()).map(|r| ParseResult { result: Assignable::MethodCall(r.result), consumed: r.consumed }),
+            |tokens: &[TokenWithSpan]| FieldAccess::parse(tokens, ParseOptions::default()).map(|r| ParseResult { result: Assignable::FieldAccess(r.result), consumed: r.consumed }),
             |tokens: &[TokenWithSpan]| Boolean::parse(tokens, ParseOptions::default()).map(|r| ParseResult { result: Assignable::Boolean(r.result), consumed: r.consumed }),
             |tokens: &[TokenWithSpan]| Array::parse(tokens, ParseOptions::default()).map(|r| ParseResult { result: Assignable::Array(r.result), consumed: r.consumed }),
             |tokens: &[TokenWithSpan]| Identifier::parse(tokens, ParseOptions::default()).map(|r| ParseResult { result: Assignable::Identifier(r.result), consumed: r.consumed }),
@@ -70,10 +74,14 @@ impl Parse for Assignable {
                     return Ok(assignable)
                 }
                 Err(err) => match &err {
-                    Error::InvalidCharacter(_) => {}
+                    Error::InvalidCharacter(_, _) => {}
                     Error::UnexpectedToken(_) => {}
                     Error::ExpectedToken(_) => {}
                     Error::UnexpectedEOF => {}
+                    Error::UnterminatedComment(_) => {}
+                    Error::InvalidEscapeSequence(..) | Error::InvalidCharLiteral(..) => {
+                        return Err(err)
+                    }
                     Error::Callstack(_) => {
                         return Err(err)
                     }
@@ -93,6 +101,8 @@ impl Assignable {
         match self {
             Assignable::Identifier(identifier) => Some(identifier.name.clone()),
             Assignable::Expression(value) => value.identifier(),
+            // `person.age = 31` assigns to the variable `person`
+            Assignable::FieldAccess(field_access) => field_access.value.identifier(),
             _ => None,
         }
     }
@@ -0,0 +1,32 @@
+use crate::core::lexer::error::Error;
+use crate::core::lexer::parse::{Parse, ParseOptions, ParseResult};
+use crate::core::lexer::token::Token;
+use crate::core::lexer::token_with_span::TokenWithSpan;
+use crate::core::model::abstract_syntax_tree_nodes::assignables::enum_path::EnumPath;
+use crate::core::model::abstract_syntax_tree_nodes::identifier::Identifier;
+use crate::core::model::types::mutability::Mutability;
+use crate::core::model::types::ty::Type;
+
+impl Parse for EnumPath {
+    fn parse(tokens: &[TokenWithSpan], _: ParseOptions) -> Result<ParseResult<Self>, Error> where Self: Sized, Self: Default {
+        if let Ok(path) = Identifier::parse_path(tokens) {
+            // the last segment is the variant, everything before the enum type like `std::Color`
+            let is_method_call = matches!(&tokens[path.consumed..], [TokenWithSpan { token: Token::ParenthesisOpen, .. }, ..] |
+                [TokenWithSpan { token: Token::DoubleColon, .. }, TokenWithSpan { token: Token::LessThan, .. }, ..]);
+
+            if let (Some((ty, variant)), false) = (path.result.name.rsplit_once("::"), is_method_call) {
+                if let Ok(ty) = Type::from_str(ty, Mutability::Immutable) {
+                    return Ok(ParseResult {
+                        result: EnumPath {
+                            ty,
+                            variant: Identifier { name: variant.to_string(), symbol: None },
+                        },
+                        consumed: path.consumed,
+                    });
+                }
+            }
+        }
+
+        Err(Error::UnexpectedToken(tokens[0].clone()))
+    }
+}
@@ -41,7 +41,10 @@ impl Expression {
 
     pub fn flip_value(&mut self) {
         if let Some(Assignable::Integer(i)) = &mut self.value.as_deref_mut() {
-            i.value = "-".to_string() + &i.value;
+            i.value = match i.value.strip_prefix('-') {
+                Some(value) => value.to_string(),
+                None => "-".to_string() + &i.value,
+            };
         }
 
         if let Some(Assignable::Float(f)) = &mut self.value.as_deref_mut() {
@@ -317,10 +317,23 @@ impl<'a> Equation<'a> {
             x.consumed += 1;
             return Ok(x);
         } else if self.eat(Some(Token::Minus)) {
-            x = self.parse_factor()?;
-            x.result.flip_value();
-            x.consumed += 1;
-            return Ok(x);
+            let value = self.parse_factor()?;
+
+            // negative literals are folded right away, so `-5` stays an integer literal
+            if let (Some(Assignable::Integer(_) | Assignable::Float(_)), None, None) = (value.result.value.as_deref(), &value.result.prefix_arithmetic, &value.result.index_operator) {
+                x = value;
+                x.result.flip_value();
+                x.consumed += 1;
+                return Ok(x);
+            }
+
+            return Ok(Self::prefix_operation(value, Operator::Sub));
+        } else if self.eat(Some(Token::LogicalNot)) {
+            let value = self.parse_factor()?;
+            return Ok(Self::prefix_operation(value, Operator::LogicalNot));
+        } else if self.eat(Some(Token::Tilde)) {
+            let value = self.parse_factor()?;
+            return Ok(Self::prefix_operation(value, Operator::BitwiseNot));
         }
 
         if let Some(MatchResult::Parse(cast_type)) = pattern!(&self.source_code[self.pos as usize..], ParenthesisOpen, @parse Type, ParenthesisClose) {
@@ -407,14 +420,23 @@ impl<'a> Equation<'a> {
                 } else if matches!(&ch.token, Token::Literal(_) | Token::True | Token::False) {
                     let mut ident = 0;
                     let mut in_brackets = false;
+                    let mut in_type_arguments = false;
 
-                    while ident != 0 || in_brackets || !self.operator_sequence() {
+                    while ident != 0 || in_brackets || in_type_arguments || !self.operator_sequence() {
                         match self.ch {
                             Some(TokenWithSpan { token: Token::ParenthesisOpen, ..}) => ident += 1,
                             Some(TokenWithSpan { token: Token::ParenthesisClose, ..}) => ident -= 1,
                             Some(TokenWithSpan { token: Token::SquareBracketOpen, ..}) => in_brackets = true,
                             Some(TokenWithSpan { token: Token::SquareBracketClose, ..}) => in_brackets = false,
                             Some(TokenWithSpan { token: Token::Literal(_), .. }) => { }
+                            // enum paths like `Color::Red` and type arguments of generic calls like `max::<i32>(a, b)`
+                            Some(TokenWithSpan { token: Token::DoubleColon, .. }) => {
+                                in_type_arguments = matches!(self.source_code.get(self.pos as usize + 1), Some(TokenWithSpan { token: Token::LessThan, .. }));
+                            }
+                            Some(TokenWithSpan { token: Token::GreaterThan, .. }) if in_type_arguments => in_type_arguments = false,
+                            _ if in_type_arguments => { }
+                            // method calls on a value like `person.greet()`
+                            Some(TokenWithSpan { token: Token::Dot, .. }) => { }
                             _ if ident <= 0 && !in_brackets => break,
                             _ => { }
                         }
@@ -435,8 +457,14 @@ impl<'a> Equation<'a> {
                         .collect::<Vec<TokenWithSpan>>();
 
 
+                    // array types inside of type arguments like `max::<[i32, 2]>(a, b)` aren't an index operation
+                    let type_arguments_end = sub_expression.windows(2)
+                        .position(|pair| pair[0].token == Token::DoubleColon && pair[1].token == Token::LessThan)
+                        .and_then(|start| sub_expression[start..].iter().position(|a| a.token == Token::GreaterThan).map(|end| start + end + 1))
+                        .unwrap_or(0);
+
                     let (index_operation, sub_string) = if let (Some(left), Some(right)) = (
-                        sub_expression.iter().position(|a| a.token == Token::SquareBracketOpen),
+                        sub_expression.iter().skip(type_arguments_end).position(|a| a.token == Token::SquareBracketOpen).map(|left| left + type_arguments_end),
                         sub_expression.iter().rposition(|a| a.token == Token::SquareBracketClose),
                     ) {
                         (
@@ -479,6 +507,17 @@ impl<'a> Equation<'a> {
         Ok(x)
     }
 
+    /// wraps the already parsed operand of a unary operator like `-`, `!` or `~`
+    fn prefix_operation(value: ParseResult<Box<Expression>>, operator: Operator) -> ParseResult<Box<Expression>> {
+        let mut x: ParseResult<Box<Expression>> = *Box::default();
+
+        x.result.value = Some(Box::new(Assignable::Expression(*value.result)));
+        x.result.prefix_arithmetic = Some(PrefixArithmetic::Operation(operator));
+        x.consumed = value.consumed + 1;
+
+        x
+    }
+
     fn undefined_or_empty(&self) -> Result<ParseResult<Box<Expression>>, crate::core::lexer::error::Error> where Self: Sized {
         if let Some(token) = self.ch {
             Err(crate::core::lexer::error::Error::UnexpectedToken(token.clone()))
@@ -0,0 +1,38 @@
+use crate::core::lexer::error::Error;
+use crate::core::lexer::parse::{Parse, ParseOptions, ParseResult};
+use crate::core::lexer::token::Token;
+use crate::core::lexer::token_with_span::TokenWithSpan;
+use crate::core::model::abstract_syntax_tree_nodes::assignable::Assignable;
+use crate::core::model::abstract_syntax_tree_nodes::assignables::field_access::FieldAccess;
+use crate::core::model::abstract_syntax_tree_nodes::identifier::Identifier;
+
+impl Parse for FieldAccess {
+    fn parse(tokens: &[TokenWithSpan], _: ParseOptions) -> Result<ParseResult<Self>, Error> where Self: Sized, Self: Default {
+        let identifier = Identifier::parse(tokens, ParseOptions::default())?;
+        let mut value = Assignable::Identifier(identifier.result);
+        let mut consumed = identifier.consumed;
+
+        while let (Some(TokenWithSpan { token: Token::Dot, .. }), Some(field_token)) = (tokens.get(consumed), tokens.get(consumed + 1)) {
+            // `person.greet()` is a method call on the field access before
+            if matches!(tokens.get(consumed + 2), Some(TokenWithSpan { token: Token::ParenthesisOpen, .. })) {
+                break;
+            }
+
+            let field = Identifier::parse(std::slice::from_ref(field_token), ParseOptions::default())?;
+
+            value = Assignable::FieldAccess(FieldAccess {
+                value: Box::new(value),
+                field: field.result,
+            });
+            consumed += 2;
+        }
+
+        match value {
+            Assignable::FieldAccess(field_access) => Ok(ParseResult {
+                result: field_access,
+                consumed,
+            }),
+            _ => Err(Error::UnexpectedToken(tokens[0].clone())),
+        }
+    }
+}
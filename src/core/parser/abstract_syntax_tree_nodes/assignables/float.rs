@@ -28,31 +28,43 @@ impl Parse for FloatAST {
         }
 
         let (float_literal, expected_type, consumed) = match tokens.iter().map(|x| x.token.clone()).collect::<Vec<Token>>().as_slice() {
-            [Token::Numbers(number), Token::Dot, Token::Literal(postfix), ..] if split_digits_ends_with_postfix(postfix, "_f32") => (format!("{}.{}", number, get_digits_part(postfix, "_f32")), FloatType::Float32, 3),
-            [Token::Numbers(number), Token::Dot, Token::Literal(postfix), ..] if split_digits_ends_with_postfix(postfix, "_f64") => (format!("{}.{}", number, get_digits_part(postfix, "_f32")), FloatType::Float64, 3),
-            [Token::Numbers(number), Token::Literal(postfix), ..] if postfix == "_f32" => (number.to_string(), FloatType::Float32, 2),
-            [Token::Numbers(number), Token::Literal(postfix), ..] if postfix == "_f64" => (number.to_string(), FloatType::Float64, 2),
-            [Token::Numbers(number), Token::Dot, Token::Numbers(decimal), Token::Literal(postfix), ..] if postfix == "_f32" => (format!("{}.{}", number, decimal), FloatType::Float32, 4),
-            [Token::Numbers(number), Token::Dot, Token::Numbers(decimal), Token::Literal(postfix), ..] if postfix == "_f64" => (format!("{}.{}", number, decimal), FloatType::Float64, 4),
-            [Token::Numbers(number), Token::Dot, Token::Numbers(decimal), ..] => (format!("{}.{}", number, decimal), FloatType::Float32, 3),
-            [Token::Dot, Token::Numbers(decimal), Token::Literal(postfix), ..] if postfix == "_f32" => (format!("0.{}", decimal), FloatType::Float32, 3),
-            [Token::Dot, Token::Numbers(decimal), Token::Literal(postfix), ..] if postfix == "_f64" => (format!("0.{}", decimal), FloatType::Float64, 3),
-            [Token::Dot, Token::Numbers(decimal), ..] => (format!("0.{}", decimal), FloatType::Float32, 2),
+            [Token::Numbers(number), Token::Dot, Token::Literal(postfix), ..] if is_decimal(number) && split_float_postfix(postfix).is_some_and(|(digits, _)| !digits.is_empty()) => {
+                let (digits, float_type) = split_float_postfix(postfix).ok_or(Error::UnexpectedToken(tokens[0].clone()))?;
+                (format!("{}.{}", number, digits), float_type, 3)
+            },
+            [Token::Numbers(number), Token::Dot, Token::Numbers(decimal), Token::Literal(postfix), ..] if is_decimal(number) && is_decimal(decimal) && split_float_postfix(postfix).is_some_and(|(digits, _)| digits.is_empty()) => {
+                let (_, float_type) = split_float_postfix(postfix).ok_or(Error::UnexpectedToken(tokens[0].clone()))?;
+                (format!("{}.{}", number, decimal), float_type, 4)
+            },
+            [Token::Numbers(number), Token::Dot, Token::Numbers(decimal), ..] if is_decimal(number) && is_decimal(decimal) => (format!("{}.{}", number, decimal), FloatType::Float32, 3),
+            [Token::Numbers(number), Token::Literal(postfix), ..] if is_decimal(number) && split_float_postfix(postfix).is_some_and(|(digits, _)| digits.is_empty()) => {
+                let (_, float_type) = split_float_postfix(postfix).ok_or(Error::UnexpectedToken(tokens[0].clone()))?;
+                (number.to_string(), float_type, 2)
+            },
+            // integer digits with a float suffix like `2f64` or `2_f32` are collected as a single literal
+            [Token::Literal(postfix), ..] if split_float_postfix(postfix).is_some_and(|(digits, _)| !digits.is_empty()) => {
+                let (digits, float_type) = split_float_postfix(postfix).ok_or(Error::UnexpectedToken(tokens[0].clone()))?;
+                (digits.to_string(), float_type, 1)
+            },
+            [Token::Dot, Token::Numbers(decimal), Token::Literal(postfix), ..] if is_decimal(decimal) && split_float_postfix(postfix).is_some_and(|(digits, _)| digits.is_empty()) => {
+                let (_, float_type) = split_float_postfix(postfix).ok_or(Error::UnexpectedToken(tokens[0].clone()))?;
+                (format!("0.{}", decimal), float_type, 3)
+            },
+            [Token::Dot, Token::Literal(postfix), ..] if split_float_postfix(postfix).is_some_and(|(digits, _)| !digits.is_empty()) => {
+                let (digits, float_type) = split_float_postfix(postfix).ok_or(Error::UnexpectedToken(tokens[0].clone()))?;
+                (format!("0.{}", digits), float_type, 2)
+            },
+            [Token::Dot, Token::Numbers(decimal), ..] if is_decimal(decimal) => (format!("0.{}", decimal), FloatType::Float32, 2),
             _ => return Err(Error::UnexpectedToken(tokens[0].clone()))
         };
 
-        let value = float_literal.parse::<f64>().map_err(|_| Error::UnexpectedToken(tokens[0].clone()))?;
-        let final_type = if (-3.40282347e+38..=3.40282347e+38).contains(&value) {
-            if matches!(expected_type, FloatType::Float64) {
-                FloatType::Float64
-            } else {
-                FloatType::Float32
-            }
-        } else {
-            if matches!(expected_type, FloatType::Float32) {
-                return Err(Error::UnexpectedToken(tokens[0].clone()));
-            }
+        let value = float_literal.replace('_', "").parse::<f64>().map_err(|_| Error::UnexpectedToken(tokens[0].clone()))?;
+        // a literal, which doesn't fit into a f32 becomes a f64, unless it's suffixed.
+        // the range of a suffixed literal is checked during the type inference
+        let final_type = if !(-3.40282347e+38..=3.40282347e+38).contains(&value) && !has_float_suffix(tokens, consumed) {
             FloatType::Float64
+        } else {
+            expected_type
         };
 
         Ok(ParseResult {
@@ -65,50 +77,43 @@ impl Parse for FloatAST {
     }
 }
 
-fn get_digits_part<'a>(target: &'a str, postfix: &'a str) -> &'a str {
-    &target[..target.len() - postfix.len()]
+/// Returns true, if the target only consists of decimal digits, optionally separated by `_`
+fn is_decimal(target: &str) -> bool {
+    lazy_regex::regex_is_match!("^\\d[\\d_]*$", target)
 }
 
-/// Returns true, if the target string starts with digits and ends with the given postfix.
-fn split_digits_ends_with_postfix(target: &str, postfix: &str) -> bool {
-    if !target.ends_with(postfix) {
-        return false;
-    }
+/// Splits a postfix like `5_f64`, `5f32` or `_f64` into its leading digits and the float type
+fn split_float_postfix(target: &str) -> Option<(&str, FloatType)> {
+    let (_, digits, float_type) = lazy_regex::regex_captures!("^(\\d[\\d_]*?)?_?(f32|f64)$", target)?;
+    let float_type = FloatType::from_str(float_type).ok()?;
 
-    let digits_part = &target[..target.len() - postfix.len()];
+    Some((digits, float_type))
+}
 
-    digits_part.chars().all(|c| c.is_ascii_digit())
+fn has_float_suffix(tokens: &[TokenWithSpan], consumed: usize) -> bool {
+    matches!(&tokens[consumed - 1].token, Token::Literal(postfix) if split_float_postfix(postfix).is_some())
 }
 
 impl FromStr for FloatAST {
     type Err = NumberErr;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if !lazy_regex::regex_is_match!("^[+-]?(\\d+\\.\\d*|\\d*\\.\\d+)(_f64|_f32)?$", s) {
+        let Some((_, number, suffix)) = lazy_regex::regex_captures!("^([+-]?(?:\\d[\\d_]*\\.[\\d_]*|\\.\\d[\\d_]*|\\d[\\d_]*?))_?(f32|f64)?$", s) else {
             return Err(NumberErr::UnmatchedRegex);
-        }
-
-        let expected_type = match s {
-            a if a.ends_with("_f64") => FloatType::Float64,
-            a if a.ends_with("_f32") => FloatType::Float32,
-            _ => FloatType::Float32
         };
 
-        let s = s.replace("_f64", "").replace("_f32", "");
+        // without a dot, it's only a float if it's suffixed
+        if !number.contains('.') && suffix.is_empty() {
+            return Err(NumberErr::UnmatchedRegex);
+        }
 
-        let value = s.parse::<f64>()?;
+        let value = number.replace('_', "").parse::<f64>()?;
 
-        let final_type = if (-3.40282347e+38..=3.40282347e+38).contains(&value) {
-            if matches!(expected_type, FloatType::Float64) {
-                FloatType::Float64
-            } else {
-                FloatType::Float32
-            }
-        } else {
-            if matches!(expected_type, FloatType::Float32) {
-                return Err(NumberErr::UnmatchedRegex);
-            }
-            FloatType::Float64
+        let final_type = match suffix {
+            "f64" => FloatType::Float64,
+            "f32" => FloatType::Float32,
+            _ if (-3.40282347e+38..=3.40282347e+38).contains(&value) => FloatType::Float32,
+            _ => FloatType::Float64,
         };
 
         Ok(FloatAST {
@@ -116,4 +121,4 @@ impl FromStr for FloatAST {
             ty: final_type,
         })
     }
-}
\ No newline at end of file
+}
@@ -1,6 +1,7 @@
 use crate::core::constants::KEYWORDS;
 use crate::core::lexer::error::Error;
 use crate::core::lexer::parse::{Parse, ParseOptions, ParseResult};
+use crate::core::lexer::token::Token;
 use crate::core::lexer::token_with_span::{TokenWithSpan};
 use crate::core::model::abstract_syntax_tree_nodes::identifier::Identifier;
 
@@ -20,8 +21,25 @@ impl Parse for Identifier {
         Ok(ParseResult {
             consumed: 1,
             result: Identifier {
-                name: target_identifier.to_string()
+                name: target_identifier.to_string(),
+                symbol: None,
             }
         })
     }
-}
\ No newline at end of file
+}
+
+impl Identifier {
+    /// Parses a path of identifiers separated by `::` like `std::printf` into a single identifier
+    pub fn parse_path(tokens: &[TokenWithSpan]) -> Result<ParseResult<Self>, Error> {
+        let mut path = Self::parse(tokens, ParseOptions::default())?;
+
+        while let (Some(TokenWithSpan { token: Token::DoubleColon, .. }), Some(_)) = (tokens.get(path.consumed), tokens.get(path.consumed + 1)) {
+            let Ok(segment) = Self::parse(&tokens[path.consumed + 1..], ParseOptions::default()) else { break };
+
+            path.result.name = format!("{}::{}", path.result.name, segment.result.name);
+            path.consumed += segment.consumed + 1;
+        }
+
+        Ok(path)
+    }
+}
@@ -4,8 +4,10 @@ use std::str::FromStr;
 use crate::core::lexer::error::Error;
 use crate::core::lexer::parse::{Parse, ParseOptions, ParseResult};
 use crate::core::lexer::token::Token;
-use crate::core::lexer::token_with_span::TokenWithSpan;
+use crate::core::lexer::token_with_span::{FilePosition, TokenWithSpan};
 use crate::core::model::types::integer::{IntegerType, IntegerAST};
+use crate::core::model::types::static_string::StaticStringError;
+use crate::core::parser::abstract_syntax_tree_nodes::assignables::string::unescape;
 
 
 impl Parse for IntegerAST {
@@ -29,22 +31,22 @@ impl Parse for IntegerAST {
             });
         }
 
+        if let [char_literal, ..] = tokens {
+            if let Token::Literal(s) = &char_literal.token {
+                if s.starts_with('\'') {
+                    return Ok(ParseResult {
+                        result: IntegerAST::from_char_literal(s, &char_literal.span)?,
+                        consumed: 1,
+                    })
+                }
+            }
+        }
+
         if let [number_literal, ..] = tokens {
             if let Token::Numbers(s) = &number_literal.token {
-                if lazy_regex::regex_is_match!("^[+-]?\\d+$", s) {
-                    let value: i128 = s.parse::<i128>().map_err(|_| Error::UnexpectedToken(tokens[0].clone()))?;
-
-                    let final_type = match value {
-                        -2_147_483_648..=2_147_483_647 => IntegerType::I32,
-                        -9_223_372_036_854_775_808..=9_223_372_036_854_775_808 => IntegerType::I64,
-                        _ => return Err(Error::UnexpectedToken(tokens[0].clone()))
-                    };
-
+                if let Ok(result) = IntegerAST::from_str(s) {
                     return Ok(ParseResult {
-                        result: IntegerAST {
-                            value: value.to_string(),
-                            ty: final_type,
-                        },
+                        result,
                         consumed: 1,
                     })
                 }
@@ -57,6 +59,40 @@ impl Parse for IntegerAST {
 
 
 
+impl IntegerAST {
+    /// Parses a character literal like `'a'` or `'\n'` into its ascii code as an `u8`
+    pub fn from_char_literal(literal: &str, file_position: &FilePosition) -> Result<Self, Error> {
+        if literal.len() < 3 || !literal.ends_with('\'') {
+            return Err(Error::InvalidCharLiteral(literal.to_string(), file_position.clone()));
+        }
+
+        let content = unescape(&literal[1..literal.len() - 1], 1).map_err(|err| match err {
+            StaticStringError::InvalidEscapeSequence(sequence, offset) => {
+                let column = *file_position.column.start() + offset as i32;
+                let byte_offset = file_position.offset.start + literal.chars().take(offset).map(char::len_utf8).sum::<usize>();
+                let file_position = FilePosition {
+                    line: file_position.line.clone(),
+                    column: column..=column + sequence.chars().count() as i32 - 1,
+                    offset: byte_offset..byte_offset + sequence.len(),
+                    file_id: file_position.file_id,
+                };
+
+                Error::InvalidEscapeSequence(sequence, file_position)
+            }
+            StaticStringError::UnmatchedRegex => Error::InvalidCharLiteral(literal.to_string(), file_position.clone()),
+        })?;
+
+        match content.chars().collect::<Vec<_>>().as_slice() {
+            [character] if character.is_ascii() => Ok(IntegerAST {
+                value: (*character as u8).to_string(),
+                ty: IntegerType::U8,
+            }),
+            _ => Err(Error::InvalidCharLiteral(literal.to_string(), file_position.clone())),
+        }
+    }
+}
+
+
 #[derive(Debug)]
 pub enum NumberErr {
     UnmatchedRegex,
@@ -79,7 +115,7 @@ impl std::error::Error for NumberErr { }
 impl Display for NumberErr {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", match self {
-            NumberErr::UnmatchedRegex => "Integer must match ^[+-]?\\d+$".to_string(),
+            NumberErr::UnmatchedRegex => "Not a valid number literal".to_string(),
             NumberErr::ParseIntError(err) => err.to_string(),
             NumberErr::ParseFloatError(err) => err.to_string()
         })
@@ -89,17 +125,37 @@ impl Display for NumberErr {
 impl FromStr for IntegerAST {
     type Err = NumberErr;
 
+    /// Parses decimal, hexadecimal (`0xFF`), binary (`0b1010`) and octal (`0o755`) literals.
+    /// Digits may be separated by `_` and a suffix like `10u8` or `5_i64` sets the type directly.
+    /// Without a suffix, the smallest fitting type of i32 and i64 is used
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if !lazy_regex::regex_is_match!("^[+-]?\\d+$", s) {
+        let Some(captures) = lazy_regex::regex_captures!("^([+-]?)(0x[0-9a-fA-F_]+|0b[01_]+|0o[0-7_]+|\\d[\\d_]*?)_?(i8|u8|i16|u16|i32|u32|i64|u64)?$", s) else {
+            return Err(NumberErr::UnmatchedRegex);
+        };
+
+        let (_, sign, digits, suffix) = captures;
+
+        let (radix, digits) = match digits.get(..2) {
+            Some("0x") => (16, &digits[2..]),
+            Some("0b") => (2, &digits[2..]),
+            Some("0o") => (8, &digits[2..]),
+            _ => (10, digits),
+        };
+
+        let digits = digits.replace('_', "");
+        if digits.is_empty() {
             return Err(NumberErr::UnmatchedRegex);
         }
 
-        let value: i128 = s.parse::<i128>()?;
+        let value = i128::from_str_radix(&format!("{sign}{digits}"), radix)?;
 
-        let final_type = match value {
-            -2_147_483_648..=2_147_483_647 => IntegerType::I32,
-            -9_223_372_036_854_775_808..=9_223_372_036_854_775_808 => IntegerType::I64,
-            _ => return Err(NumberErr::UnmatchedRegex)
+        let final_type = match (suffix, value) {
+            ("", -2_147_483_648..=2_147_483_647) => IntegerType::I32,
+            ("", -9_223_372_036_854_775_808..=9_223_372_036_854_775_808) => IntegerType::I64,
+            ("", _) => return Err(NumberErr::UnmatchedRegex),
+            // the range of a suffixed literal is checked during the type inference
+            (suffix, -18_446_744_073_709_551_615..=18_446_744_073_709_551_615) => IntegerType::from_str(suffix).map_err(|_| NumberErr::UnmatchedRegex)?,
+            (_, _) => return Err(NumberErr::UnmatchedRegex),
         };
 
         Ok(IntegerAST {
@@ -107,4 +163,4 @@ impl FromStr for IntegerAST {
             ty: final_type,
         })
     }
-}
\ No newline at end of file
+}
@@ -1,11 +1,15 @@
 use crate::core::lexer::collect_tokens_until_scope_close::CollectTokensFromUntil;
 use crate::core::lexer::error::Error;
 use crate::core::lexer::parse::{Parse, ParseOptions, ParseResult};
+use crate::core::lexer::token::Token;
 use crate::core::lexer::token_match::MatchResult;
 use crate::core::lexer::token_with_span::{FilePosition, TokenWithSpan};
 use crate::core::model::abstract_syntax_tree_nodes::assignable::{Assignable};
+use crate::core::model::abstract_syntax_tree_nodes::assignables::field_access::FieldAccess;
 use crate::core::model::abstract_syntax_tree_nodes::assignables::method_call::MethodCall;
+use crate::core::model::abstract_syntax_tree_nodes::identifier::Identifier;
 use crate::core::model::abstract_syntax_tree_nodes::l_value::LValue;
+use crate::core::model::types::ty::Type;
 use crate::core::parser::utils::dyck::{dyck_language};
 use crate::pattern;
 
@@ -13,12 +17,84 @@ fn contains(a: &[TokenWithSpan], b: &TokenWithSpan) -> bool {
     a.iter().any(|x| x.token == b.token)
 }
 
+/// methods of other modules are called by their path like `std::printf`. Generic methods are called with optional type arguments like `max::<i32>`
+fn parse_method_name(tokens: &[TokenWithSpan]) -> Option<ParseResult<(LValue, Vec<Type>)>> {
+    let fn_name = if let Some(TokenWithSpan { token: Token::DoubleColon, .. }) = tokens.get(1) {
+        let path = Identifier::parse_path(tokens).ok()?;
+
+        ParseResult {
+            result: LValue::Identifier(path.result),
+            consumed: path.consumed,
+        }
+    } else {
+        match pattern!(tokens, @ parse LValue,) {
+            Some(MatchResult::Parse(fn_name)) => fn_name,
+            _ => return None,
+        }
+    };
+
+    let type_arguments = parse_type_arguments(&tokens[fn_name.consumed..])?;
+
+    Some(ParseResult {
+        result: (fn_name.result, type_arguments.result),
+        consumed: fn_name.consumed + type_arguments.consumed,
+    })
+}
+
+/// the type arguments of a call like `::<i32, f64>`. Calls without type arguments don't consume anything
+fn parse_type_arguments(tokens: &[TokenWithSpan]) -> Option<ParseResult<Vec<Type>>> {
+    let [TokenWithSpan { token: Token::DoubleColon, .. }, TokenWithSpan { token: Token::LessThan, .. }, ..] = tokens else {
+        return Some(ParseResult { result: vec![], consumed: 0 });
+    };
+
+    let mut type_arguments = vec![];
+    let mut consumed = 2;
+
+    loop {
+        let ty = Type::parse(&tokens[consumed..], ParseOptions::default()).ok()?;
+        type_arguments.push(ty.result);
+        consumed += ty.consumed;
+
+        match tokens.get(consumed)?.token {
+            Token::Comma => consumed += 1,
+            Token::GreaterThan => break,
+            _ => return None,
+        }
+    }
+
+    Some(ParseResult {
+        result: type_arguments,
+        consumed: consumed + 1,
+    })
+}
+
+/// methods of impl blocks are called on a value like `person.greet()` or `person.mother.greet()`
+fn parse_receiver(tokens: &[TokenWithSpan]) -> Option<ParseResult<Assignable>> {
+    let receiver = FieldAccess::parse(tokens, ParseOptions::default())
+        .map(|receiver| ParseResult { result: Assignable::FieldAccess(receiver.result), consumed: receiver.consumed })
+        .or_else(|_| Identifier::parse(tokens, ParseOptions::default()).map(|receiver| ParseResult { result: Assignable::Identifier(receiver.result), consumed: receiver.consumed }))
+        .ok()?;
+
+    if let [TokenWithSpan { token: Token::Dot, .. }, TokenWithSpan { token: Token::Literal(_), .. }, TokenWithSpan { token: Token::ParenthesisOpen, .. }, ..] = &tokens[receiver.consumed..] {
+        return Some(ParseResult {
+            result: receiver.result,
+            consumed: receiver.consumed + 1,
+        });
+    }
+
+    None
+}
+
 
 impl Parse for MethodCall {
     fn parse(tokens: &[TokenWithSpan], parse_options: ParseOptions) -> Result<ParseResult<Self>, Error> where Self: Sized, Self: Default {
+        let receiver = parse_receiver(tokens);
+        let receiver_consumed = receiver.as_ref().map_or(0, |receiver| receiver.consumed);
+        let receiver = receiver.map(|receiver| Box::new(receiver.result));
+
         if parse_options.ends_with_semicolon {
-            if let Some(MatchResult::Parse(fn_name)) = pattern!(tokens, @ parse LValue,) {
-                if let Some(MatchResult::Collect(parsed_parameters)) = pattern!(&tokens[fn_name.consumed..], ParenthesisOpen, @ parse CollectTokensFromUntil<'(', ')'>, ParenthesisClose, SemiColon) {
+            if let Some(fn_name) = parse_method_name(&tokens[receiver_consumed..]) {
+                if let Some(MatchResult::Collect(parsed_parameters)) = pattern!(&tokens[receiver_consumed + fn_name.consumed..], ParenthesisOpen, @ parse CollectTokensFromUntil<'(', ')'>, ParenthesisClose, SemiColon) {
                     let parameters = dyck_language(&parsed_parameters, [vec!['(', '{'], vec![','], vec![')', '}']], vec![')'], contains)
                         .map_err(|_| Error::UnexpectedToken(tokens[0].clone()))?
                         .iter()
@@ -32,14 +108,17 @@ impl Parse for MethodCall {
                         return Err(Error::UnexpectedToken(tokens[0].clone()));
                     }
 
-                    let consumed = fn_name.consumed +
+                    let consumed = receiver_consumed +
+                        fn_name.consumed +
                         parameters.iter().map(|p| p.consumed).sum::<usize>() +
                         amount_kommata +
                         3;
 
                     return Ok(ParseResult {
                         result: MethodCall {
-                            identifier: fn_name.result,
+                            receiver,
+                            identifier: fn_name.result.0,
+                            type_arguments: fn_name.result.1,
                             arguments: parameters.iter().map(|p| p.result.clone()).collect(),
                             file_position: FilePosition::from_min_max(&tokens[0], &tokens[consumed - 1]),
                         },
@@ -49,8 +128,8 @@ impl Parse for MethodCall {
             }
         }
 
-        if let Some(MatchResult::Parse(fn_name)) = pattern!(tokens, @ parse LValue,) {
-            if let Some(MatchResult::Collect(parsed_parameters)) = pattern!(&tokens[fn_name.consumed..], ParenthesisOpen, @ parse CollectTokensFromUntil<'(', ')'>, ParenthesisClose) {
+        if let Some(fn_name) = parse_method_name(&tokens[receiver_consumed..]) {
+            if let Some(MatchResult::Collect(parsed_parameters)) = pattern!(&tokens[receiver_consumed + fn_name.consumed..], ParenthesisOpen, @ parse CollectTokensFromUntil<'(', ')'>, ParenthesisClose) {
                 let parameters = dyck_language(&parsed_parameters, [vec!['(', '{'], vec![','], vec![')', '}']], vec![')'], contains)
                     .map_err(|_| Error::UnexpectedToken(tokens[0].clone()))?
                     .iter()
@@ -64,14 +143,17 @@ impl Parse for MethodCall {
                     return Err(Error::UnexpectedToken(tokens[0].clone()));
                 }
 
-                let consumed = fn_name.consumed +
+                let consumed = receiver_consumed +
+                    fn_name.consumed +
                     parameters.iter().map(|p| p.consumed).sum::<usize>() +
                     amount_kommata +
                     2;
 
                 return Ok(ParseResult {
                     result: MethodCall {
-                        identifier: fn_name.result,
+                        receiver,
+                        identifier: fn_name.result.0,
+                        type_arguments: fn_name.result.1,
                         arguments: parameters.iter().map(|p| p.result.clone()).collect(),
                         file_position: FilePosition::from_min_max(&tokens[0], &tokens[consumed - 1]),
                     },
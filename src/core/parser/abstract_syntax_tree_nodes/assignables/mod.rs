@@ -3,8 +3,10 @@ pub mod string;
 pub mod float;
 pub mod method_call;
 pub mod object;
+pub mod enum_path;
 pub mod equation_parser;
 pub mod boolean;
 pub mod array;
 mod identifier;
-mod field;
\ No newline at end of file
+mod field;
+pub mod field_access;
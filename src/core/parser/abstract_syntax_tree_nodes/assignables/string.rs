@@ -3,18 +3,33 @@ use std::str::FromStr;
 use crate::core::lexer::error::Error;
 use crate::core::lexer::parse::{Parse, ParseOptions, ParseResult};
 use crate::core::lexer::token::Token;
-use crate::core::lexer::token_with_span::TokenWithSpan;
+use crate::core::lexer::token_with_span::{FilePosition, TokenWithSpan};
 use crate::core::model::types::static_string::{StaticString, StaticStringError};
 
 impl Parse for StaticString {
     fn parse(tokens: &[TokenWithSpan], _: ParseOptions) -> Result<ParseResult<Self>, Error> where Self: Sized, Self: Default {
         if let [string_literal, ..] = tokens {
             if let Token::Literal(s) = &string_literal.token {
-                if lazy_regex::regex_is_match!("^\".*\"$", s) {
+                if lazy_regex::regex_is_match!("^r?\".*\"$", s) {
+                    let result = StaticString {
+                        value: s.to_string()
+                    };
+
+                    if let Err(StaticStringError::InvalidEscapeSequence(sequence, offset)) = result.content() {
+                        let column = *string_literal.span.column.start() + offset as i32;
+                        let byte_offset = string_literal.span.offset.start + s.chars().take(offset).map(char::len_utf8).sum::<usize>();
+                        let file_position = FilePosition {
+                            line: string_literal.span.line.clone(),
+                            column: column..=column + sequence.chars().count() as i32 - 1,
+                            offset: byte_offset..byte_offset + sequence.len(),
+                            file_id: string_literal.span.file_id,
+                        };
+
+                        return Err(Error::InvalidEscapeSequence(sequence, file_position));
+                    }
+
                     return Ok(ParseResult {
-                        result: StaticString {
-                            value: s.to_string()
-                        },
+                        result,
                         consumed: 1,
                     })
                 }
@@ -29,12 +44,107 @@ impl FromStr for StaticString {
     type Err = StaticStringError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if !lazy_regex::regex_is_match!("^\".*\"$", s) {
+        if !lazy_regex::regex_is_match!("^r?\".*\"$", s) {
             return Err(StaticStringError::UnmatchedRegex);
         }
 
-        Ok(StaticString {
+        let result = StaticString {
             value: s.to_string()
-        })
+        };
+
+        result.content()?;
+        Ok(result)
+    }
+}
+
+impl StaticString {
+    /// Creates a string literal, which evaluates to the provided content
+    pub fn from_content(content: &str) -> StaticString {
+        let mut value = String::from("\"");
+
+        for character in content.chars() {
+            match character {
+                '\n' => value.push_str("\\n"),
+                '\t' => value.push_str("\\t"),
+                '\r' => value.push_str("\\r"),
+                '\0' => value.push_str("\\0"),
+                '"' => value.push_str("\\\""),
+                '\\' => value.push_str("\\\\"),
+                c if c.is_ascii_control() => value.push_str(&format!("\\x{:02X}", c as u8)),
+                c => value.push(c),
+            }
+        }
+
+        value.push('"');
+        StaticString { value }
+    }
+
+    pub fn is_raw(&self) -> bool {
+        self.value.starts_with('r')
+    }
+
+    /// Returns the content between the quotes with all escape sequences resolved.
+    /// Raw strings are returned as they are written
+    pub fn content(&self) -> Result<String, StaticStringError> {
+        let quote_start = if self.is_raw() { 2 } else { 1 };
+
+        if self.value.len() < quote_start + 1 {
+            return Err(StaticStringError::UnmatchedRegex);
+        }
+
+        let inner = &self.value[quote_start..self.value.len() - 1];
+
+        if self.is_raw() {
+            return Ok(inner.to_string());
+        }
+
+        unescape(inner, quote_start)
     }
-}
\ No newline at end of file
+}
+
+/// Resolves the escape sequences in the content of a string or char literal.
+/// `offset` is the position of the content inside the literal, so errors point to the escape sequence
+///
+/// Supported escape sequences: `\n`, `\t`, `\r`, `\0`, `\"`, `\'`, `\\` and `\xHH` up to `\x7F`
+pub fn unescape(inner: &str, offset: usize) -> Result<String, StaticStringError> {
+    let chars = inner.chars().collect::<Vec<_>>();
+    let mut result = String::new();
+    let mut index = 0;
+
+    while index < chars.len() {
+        if chars[index] != '\\' {
+            result.push(chars[index]);
+            index += 1;
+            continue;
+        }
+
+        let escaped = match chars.get(index + 1) {
+            Some('n') => '\n',
+            Some('t') => '\t',
+            Some('r') => '\r',
+            Some('0') => '\0',
+            Some('"') => '"',
+            Some('\'') => '\'',
+            Some('\\') => '\\',
+            Some('x') => {
+                let digits = chars.iter().skip(index + 2).take(2).collect::<String>();
+
+                match u8::from_str_radix(&digits, 16) {
+                    Ok(byte) if digits.len() == 2 && digits.chars().all(|c| c.is_ascii_hexdigit()) && byte <= 0x7F => {
+                        result.push(byte as char);
+                        index += 4;
+                        continue;
+                    }
+                    _ => return Err(StaticStringError::InvalidEscapeSequence(format!("\\x{digits}"), index + offset)),
+                }
+            }
+            Some(c) => return Err(StaticStringError::InvalidEscapeSequence(format!("\\{c}"), index + offset)),
+            None => return Err(StaticStringError::InvalidEscapeSequence("\\".to_string(), index + offset)),
+        };
+
+        result.push(escaped);
+        index += 2;
+    }
+
+    Ok(result)
+}
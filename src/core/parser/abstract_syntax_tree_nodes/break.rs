@@ -0,0 +1,26 @@
+use crate::core::lexer::parse::{Parse, ParseOptions, ParseResult};
+use crate::core::lexer::token::Token;
+use crate::core::lexer::token_with_span::{FilePosition, TokenWithSpan};
+use crate::core::model::abstract_syntax_tree_nodes::break_::Break;
+
+impl Parse for Break {
+    fn parse(tokens: &[TokenWithSpan], _: ParseOptions) -> Result<ParseResult<Self>, crate::core::lexer::error::Error> where Self: Sized, Self: Default {
+        match tokens {
+            [TokenWithSpan { token: Token::Break, .. }, TokenWithSpan { token: Token::SemiColon, .. }, ..] => Ok(ParseResult {
+                result: Break {
+                    label: None,
+                    file_position: FilePosition::from_min_max(&tokens[0], &tokens[1]),
+                },
+                consumed: 2,
+            }),
+            [TokenWithSpan { token: Token::Break, .. }, TokenWithSpan { token: Token::Label(label), .. }, TokenWithSpan { token: Token::SemiColon, .. }, ..] => Ok(ParseResult {
+                result: Break {
+                    label: Some(label.clone()),
+                    file_position: FilePosition::from_min_max(&tokens[0], &tokens[2]),
+                },
+                consumed: 3,
+            }),
+            _ => Err(crate::core::lexer::error::Error::first_unexpected_token(&tokens[0..1], &[Token::Break.into()]))
+        }
+    }
+}
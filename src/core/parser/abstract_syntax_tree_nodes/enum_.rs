@@ -0,0 +1,118 @@
+use std::collections::HashMap;
+use crate::core::lexer::collect_tokens_until_scope_close::CollectTokensFromUntil;
+use crate::core::lexer::error::Error;
+use crate::core::lexer::parse::{Parse, ParseOptions, ParseResult};
+use crate::core::lexer::token::Token;
+use crate::core::lexer::token_match::MatchResult;
+use crate::core::lexer::token_with_span::{FilePosition, TokenWithSpan};
+use crate::core::model::abstract_syntax_tree_nodes::assignables::equation_parser::operator::Operator;
+use crate::core::model::abstract_syntax_tree_nodes::enum_::{Enum, EnumVariant};
+use crate::core::model::abstract_syntax_tree_nodes::identifier::Identifier;
+use crate::core::model::types::integer::IntegerAST;
+use crate::core::model::types::mutability::Mutability;
+use crate::core::model::types::ty::Type;
+use crate::core::parser::utils::dyck::dyck_language;
+use crate::pattern;
+
+fn contains(a: &[TokenWithSpan], b: &TokenWithSpan) -> bool {
+    a.iter().any(|x| x.token == b.token)
+}
+
+impl Parse for Enum {
+    fn parse(tokens: &[TokenWithSpan], _options: ParseOptions) -> Result<ParseResult<Self>, Error> where Self: Sized, Self: Default {
+        if let [TokenWithSpan { token: Token::Pub, .. }, definition_tokens @ ..] = tokens {
+            let mut enum_definition = Self::parse(definition_tokens, ParseOptions::default())?;
+            enum_definition.result.is_public = true;
+            enum_definition.result.file_position = FilePosition::from_min_max(&tokens[0], &tokens[enum_definition.consumed]);
+            enum_definition.consumed += 1;
+
+            return Ok(enum_definition);
+        }
+
+        if let Some(MatchResult::Parse(enum_type)) = pattern!(tokens, Enum, @ parse Type,) {
+            if let Some(MatchResult::Collect(parsed_variants)) = pattern!(&tokens[enum_type.consumed + 1..], CurlyBraceOpen, @ parse CollectTokensFromUntil<'{', '}'>, CurlyBraceClose) {
+                let parsed_variants = dyck_language(
+                    &parsed_variants,
+                    [vec!['(', '{'], vec![','], vec![')', '}']],
+                    vec!['}'],
+                    contains,
+                )
+                .map_err(|_| Error::UnexpectedToken(tokens[0].clone()))?
+                .iter()
+                .map(|variant_tokens| EnumVariant::parse(variant_tokens, ParseOptions::default()))
+                .collect::<Result<Vec<ParseResult<_>>, Error>>()?;
+
+                let amount_kommata = (parsed_variants.len() as isize - 1).max(0) as usize;
+                let consumed = enum_type.consumed
+                    + parsed_variants.iter().map(|v| v.consumed).sum::<usize>()
+                    + amount_kommata
+                    + 3;
+
+                return Ok(ParseResult {
+                    result: Enum {
+                        ty: enum_type.result,
+                        variants: parsed_variants.iter().map(|p| p.result.clone()).collect(),
+                        is_public: false,
+                        file_position: FilePosition::from_min_max(
+                            &tokens[0],
+                            &tokens[consumed - 1],
+                        ),
+                    },
+                    consumed,
+                });
+            }
+        }
+
+        Err(Error::UnexpectedToken(tokens[0].clone()))
+    }
+}
+
+impl Parse for EnumVariant {
+    fn parse(tokens: &[TokenWithSpan], _options: ParseOptions) -> Result<ParseResult<Self>, Error> where Self: Sized, Self: Default {
+        if tokens.is_empty() {
+            return Err(Error::UnexpectedEOF);
+        }
+
+        let name = Identifier::parse(tokens, ParseOptions::default())?;
+
+        if let Some(TokenWithSpan { token: Token::Equals, .. }) = tokens.get(name.consumed) {
+            let discriminant = IntegerAST::parse(&tokens[name.consumed + 1..], ParseOptions::default())?;
+            let consumed = name.consumed + discriminant.consumed + 1;
+
+            if consumed != tokens.len() {
+                return Err(Error::UnexpectedToken(tokens[consumed].clone()));
+            }
+
+            return Ok(ParseResult {
+                result: EnumVariant {
+                    name: name.result,
+                    discriminant: Some(discriminant.result),
+                },
+                consumed,
+            });
+        }
+
+        if name.consumed != tokens.len() {
+            return Err(Error::UnexpectedToken(tokens[name.consumed].clone()));
+        }
+
+        Ok(ParseResult {
+            result: EnumVariant {
+                name: name.result,
+                discriminant: None,
+            },
+            consumed: name.consumed,
+        })
+    }
+}
+
+impl Enum {
+    /// values of the same enum can be compared for equality. Different enums can't be mixed
+    pub fn operation_matrix(&self, base_type_matrix: &mut HashMap<(Type, Operator, Type), Type>) {
+        let mut ty = self.ty.clone();
+        ty.set_mutability(Mutability::Immutable);
+
+        base_type_matrix.insert((ty.clone(), Operator::Equal, ty.clone()), Type::Bool(Mutability::Immutable));
+        base_type_matrix.insert((ty.clone(), Operator::NotEqual, ty), Type::Bool(Mutability::Immutable));
+    }
+}
@@ -10,6 +10,15 @@ use crate::pattern;
 
 impl Parse for For {
     fn parse(tokens: &[TokenWithSpan], _: ParseOptions) -> Result<ParseResult<Self>, crate::core::lexer::error::Error> where Self: Sized, Self: Default {
+        if let [TokenWithSpan { token: Token::Label(label), .. }, TokenWithSpan { token: Token::Colon, .. }, _, ..] = tokens {
+            let mut for_loop = For::parse(&tokens[2..], ParseOptions::default())?;
+            for_loop.result.label = Some(label.clone());
+            for_loop.result.file_position = FilePosition::from_min_max(&tokens[0], &tokens[for_loop.consumed + 1]);
+            for_loop.consumed += 2;
+
+            return Ok(for_loop);
+        }
+
         if let Some(MatchResult::Parse(variable)) = pattern!(tokens, For, ParenthesisOpen, @ parse Variable::<'=', ';'>,) {
             if let Some(MatchResult::Parse(assignable)) = pattern!(&tokens[variable.consumed + 2..], @ parse Assignable, SemiColon) {
                 if let Some(MatchResult::Parse(update_variable)) = pattern!(&tokens[variable.consumed + assignable.consumed + 3..], @ parse Variable<'=', ';'>, ParenthesisClose) {
@@ -18,6 +27,7 @@ impl Parse for For {
 
                     return Ok(ParseResult {
                         result: For {
+                            label: None,
                             initialization: variable.result,
                             condition: assignable.result,
                             update: update_variable.result,
@@ -6,6 +6,7 @@ impl Identifier {
     pub fn uuid() -> Identifier {
         Identifier {
             name: Uuid::new_v4().to_string(),
+            symbol: None,
         }
     }
 }
\ No newline at end of file
@@ -42,7 +42,15 @@ impl Parse for If {
             return Err(Error::first_unexpected_token(tokens, &[Token::If.into(), Token::ParenthesisOpen.into(), ErrorMatch::Collect(0), Token::ParenthesisClose.into()]));
         }
 
-        if let [TokenWithSpan { token: Token::Else, .. }, ..] = &tokens[parse_result.consumed..]  {
+        if let [TokenWithSpan { token: Token::Else, .. }, TokenWithSpan { token: Token::If, .. }, ..] = &tokens[parse_result.consumed..] {
+            // an `else if` chain is an if statement nested inside the else branch
+            let else_if = If::parse(&tokens[parse_result.consumed + 1..], ParseOptions::default())
+                .map_err(|e| Error::Callstack(Box::new(e)).with_context(&tokens[parse_result.consumed]))?;
+
+            parse_result.result.else_stack = Some(vec![AbstractSyntaxTreeNode::If(else_if.result)]);
+            parse_result.result.file_position = FilePosition::from_min_max(&tokens[0], &tokens[parse_result.consumed + else_if.consumed]);
+            parse_result.consumed += else_if.consumed + 1;
+        } else if let [TokenWithSpan { token: Token::Else, .. }, ..] = &tokens[parse_result.consumed..]  {
             let else_scope = Scope::parse(&tokens[parse_result.consumed + 1..], ParseOptions::default())
                 .map_err(|e| Error::Callstack(Box::new(e)).with_context(&tokens[parse_result.consumed]))?;
 
@@ -54,25 +62,3 @@ impl Parse for If {
         Ok(parse_result)
     }
 }
-
-impl If {
-    pub fn ends_with_return_in_each_branch(&self) -> bool {
-        if self.else_stack.is_none() {
-            return false;
-        }
-
-        if let [.., last_if] = &self.if_stack[..] {
-            if let AbstractSyntaxTreeNode::If(inner_if) = last_if {
-                return inner_if.ends_with_return_in_each_branch();
-            }
-
-            if let Some(else_stack) = &self.else_stack {
-                if let [.., last_else] = &else_stack[..] {
-                    return matches!(last_if, AbstractSyntaxTreeNode::Return(_)) && matches!(last_else, AbstractSyntaxTreeNode::Return(_));
-                }
-            }
-        }
-
-        false
-    }
-}
\ No newline at end of file
@@ -0,0 +1,40 @@
+use crate::core::lexer::collect_tokens_until_scope_close::CollectTokensFromUntil;
+use crate::core::lexer::error::Error;
+use crate::core::lexer::parse::{Parse, ParseOptions, ParseResult};
+use crate::core::lexer::token_match::MatchResult;
+use crate::core::lexer::token_with_span::{FilePosition, TokenWithSpan};
+use crate::core::model::abstract_syntax_tree_nodes::impl_::Impl;
+use crate::core::model::abstract_syntax_tree_nodes::method_definition::MethodDefinition;
+use crate::core::model::types::ty::Type;
+use crate::pattern;
+
+impl Parse for Impl {
+    fn parse(tokens: &[TokenWithSpan], _options: ParseOptions) -> Result<ParseResult<Self>, Error> where Self: Sized, Self: Default {
+        if let Some(MatchResult::Parse(impl_type)) = pattern!(tokens, Impl, @ parse Type,) {
+            if let Some(MatchResult::Collect(method_tokens)) = pattern!(&tokens[impl_type.consumed + 1..], CurlyBraceOpen, @ parse CollectTokensFromUntil<'{', '}'>, CurlyBraceClose) {
+                let mut methods = vec![];
+                let mut index = 0;
+
+                while index < method_tokens.len() {
+                    let method_definition = MethodDefinition::parse(&method_tokens[index..], ParseOptions::default())?;
+
+                    index += method_definition.consumed;
+                    methods.push(method_definition.result);
+                }
+
+                let consumed = impl_type.consumed + method_tokens.len() + 3;
+
+                return Ok(ParseResult {
+                    result: Impl {
+                        ty: impl_type.result,
+                        methods,
+                        file_position: FilePosition::from_min_max(&tokens[0], &tokens[consumed - 1]),
+                    },
+                    consumed,
+                });
+            }
+        }
+
+        Err(Error::UnexpectedToken(tokens[0].clone()))
+    }
+}
@@ -1,28 +1,57 @@
-use crate::core::io::monkey_file::MonkeyFile;
+use crate::core::lexer::error::Error;
 use crate::core::lexer::parse::{Parse, ParseOptions, ParseResult};
 use crate::core::lexer::token::Token;
-use crate::core::lexer::token_with_span::TokenWithSpan;
+use crate::core::lexer::token_with_span::{FilePosition, TokenWithSpan};
+use crate::core::model::abstract_syntax_tree_nodes::identifier::Identifier;
 use crate::core::model::abstract_syntax_tree_nodes::import::Import;
-use std::error::Error;
 use std::fmt::{Display, Formatter};
-use std::path::PathBuf;
 
 
 #[derive(Debug)]
 pub enum ImportError {
-    MonkeyFileRead(anyhow::Error)
+    MonkeyFileRead(anyhow::Error),
+    /// the module couldn't be parsed at all
+    Parse(Error),
+    ModuleNotFound { namespace: String, file_position: FilePosition },
+    /// the modules, which import each other. The first module is imported again by the last one
+    CyclicImport { cycle: Vec<String>, file_position: FilePosition },
+    /// two different files are imported with the same namespace
+    NamespaceCollision { namespace: String, file_position: FilePosition },
+    /// only definitions and imports are allowed at the top level of a module
+    StatementInModule { namespace: String, file_position: FilePosition },
+    PrivateAccess { name: String, file_position: FilePosition },
 }
 
 
 impl Display for ImportError {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", match self {
-            ImportError::MonkeyFileRead(a) => format!("Cannot read the file: {a}")
+            ImportError::MonkeyFileRead(a) => format!("Cannot read the file: {a}"),
+            ImportError::Parse(error) => error.to_string(),
+            ImportError::ModuleNotFound { namespace, file_position } => format!("Line: {}\tCannot find the module `{namespace}`", file_position),
+            ImportError::CyclicImport { cycle, file_position } => format!("Line: {}\tCyclic import: {}", file_position, cycle.join(" -> ")),
+            ImportError::NamespaceCollision { namespace, file_position } => format!("Line: {}\tAnother module is already imported as `{namespace}`", file_position),
+            ImportError::StatementInModule { namespace, file_position } => format!("Line: {}\tOnly definitions are allowed at the top level of the module `{namespace}`", file_position),
+            ImportError::PrivateAccess { name, file_position } => format!("Line: {}\t`{name}` is private. Mark it as `pub` to access it from other modules", file_position),
         })
     }
 }
 
-impl Error for ImportError { }
+impl ImportError {
+    pub fn file_position(&self) -> Option<&FilePosition> {
+        match self {
+            ImportError::MonkeyFileRead(_) => None,
+            ImportError::Parse(error) => error.file_position(),
+            ImportError::ModuleNotFound { file_position, .. } |
+            ImportError::CyclicImport { file_position, .. } |
+            ImportError::NamespaceCollision { file_position, .. } |
+            ImportError::StatementInModule { file_position, .. } |
+            ImportError::PrivateAccess { file_position, .. } => Some(file_position),
+        }
+    }
+}
+
+impl std::error::Error for ImportError { }
 
 impl From<anyhow::Error> for ImportError {
     fn from(value: anyhow::Error) -> Self {
@@ -30,21 +59,30 @@ impl From<anyhow::Error> for ImportError {
     }
 }
 
+impl From<Error> for ImportError {
+    fn from(value: Error) -> Self {
+        ImportError::Parse(value)
+    }
+}
+
 impl Parse for Import {
-    fn parse(tokens: &[TokenWithSpan], _: ParseOptions) -> Result<ParseResult<Self>, crate::core::lexer::error::Error> where Self: Sized, Self: Default {
-        if let [TokenWithSpan { token: Token::Module, .. }, TokenWithSpan { token: Token::Literal(ref literal), .. }, TokenWithSpan { token: Token::SemiColon, .. }, ..] = tokens[..] {
-            return Ok(ParseResult {
-                result: Import {
-                    monkey_file: MonkeyFile::read(PathBuf::from(literal)).map_err(|e| {
-                        eprintln!("{}", e);
-                        crate::core::lexer::error::Error::UnexpectedEOF
-                    })?,
-                    file_position: Default::default(),
-                },
-                consumed: 3,
-            })
+    fn parse(tokens: &[TokenWithSpan], _: ParseOptions) -> Result<ParseResult<Self>, Error> where Self: Sized, Self: Default {
+        if let [TokenWithSpan { token: Token::Module, .. }, path_tokens @ ..] = tokens {
+            if let Ok(path) = Identifier::parse_path(path_tokens) {
+                if let Some(TokenWithSpan { token: Token::SemiColon, .. }) = path_tokens.get(path.consumed) {
+                    let consumed = path.consumed + 2;
+
+                    return Ok(ParseResult {
+                        result: Import {
+                            path: path.result.name.split("::").map(|segment| Identifier { name: segment.to_string(), symbol: None }).collect(),
+                            file_position: FilePosition::from_min_max(&tokens[0], &tokens[consumed - 1]),
+                        },
+                        consumed,
+                    })
+                }
+            }
         }
 
-        Err(crate::core::lexer::error::Error::UnexpectedToken(tokens[0].clone()))
+        Err(Error::UnexpectedToken(tokens[0].clone()))
     }
-}
\ No newline at end of file
+}
@@ -4,8 +4,10 @@ use crate::core::lexer::token::Token;
 use crate::core::lexer::token_with_span::TokenWithSpan;
 use crate::core::model::abstract_syntax_tree_nodes::assignable::Assignable;
 use crate::core::model::abstract_syntax_tree_nodes::assignables::equation_parser::expression::Expression;
+use crate::core::model::abstract_syntax_tree_nodes::assignables::field_access::FieldAccess;
 use crate::core::model::abstract_syntax_tree_nodes::identifier::Identifier;
 use crate::core::model::abstract_syntax_tree_nodes::l_value::LValue;
+use crate::core::model::symbol_table::SymbolId;
 
 
 impl Parse for LValue {
@@ -19,6 +21,15 @@ impl Parse for LValue {
             }
         }
 
+        if let Some(TokenWithSpan { token: Token::Dot, .. }) = tokens.get(1) {
+            if let Ok(field_access) = FieldAccess::parse(tokens, ParseOptions::default()) {
+                return Ok(ParseResult {
+                    consumed: field_access.consumed,
+                    result: LValue::Expression(Expression::from(Some(Box::new(Assignable::FieldAccess(field_access.result)))))
+                })
+            }
+        }
+
         if let Ok(identifier) = Identifier::parse(tokens, ParseOptions::default()) {
             Ok(ParseResult {
                 consumed: identifier.consumed,
@@ -54,4 +65,18 @@ impl LValue {
             LValue::Expression(node) => node.identifier().unwrap_or("Expression".to_string()),
         }
     }
+
+    /// the declaration a plain name refers to. Expressions like `a[0]` or `person.age` don't refer to a declaration
+    pub fn symbol(&self) -> Option<SymbolId> {
+        match self {
+            LValue::Identifier(identifier) => identifier.symbol,
+            LValue::Expression(_) => None,
+        }
+    }
+
+    pub fn set_symbol(&mut self, symbol: Option<SymbolId>) {
+        if let LValue::Identifier(identifier) = self {
+            identifier.symbol = symbol;
+        }
+    }
 }
\ No newline at end of file
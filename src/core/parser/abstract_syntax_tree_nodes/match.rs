@@ -0,0 +1,239 @@
+use crate::core::lexer::collect_tokens_until_scope_close::CollectTokensFromUntil;
+use crate::core::lexer::error::{Error, ErrorMatch};
+use crate::core::lexer::parse::{Parse, ParseOptions, ParseResult};
+use crate::core::lexer::token::Token;
+use crate::core::lexer::token_match::MatchResult;
+use crate::core::lexer::token_with_span::{FilePosition, TokenWithSpan};
+use crate::core::model::abstract_syntax_tree_nodes::assignable::Assignable;
+use crate::core::model::abstract_syntax_tree_nodes::match_::{Match, MatchArm, MatchPattern};
+use crate::core::model::scope::Scope;
+use crate::core::model::types::ty::Type;
+use crate::core::parser::static_type_context::StaticTypeContext;
+use crate::pattern;
+
+
+impl Parse for Match {
+    fn parse(tokens: &[TokenWithSpan], _: ParseOptions) -> Result<ParseResult<Self>, Error> where Self: Sized, Self: Default {
+        if let Some(MatchResult::Parse(value)) = pattern!(tokens, Match, ParenthesisOpen, @parse Assignable, ParenthesisClose) {
+            let arms_start = value.consumed + 3;
+
+            let Some(MatchResult::Collect(arm_tokens)) = pattern!(&tokens[arms_start..], CurlyBraceOpen, @parse CollectTokensFromUntil<'{', '}'>, CurlyBraceClose) else {
+                return Err(Error::ExpectedToken(Token::CurlyBraceOpen).with_context(&tokens[0]));
+            };
+
+            let mut arms = vec![];
+            let mut index = 0;
+
+            while index < arm_tokens.len() {
+                let arm = MatchArm::parse(&arm_tokens[index..], ParseOptions::default())
+                    .map_err(|e| Error::Callstack(Box::new(e)).with_context(&tokens[0]))?;
+
+                index += arm.consumed;
+                arms.push(arm.result);
+
+                // arms can optionally be separated by `,`
+                if let Some(TokenWithSpan { token: Token::Comma, .. }) = arm_tokens.get(index) {
+                    index += 1;
+                }
+            }
+
+            let consumed = arms_start + index + 2;
+
+            return Ok(ParseResult {
+                result: Match {
+                    value: value.result,
+                    arms,
+                    file_position: FilePosition::from_min_max(&tokens[0], &tokens[consumed - 1]),
+                },
+                consumed,
+            });
+        }
+
+        Err(Error::first_unexpected_token(tokens, &[Token::Match.into(), Token::ParenthesisOpen.into(), ErrorMatch::Collect(0), Token::ParenthesisClose.into()]))
+    }
+}
+
+impl Parse for MatchArm {
+    fn parse(tokens: &[TokenWithSpan], _: ParseOptions) -> Result<ParseResult<Self>, Error> where Self: Sized, Self: Default {
+        let Some(fat_arrow) = tokens.iter().position(|token| token.token == Token::FatArrow) else {
+            return Err(Error::ExpectedToken(Token::FatArrow));
+        };
+
+        if fat_arrow == 0 {
+            return Err(Error::UnexpectedToken(tokens[0].clone()));
+        }
+
+        let patterns = tokens[..fat_arrow]
+            .split(|token| token.token == Token::Pipe)
+            .map(|pattern_tokens| MatchPattern::parse(pattern_tokens, ParseOptions::default()).map(|pattern| pattern.result))
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        let scope = Scope::parse(&tokens[fat_arrow + 1..], ParseOptions::default())?;
+        let consumed = fat_arrow + scope.consumed + 1;
+
+        Ok(ParseResult {
+            result: MatchArm {
+                patterns,
+                stack: scope.result.ast_nodes,
+                file_position: FilePosition::from_min_max(&tokens[0], &tokens[consumed - 1]),
+            },
+            consumed,
+        })
+    }
+}
+
+impl Parse for MatchPattern {
+    /// Parses exactly the provided tokens as one pattern
+    fn parse(tokens: &[TokenWithSpan], _: ParseOptions) -> Result<ParseResult<Self>, Error> where Self: Sized, Self: Default {
+        if let [TokenWithSpan { token: Token::Underscore, .. }] = tokens {
+            return Ok(ParseResult { result: MatchPattern::Wildcard, consumed: 1 });
+        }
+
+        let range_operator = tokens.iter().position(|token| matches!(token.token, Token::DotDot | Token::DotDotEquals));
+
+        let result = if let Some(range_operator) = range_operator {
+            MatchPattern::Range {
+                start: Self::parse_literal(&tokens[..range_operator])?,
+                end: Self::parse_literal(&tokens[range_operator + 1..])?,
+                inclusive: tokens[range_operator].token == Token::DotDotEquals,
+            }
+        } else {
+            MatchPattern::Literal(Self::parse_literal(tokens)?)
+        };
+
+        Ok(ParseResult { result, consumed: tokens.len() })
+    }
+}
+
+impl MatchPattern {
+    /// only integers, booleans and enum variants are allowed as literals
+    fn parse_literal(tokens: &[TokenWithSpan]) -> Result<Assignable, Error> {
+        if tokens.is_empty() {
+            return Err(Error::UnexpectedEOF);
+        }
+
+        let literal = Assignable::parse(tokens, ParseOptions::default())?;
+
+        if literal.consumed != tokens.len() {
+            return Err(Error::UnexpectedToken(tokens[literal.consumed.min(tokens.len() - 1)].clone()));
+        }
+
+        match literal.result {
+            Assignable::Integer(_) | Assignable::Boolean(_) | Assignable::EnumPath(_) => Ok(literal.result),
+            _ => Err(Error::UnexpectedToken(tokens[0].clone())),
+        }
+    }
+
+    /// Returns the first and the last value covered by the pattern. Booleans are represented as `0` and `1`,
+    /// enum variants by their discriminant. Wildcards and unknown literals result in `None`
+    pub fn interval(&self, type_context: &StaticTypeContext) -> Option<(i128, i128)> {
+        match self {
+            MatchPattern::Literal(literal) => {
+                let value = Self::literal_value(literal, type_context)?;
+                Some((value, value))
+            }
+            MatchPattern::Range { start, end, inclusive } => {
+                let end = Self::literal_value(end, type_context)?;
+                Some((Self::literal_value(start, type_context)?, if *inclusive { end } else { end - 1 }))
+            }
+            MatchPattern::Wildcard => None,
+        }
+    }
+
+    fn literal_value(literal: &Assignable, type_context: &StaticTypeContext) -> Option<i128> {
+        match literal {
+            Assignable::Integer(integer) => integer.value.parse::<i128>().ok(),
+            Assignable::Boolean(boolean) => Some(boolean.value as i128),
+            Assignable::EnumPath(enum_path) => type_context
+                .enum_definition(&enum_path.ty)?
+                .discriminant(&enum_path.variant.name)
+                .map(i128::from),
+            _ => None,
+        }
+    }
+}
+
+impl Match {
+    /// Checks, if every possible value of the provided type is matched by at least one arm
+    pub fn is_exhaustive(&self, value_type: &Type, type_context: &StaticTypeContext) -> bool {
+        let patterns = self.arms.iter().flat_map(|arm| &arm.patterns);
+
+        if patterns.clone().any(|pattern| *pattern == MatchPattern::Wildcard) {
+            return true;
+        }
+
+        let mut intervals = patterns
+            .filter_map(|pattern| pattern.interval(type_context))
+            .collect::<Vec<_>>();
+        intervals.sort();
+
+        covers_type(&intervals, value_type, type_context)
+    }
+
+    /// Returns the first pattern, which can't match any value, because the previous patterns already match all of its values
+    pub fn unreachable_pattern(&self, value_type: &Type, type_context: &StaticTypeContext) -> Option<(&MatchArm, &MatchPattern)> {
+        let mut intervals: Vec<(i128, i128)> = vec![];
+        let mut wildcard_found = false;
+
+        for (arm, pattern) in self.arms.iter().flat_map(|arm| arm.patterns.iter().map(move |pattern| (arm, pattern))) {
+            if wildcard_found {
+                return Some((arm, pattern));
+            }
+
+            intervals.sort();
+
+            match pattern.interval(type_context) {
+                Some((start, end)) if start <= end => {
+                    if covers(&intervals, start, end) {
+                        return Some((arm, pattern));
+                    }
+
+                    intervals.push((start, end));
+                }
+                Some(_) => {}
+                None => {
+                    if *pattern == MatchPattern::Wildcard && covers_type(&intervals, value_type, type_context) {
+                        return Some((arm, pattern));
+                    }
+
+                    wildcard_found = *pattern == MatchPattern::Wildcard;
+                }
+            }
+        }
+
+        None
+    }
+}
+
+/// Checks, if the sorted intervals contain every possible value of the provided type
+fn covers_type(intervals: &[(i128, i128)], value_type: &Type, type_context: &StaticTypeContext) -> bool {
+    let is_covered = |value: i128| intervals.iter().any(|(start, end)| (*start..=*end).contains(&value));
+
+    match value_type {
+        Type::Bool(_) => is_covered(0) && is_covered(1),
+        Type::Integer(integer_type, _) => {
+            let range = integer_type.range();
+            covers(intervals, *range.start(), *range.end())
+        }
+        _ => type_context.enum_definition(value_type).is_some_and(|enum_definition| {
+            enum_definition.variants
+                .iter()
+                .all(|variant| enum_definition.discriminant(&variant.name.name).is_some_and(|discriminant| is_covered(discriminant.into())))
+        }),
+    }
+}
+
+/// Checks, if the sorted intervals contain every value from `start` to `end`
+fn covers(intervals: &[(i128, i128)], start: i128, end: i128) -> bool {
+    let mut next_uncovered = start;
+
+    for (interval_start, interval_end) in intervals {
+        if *interval_start > next_uncovered {
+            break;
+        }
+
+        next_uncovered = next_uncovered.max(interval_end + 1);
+    }
+
+    next_uncovered > end
+}
@@ -4,7 +4,7 @@ use crate::core::lexer::token::Token;
 use crate::core::lexer::token_match::MatchResult;
 use crate::core::lexer::token_with_span::{FilePosition, TokenWithSpan};
 use crate::core::model::abstract_syntax_tree_nodes::l_value::LValue;
-use crate::core::model::abstract_syntax_tree_nodes::method_definition::{MethodArgument, MethodDefinition};
+use crate::core::model::abstract_syntax_tree_nodes::method_definition::{GenericParameter, MethodArgument, MethodDefinition};
 use crate::core::model::scope::Scope;
 use crate::core::model::types::ty::Type;
 use crate::core::parser::utils::dyck::dyck_language;
@@ -16,6 +16,30 @@ fn contains(a: &[TokenWithSpan], b: &TokenWithSpan) -> bool {
 
 impl Parse for MethodDefinition {
     fn parse(tokens: &[TokenWithSpan], _: ParseOptions) -> Result<ParseResult<Self>, crate::core::lexer::error::Error> where Self: Sized, Self: Default {
+        // pub fn name(args): return_type { }
+        if let [TokenWithSpan { token: Token::Pub, .. }, definition_tokens @ ..] = tokens {
+            let mut method_definition = Self::parse(definition_tokens, ParseOptions::default())?;
+            method_definition.result.is_public = true;
+            method_definition.result.file_position = FilePosition::from_min_max(&tokens[0], &tokens[method_definition.consumed]);
+            method_definition.consumed += 1;
+
+            return Ok(method_definition);
+        }
+
+        // fn name<T, U: Numeric>(args): return_type { }
+        if let [TokenWithSpan { token: Token::Fn, .. }, TokenWithSpan { token: Token::Literal(_), .. }, TokenWithSpan { token: Token::LessThan, .. }, ..] = tokens {
+            let generic_parameters = GenericParameter::parse_list(&tokens[3..])?;
+            // the definition without the type parameters is parsed like any other method
+            let definition_tokens = [&tokens[..2], &tokens[generic_parameters.consumed + 3..]].concat();
+
+            let mut method_definition = Self::parse(&definition_tokens, ParseOptions::default())?;
+            method_definition.result.generic_parameters = generic_parameters.result;
+            method_definition.consumed += generic_parameters.consumed + 1;
+            method_definition.result.file_position = FilePosition::from_min_max(&tokens[0], &tokens[method_definition.consumed - 1]);
+
+            return Ok(method_definition);
+        }
+
         // extern fn name(args): return_type;
         if let Some(MatchResult::Parse(parsed_fn_name)) = pattern!(tokens, Extern, Fn, @ parse LValue,) {
             if let Some(MatchResult::Collect(parsed_parameters)) = pattern!(&tokens[parsed_fn_name.consumed + 2..], ParenthesisOpen, @ parse CollectTokensFromUntil<'(', ')'>, ParenthesisClose) {
@@ -37,10 +61,12 @@ impl Parse for MethodDefinition {
                     return Ok(ParseResult {
                         result: MethodDefinition {
                             identifier: parsed_fn_name.result,
+                            generic_parameters: vec![],
                             return_type: parsed_return_type.result,
                             arguments: parsed_parameters.iter().map(|p| p.result.clone()).collect(),
                             stack: vec![],
                             is_extern: true,
+                            is_public: false,
                             file_position: FilePosition::from_min_max(&tokens[0], &tokens[consumed - 1]),
                         },
                         consumed,
@@ -75,10 +101,12 @@ impl Parse for MethodDefinition {
                     return Ok(ParseResult {
                         result: MethodDefinition {
                             identifier: parsed_fn_name.result,
+                            generic_parameters: vec![],
                             return_type: parsed_return_type.result,
                             arguments: parsed_parameters.iter().map(|p| p.result.clone()).collect(),
                             stack: scope.result.ast_nodes.to_vec(),
                             is_extern: false,
+                            is_public: false,
                             file_position: FilePosition::from_min_max(&tokens[0], &tokens[consumed - 1]),
                         },
                         consumed,
@@ -116,10 +144,12 @@ impl Parse for MethodDefinition {
                 return Ok(ParseResult {
                     result: MethodDefinition {
                         identifier: parsed_fn_name.result,
+                        generic_parameters: vec![],
                         return_type: return_type.result,
                         arguments: parsed_parameters.iter().map(|p| p.result.clone()).collect(),
                         stack: scope.result.ast_nodes.to_vec(),
                         is_extern: false,
+                        is_public: false,
                         file_position: FilePosition::from_min_max(&tokens[0], &tokens[consumed - 1]),
                     },
                     consumed,
@@ -131,6 +161,43 @@ impl Parse for MethodDefinition {
     }
 }
 
+impl GenericParameter {
+    /// Parses the type parameters after the `<` of a generic method like `T, U: Numeric>`. The closing `>` is consumed as well
+    pub fn parse_list(tokens: &[TokenWithSpan]) -> Result<ParseResult<Vec<Self>>, crate::core::lexer::error::Error> {
+        let mut generic_parameters = vec![];
+        let mut consumed = 0;
+
+        loop {
+            let (name, bound, parameter_consumed) = match &tokens[consumed..] {
+                [TokenWithSpan { token: Token::Literal(name), .. }, TokenWithSpan { token: Token::Colon, .. }, bound @ TokenWithSpan { token: Token::Literal(bound_name), .. }, ..] => {
+                    let bound_name = bound_name.parse()
+                        .map_err(|_| crate::core::lexer::error::Error::UnexpectedToken(bound.clone()))?;
+
+                    (name, Some(bound_name), 3)
+                }
+                [TokenWithSpan { token: Token::Literal(name), .. }, ..] => (name, None, 1),
+                [token, ..] => return Err(crate::core::lexer::error::Error::UnexpectedToken(token.clone())),
+                [] => return Err(crate::core::lexer::error::Error::UnexpectedEOF),
+            };
+
+            generic_parameters.push(GenericParameter { name: name.clone(), bound });
+            consumed += parameter_consumed;
+
+            match tokens.get(consumed) {
+                Some(TokenWithSpan { token: Token::Comma, .. }) => consumed += 1,
+                Some(TokenWithSpan { token: Token::GreaterThan, .. }) => break,
+                Some(token) => return Err(crate::core::lexer::error::Error::UnexpectedToken(token.clone())),
+                None => return Err(crate::core::lexer::error::Error::UnexpectedEOF),
+            }
+        }
+
+        Ok(ParseResult {
+            result: generic_parameters,
+            consumed: consumed + 1,
+        })
+    }
+}
+
 impl Parse for MethodArgument {
     fn parse(tokens: &[TokenWithSpan], _: ParseOptions) -> Result<ParseResult<Self>, crate::core::lexer::error::Error> where Self: Sized, Self: Default {
         if let Some(MatchResult::Parse(name)) = pattern!(tokens, @ parse LValue, Colon,) {
@@ -4,11 +4,17 @@ pub mod assignable;
 pub mod assignables;
 pub mod method_definition;
 pub mod r#if;
+pub mod r#match;
 
 pub mod import;
 pub mod r#return;
 pub mod parameter;
 pub mod r#for;
 pub mod r#while;
+pub mod r#break;
+pub mod r#continue;
 pub mod l_value;
 mod struct_;
+mod enum_;
+
+mod impl_;
\ No newline at end of file
@@ -1,6 +1,7 @@
 use crate::core::lexer::collect_tokens_until_scope_close::CollectTokensFromUntil;
 use crate::core::lexer::error::Error;
 use crate::core::lexer::parse::{Parse, ParseOptions, ParseResult};
+use crate::core::lexer::token::Token;
 use crate::core::lexer::token_match::MatchResult;
 use crate::core::lexer::token_with_span::{FilePosition, TokenWithSpan};
 use crate::core::model::abstract_syntax_tree_nodes::struct_::{Field, Struct};
@@ -14,6 +15,15 @@ fn contains(a: &[TokenWithSpan], b: &TokenWithSpan) -> bool {
 
 impl Parse for Struct {
     fn parse(tokens: &[TokenWithSpan], _options: ParseOptions) -> Result<ParseResult<Self>, Error> where Self: Sized, Self: Default {
+        if let [TokenWithSpan { token: Token::Pub, .. }, definition_tokens @ ..] = tokens {
+            let mut struct_definition = Self::parse(definition_tokens, ParseOptions::default())?;
+            struct_definition.result.is_public = true;
+            struct_definition.result.file_position = FilePosition::from_min_max(&tokens[0], &tokens[struct_definition.consumed]);
+            struct_definition.consumed += 1;
+
+            return Ok(struct_definition);
+        }
+
         if let Some(MatchResult::Parse(struct_type)) = pattern!(tokens, Struct, @ parse Type,) {
             if let Some(MatchResult::Collect(parsed_fields)) = pattern!(&tokens[struct_type.consumed + 1..], CurlyBraceOpen, @ parse CollectTokensFromUntil<'{', '}'>, CurlyBraceClose) {
                 let parsed_fields = dyck_language(
@@ -37,6 +47,7 @@ impl Parse for Struct {
                     result: Struct {
                         ty: struct_type.result,
                         fields: parsed_fields.iter().map(|p| p.result.clone()).collect(),
+                        is_public: false,
                         file_position: FilePosition::from_min_max(
                             &tokens[0],
                             &tokens[consumed - 1],
@@ -1,7 +1,10 @@
 use crate::core::lexer::parse::{Parse, ParseOptions, ParseResult};
+use crate::core::lexer::token::Token;
 use crate::core::lexer::token_match::MatchResult;
 use crate::core::lexer::token_with_span::{FilePosition, TokenWithSpan};
 use crate::core::model::abstract_syntax_tree_nodes::assignable::{Assignable};
+use crate::core::model::abstract_syntax_tree_nodes::assignables::equation_parser::expression::Expression;
+use crate::core::model::abstract_syntax_tree_nodes::assignables::equation_parser::operator::Operator;
 use crate::core::model::abstract_syntax_tree_nodes::l_value::LValue;
 use crate::core::model::abstract_syntax_tree_nodes::variable::Variable;
 use crate::core::model::types::ty::Type;
@@ -17,6 +20,7 @@ impl Parse for Variable<'=', ';'> {
                         mutability: false,
                         ty: None,
                         define: true,
+                        compound_operator: None,
                         assignable: assign.result,
                         file_position: FilePosition::from_min_max(&tokens[0], &tokens[l_value.consumed + assign.consumed + 2]),
                     },
@@ -33,6 +37,7 @@ impl Parse for Variable<'=', ';'> {
                         mutability: false,
                         ty: None,
                         define: false,
+                        compound_operator: None,
                         assignable: assign.result,
                         file_position: FilePosition::from_min_max(&tokens[0], &tokens[l_value.consumed + assign.consumed + 1]),
                     },
@@ -41,6 +46,25 @@ impl Parse for Variable<'=', ';'> {
             }
         }
 
+        if let Some(MatchResult::Parse(l_value)) = pattern!(tokens, @parse LValue,) {
+            if let Some(operator) = tokens.get(l_value.consumed).and_then(|token| compound_operator(&token.token)) {
+                if let Some(MatchResult::Parse(assign)) = pattern!(&tokens[l_value.consumed + 1..], @parse Assignable, SemiColon) {
+                    return Ok(ParseResult {
+                        result: Variable {
+                            l_value: l_value.result,
+                            mutability: false,
+                            ty: None,
+                            define: false,
+                            compound_operator: Some(operator),
+                            assignable: assign.result,
+                            file_position: FilePosition::from_min_max(&tokens[0], &tokens[l_value.consumed + assign.consumed + 1]),
+                        },
+                        consumed: l_value.consumed + assign.consumed + 2,
+                    });
+                }
+            }
+        }
+
         if let Some(MatchResult::Parse(l_value)) = pattern!(tokens, Let, Mut, @parse LValue, Equals) {
             if let Some(MatchResult::Parse(assign)) = pattern!(&tokens[l_value.consumed + 3..], @parse Assignable, SemiColon) {
                 return Ok(ParseResult {
@@ -49,6 +73,7 @@ impl Parse for Variable<'=', ';'> {
                         mutability: true,
                         ty: None,
                         define: true,
+                        compound_operator: None,
                         assignable: assign.result,
                         file_position: FilePosition::from_min_max(&tokens[0], &tokens[l_value.consumed + assign.consumed + 3]),
                     },
@@ -66,6 +91,7 @@ impl Parse for Variable<'=', ';'> {
                             mutability: false,
                             ty: Some(ty.result),
                             define: true,
+                            compound_operator: None,
                             assignable: assign.result,
                             file_position: FilePosition::from_min_max(&tokens[0], &tokens[l_value.consumed + ty.consumed + assign.consumed + 3]),
                         },
@@ -84,6 +110,7 @@ impl Parse for Variable<'=', ';'> {
                             mutability: true,
                             ty: Some(ty.result),
                             define: true,
+                            compound_operator: None,
                             assignable: assign.result,
                             file_position: FilePosition::from_min_max(&tokens[0], &tokens[l_value.consumed + ty.consumed + assign.consumed + 4]),
                         },
@@ -93,11 +120,84 @@ impl Parse for Variable<'=', ';'> {
             }
         }
 
+        // declarations without a value like `let mut a: i32;` are assigned later
+        if let Some(MatchResult::Parse(l_value)) = pattern!(tokens, Let, @parse LValue, Colon) {
+            if let Some(MatchResult::Parse(ty)) = pattern!(&tokens[l_value.consumed + 2..], @parse Type, SemiColon) {
+                return Ok(ParseResult {
+                    result: Variable {
+                        l_value: l_value.result,
+                        mutability: false,
+                        ty: Some(ty.result),
+                        define: true,
+                        compound_operator: None,
+                        assignable: Assignable::Uninitialized,
+                        file_position: FilePosition::from_min_max(&tokens[0], &tokens[l_value.consumed + ty.consumed + 2]),
+                    },
+                    consumed: l_value.consumed + ty.consumed + 3,
+                });
+            }
+        }
+
+        if let Some(MatchResult::Parse(l_value)) = pattern!(tokens, Let, Mut, @parse LValue, Colon) {
+            if let Some(MatchResult::Parse(ty)) = pattern!(&tokens[l_value.consumed + 3..], @parse Type, SemiColon) {
+                return Ok(ParseResult {
+                    result: Variable {
+                        l_value: l_value.result,
+                        mutability: true,
+                        ty: Some(ty.result),
+                        define: true,
+                        compound_operator: None,
+                        assignable: Assignable::Uninitialized,
+                        file_position: FilePosition::from_min_max(&tokens[0], &tokens[l_value.consumed + ty.consumed + 3]),
+                    },
+                    consumed: l_value.consumed + ty.consumed + 4,
+                });
+            }
+        }
+
 
         Err(crate::core::lexer::error::Error::UnexpectedToken(tokens[0].clone()))
     }
 }
 
+impl<const ASSIGNMENT: char, const SEPARATOR: char> Variable<ASSIGNMENT, SEPARATOR> {
+    /// Returns the expression `l_value <operator> assignable`, which is computed by a compound assignment
+    pub fn compound_expression(&self) -> Option<Expression> {
+        let operator = self.compound_operator?;
+        let l_value = match &self.l_value {
+            LValue::Identifier(identifier) => Expression::from(Some(Box::new(Assignable::Identifier(identifier.clone())))),
+            LValue::Expression(expression) => expression.clone(),
+        };
+        let assignable = match &self.assignable {
+            Assignable::Expression(expression) => expression.clone(),
+            assignable => Expression::from(Some(Box::new(assignable.clone()))),
+        };
+
+        Some(Expression {
+            lhs: Some(Box::new(l_value)),
+            rhs: Some(Box::new(assignable)),
+            operator,
+            ..Default::default()
+        })
+    }
+}
+
+fn compound_operator(token: &Token) -> Option<Operator> {
+    Some(match token {
+        Token::PlusEquals => Operator::Add,
+        Token::MinusEquals => Operator::Sub,
+        Token::MultiplyEquals => Operator::Mul,
+        Token::DivideEquals => Operator::Div,
+        Token::ModuloEquals => Operator::Mod,
+        Token::LeftShiftEquals => Operator::LeftShift,
+        Token::RightShiftEquals => Operator::RightShift,
+        Token::AmpersandEquals => Operator::BitwiseAnd,
+        Token::PipeEquals => Operator::BitwiseOr,
+        Token::XorEquals => Operator::BitwiseXor,
+        _ => return None,
+    })
+}
+
 impl Parse for Variable<':', ','> {
     fn parse(tokens: &[TokenWithSpan], _: ParseOptions) -> Result<ParseResult<Self>, crate::core::lexer::error::Error> where Self: Sized, Self: Default {
         if let Some(MatchResult::Parse(l_value)) = pattern!(tokens, @parse LValue, Colon) {
@@ -108,6 +208,7 @@ impl Parse for Variable<':', ','> {
                         mutability: false,
                         ty: None,
                         define: false,
+                        compound_operator: None,
                         assignable: assign.result,
                         file_position: FilePosition::from_min_max(&tokens[0], &tokens[l_value.consumed + assign.consumed]),
                     },
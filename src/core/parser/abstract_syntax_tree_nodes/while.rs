@@ -42,12 +42,22 @@ impl Display for WhileErr {
 
 impl Parse for While {
     fn parse(tokens: &[TokenWithSpan], _: ParseOptions) -> Result<ParseResult<Self>, crate::core::lexer::error::Error> where Self: Sized, Self: Default {
+        if let [TokenWithSpan { token: Token::Label(label), .. }, TokenWithSpan { token: Token::Colon, .. }, _, ..] = tokens {
+            let mut while_loop = While::parse(&tokens[2..], ParseOptions::default())?;
+            while_loop.result.label = Some(label.clone());
+            while_loop.result.file_position = FilePosition::from_min_max(&tokens[0], &tokens[while_loop.consumed + 1]);
+            while_loop.consumed += 2;
+
+            return Ok(while_loop);
+        }
+
         if let Some(MatchResult::Parse(condition)) = pattern!(tokens, While, ParenthesisOpen, @ parse Assignable, ParenthesisClose) {
             let scope = Scope::parse(&tokens[condition.consumed + 3..], ParseOptions::default())
                 .map_err(|e| crate::core::lexer::error::Error::Callstack(Box::new(e)).with_context(&tokens[0]))?;
 
             return Ok(ParseResult {
                 result: While {
+                    label: None,
                     condition: condition.result,
                     stack: scope.result.ast_nodes,
                     file_position: FilePosition::from_min_max(&tokens[0], &tokens[condition.consumed + scope.consumed + 2]),
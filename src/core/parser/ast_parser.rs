@@ -10,6 +10,8 @@ use crate::core::model::scope::Scope;
 pub struct ASTParser {
     pub program: Vec<AbstractSyntaxTreeNode>,
     pub has_main_method: bool,
+    /// Every syntax error of the program in the order of appearance
+    pub diagnostics: Vec<Error>,
 }
 
 impl Display for ASTParser {
@@ -26,18 +28,27 @@ impl Display for ASTParser {
 }
 
 impl ASTParser {
+    /// Parses the program and continues after syntax errors. Statements, which couldn't be parsed,
+    /// are kept as [`AbstractSyntaxTreeNode::SyntaxError`] and collected in `diagnostics`.
+    /// Only an unbalanced program fails as a whole
     pub fn parse(tokens: &[TokenWithSpan]) -> Result<ParseResult<Self>, Error> where Self: Sized + Clone {
         let mut tokens = tokens.to_vec();
 
-        tokens.insert(0, TokenWithSpan {token: Token::CurlyBraceOpen, span: FilePosition::default() });
-        tokens.push(TokenWithSpan {token: Token::CurlyBraceClose, span: FilePosition::default() });
+        tokens.insert(0, TokenWithSpan {token: Token::CurlyBraceOpen, span: FilePosition::default(), trivia: None });
+        tokens.push(TokenWithSpan {token: Token::CurlyBraceClose, span: FilePosition::default(), trivia: None });
 
         let program = Scope::parse(&tokens, ParseOptions::default())?;
 
         assert_eq!(program.consumed - 2, tokens.len() - 2);
 
+        // the methods of impl blocks are lowered into method definitions named like `Person::greet`
+        let ast_nodes = program.result.ast_nodes.into_iter().flat_map(|node| match node {
+            AbstractSyntaxTreeNode::Impl(impl_block) => impl_block.mangled_methods().into_iter().map(AbstractSyntaxTreeNode::MethodDefinition).collect(),
+            node => vec![node],
+        }).collect::<Vec<_>>();
+
         let mut has_main_method = false;
-        for ast_nodes in &program.result.ast_nodes {
+        for ast_nodes in &ast_nodes {
             if let AbstractSyntaxTreeNode::MethodDefinition(method_definition) = ast_nodes {
                 if method_definition.identifier.identifier() == "main" && method_definition.arguments.is_empty() && !method_definition.is_extern {
                     has_main_method = true;
@@ -46,12 +57,28 @@ impl ASTParser {
             }
         }
 
+        let mut diagnostics = vec![];
+        Self::collect_diagnostics(&ast_nodes, &mut diagnostics);
+
         Ok(ParseResult {
             result: ASTParser {
-                program: program.result.ast_nodes,
-                has_main_method
+                program: ast_nodes,
+                has_main_method,
+                diagnostics,
             },
             consumed: program.consumed - 2, // reduce the open and close scope. those virtual tokens are not part of the program
         })
     }
+
+    fn collect_diagnostics(scope: &[AbstractSyntaxTreeNode], diagnostics: &mut Vec<Error>) {
+        for node in scope {
+            if let AbstractSyntaxTreeNode::SyntaxError(syntax_error) = node {
+                diagnostics.push(syntax_error.error.clone());
+            }
+
+            for inner_scope in node.scope().unwrap_or_default() {
+                Self::collect_diagnostics(inner_scope, diagnostics);
+            }
+        }
+    }
 }
\ No newline at end of file
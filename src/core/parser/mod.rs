@@ -6,4 +6,5 @@ pub mod errors;
 pub mod static_type_context;
 pub mod types;
 pub mod scope_iterator;
-pub mod utils;
\ No newline at end of file
+pub mod utils;
+pub mod module_loader;
\ No newline at end of file
@@ -0,0 +1,411 @@
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use crate::core::io::monkey_file::MonkeyFile;
+use crate::core::io::source_map::SourceMap;
+use crate::core::lexer::token_with_span::FilePosition;
+use crate::core::model::abstract_syntax_tree_node::AbstractSyntaxTreeNode;
+use crate::core::model::abstract_syntax_tree_nodes::assignable::Assignable;
+use crate::core::model::abstract_syntax_tree_nodes::assignables::equation_parser::expression::Expression;
+use crate::core::model::abstract_syntax_tree_nodes::assignables::equation_parser::prefix_arithmetic::PrefixArithmetic;
+use crate::core::model::abstract_syntax_tree_nodes::assignables::method_call::MethodCall;
+use crate::core::model::abstract_syntax_tree_nodes::import::Import;
+use crate::core::model::abstract_syntax_tree_nodes::l_value::LValue;
+use crate::core::model::abstract_syntax_tree_nodes::match_::MatchPattern;
+use crate::core::model::abstract_syntax_tree_nodes::method_definition::MethodDefinition;
+use crate::core::model::abstract_syntax_tree_nodes::variable::Variable;
+use crate::core::model::types::ty::Type;
+use crate::core::parser::abstract_syntax_tree_nodes::import::ImportError;
+use crate::core::parser::ast_parser::ASTParser;
+
+/// Called with every name, which refers to a top level definition, together with the position of the surrounding node
+type VisitName<'a> = dyn FnMut(&mut String, &FilePosition) -> Result<(), ImportError> + 'a;
+
+/// Loads the entry point file and every module it imports.
+///
+/// `module collections::list;` loads `collections/list.monkey` relative to the importing file or, if it doesn't exist there,
+/// relative to one of the search paths. The directory of the entry point file is always the first search path. Every top level definition of a module is renamed into its namespace,
+/// so it's called like `collections::list::push(...)`. The namespace follows the location of the file inside its search path, so `module list;` next to
+/// `collections/list.monkey` imports `collections::list` as well. A file is only loaded once, even if it's imported multiple times
+#[derive(Debug, Default)]
+pub struct ModuleLoader {
+    search_paths: Vec<PathBuf>,
+    /// the canonical path of every loaded module with its namespace
+    loaded_modules: HashMap<PathBuf, String>,
+    /// the canonical path and the name of every file, which is currently loaded. Importing one of them again is cyclic
+    loading: Vec<(PathBuf, String)>,
+    /// the definitions of every loaded module, renamed into the namespace of the module
+    definitions: Vec<AbstractSyntaxTreeNode>,
+    /// the namespace and the visibility of every definition of the loaded modules by its qualified name
+    visibility: HashMap<String, (String, bool)>,
+    /// every lexical and syntax error of the loaded files
+    diagnostics: Vec<crate::core::lexer::error::Error>,
+}
+
+impl ModuleLoader {
+    pub fn new(search_paths: Vec<PathBuf>) -> Self {
+        Self {
+            search_paths,
+            ..Default::default()
+        }
+    }
+
+    /// Loads the entry point file and all of its modules into one program. The definitions of the modules are placed in front of
+    /// the entry point file. The diagnostics of the returned program contain the lexical and syntax errors of every loaded file.
+    /// Every loaded file is registered in the provided source map
+    pub fn load<P: AsRef<Path>>(mut self, entry_point: P, source_map: &mut SourceMap) -> Result<ASTParser, ImportError> {
+        let (monkey_file, mut top_level_scope) = Self::parse_file(entry_point.as_ref(), source_map)?;
+        let name = monkey_file.path.file_stem().map(|stem| stem.to_string_lossy().to_string()).unwrap_or_default();
+
+        if let Some(project_directory) = monkey_file.path.parent() {
+            self.search_paths.insert(0, project_directory.to_path_buf());
+        }
+
+        self.loading.push((Self::canonical_path(&monkey_file.path)?, name));
+        self.load_imports(&mut top_level_scope.program, &monkey_file.path, source_map)?;
+        self.check_visibility(&mut top_level_scope.program, None)?;
+
+        self.diagnostics.extend(monkey_file.errors);
+        self.diagnostics.append(&mut top_level_scope.diagnostics);
+        self.definitions.append(&mut top_level_scope.program);
+
+        Ok(ASTParser {
+            program: self.definitions,
+            has_main_method: top_level_scope.has_main_method,
+            diagnostics: self.diagnostics,
+        })
+    }
+
+    fn parse_file(path: &Path, source_map: &mut SourceMap) -> Result<(MonkeyFile, ASTParser), ImportError> {
+        let monkey_file = MonkeyFile::read(path, source_map)?;
+        let top_level_scope = ASTParser::parse(&monkey_file.tokens)?.result;
+
+        Ok((monkey_file, top_level_scope))
+    }
+
+    fn canonical_path(path: &Path) -> Result<PathBuf, ImportError> {
+        Ok(std::fs::canonicalize(path).map_err(anyhow::Error::from)?)
+    }
+
+    /// Finds the file of the module next to the importing file or inside one of the search paths
+    fn resolve(&self, import: &Import, importing_file: &Path) -> Option<PathBuf> {
+        let relative_path = import.relative_path();
+        let importing_directory = importing_file.parent().unwrap_or(Path::new(""));
+
+        std::iter::once(importing_directory)
+            .chain(self.search_paths.iter().map(PathBuf::as_path))
+            .map(|directory| directory.join(&relative_path))
+            .find(|path| path.is_file())
+    }
+
+    /// Loads every module imported by the scope. References to a module, which is imported with a relative name like `module list;`, are renamed into its namespace
+    fn load_imports(&mut self, scope: &mut [AbstractSyntaxTreeNode], importing_file: &Path, source_map: &mut SourceMap) -> Result<(), ImportError> {
+        let mut renamed_namespaces = vec![];
+
+        for node in scope.iter() {
+            if let AbstractSyntaxTreeNode::Import(import) = node {
+                let namespace = self.load_module(import, importing_file, source_map)?;
+
+                if namespace != import.namespace() {
+                    renamed_namespaces.push((format!("{}::", import.namespace()), format!("{namespace}::")));
+                }
+            }
+        }
+
+        if renamed_namespaces.is_empty() {
+            return Ok(());
+        }
+
+        // `a::b::f` belongs to the import `a::b` and not to the import `a`
+        renamed_namespaces.sort_by_key(|(imported_as, _)| std::cmp::Reverse(imported_as.len()));
+
+        visit_nodes(scope, &mut |name: &mut String, _: &FilePosition| {
+            if let Some((imported_as, namespace)) = renamed_namespaces.iter().find(|(imported_as, _)| name.starts_with(imported_as.as_str())) {
+                *name = format!("{namespace}{}", &name[imported_as.len()..]);
+            }
+
+            Ok(())
+        })
+    }
+
+    /// The namespace of a module file is its path relative to the first search path containing it, for example `collections::list`
+    fn namespace_of(&self, canonical_path: &Path) -> Option<String> {
+        self.search_paths.iter()
+            .filter_map(|search_path| std::fs::canonicalize(search_path).ok())
+            .find_map(|search_path| canonical_path.strip_prefix(search_path).ok().map(Path::to_path_buf))
+            .map(|relative_path| relative_path.with_extension("").iter().map(|segment| segment.to_string_lossy().to_string()).collect::<Vec<_>>().join("::"))
+    }
+
+    /// Loads the imported module, if it isn't loaded yet, and returns its namespace
+    fn load_module(&mut self, import: &Import, importing_file: &Path, source_map: &mut SourceMap) -> Result<String, ImportError> {
+        let path = self.resolve(import, importing_file).ok_or(ImportError::ModuleNotFound {
+            namespace: import.namespace(),
+            file_position: import.file_position.clone(),
+        })?;
+        let canonical_path = Self::canonical_path(&path)?;
+        let namespace = self.namespace_of(&canonical_path).unwrap_or_else(|| import.namespace());
+
+        if let Some(index) = self.loading.iter().position(|(loading_path, _)| *loading_path == canonical_path) {
+            return Err(ImportError::CyclicImport {
+                cycle: self.loading[index..].iter().map(|(_, name)| name.clone()).chain(std::iter::once(namespace)).collect(),
+                file_position: import.file_position.clone(),
+            });
+        }
+
+        if let Some(namespace) = self.loaded_modules.get(&canonical_path) {
+            return Ok(namespace.clone());
+        }
+
+        if self.loaded_modules.values().any(|loaded_namespace| *loaded_namespace == namespace) {
+            return Err(ImportError::NamespaceCollision { namespace, file_position: import.file_position.clone() });
+        }
+
+        let (monkey_file, mut module) = Self::parse_file(&path, source_map)?;
+
+        self.loading.push((canonical_path.clone(), namespace.clone()));
+        self.load_imports(&mut module.program, &path, source_map)?;
+        self.loading.pop();
+
+        let local_names = module.program.iter().filter_map(Self::definition_name).collect::<HashSet<_>>();
+        let mut qualify = |name: &mut String, _: &FilePosition| {
+            if local_names.contains(name.as_str()) {
+                *name = format!("{namespace}::{name}");
+            }
+
+            Ok(())
+        };
+
+        visit_nodes(&mut module.program, &mut qualify)?;
+        self.check_visibility(&mut module.program, Some(&namespace))?;
+
+        for node in module.program {
+            match node {
+                AbstractSyntaxTreeNode::MethodDefinition(_) | AbstractSyntaxTreeNode::StructDefinition(_) | AbstractSyntaxTreeNode::EnumDefinition(_) => {
+                    if let Some((name, is_public)) = Self::definition_name(&node).zip(Self::is_public(&node)) {
+                        self.visibility.insert(name, (namespace.clone(), is_public));
+                    }
+
+                    self.definitions.push(node);
+                }
+                AbstractSyntaxTreeNode::Import(_) | AbstractSyntaxTreeNode::SyntaxError(_) => {}
+                statement => return Err(ImportError::StatementInModule { namespace, file_position: statement.file_position() }),
+            }
+        }
+
+        self.diagnostics.extend(monkey_file.errors);
+        self.diagnostics.append(&mut module.diagnostics);
+        self.loaded_modules.insert(canonical_path, namespace.clone());
+
+        Ok(namespace)
+    }
+
+    /// Private definitions are only accessible inside of the namespace they are defined in
+    fn check_visibility(&self, scope: &mut [AbstractSyntaxTreeNode], namespace: Option<&str>) -> Result<(), ImportError> {
+        visit_nodes(scope, &mut |name: &mut String, file_position: &FilePosition| {
+            match self.visibility.get(name.as_str()) {
+                Some((definition_namespace, false)) if Some(definition_namespace.as_str()) != namespace => Err(ImportError::PrivateAccess {
+                    name: name.clone(),
+                    file_position: file_position.clone(),
+                }),
+                _ => Ok(()),
+            }
+        })
+    }
+
+    fn definition_name(node: &AbstractSyntaxTreeNode) -> Option<String> {
+        match node {
+            AbstractSyntaxTreeNode::MethodDefinition(method_definition) => Some(method_definition.identifier.identifier()),
+            AbstractSyntaxTreeNode::StructDefinition(struct_definition) => Some(struct_definition.ty.to_string()),
+            AbstractSyntaxTreeNode::EnumDefinition(enum_definition) => Some(enum_definition.ty.to_string()),
+            _ => None,
+        }
+    }
+
+    fn is_public(node: &AbstractSyntaxTreeNode) -> Option<bool> {
+        match node {
+            AbstractSyntaxTreeNode::MethodDefinition(method_definition) => Some(method_definition.is_public),
+            AbstractSyntaxTreeNode::StructDefinition(struct_definition) => Some(struct_definition.is_public),
+            AbstractSyntaxTreeNode::EnumDefinition(enum_definition) => Some(enum_definition.is_public),
+            _ => None,
+        }
+    }
+}
+
+fn visit_nodes(scope: &mut [AbstractSyntaxTreeNode], visit: &mut VisitName<'_>) -> Result<(), ImportError> {
+    for node in scope {
+        let file_position = node.file_position();
+
+        match node {
+            AbstractSyntaxTreeNode::Variable(variable) => visit_variable(variable, visit)?,
+            AbstractSyntaxTreeNode::MethodCall(method_call) => visit_assignable_method_call(method_call, &file_position, visit)?,
+            AbstractSyntaxTreeNode::MethodDefinition(method_definition) => visit_method_definition(method_definition, &file_position, visit)?,
+            AbstractSyntaxTreeNode::Impl(impl_block) => {
+                visit_type(&mut impl_block.ty, &file_position, visit)?;
+
+                for method_definition in &mut impl_block.methods {
+                    visit_method_definition(method_definition, &file_position, visit)?;
+                }
+            }
+            AbstractSyntaxTreeNode::Return(return_statement) => {
+                if let Some(assignable) = &mut return_statement.assignable {
+                    visit_assignable(assignable, &file_position, visit)?;
+                }
+            }
+            AbstractSyntaxTreeNode::If(if_statement) => {
+                visit_assignable(&mut if_statement.condition, &file_position, visit)?;
+                visit_nodes(&mut if_statement.if_stack, visit)?;
+
+                if let Some(else_stack) = &mut if_statement.else_stack {
+                    visit_nodes(else_stack, visit)?;
+                }
+            }
+            AbstractSyntaxTreeNode::Match(match_statement) => {
+                visit_assignable(&mut match_statement.value, &file_position, visit)?;
+
+                for arm in &mut match_statement.arms {
+                    for pattern in &mut arm.patterns {
+                        match pattern {
+                            MatchPattern::Literal(literal) => visit_assignable(literal, &arm.file_position, visit)?,
+                            MatchPattern::Range { start, end, .. } => {
+                                visit_assignable(start, &arm.file_position, visit)?;
+                                visit_assignable(end, &arm.file_position, visit)?;
+                            }
+                            MatchPattern::Wildcard => {}
+                        }
+                    }
+
+                    visit_nodes(&mut arm.stack, visit)?;
+                }
+            }
+            AbstractSyntaxTreeNode::For(for_loop) => {
+                visit_variable(&mut for_loop.initialization, visit)?;
+                visit_assignable(&mut for_loop.condition, &file_position, visit)?;
+                visit_variable(&mut for_loop.update, visit)?;
+                visit_nodes(&mut for_loop.stack, visit)?;
+            }
+            AbstractSyntaxTreeNode::While(while_loop) => {
+                visit_assignable(&mut while_loop.condition, &file_position, visit)?;
+                visit_nodes(&mut while_loop.stack, visit)?;
+            }
+            AbstractSyntaxTreeNode::StructDefinition(struct_definition) => {
+                visit_type(&mut struct_definition.ty, &file_position, visit)?;
+
+                for field in &mut struct_definition.fields {
+                    visit_type(&mut field.ty, &file_position, visit)?;
+                }
+            }
+            AbstractSyntaxTreeNode::EnumDefinition(enum_definition) => visit_type(&mut enum_definition.ty, &file_position, visit)?,
+            AbstractSyntaxTreeNode::Import(_) | AbstractSyntaxTreeNode::Break(_) | AbstractSyntaxTreeNode::Continue(_) | AbstractSyntaxTreeNode::SyntaxError(_) => {}
+        }
+    }
+
+    Ok(())
+}
+
+fn visit_method_definition(method_definition: &mut MethodDefinition, file_position: &FilePosition, visit: &mut VisitName<'_>) -> Result<(), ImportError> {
+    if let LValue::Identifier(identifier) = &mut method_definition.identifier {
+        visit(&mut identifier.name, file_position)?;
+    }
+
+    for argument in &mut method_definition.arguments {
+        visit_type(&mut argument.ty, file_position, visit)?;
+    }
+
+    visit_type(&mut method_definition.return_type, file_position, visit)?;
+    visit_nodes(&mut method_definition.stack, visit)
+}
+
+fn visit_variable<const ASSIGNMENT: char, const SEPARATOR: char>(variable: &mut Variable<ASSIGNMENT, SEPARATOR>, visit: &mut VisitName<'_>) -> Result<(), ImportError> {
+    if let LValue::Expression(expression) = &mut variable.l_value {
+        visit_expression(expression, &variable.file_position, visit)?;
+    }
+
+    if let Some(ty) = &mut variable.ty {
+        visit_type(ty, &variable.file_position, visit)?;
+    }
+
+    visit_assignable(&mut variable.assignable, &variable.file_position, visit)
+}
+
+fn visit_assignable_method_call(method_call: &mut MethodCall, file_position: &FilePosition, visit: &mut VisitName<'_>) -> Result<(), ImportError> {
+    // the method of a call like `person.greet()` is looked up in the type of the receiver
+    if let Some(receiver) = &mut method_call.receiver {
+        visit_assignable(receiver, file_position, visit)?;
+    } else {
+        match &mut method_call.identifier {
+            LValue::Identifier(identifier) => visit(&mut identifier.name, file_position)?,
+            LValue::Expression(expression) => visit_expression(expression, file_position, visit)?,
+        }
+    }
+
+    for ty in &mut method_call.type_arguments {
+        visit_type(ty, file_position, visit)?;
+    }
+
+    for argument in &mut method_call.arguments {
+        visit_assignable(argument, file_position, visit)?;
+    }
+
+    Ok(())
+}
+
+fn visit_assignable(assignable: &mut Assignable, file_position: &FilePosition, visit: &mut VisitName<'_>) -> Result<(), ImportError> {
+    match assignable {
+        Assignable::MethodCall(method_call) => visit_assignable_method_call(method_call, file_position, visit),
+        Assignable::Object(object) => {
+            visit_type(&mut object.ty, file_position, visit)?;
+
+            for field in &mut object.fields {
+                visit_variable(field, visit)?;
+            }
+
+            Ok(())
+        }
+        Assignable::EnumPath(enum_path) => visit_type(&mut enum_path.ty, file_position, visit),
+        // the field names are resolved with the type of the value
+        Assignable::FieldAccess(field_access) => visit_assignable(&mut field_access.value, file_position, visit),
+        Assignable::Array(array) => {
+            for value in &mut array.values {
+                visit_assignable(value, file_position, visit)?;
+            }
+
+            Ok(())
+        }
+        Assignable::Expression(expression) => visit_expression(expression, file_position, visit),
+        Assignable::Parameter(parameter) => visit_type(&mut parameter.ty, file_position, visit),
+        Assignable::String(_) | Assignable::Integer(_) | Assignable::Float(_) | Assignable::Boolean(_) | Assignable::Identifier(_) | Assignable::Uninitialized => Ok(()),
+    }
+}
+
+fn visit_expression(expression: &mut Expression, file_position: &FilePosition, visit: &mut VisitName<'_>) -> Result<(), ImportError> {
+    for side in [&mut expression.lhs, &mut expression.rhs].into_iter().flatten() {
+        visit_expression(side, file_position, visit)?;
+    }
+
+    for assignable in [&mut expression.value, &mut expression.index_operator].into_iter().flatten() {
+        visit_assignable(assignable, file_position, visit)?;
+    }
+
+    if let Some(PrefixArithmetic::Cast(ty)) = &mut expression.prefix_arithmetic {
+        visit_type(ty, file_position, visit)?;
+    }
+
+    Ok(())
+}
+
+/// pointers like `*Person` are stored as custom types, the name is visited without the pointer prefix
+fn visit_type(ty: &mut Type, file_position: &FilePosition, visit: &mut VisitName<'_>) -> Result<(), ImportError> {
+    match ty {
+        Type::Custom(identifier, _) => {
+            let name_start = identifier.name.find(|c| !matches!(c, '*' | '&')).unwrap_or(identifier.name.len());
+            let (pointer, name) = identifier.name.split_at(name_start);
+            let mut name = name.to_string();
+
+            visit(&mut name, file_position)?;
+            identifier.name = format!("{pointer}{name}");
+
+            Ok(())
+        }
+        Type::Array(inner_type, _, _) => visit_type(inner_type, file_position, visit),
+        Type::Integer(_, _) | Type::Float(_, _) | Type::Bool(_) | Type::Void | Type::Statement => Ok(()),
+    }
+}
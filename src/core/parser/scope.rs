@@ -2,8 +2,10 @@ use crate::core::lexer::collect_tokens_until_scope_close::CollectTokensFromUntil
 use crate::core::lexer::error::Error;
 use crate::core::lexer::parse::{Parse, ParseOptions, ParseResult};
 use crate::core::lexer::token_match::MatchResult;
-use crate::core::lexer::token_with_span::TokenWithSpan;
+use crate::core::lexer::token::Token;
+use crate::core::lexer::token_with_span::{FilePosition, TokenWithSpan};
 use crate::core::model::abstract_syntax_tree_node::AbstractSyntaxTreeNode;
+use crate::core::model::abstract_syntax_tree_nodes::syntax_error::SyntaxError;
 use crate::core::model::scope::Scope;
 use crate::core::parser::scope_iterator::ScopeIterator;
 use crate::pattern;
@@ -19,6 +21,7 @@ impl Parse for Scope {
             'outer: while index < scope_tokens.len() {
                 let scope_iterator = Scope::iter();
                 let mut consumed = 0;
+                let mut error = None;
 
                 for parsing_iteration_item in scope_iterator {
                     let parsing_function = parsing_iteration_item.parser;
@@ -28,9 +31,10 @@ impl Parse for Scope {
                             ast_nodes.push(ast.result.clone());
                             ast.consumed
                         }
-                        // this type of error counts as unrecoverable, so we return it
+                        // this type of error counts as unrecoverable for the statement, so no other parser is tried
                         Err(err) if matches!(err, Error::WithContext { .. }) => {
-                            return Err(err);
+                            error = Some(err);
+                            break;
                         },
                         Err(_) => {
                             0
@@ -52,7 +56,16 @@ impl Parse for Scope {
                 }
 
                 if consumed == 0 {
-                    return Err(Error::UnexpectedToken(scope_tokens[index].clone()))
+                    // skip the statement and continue parsing with the next one
+                    let skipped = Scope::synchronize(&scope_tokens[index..]);
+
+                    ast_nodes.push(AbstractSyntaxTreeNode::SyntaxError(SyntaxError {
+                        error: error.unwrap_or_else(|| Error::UnexpectedToken(scope_tokens[index].clone())),
+                        file_position: FilePosition::from_min_max(&scope_tokens[index], &scope_tokens[index + skipped - 1]),
+                    }));
+
+                    index += skipped;
+                    total_consumed += skipped;
                 }
             }
 
@@ -72,6 +85,36 @@ impl Scope {
     pub fn iter() -> ScopeIterator {
         ScopeIterator::new()
     }
+
+    /// Returns the amount of tokens belonging to a statement, which couldn't be parsed.
+    /// The statement ends after the next `;`, after the next block or in front of the next `fn`, `struct`, `enum`, `impl`, `let`, `pub` or `module`.
+    /// A `;` or `let` inside parentheses or brackets, like in the header of a `for`, doesn't end the statement
+    fn synchronize(tokens: &[TokenWithSpan]) -> usize {
+        let mut depth = 0;
+        let mut parentheses: usize = 0;
+
+        for (index, token) in tokens.iter().enumerate() {
+            match token.token {
+                Token::ParenthesisOpen | Token::SquareBracketOpen => parentheses += 1,
+                Token::ParenthesisClose | Token::SquareBracketClose => parentheses = parentheses.saturating_sub(1),
+                Token::CurlyBraceOpen => depth += 1,
+                Token::CurlyBraceClose => {
+                    depth -= 1;
+
+                    let else_follows = matches!(tokens.get(index + 1), Some(TokenWithSpan { token: Token::Else, .. }));
+                    if depth <= 0 && !else_follows {
+                        return index + 1;
+                    }
+                }
+                Token::SemiColon if depth == 0 && parentheses == 0 => return index + 1,
+                Token::Let if depth == 0 && parentheses == 0 && index > 0 => return index,
+                Token::Fn | Token::Struct | Token::Enum | Token::Impl | Token::Pub | Token::Module if depth == 0 && index > 0 => return index,
+                _ => {}
+            }
+        }
+
+        tokens.len()
+    }
 }
 
 impl Debug for Scope {
@@ -3,9 +3,14 @@ use crate::core::lexer::parse::{Parse, ParseOptions, ParseResult};
 use crate::core::lexer::token_with_span::TokenWithSpan;
 use crate::core::model::abstract_syntax_tree_node::AbstractSyntaxTreeNode;
 use crate::core::model::abstract_syntax_tree_nodes::assignables::method_call::MethodCall;
+use crate::core::model::abstract_syntax_tree_nodes::break_::Break;
+use crate::core::model::abstract_syntax_tree_nodes::continue_::Continue;
+use crate::core::model::abstract_syntax_tree_nodes::enum_::Enum;
 use crate::core::model::abstract_syntax_tree_nodes::for_::For;
 use crate::core::model::abstract_syntax_tree_nodes::if_::If;
+use crate::core::model::abstract_syntax_tree_nodes::impl_::Impl;
 use crate::core::model::abstract_syntax_tree_nodes::import::Import;
+use crate::core::model::abstract_syntax_tree_nodes::match_::Match;
 use crate::core::model::abstract_syntax_tree_nodes::method_definition::MethodDefinition;
 use crate::core::model::abstract_syntax_tree_nodes::ret::Return;
 use crate::core::model::abstract_syntax_tree_nodes::struct_::Struct;
@@ -48,15 +53,20 @@ impl Iterator for ScopeIterator {
         }
 
         let next_token = match self.index {
-            AbstractSyntaxTreeNode::If(_) => AbstractSyntaxTreeNode::Variable(Variable::default()),
+            AbstractSyntaxTreeNode::If(_) => AbstractSyntaxTreeNode::Match(Match::default()),
+            AbstractSyntaxTreeNode::Match(_) => AbstractSyntaxTreeNode::Variable(Variable::default()),
             AbstractSyntaxTreeNode::Variable(_) => AbstractSyntaxTreeNode::MethodCall(MethodCall::default()),
             AbstractSyntaxTreeNode::MethodCall(_) => AbstractSyntaxTreeNode::MethodDefinition(MethodDefinition::default()),
             AbstractSyntaxTreeNode::MethodDefinition(_) => AbstractSyntaxTreeNode::Import(Import::default()),
             AbstractSyntaxTreeNode::Import(_) => AbstractSyntaxTreeNode::Return(Return::default()),
             AbstractSyntaxTreeNode::Return(_) => AbstractSyntaxTreeNode::StructDefinition(Struct::default()),
-            AbstractSyntaxTreeNode::StructDefinition(_) => AbstractSyntaxTreeNode::For(For::default()),
+            AbstractSyntaxTreeNode::StructDefinition(_) => AbstractSyntaxTreeNode::EnumDefinition(Enum::default()),
+            AbstractSyntaxTreeNode::EnumDefinition(_) => AbstractSyntaxTreeNode::Impl(Impl::default()),
+            AbstractSyntaxTreeNode::Impl(_) => AbstractSyntaxTreeNode::For(For::default()),
             AbstractSyntaxTreeNode::For(_) => AbstractSyntaxTreeNode::While(While::default()),
-            AbstractSyntaxTreeNode::While(_) => AbstractSyntaxTreeNode::If(If::default()),
+            AbstractSyntaxTreeNode::While(_) => AbstractSyntaxTreeNode::Break(Break::default()),
+            AbstractSyntaxTreeNode::Break(_) => AbstractSyntaxTreeNode::Continue(Continue::default()),
+            AbstractSyntaxTreeNode::Continue(_) | AbstractSyntaxTreeNode::SyntaxError(_) => AbstractSyntaxTreeNode::If(If::default()),
         };
 
         self.index = next_token.clone();
@@ -68,6 +78,9 @@ impl Iterator for ScopeIterator {
             AbstractSyntaxTreeNode::If(_) => ScopeIterationItem {
                 parser: Box::new(move |tokens| If::parse(tokens, ParseOptions::default())?.into()),
             },
+            AbstractSyntaxTreeNode::Match(_) => ScopeIterationItem {
+                parser: Box::new(move |tokens| Match::parse(tokens, ParseOptions::default())?.into()),
+            },
             AbstractSyntaxTreeNode::Variable(_) => ScopeIterationItem {
                 parser: Box::new(move |tokens| Variable::<'=', ';'>::parse(tokens, ParseOptions::default())?.into()),
             },
@@ -83,6 +96,12 @@ impl Iterator for ScopeIterator {
             AbstractSyntaxTreeNode::While(_) => ScopeIterationItem {
                 parser: Box::new(move |tokens| While::parse(tokens, ParseOptions::default())?.into()),
             },
+            AbstractSyntaxTreeNode::Break(_) => ScopeIterationItem {
+                parser: Box::new(move |tokens| Break::parse(tokens, ParseOptions::default())?.into()),
+            },
+            AbstractSyntaxTreeNode::Continue(_) => ScopeIterationItem {
+                parser: Box::new(move |tokens| Continue::parse(tokens, ParseOptions::default())?.into()),
+            },
             AbstractSyntaxTreeNode::MethodDefinition(_) => ScopeIterationItem {
                 parser: Box::new(move |tokens| MethodDefinition::parse(tokens, ParseOptions::default())?.into()),
             },
@@ -91,7 +110,14 @@ impl Iterator for ScopeIterator {
             },
             AbstractSyntaxTreeNode::StructDefinition(_) => ScopeIterationItem {
                 parser: Box::new(move |tokens| Struct::parse(tokens, ParseOptions::default())?.into()),
-            }
+            },
+            AbstractSyntaxTreeNode::EnumDefinition(_) => ScopeIterationItem {
+                parser: Box::new(move |tokens| Enum::parse(tokens, ParseOptions::default())?.into()),
+            },
+            AbstractSyntaxTreeNode::Impl(_) => ScopeIterationItem {
+                parser: Box::new(move |tokens| Impl::parse(tokens, ParseOptions::default())?.into()),
+            },
+            AbstractSyntaxTreeNode::SyntaxError(_) => unreachable!("Syntax errors are created by the scope, when no parser matches"),
         })
     }
 }
\ No newline at end of file
@@ -2,10 +2,13 @@ use std::collections::{HashMap};
 use std::ops::{Deref, DerefMut};
 use crate::core::lexer::token_with_span::FilePosition;
 use crate::core::model::abstract_syntax_tree_node::AbstractSyntaxTreeNode;
+use crate::core::model::abstract_syntax_tree_nodes::enum_::Enum;
 use crate::core::model::abstract_syntax_tree_nodes::l_value::LValue;
 use crate::core::model::abstract_syntax_tree_nodes::method_definition::MethodDefinition;
 use crate::core::model::abstract_syntax_tree_nodes::struct_::Struct;
 use crate::core::model::abstract_syntax_tree_nodes::variable::Variable;
+use crate::core::model::symbol_table::SymbolTable;
+use crate::core::model::types::mutability::Mutability;
 use crate::core::model::types::ty::Type;
 use crate::core::parser::types::r#type::{InferTypeError};
 
@@ -17,26 +20,54 @@ pub struct CurrentMethodInfo {
 }
 
 /// Contains all static type information about the provided scope
-/// At the moment variables and method definitions are included
+/// At the moment variables, method definitions, structs and enums are included
 #[derive(Debug, Default, Clone)]
 pub struct StaticTypeContext {
-    pub context: Vec<Variable<'=', ';'>>,
+    /// all variables visible in the current scope
+    pub symbols: SymbolTable<Variable<'=', ';'>>,
     pub expected_return_type: Option<CurrentMethodInfo>,
     pub custom_defined_types: HashMap<Type, Struct>,
+    pub custom_defined_enums: HashMap<Type, Enum>,
     pub current_file_position: FilePosition,
-    pub methods: Vec<MethodDefinition>
+    pub methods: Vec<MethodDefinition>,
+    /// the generic methods, which are instantiated for the type arguments of their calls
+    pub generic_methods: Vec<MethodDefinition>,
+    /// the instances of generic methods, which were created while inferring the types and aren't part of the program yet
+    pub monomorphized: Vec<MethodDefinition>,
+    /// The labels of all loops surrounding the current statement. The innermost loop is the last one
+    pub loops: Vec<Option<String>>,
 }
 
 impl StaticTypeContext {
-    // adds all information from the other context to this context
+    // adds all information from the other context to this context. The variables of the other context are declared in the current scope
     pub fn merge(&mut self, other: StaticTypeContext) {
-        for variable in other.context {
-            self.context.push(variable);
+        for variable in other.symbols.iter() {
+            self.symbols.declare(variable.clone());
         }
 
         for (ty, struct_def) in other.custom_defined_types {
             self.custom_defined_types.insert(ty, struct_def);
         }
+
+        for (ty, enum_def) in other.custom_defined_enums {
+            self.custom_defined_enums.insert(ty, enum_def);
+        }
+    }
+
+    /// returns the definition of the enum, if the type is an enum. The mutability of the type is ignored
+    pub fn enum_definition(&self, ty: &Type) -> Option<&Enum> {
+        let mut ty = ty.clone();
+        ty.set_mutability(Mutability::Immutable);
+
+        self.custom_defined_enums.get(&ty)
+    }
+
+    /// returns the definition of the struct, if the type is a struct. The mutability of the type is ignored
+    pub fn struct_definition(&self, ty: &Type) -> Option<&Struct> {
+        let mut ty = ty.clone();
+        ty.set_mutability(Mutability::Immutable);
+
+        self.custom_defined_types.get(&ty)
     }
 
     /// checks, if the provided methods have any name collisions
@@ -54,7 +85,7 @@ impl StaticTypeContext {
                 }
             }
 
-            for variable in &context.context {
+            for variable in context.symbols.iter() {
                 if !variable.define { continue; }
                 let value = match &variable.l_value {
                     LValue::Identifier(a) => a.name.as_str(),
@@ -80,55 +111,63 @@ impl StaticTypeContext {
 }
 
 impl Deref for StaticTypeContext {
-    type Target = Vec<Variable<'=', ';'>>;
+    type Target = SymbolTable<Variable<'=', ';'>>;
 
     fn deref(&self) -> &Self::Target {
-        &self.context
+        &self.symbols
     }
 }
 
 impl DerefMut for StaticTypeContext {
     fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.context
+        &mut self.symbols
     }
 }
 
 impl StaticTypeContext {
     /// Constructs a context containing all type information the ast can infer. This is especially useful to infer further types, that could not be inferred before like function calls and variable assignments
     pub fn new(scope: &Vec<AbstractSyntaxTreeNode>) -> StaticTypeContext {
-        let mut context: Vec<Variable<'=', ';'>> = Vec::new();
+        let mut symbols = SymbolTable::default();
         let mut methods = Vec::new();
+        let mut generic_methods = Vec::new();
         let mut custom_defined_types = HashMap::new();
+        let mut custom_defined_enums = HashMap::new();
 
         for node in scope {
             match node {
                 AbstractSyntaxTreeNode::Variable(variable) => {
-                    if variable.ty.is_some() {
-                        context.push(variable.clone());
+                    if variable.define && variable.ty.is_some() {
+                        symbols.declare(variable.clone());
                     }
                 },
                 AbstractSyntaxTreeNode::StructDefinition(struct_def) => {
                     custom_defined_types.insert(struct_def.ty.clone(), struct_def.clone());
                 },
+                AbstractSyntaxTreeNode::EnumDefinition(enum_def) => {
+                    custom_defined_enums.insert(enum_def.ty.clone(), enum_def.clone());
+                },
+                AbstractSyntaxTreeNode::MethodDefinition(method_definition) if method_definition.is_generic() => {
+                    generic_methods.push(method_definition.clone());
+                },
                 AbstractSyntaxTreeNode::MethodDefinition(method_definition) => {
                     methods.push(method_definition.clone());
                 },
-                AbstractSyntaxTreeNode::For(for_loop) => {
-                    if for_loop.initialization.ty.is_some() {
-                        context.push(for_loop.initialization.clone());
-                    }
-                },
-                AbstractSyntaxTreeNode::While(_) | AbstractSyntaxTreeNode::MethodCall(_) | AbstractSyntaxTreeNode::If(_) | AbstractSyntaxTreeNode::Import(_) | AbstractSyntaxTreeNode::Return(_) => {}
+                AbstractSyntaxTreeNode::For(_) | AbstractSyntaxTreeNode::While(_) | AbstractSyntaxTreeNode::MethodCall(_) | AbstractSyntaxTreeNode::If(_) | AbstractSyntaxTreeNode::Match(_) | AbstractSyntaxTreeNode::Import(_) | AbstractSyntaxTreeNode::Return(_) | AbstractSyntaxTreeNode::SyntaxError(_) |
+                AbstractSyntaxTreeNode::Impl(_) | AbstractSyntaxTreeNode::Break(_) | AbstractSyntaxTreeNode::Continue(_) => {}
             }
         }
 
 
         Self {
-            context,
+            symbols,
             expected_return_type: None,
             custom_defined_types,
+            custom_defined_enums,
             current_file_position: scope.first().map_or(FilePosition::default(), |n| n.file_position().clone()),
             methods,
+            generic_methods,
+            monomorphized: vec![],
+            loops: vec![],
         }
     }
 }
\ No newline at end of file
@@ -43,6 +43,8 @@ impl OperatorToASM for Boolean {
             Operator::GreaterThanEqual => no_operation("greater than equal", meta.file_position.clone()),
             Operator::BitwiseXor => no_operation("bitwise xor", meta.file_position.clone()),
             Operator::Mod => no_operation("modulo", meta.file_position.clone()),
+            Operator::LogicalNot => no_operation("logical not", meta.file_position.clone()),
+            Operator::BitwiseNot => no_operation("bitwise not", meta.file_position.clone()),
             Operator::Equal | Operator::NotEqual => Ok(AssemblerOperation {
                 prefix: None,
                 operation: AssemblerOperation::compare(&operator.to_asm(&mut Default::default(), &mut Default::default(), None)?.to_string(), &registers[0], &registers[1])?,
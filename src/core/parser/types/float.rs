@@ -149,6 +149,14 @@ impl Castable<FloatType, FloatType> for FloatType {
 }
 
 impl FloatType {
+    /// Returns true, if the value can be represented by this type
+    pub fn fits(&self, value: f64) -> bool {
+        match self {
+            FloatType::Float32 => (-3.40282347e+38..=3.40282347e+38).contains(&value),
+            FloatType::Float64 => value.is_finite(),
+        }
+    }
+
     pub fn operation_matrix(base_type_matrix: &mut HashMap<(Type, Operator, Type), Type>) {
         let types = [FloatType::Float32, FloatType::Float64];
 
@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 use std::fmt::{Display, Formatter};
+use std::ops::RangeInclusive;
 use std::str::FromStr;
 
 use crate::core::code_generator::abstract_syntax_tree_nodes::assignables::equation_parser::operator::{AssemblerOperation, OperatorToASM};
@@ -168,6 +169,29 @@ impl IntegerType {
         value.parse().map_err(|_| Box::new(InferTypeError::IllegalType(String::from(value), file_position.clone())))
     }
 
+    /// Returns true, if the number literal can be represented by this type
+    pub fn fits(&self, value: &str) -> bool {
+        let Ok(value) = value.parse::<i128>() else {
+            return false;
+        };
+
+        self.range().contains(&value)
+    }
+
+    /// Returns the range of values, which can be represented by this type
+    pub fn range(&self) -> RangeInclusive<i128> {
+        match self {
+            IntegerType::I8 => i8::MIN as i128..=i8::MAX as i128,
+            IntegerType::U8 => u8::MIN as i128..=u8::MAX as i128,
+            IntegerType::I16 => i16::MIN as i128..=i16::MAX as i128,
+            IntegerType::U16 => u16::MIN as i128..=u16::MAX as i128,
+            IntegerType::I32 => i32::MIN as i128..=i32::MAX as i128,
+            IntegerType::U32 => u32::MIN as i128..=u32::MAX as i128,
+            IntegerType::I64 => i64::MIN as i128..=i64::MAX as i128,
+            IntegerType::U64 => u64::MIN as i128..=u64::MAX as i128,
+        }
+    }
+
     pub fn signed(&self) -> bool {
         matches!(self, IntegerType::I8 | IntegerType::I16 | IntegerType::I32 | IntegerType::I64)
     }
@@ -223,6 +247,7 @@ impl OperatorToASM for IntegerType {
             Operator::Noop => Err(ASMGenerateError::InternalError("Noop instruction is not supported".to_string(), meta.file_position.clone())),
             Operator::LogicalAnd => Err(ASMGenerateError::InternalError("`Logical And` instruction is not supported".to_string(), meta.file_position.clone())),
             Operator::LogicalOr => Err(ASMGenerateError::InternalError("`Logical Or` instruction is not supported".to_string(), meta.file_position.clone())),
+            Operator::LogicalNot | Operator::BitwiseNot => Err(ASMGenerateError::InternalError(format!("`{operator}` is a unary operator"), meta.file_position.clone())),
             Operator::Add | Operator::Sub | Operator::BitwiseAnd | Operator::BitwiseXor | Operator::BitwiseOr => Ok(
                 AssemblerOperation::two_operands(&operator.to_asm(stack, meta, None)?.to_string(), &registers[0], &registers[1], &meta.file_position)?
             ),
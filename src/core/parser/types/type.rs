@@ -1,6 +1,6 @@
 use crate::core::code_generator::abstract_syntax_tree_nodes::assignables::equation_parser::operator::{AssemblerOperation, OperatorToASM};
 use crate::core::code_generator::generator::Stack;
-use crate::core::code_generator::{ASMGenerateError, MetaInfo, ToASM};
+use crate::core::code_generator::{ASMGenerateError, MetaInfo};
 use crate::core::lexer::parse::{Parse, ParseOptions, ParseResult};
 use crate::core::lexer::token::Token;
 use crate::core::lexer::token_match::MatchResult;
@@ -9,6 +9,7 @@ use crate::core::model::abstract_syntax_tree_nodes::assignable::Assignable;
 use crate::core::model::abstract_syntax_tree_nodes::assignables::equation_parser::operator::Operator;
 use crate::core::model::abstract_syntax_tree_nodes::identifier::{Identifier};
 use crate::core::model::abstract_syntax_tree_nodes::l_value::LValue;
+use crate::core::model::abstract_syntax_tree_nodes::method_definition::GenericParameter;
 use crate::core::model::abstract_syntax_tree_nodes::variable::Variable;
 use crate::core::model::types::float::FloatType;
 use crate::core::model::types::integer::{IntegerAST, IntegerType};
@@ -28,7 +29,7 @@ pub mod common {
     use crate::core::parser::types::r#type::Type;
 
     #[allow(unused)]
-    pub fn string() -> Type { Type::Custom(Identifier { name: "*string".to_string() }, Mutability::Immutable)}
+    pub fn string() -> Type { Type::Custom(Identifier { name: "*string".to_string(), symbol: None }, Mutability::Immutable)}
 }
 
 #[derive(Debug)]
@@ -40,6 +41,7 @@ pub enum InferTypeError {
     IllegalArrayTypeLookup(Type, FilePosition),
     IllegalType(String, FilePosition),
     IllegalIndexOperation(Type, FilePosition),
+    IllegalUnaryOperation(Operator, Type, FilePosition),
     NoTypePresent(LValue, FilePosition),
     DefineNotAllowed(Variable<'=', ';'>, FilePosition),
     IntegerTooSmall { ty: Type, literal: String , file_position: FilePosition },
@@ -51,12 +53,48 @@ pub enum InferTypeError {
     MethodCallSignatureMismatch { signatures: Vec<Vec<Type>>, method_name: LValue, file_position: FilePosition, provided: Vec<Type> },
     NameCollision(String, FilePosition),
     MismatchedTypes { expected: Type, actual: Type, file_position: FilePosition },
+    UnknownField { ty: Type, field: String, file_position: FilePosition },
+    TypeArgumentAmountMismatch { method_name: String, expected: usize, actual: usize, file_position: FilePosition },
+    UninferableTypeParameter { method_name: String, parameter: String, file_position: FilePosition },
+    UnsatisfiedTypeBound { method_name: String, parameter: GenericParameter, ty: Type, file_position: FilePosition },
+}
+
+impl InferTypeError {
+    pub fn file_position(&self) -> &FilePosition {
+        match self {
+            InferTypeError::TypesNotCalculable(_, _, _, file_position) |
+            InferTypeError::UnresolvedReference(_, file_position) |
+            InferTypeError::IllegalDereference(_, _, file_position) |
+            InferTypeError::IllegalArrayTypeLookup(_, file_position) |
+            InferTypeError::IllegalType(_, file_position) |
+            InferTypeError::IllegalIndexOperation(_, file_position) |
+            InferTypeError::IllegalUnaryOperation(_, _, file_position) |
+            InferTypeError::NoTypePresent(_, file_position) |
+            InferTypeError::DefineNotAllowed(_, file_position) |
+            InferTypeError::NameCollision(_, file_position) |
+            InferTypeError::MultipleTypesInArray { file_position, .. } |
+            InferTypeError::IntegerTooSmall { file_position, .. } |
+            InferTypeError::FloatTooSmall { file_position, .. } |
+            InferTypeError::MethodCallArgumentAmountMismatch { file_position, .. } |
+            InferTypeError::MethodReturnArgumentTypeMismatch { file_position, .. } |
+            InferTypeError::MethodReturnSignatureMismatch { file_position, .. } |
+            InferTypeError::MethodCallSignatureMismatch { file_position, .. } |
+            InferTypeError::MismatchedTypes { file_position, .. } |
+            InferTypeError::UnknownField { file_position, .. } |
+            InferTypeError::TypeArgumentAmountMismatch { file_position, .. } |
+            InferTypeError::UninferableTypeParameter { file_position, .. } |
+            InferTypeError::UnsatisfiedTypeBound { file_position, .. } => file_position,
+            InferTypeError::MethodCallArgumentTypeMismatch { info } => &info.file_position,
+        }
+    }
 }
 
 #[derive(Debug)]
 pub enum MethodCallSignatureMismatchCause {
     ReturnMismatch,
     IfCondition,
+    MatchArm,
+    LoopCondition,
 }
 
 impl Display for Mutability {
@@ -73,6 +111,8 @@ impl Display for MethodCallSignatureMismatchCause {
         write!(f, "{}", match self {
             MethodCallSignatureMismatchCause::ReturnMismatch => "",
             MethodCallSignatureMismatchCause::IfCondition => "Every branch of an if statement must end with a return statement",
+            MethodCallSignatureMismatchCause::MatchArm => "Every arm of a match statement must end with a return statement",
+            MethodCallSignatureMismatchCause::LoopCondition => "A loop, which can end, must be followed by a return statement",
         })
     }
 }
@@ -117,7 +157,11 @@ impl OperatorToASM for Type {
             Type::Bool(_) => Boolean::True.operation_to_asm(operator, registers, stack, meta),
             Type::Void => Err(ASMGenerateError::InternalError("Void cannot be operated on".to_string(), meta.file_position.clone())),
             Type::Statement => Err(ASMGenerateError::InternalError("Statements cannot be operated on".to_string(), meta.file_position.clone())),
-            Type::Array(_, _, _) | Type::Custom(_, _) => todo!(),
+            // enums are compared by their discriminant
+            Type::Custom(_, _) if meta.static_type_information.enum_definition(self).is_some() => IntegerType::I64.operation_to_asm(operator, registers, stack, meta),
+            // pointers are compared by their address
+            Type::Custom(_, _) if self.is_pointer() => IntegerType::I64.operation_to_asm(operator, registers, stack, meta),
+            Type::Array(_, _, _) | Type::Custom(_, _) => Err(ASMGenerateError::InternalError(format!("`{}` cannot be operated on with `{}`", self, operator), meta.file_position.clone())),
         }
     }
 }
@@ -130,7 +174,13 @@ impl Display for InferTypeError {
             InferTypeError::TypesNotCalculable(a, o, b, file_position) => write!(f, "Line: {}: \tCannot {} between types {} and {}", file_position, o, a, b),
             InferTypeError::UnresolvedReference(s, file_position) => write!(f, "Line: {}: \tUnresolved reference: {s}", file_position),
             InferTypeError::MismatchedTypes { expected, actual, file_position } => write!(f, "Line: {}: \tMismatched types: Expected `{expected}` but found `{actual}`", file_position),
+            InferTypeError::UnknownField { ty, field, file_position } => write!(f, "Line: {}: \tThe type `{ty}` has no field `{field}`", file_position),
             InferTypeError::NameCollision(name, file_position) => write!(f, "Line: {}: \tTwo symbols share the same name: `{name}`", file_position),
+            InferTypeError::TypeArgumentAmountMismatch { method_name, expected, actual, file_position } => write!(f, "Line: {file_position}: \tThe method `{method_name}` expects {expected} type arguments, but {actual} are provided"),
+            InferTypeError::UninferableTypeParameter { method_name, parameter, file_position } =>
+                write!(f, "Line: {file_position}: \tThe type parameter `{parameter}` of the method `{method_name}` cannot be inferred, the type arguments have to be provided like `{method_name}::<...>(...)`"),
+            InferTypeError::UnsatisfiedTypeBound { method_name, parameter, ty, file_position } =>
+                write!(f, "Line: {file_position}: \tThe type `{ty}` doesn't satisfy the bound `{parameter}` of the method `{method_name}`"),
             InferTypeError::MethodCallArgumentAmountMismatch { expected, actual, file_position } => write!(f, "Line: {:?}: \tThe method expects {} parameter, but {} are provided", file_position, expected, actual),
             InferTypeError::MethodCallArgumentTypeMismatch { info } => write!(f, "Line: {}: \t The {}. argument must be of type: `{}` but `{}` is provided", info.file_position, info.nth_parameter, info.expected, info.actual),
             InferTypeError::MethodReturnArgumentTypeMismatch { expected, actual, file_position } => write!(f, "Line: {}: \t The return type is: `{}` but `{}` is provided", file_position, expected, actual),
@@ -153,6 +203,7 @@ impl Display for InferTypeError {
             InferTypeError::IllegalDereference(assignable, ty, file_position) => write!(f, "Line: {}\tType `{ty}` cannot be dereferenced: {assignable}", file_position),
             InferTypeError::IllegalArrayTypeLookup(ty, file_position) => write!(f, "Line: {}\tType `{ty}` cannot be indexed", file_position),
             InferTypeError::IllegalIndexOperation(ty, file_position) => write!(f, "Line: {}\tType `{ty}` cannot be used as an index", file_position),
+            InferTypeError::IllegalUnaryOperation(operator, ty, file_position) => write!(f, "Line: {}\tCannot apply unary `{operator}` to type `{ty}`", file_position),
             InferTypeError::IntegerTooSmall { ty, literal: integer, file_position } => write!(f, "Line: {}\t`{integer}` doesn't fit into the type `{ty}`", file_position),
             InferTypeError::FloatTooSmall { ty, float, file_position } =>
                 write!(f, "Line: {}\t`{float}` doesn't fit into the type `{ty}`", file_position),
@@ -196,6 +247,18 @@ impl Parse for Type {
             }
         }
 
+        // a type defined inside of a module like `std::Person`
+        if let Some(TokenWithSpan { token: Token::DoubleColon, .. }) = tokens.get(1) {
+            if let Ok(path) = Identifier::parse_path(tokens) {
+                if path.consumed > 1 {
+                    return Ok(ParseResult {
+                        result: Type::Custom(path.result, Mutability::Immutable),
+                        consumed: path.consumed,
+                    });
+                }
+            }
+        }
+
         // base case
         if let [TokenWithSpan { token: Token::Literal(ty), .. }, ..] = tokens {
             if let Ok(parsed_type) = Type::from_str(ty, Mutability::Immutable) {
@@ -253,6 +316,8 @@ impl Type {
         Ok(match s {
             "bool" => Type::Bool(Mutability::Immutable),
             "void" => Type::Void,
+            // characters are ascii bytes
+            "char" => Type::Integer(IntegerType::U8, mutability),
             custom => {
                 if let Ok(int) = IntegerType::from_str(custom) {
                     return Ok(Type::Integer(int, mutability));
@@ -269,11 +334,11 @@ impl Type {
                     }
                 }
 
-                if !lazy_regex::regex_is_match!(r"^[\*&]*[a-zA-Z_$][a-zA-Z_$0-9]*[\*&]*$", s) {
+                if !lazy_regex::regex_is_match!(r"^[\*&]*[a-zA-Z_$][a-zA-Z_$0-9]*(::[a-zA-Z_$][a-zA-Z_$0-9]*)*[\*&]*$", s) {
                     return Err(Box::new(InferTypeError::IllegalType(String::from(custom), FilePosition::default())));
                 }
 
-                Type::Custom(Identifier { name: custom.to_string() }, mutability)
+                Type::Custom(Identifier { name: custom.to_string(), symbol: None }, mutability)
             }
         })
     }
@@ -290,6 +355,15 @@ impl Type {
         }
     }
 
+    /// Compares the types without their mutability, so `mut i32` equals `i32`
+    pub fn eq_ignoring_mutability(&self, other: &Type) -> bool {
+        let (mut left, mut right) = (self.clone(), other.clone());
+        left.set_mutability(Mutability::Immutable);
+        right.set_mutability(Mutability::Immutable);
+
+        left == right
+    }
+
     pub fn cast_to(&self, to: &Type) -> CastTo {
         CastTo {
             from: self.clone(),
@@ -336,13 +410,13 @@ impl Type {
     /// adds * from type
     pub fn push_pointer(&self) -> Self {
         match self {
-            Type::Integer(int, mutability) => Type::Custom(Identifier { name: format!("*{}", int) }, mutability.clone()),
-            Type::Float(float, mutability) => Type::Custom(Identifier { name: format!("*{}", float) }, mutability.clone()),
-            Type::Bool(mutability) => Type::Custom(Identifier { name: "*bool".to_string() }, mutability.clone()),
-            Type::Void => Type::Custom(Identifier { name: format!("*{}", Type::Void) }, Mutability::Immutable),
-            Type::Statement => Type::Custom(Identifier { name: format!("*{}", Type::Statement) }, Mutability::Immutable),
-            Type::Array(array_type, _, mutability) => Type::Custom(Identifier { name: format!("*{}", array_type)}, mutability.clone()),
-            Type::Custom(custom, mutability) => Type::Custom(Identifier { name: format!("*{}", custom) }, mutability.clone()),
+            Type::Integer(int, mutability) => Type::Custom(Identifier { name: format!("*{}", int), symbol: None }, mutability.clone()),
+            Type::Float(float, mutability) => Type::Custom(Identifier { name: format!("*{}", float), symbol: None }, mutability.clone()),
+            Type::Bool(mutability) => Type::Custom(Identifier { name: "*bool".to_string(), symbol: None }, mutability.clone()),
+            Type::Void => Type::Custom(Identifier { name: format!("*{}", Type::Void), symbol: None }, Mutability::Immutable),
+            Type::Statement => Type::Custom(Identifier { name: format!("*{}", Type::Statement), symbol: None }, Mutability::Immutable),
+            Type::Array(array_type, _, mutability) => Type::Custom(Identifier { name: format!("*{}", array_type), symbol: None }, mutability.clone()),
+            Type::Custom(custom, mutability) => Type::Custom(Identifier { name: format!("*{}", custom), symbol: None }, mutability.clone()),
         }
     }
 
@@ -442,26 +516,23 @@ impl Type {
         }
     }
 
-    pub fn byte_size_with_meta(&self, meta: &MetaInfo) -> usize {
+    /// the size of the type including the fields of structs
+    pub fn byte_size_with_meta(&self, meta: &MetaInfo) -> Result<usize, ASMGenerateError> {
         match self {
-            Type::Integer(int, _) => int.byte_size(),
-            Type::Float(float, _) => float.byte_size(),
-            Type::Array(array_type, _, _) => array_type.byte_size(),
-            Type::Bool(_) => 1,
-            Type::Void => 0,
-            Type::Statement => 0,
             Type::Custom(identifier_type, _) => {
                 if identifier_type.name.starts_with('*') {
-                    return 8; // pointer size
+                    return Ok(8); // pointer size
                 }
 
-                if let Some(struct_def) = meta.static_type_information.custom_defined_types.get(&self).cloned() {
-                    struct_def.byte_size(meta)
+                if let Some(struct_def) = meta.static_type_information.struct_definition(self) {
+                    struct_def.byte_size_with_meta(meta)
+                } else if meta.static_type_information.enum_definition(self).is_some() {
+                    Ok(self.byte_size())
                 } else {
-                    eprintln!("Warning: Cannot calculate byte size of custom type: {}", identifier_type);
-                    8
+                    Err(ASMGenerateError::InternalError(format!("Cannot calculate the byte size of the custom type `{}`", identifier_type), meta.file_position.clone()))
                 }
             }
+            _ => Ok(self.byte_size())
         }
     }
 }
\ No newline at end of file
@@ -30,6 +30,7 @@ impl ArrayOrObject<TokenWithSpan> for Vec<char> {
         self.iter().map(|c| TokenWithSpan {
             token: Token::from(*c),
             span: FilePosition::default(),
+            trivia: None,
         }).collect::<Vec<_>>()
     }
 }
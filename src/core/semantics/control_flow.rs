@@ -0,0 +1,294 @@
+use std::collections::HashSet;
+use crate::core::model::abstract_syntax_tree_node::AbstractSyntaxTreeNode;
+use crate::core::model::abstract_syntax_tree_nodes::assignable::Assignable;
+use crate::core::model::abstract_syntax_tree_nodes::method_definition::MethodDefinition;
+use crate::core::model::types::ty::Type;
+use crate::core::parser::types::r#type::MethodCallSignatureMismatchCause;
+
+/// Identifies a basic block inside of a [ControlFlowGraph]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BlockId(usize);
+
+/// A sequence of statements, which are executed one after another without any jumps in between.
+/// Statements like `if` and `while` end a block, their branches start new blocks
+#[derive(Debug, Default)]
+pub struct BasicBlock<'a> {
+    pub statements: Vec<&'a AbstractSyntaxTreeNode>,
+    pub successors: Vec<BlockId>,
+    pub predecessors: Vec<BlockId>,
+}
+
+/// The control flow graph of a list of statements, like the body of a method
+/// ```text
+/// fn sign(a: i32): i32 {      entry: [if]
+///     if (a < 0) {              ├── [return -1] ──> returned
+///         return -1;            └── [ ] ─────┐
+///     }                                      v
+///     return 1;               join:  [return 1] ──> returned
+/// }                           end:   [ ]   (not reachable, every path returns)
+/// ```
+#[derive(Debug)]
+pub struct ControlFlowGraph<'a> {
+    blocks: Vec<BasicBlock<'a>>,
+    /// the block after the last statement
+    end: BlockId,
+    reachable: HashSet<BlockId>,
+}
+
+const ENTRY: BlockId = BlockId(0);
+/// the target of every `return`
+const RETURNED: BlockId = BlockId(1);
+
+struct LoopTargets {
+    label: Option<String>,
+    continue_block: BlockId,
+    break_block: BlockId,
+}
+
+struct ControlFlowGraphBuilder<'a> {
+    blocks: Vec<BasicBlock<'a>>,
+    loops: Vec<LoopTargets>,
+}
+
+impl<'a> ControlFlowGraph<'a> {
+    pub fn new(stack: &'a [AbstractSyntaxTreeNode]) -> Self {
+        let mut builder = ControlFlowGraphBuilder { blocks: vec![], loops: vec![] };
+        let entry = builder.new_block();
+        let _returned = builder.new_block();
+
+        let end = builder.scope(stack, entry);
+
+        let mut graph = ControlFlowGraph { blocks: builder.blocks, end, reachable: HashSet::new() };
+        graph.reachable = graph.reachable_blocks();
+        graph
+    }
+
+    pub fn block(&self, id: BlockId) -> &BasicBlock<'a> {
+        &self.blocks[id.0]
+    }
+
+    pub fn is_reachable(&self, id: BlockId) -> bool {
+        self.reachable.contains(&id)
+    }
+
+    /// true, if the end of the statements can be reached without passing a `return`
+    pub fn falls_through(&self) -> bool {
+        self.is_reachable(self.end)
+    }
+
+    /// The first statement of every region of code, which can never run.
+    /// Statements following an unreachable statement or nested inside of it aren't listed again
+    pub fn unreachable_statements(&self) -> Vec<&'a AbstractSyntaxTreeNode> {
+        // a block carries dead code, if it contains unreachable statements or follows a block carrying dead code
+        let mut carries_dead_code = vec![false; self.blocks.len()];
+        let mut changed = true;
+
+        while changed {
+            changed = false;
+
+            for (index, block) in self.blocks.iter().enumerate() {
+                let carries = !self.is_reachable(BlockId(index)) &&
+                    (!block.statements.is_empty() || block.predecessors.iter().any(|predecessor| carries_dead_code[predecessor.0]));
+
+                if carries && !carries_dead_code[index] {
+                    carries_dead_code[index] = true;
+                    changed = true;
+                }
+            }
+        }
+
+        self.blocks.iter().enumerate()
+            .filter(|(index, block)| !self.is_reachable(BlockId(*index)) && !block.predecessors.iter().any(|predecessor| carries_dead_code[predecessor.0]))
+            .filter_map(|(_, block)| block.statements.first().copied())
+            .collect()
+    }
+
+    fn reachable_blocks(&self) -> HashSet<BlockId> {
+        let mut reachable = HashSet::from([ENTRY]);
+        let mut pending = vec![ENTRY];
+
+        while let Some(id) = pending.pop() {
+            for successor in &self.block(id).successors {
+                if reachable.insert(*successor) {
+                    pending.push(*successor);
+                }
+            }
+        }
+
+        reachable
+    }
+}
+
+impl<'a> ControlFlowGraphBuilder<'a> {
+    fn new_block(&mut self) -> BlockId {
+        self.blocks.push(BasicBlock::default());
+        BlockId(self.blocks.len() - 1)
+    }
+
+    fn edge(&mut self, from: BlockId, to: BlockId) {
+        self.blocks[from.0].successors.push(to);
+        self.blocks[to.0].predecessors.push(from);
+    }
+
+    fn push(&mut self, block: BlockId, node: &'a AbstractSyntaxTreeNode) {
+        self.blocks[block.0].statements.push(node);
+    }
+
+    /// adds the statements to the graph, starting in the `current` block. Returns the block after the last statement
+    fn scope(&mut self, stack: &'a [AbstractSyntaxTreeNode], mut current: BlockId) -> BlockId {
+        for node in stack {
+            current = self.node(node, current);
+        }
+
+        current
+    }
+
+    fn node(&mut self, node: &'a AbstractSyntaxTreeNode, current: BlockId) -> BlockId {
+        match node {
+            // the code after leaving the block starts a new block without any predecessors
+            AbstractSyntaxTreeNode::Return(_) => {
+                self.push(current, node);
+                self.edge(current, RETURNED);
+                self.new_block()
+            }
+            AbstractSyntaxTreeNode::Break(break_definition) => {
+                self.push(current, node);
+                if let Some(target) = self.loop_targets(&break_definition.label).map(|targets| targets.break_block) {
+                    self.edge(current, target);
+                }
+                self.new_block()
+            }
+            AbstractSyntaxTreeNode::Continue(continue_definition) => {
+                self.push(current, node);
+                if let Some(target) = self.loop_targets(&continue_definition.label).map(|targets| targets.continue_block) {
+                    self.edge(current, target);
+                }
+                self.new_block()
+            }
+            AbstractSyntaxTreeNode::If(if_definition) => {
+                self.push(current, node);
+                let condition = constant_condition(&if_definition.condition);
+                let join = self.new_block();
+
+                let if_block = self.new_block();
+                if condition != Some(false) {
+                    self.edge(current, if_block);
+                }
+                let if_end = self.scope(&if_definition.if_stack, if_block);
+                self.edge(if_end, join);
+
+                match &if_definition.else_stack {
+                    Some(else_stack) => {
+                        let else_block = self.new_block();
+                        if condition != Some(true) {
+                            self.edge(current, else_block);
+                        }
+                        let else_end = self.scope(else_stack, else_block);
+                        self.edge(else_end, join);
+                    }
+                    None if condition != Some(true) => self.edge(current, join),
+                    None => {}
+                }
+
+                join
+            }
+            // the arms of a match are exhaustive
+            AbstractSyntaxTreeNode::Match(match_definition) => {
+                self.push(current, node);
+                let join = self.new_block();
+
+                for arm in &match_definition.arms {
+                    let arm_block = self.new_block();
+                    self.edge(current, arm_block);
+                    let arm_end = self.scope(&arm.stack, arm_block);
+                    self.edge(arm_end, join);
+                }
+
+                if match_definition.arms.is_empty() {
+                    self.edge(current, join);
+                }
+
+                join
+            }
+            AbstractSyntaxTreeNode::While(while_definition) => {
+                let condition_block = self.new_block();
+                self.edge(current, condition_block);
+                self.push(condition_block, node);
+
+                self.loop_body(&while_definition.label, &while_definition.condition, condition_block, condition_block, &while_definition.stack)
+            }
+            AbstractSyntaxTreeNode::For(for_definition) => {
+                // the initialization runs once before the condition
+                self.push(current, node);
+                let condition_block = self.new_block();
+                self.edge(current, condition_block);
+
+                let update_block = self.new_block();
+                self.edge(update_block, condition_block);
+
+                self.loop_body(&for_definition.label, &for_definition.condition, condition_block, update_block, &for_definition.stack)
+            }
+            AbstractSyntaxTreeNode::Variable(_) | AbstractSyntaxTreeNode::MethodCall(_) | AbstractSyntaxTreeNode::MethodDefinition(_) |
+            AbstractSyntaxTreeNode::Impl(_) | AbstractSyntaxTreeNode::StructDefinition(_) | AbstractSyntaxTreeNode::EnumDefinition(_) |
+            AbstractSyntaxTreeNode::Import(_) | AbstractSyntaxTreeNode::SyntaxError(_) => {
+                self.push(current, node);
+                current
+            }
+        }
+    }
+
+    /// adds the body of a loop. The body runs, as long as the condition in the `condition_block` holds. Returns the block after the loop
+    fn loop_body(&mut self, label: &Option<String>, condition: &Assignable, condition_block: BlockId, continue_block: BlockId, stack: &'a [AbstractSyntaxTreeNode]) -> BlockId {
+        let condition = constant_condition(condition);
+        let after = self.new_block();
+        let body = self.new_block();
+
+        if condition != Some(false) {
+            self.edge(condition_block, body);
+        }
+
+        // `while (true)` is only left by `break`
+        if condition != Some(true) {
+            self.edge(condition_block, after);
+        }
+
+        self.loops.push(LoopTargets { label: label.clone(), continue_block, break_block: after });
+        let body_end = self.scope(stack, body);
+        let _ = self.loops.pop();
+
+        self.edge(body_end, continue_block);
+
+        after
+    }
+
+    /// the loop a `break` or `continue` refers to. Without a label, it's the innermost loop
+    fn loop_targets(&self, label: &Option<String>) -> Option<&LoopTargets> {
+        self.loops.iter().rev().find(|targets| label.is_none() || targets.label == *label)
+    }
+}
+
+/// the value of a condition, which is known at compile time, like `true`
+pub(crate) fn constant_condition(condition: &Assignable) -> Option<bool> {
+    match condition {
+        Assignable::Boolean(boolean) => Some(boolean.value),
+        Assignable::Expression(expression) if expression.lhs.is_none() && expression.rhs.is_none() &&
+            expression.prefix_arithmetic.is_none() && expression.index_operator.is_none() => expression.value.as_deref().and_then(constant_condition),
+        _ => None,
+    }
+}
+
+impl MethodDefinition {
+    /// Returns the reason, if a path through the method ends without returning a value
+    pub fn missing_return(&self) -> Option<MethodCallSignatureMismatchCause> {
+        if self.is_extern || self.return_type == Type::Void || !ControlFlowGraph::new(&self.stack).falls_through() {
+            return None;
+        }
+
+        Some(match self.stack.last() {
+            Some(AbstractSyntaxTreeNode::If(_)) => MethodCallSignatureMismatchCause::IfCondition,
+            Some(AbstractSyntaxTreeNode::Match(_)) => MethodCallSignatureMismatchCause::MatchArm,
+            Some(AbstractSyntaxTreeNode::While(_) | AbstractSyntaxTreeNode::For(_)) => MethodCallSignatureMismatchCause::LoopCondition,
+            _ => MethodCallSignatureMismatchCause::ReturnMismatch,
+        })
+    }
+}
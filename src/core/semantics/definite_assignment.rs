@@ -0,0 +1,428 @@
+use std::collections::HashSet;
+use crate::core::lexer::token_with_span::FilePosition;
+use crate::core::model::abstract_syntax_tree_node::AbstractSyntaxTreeNode;
+use crate::core::model::abstract_syntax_tree_nodes::assignable::Assignable;
+use crate::core::model::abstract_syntax_tree_nodes::assignables::equation_parser::expression::Expression;
+use crate::core::model::abstract_syntax_tree_nodes::assignables::equation_parser::prefix_arithmetic::{PointerArithmetic, PrefixArithmetic};
+use crate::core::model::abstract_syntax_tree_nodes::assignables::method_call::MethodCall;
+use crate::core::model::abstract_syntax_tree_nodes::l_value::LValue;
+use crate::core::model::abstract_syntax_tree_nodes::method_definition::MethodDefinition;
+use crate::core::model::abstract_syntax_tree_nodes::variable::Variable;
+use crate::core::model::symbol_table::{Symbol, SymbolId, SymbolTable};
+use crate::core::semantics::control_flow::constant_condition;
+use crate::core::semantics::static_type_check::static_type_checker::StaticTypeCheckError;
+
+/// Checks, that every local variable is declared and has a value on every path, before it is read
+/// ```text
+/// let mut a: i32;
+/// if (condition) {
+///     a = 1;
+/// }
+/// let b = a; // `a` is possibly uninitialized
+/// ```
+/// The check only needs the names of the variables, so it runs before the type inference. Otherwise the inference reports
+/// a variable read before its declaration as an unresolved reference
+#[allow(clippy::result_large_err)]
+pub fn definite_assignment_check(scope: &[AbstractSyntaxTreeNode]) -> Result<(), StaticTypeCheckError> {
+    let mut analysis = DefiniteAssignment::default();
+    let _ = analysis.scope(scope, FlowState::default())?;
+
+    Ok(())
+}
+
+struct LocalVariable {
+    name: String,
+    mutable: bool,
+}
+
+impl Symbol for LocalVariable {
+    fn symbol_name(&self) -> String {
+        self.name.clone()
+    }
+}
+
+/// The variables, which definitely have a value at a point of the program
+#[derive(Debug, Default, Clone)]
+struct FlowState {
+    assigned: HashSet<SymbolId>,
+    /// the variables, which have a value on at least one path. An immutable variable can only be assigned, if it has no value on any path
+    possibly_assigned: HashSet<SymbolId>,
+    /// the point can't be reached, because every path before returned or left the loop
+    diverges: bool,
+}
+
+impl FlowState {
+    /// the state after two paths joined. A variable has a value, if it has a value on both paths
+    fn join(self, other: FlowState) -> FlowState {
+        if self.diverges {
+            return other;
+        }
+
+        if other.diverges {
+            return self;
+        }
+
+        FlowState {
+            assigned: self.assigned.intersection(&other.assigned).copied().collect(),
+            possibly_assigned: self.possibly_assigned.union(&other.possibly_assigned).copied().collect(),
+            diverges: false,
+        }
+    }
+
+    fn assign(&mut self, id: SymbolId) {
+        let _ = self.assigned.insert(id);
+        let _ = self.possibly_assigned.insert(id);
+    }
+
+    /// the state of a point, which is never reached
+    fn unreachable() -> FlowState {
+        FlowState { diverges: true, ..FlowState::default() }
+    }
+}
+
+/// A loop surrounding the current statement
+struct LoopFlow {
+    label: Option<String>,
+    /// the joined states of all `continue` jumping to the next iteration of the loop
+    continue_state: Option<FlowState>,
+    /// the joined states of all `break` leaving the loop
+    break_state: Option<FlowState>,
+}
+
+#[derive(Default)]
+struct DefiniteAssignment {
+    symbols: SymbolTable<LocalVariable>,
+    /// the names declared in each open scope, which aren't declared yet at the current point
+    upcoming: Vec<Vec<String>>,
+    /// the surrounding loops. The innermost loop is the last one
+    loops: Vec<LoopFlow>,
+}
+
+impl DefiniteAssignment {
+    #[allow(clippy::result_large_err)]
+    fn scope(&mut self, scope: &[AbstractSyntaxTreeNode], mut state: FlowState) -> Result<FlowState, StaticTypeCheckError> {
+        self.symbols.push_scope();
+        self.upcoming.push(scope.iter().filter_map(|node| match node {
+            AbstractSyntaxTreeNode::Variable(variable) if variable.define => Some(variable.l_value.identifier()),
+            _ => None,
+        }).collect());
+
+        for node in scope {
+            state = self.node(node, state)?;
+        }
+
+        let _ = self.upcoming.pop();
+        let _ = self.symbols.pop_scope();
+
+        Ok(state)
+    }
+
+    #[allow(clippy::result_large_err)]
+    fn node(&mut self, node: &AbstractSyntaxTreeNode, mut state: FlowState) -> Result<FlowState, StaticTypeCheckError> {
+        match node {
+            AbstractSyntaxTreeNode::Variable(variable) => self.variable(variable, state),
+            AbstractSyntaxTreeNode::MethodCall(method_call) => {
+                self.method_call(method_call, &mut state, &method_call.file_position)?;
+                Ok(state)
+            }
+            AbstractSyntaxTreeNode::Return(ret) => {
+                if let Some(assignable) = &ret.assignable {
+                    self.assignable(assignable, &mut state, &ret.file_position)?;
+                }
+
+                state.diverges = true;
+                Ok(state)
+            }
+            AbstractSyntaxTreeNode::Break(break_) => {
+                if let Some(target) = self.loop_flow(&break_.label) {
+                    target.break_state = Some(match target.break_state.take() {
+                        Some(break_state) => break_state.join(state.clone()),
+                        None => state.clone(),
+                    });
+                }
+
+                state.diverges = true;
+                Ok(state)
+            }
+            AbstractSyntaxTreeNode::Continue(continue_) => {
+                if let Some(target) = self.loop_flow(&continue_.label) {
+                    target.continue_state = Some(match target.continue_state.take() {
+                        Some(continue_state) => continue_state.join(state.clone()),
+                        None => state.clone(),
+                    });
+                }
+
+                state.diverges = true;
+                Ok(state)
+            }
+            AbstractSyntaxTreeNode::If(if_definition) => {
+                self.assignable(&if_definition.condition, &mut state, &if_definition.file_position)?;
+
+                let if_state = self.scope(&if_definition.if_stack, state.clone())?;
+                let else_state = match &if_definition.else_stack {
+                    Some(else_stack) => self.scope(else_stack, state)?,
+                    None => state,
+                };
+
+                Ok(if_state.join(else_state))
+            }
+            AbstractSyntaxTreeNode::Match(match_definition) => {
+                self.assignable(&match_definition.value, &mut state, &match_definition.file_position)?;
+
+                let mut arm_states = vec![];
+                for arm in &match_definition.arms {
+                    arm_states.push(self.scope(&arm.stack, state.clone())?);
+                }
+
+                Ok(arm_states.into_iter().reduce(FlowState::join).unwrap_or(state))
+            }
+            AbstractSyntaxTreeNode::While(while_definition) => {
+                self.assignable(&while_definition.condition, &mut state, &while_definition.file_position)?;
+                self.loop_body(&while_definition.label, &while_definition.condition, &while_definition.stack, None, state)
+            }
+            AbstractSyntaxTreeNode::For(for_definition) => {
+                self.symbols.push_scope();
+                self.upcoming.push(vec![]);
+
+                let mut header_state = self.variable(&for_definition.initialization, state.clone())?;
+                self.assignable(&for_definition.condition, &mut header_state, &for_definition.file_position)?;
+                let exit_state = self.loop_body(&for_definition.label, &for_definition.condition, &for_definition.stack, Some(&for_definition.update), header_state)?;
+
+                let _ = self.upcoming.pop();
+                let _ = self.symbols.pop_scope();
+
+                Ok(exit_state)
+            }
+            AbstractSyntaxTreeNode::MethodDefinition(method_definition) => {
+                DefiniteAssignment::default().method_definition(method_definition)?;
+                Ok(state)
+            }
+            AbstractSyntaxTreeNode::Impl(impl_definition) => {
+                for method_definition in &impl_definition.methods {
+                    DefiniteAssignment::default().method_definition(method_definition)?;
+                }
+
+                Ok(state)
+            }
+            AbstractSyntaxTreeNode::Import(_) | AbstractSyntaxTreeNode::StructDefinition(_) | AbstractSyntaxTreeNode::EnumDefinition(_) |
+            AbstractSyntaxTreeNode::SyntaxError(_) => Ok(state),
+        }
+    }
+
+    /// Checks the body and the update of a loop and returns the state after the loop. The body runs again after it assigned a variable,
+    /// so it's checked a second time, if it assigned an immutable variable declared before the loop
+    #[allow(clippy::result_large_err)]
+    fn loop_body(&mut self, label: &Option<String>, condition: &Assignable, stack: &[AbstractSyntaxTreeNode], update: Option<&Variable<'=', ';'>>, state: FlowState) -> Result<FlowState, StaticTypeCheckError> {
+        let condition = constant_condition(condition);
+
+        // the body of `while (false)` is never executed
+        let mut body_state = state.clone();
+        body_state.diverges |= condition == Some(false);
+
+        let (end_state, mut break_state) = self.iteration(label, stack, update, body_state.clone())?;
+
+        // the next iteration only runs, if the end of the body is reachable
+        let assigned_immutable = !end_state.diverges && end_state.possibly_assigned.difference(&body_state.possibly_assigned)
+            .any(|id| self.symbols.get(*id).is_some_and(|local| !local.mutable));
+
+        if assigned_immutable {
+            let mut next_iteration = body_state;
+            next_iteration.possibly_assigned.extend(end_state.possibly_assigned.iter().copied());
+            let (_, next_break_state) = self.iteration(label, stack, update, next_iteration)?;
+
+            break_state = match (break_state, next_break_state) {
+                (Some(break_state), Some(next_break_state)) => Some(break_state.join(next_break_state)),
+                (break_state, next_break_state) => break_state.or(next_break_state),
+            };
+        }
+
+        // the body of a loop might not be executed at all. `while (true)` is only left by `break`
+        let exit_state = match condition {
+            Some(true) => FlowState::unreachable(),
+            _ => state.join(end_state),
+        };
+
+        Ok(match break_state {
+            Some(break_state) => exit_state.join(break_state),
+            None => exit_state,
+        })
+    }
+
+    /// the state at the end of one iteration of a loop and the joined states of the `break` leaving it
+    #[allow(clippy::result_large_err)]
+    fn iteration(&mut self, label: &Option<String>, stack: &[AbstractSyntaxTreeNode], update: Option<&Variable<'=', ';'>>, state: FlowState) -> Result<(FlowState, Option<FlowState>), StaticTypeCheckError> {
+        self.loops.push(LoopFlow { label: label.clone(), continue_state: None, break_state: None });
+        let body_state = self.scope(stack, state)?;
+        let (continue_state, break_state) = self.loops.pop()
+            .map(|loop_flow| (loop_flow.continue_state, loop_flow.break_state))
+            .unwrap_or_default();
+
+        // `continue` jumps to the update as well
+        let end_state = match continue_state {
+            Some(continue_state) => body_state.join(continue_state),
+            None => body_state,
+        };
+
+        let end_state = match update {
+            Some(update) => self.variable(update, end_state)?,
+            None => end_state,
+        };
+
+        Ok((end_state, break_state))
+    }
+
+    /// the loop a `break` or `continue` refers to. Without a label, it's the innermost loop
+    fn loop_flow(&mut self, label: &Option<String>) -> Option<&mut LoopFlow> {
+        self.loops.iter_mut().rev().find(|loop_flow| label.is_none() || loop_flow.label == *label)
+    }
+
+    /// the parameters of a method always have a value
+    #[allow(clippy::result_large_err)]
+    fn method_definition(&mut self, method_definition: &MethodDefinition) -> Result<(), StaticTypeCheckError> {
+        let mut state = FlowState::default();
+        self.symbols.push_scope();
+
+        for argument in &method_definition.arguments {
+            let id = self.symbols.declare(LocalVariable { name: argument.identifier.identifier(), mutable: argument.ty.mutable() });
+            state.assign(id);
+        }
+
+        let _ = self.scope(&method_definition.stack, state)?;
+        let _ = self.symbols.pop_scope();
+
+        Ok(())
+    }
+
+    #[allow(clippy::result_large_err)]
+    fn variable(&mut self, variable: &Variable<'=', ';'>, mut state: FlowState) -> Result<FlowState, StaticTypeCheckError> {
+        self.assignable(&variable.assignable, &mut state, &variable.file_position)?;
+
+        if variable.define {
+            let name = variable.l_value.identifier();
+            if let Some(upcoming) = self.upcoming.last_mut() {
+                if let Some(index) = upcoming.iter().position(|upcoming_name| *upcoming_name == name) {
+                    let _ = upcoming.remove(index);
+                }
+            }
+
+            let id = self.symbols.declare(LocalVariable { name, mutable: variable.mutability });
+            if variable.assignable != Assignable::Uninitialized {
+                state.assign(id);
+            }
+
+            return Ok(state);
+        }
+
+        match &variable.l_value {
+            LValue::Identifier(identifier) => {
+                // `a += 1` reads `a` first
+                if variable.compound_operator.is_some() {
+                    self.read(&identifier.name, &state, &variable.file_position)?;
+                }
+
+                match self.symbols.lookup_id(&identifier.name) {
+                    // `let a: i32; a = 1;` assigns the immutable variable once
+                    Some(id) if !state.diverges && state.possibly_assigned.contains(&id) && self.symbols.get(id).is_some_and(|local| !local.mutable) => {
+                        return Err(StaticTypeCheckError::ImmutabilityViolated {
+                            name: variable.l_value.clone(),
+                            file_position: variable.file_position.clone(),
+                        });
+                    }
+                    Some(id) => state.assign(id),
+                    None => self.undeclared(&identifier.name, &variable.file_position)?,
+                }
+            }
+            // writing an element or a field needs the value of the variable
+            LValue::Expression(expression) => self.expression(expression, &mut state, &variable.file_position)?,
+        }
+
+        Ok(state)
+    }
+
+    #[allow(clippy::result_large_err)]
+    fn assignable(&mut self, assignable: &Assignable, state: &mut FlowState, file_position: &FilePosition) -> Result<(), StaticTypeCheckError> {
+        match assignable {
+            Assignable::Identifier(identifier) => self.read(&identifier.name, state, file_position),
+            Assignable::Expression(expression) => self.expression(expression, state, file_position),
+            Assignable::MethodCall(method_call) => self.method_call(method_call, state, file_position),
+            Assignable::Object(object) => {
+                for field in &object.fields {
+                    self.assignable(&field.assignable, state, file_position)?;
+                }
+
+                Ok(())
+            }
+            Assignable::Array(array) => {
+                for value in &array.values {
+                    self.assignable(value, state, file_position)?;
+                }
+
+                Ok(())
+            }
+            Assignable::FieldAccess(field_access) => self.assignable(&field_access.value, state, file_position),
+            Assignable::String(_) | Assignable::Integer(_) | Assignable::Float(_) | Assignable::Boolean(_) |
+            Assignable::Parameter(_) | Assignable::EnumPath(_) | Assignable::Uninitialized => Ok(()),
+        }
+    }
+
+    #[allow(clippy::result_large_err)]
+    fn method_call(&mut self, method_call: &MethodCall, state: &mut FlowState, file_position: &FilePosition) -> Result<(), StaticTypeCheckError> {
+        if let Some(receiver) = &method_call.receiver {
+            self.assignable(receiver, state, file_position)?;
+        }
+
+        for argument in &method_call.arguments {
+            self.assignable(argument, state, file_position)?;
+        }
+
+        Ok(())
+    }
+
+    #[allow(clippy::result_large_err)]
+    fn expression(&mut self, expression: &Expression, state: &mut FlowState, file_position: &FilePosition) -> Result<(), StaticTypeCheckError> {
+        // the value behind a reference like `&a` can be written by the receiver of the reference
+        if let (Some(PrefixArithmetic::PointerArithmetic(PointerArithmetic::Ampersand)), Some(name)) = (&expression.prefix_arithmetic, expression.identifier()) {
+            match self.symbols.lookup_id(&name) {
+                Some(id) => state.assign(id),
+                None => self.undeclared(&name, file_position)?,
+            }
+
+            return Ok(());
+        }
+
+        for operand in [&expression.lhs, &expression.rhs].into_iter().flatten() {
+            self.expression(operand, state, file_position)?;
+        }
+
+        for value in [&expression.value, &expression.index_operator].into_iter().flatten() {
+            self.assignable(value, state, file_position)?;
+        }
+
+        Ok(())
+    }
+
+    #[allow(clippy::result_large_err)]
+    fn read(&self, name: &str, state: &FlowState, file_position: &FilePosition) -> Result<(), StaticTypeCheckError> {
+        match self.symbols.lookup_id(name) {
+            Some(id) if !state.diverges && !state.assigned.contains(&id) => Err(StaticTypeCheckError::PossiblyUninitialized {
+                name: name.to_string(),
+                file_position: file_position.clone(),
+            }),
+            Some(_) => Ok(()),
+            None => self.undeclared(name, file_position),
+        }
+    }
+
+    /// names, which aren't declared at all, are reported by the type checker
+    #[allow(clippy::result_large_err)]
+    fn undeclared(&self, name: &str, file_position: &FilePosition) -> Result<(), StaticTypeCheckError> {
+        if self.upcoming.iter().any(|upcoming| upcoming.iter().any(|upcoming_name| upcoming_name == name)) {
+            return Err(StaticTypeCheckError::UsedBeforeDeclaration {
+                name: name.to_string(),
+                file_position: file_position.clone(),
+            });
+        }
+
+        Ok(())
+    }
+}
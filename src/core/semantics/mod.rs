@@ -1,2 +1,6 @@
 pub mod type_infer;
-pub mod static_type_check;
\ No newline at end of file
+pub mod static_type_check;
+pub mod definite_assignment;
+pub mod warnings;
+pub mod control_flow;
+pub mod monomorphization;
\ No newline at end of file
@@ -0,0 +1,293 @@
+use std::collections::HashMap;
+use crate::core::code_generator::conventions;
+use crate::core::model::abstract_syntax_tree_node::AbstractSyntaxTreeNode;
+use crate::core::model::abstract_syntax_tree_nodes::assignable::Assignable;
+use crate::core::model::abstract_syntax_tree_nodes::assignables::equation_parser::expression::Expression;
+use crate::core::model::abstract_syntax_tree_nodes::assignables::equation_parser::prefix_arithmetic::PrefixArithmetic;
+use crate::core::model::abstract_syntax_tree_nodes::assignables::method_call::MethodCall;
+use crate::core::model::abstract_syntax_tree_nodes::identifier::Identifier;
+use crate::core::model::abstract_syntax_tree_nodes::l_value::LValue;
+use crate::core::model::abstract_syntax_tree_nodes::match_::MatchPattern;
+use crate::core::model::abstract_syntax_tree_nodes::method_definition::{GenericParameter, MethodDefinition};
+use crate::core::model::abstract_syntax_tree_nodes::variable::Variable;
+use crate::core::model::types::mutability::Mutability;
+use crate::core::model::types::ty::Type;
+use crate::core::parser::static_type_context::StaticTypeContext;
+use crate::core::parser::types::r#type::InferTypeError;
+
+/// The name of the instance of a generic method for the type arguments like `max<i32>`.
+/// Identifiers can't contain `<`, so the name never collides with a method of the program
+pub fn instance_name(method_name: &str, type_arguments: &[Type]) -> String {
+    format!("{method_name}<{}>", type_arguments.iter().map(|ty| ty.to_string()).collect::<Vec<String>>().join(", "))
+}
+
+impl MethodCall {
+    /// Calls a generic method, if no other method matches the call.
+    /// The type arguments are taken from the call like `max::<i32>(a, b)` or inferred from the types of the arguments like `max(a, b)`.
+    /// The call is renamed to the instance of the generic method for these type arguments. The instance is created, the first time it's called,
+    /// and added to the methods and the monomorphized methods of the context.
+    /// Returns the return type of the instance or `None`, if there is no generic method with the name of the call
+    pub fn monomorphize(&mut self, type_context: &mut StaticTypeContext) -> Result<Option<Type>, Box<InferTypeError>> {
+        let method_name = self.identifier.identifier();
+        let Some(generic_method) = type_context.generic_methods.iter()
+            .find(|method_definition| method_definition.identifier.identifier() == method_name && method_definition.arguments.len() == self.arguments.len())
+            .cloned() else {
+            return Ok(None);
+        };
+
+        let type_arguments = self.resolve_type_arguments(&generic_method, type_context)?;
+        let instance_name = instance_name(&method_name, &type_arguments);
+
+        if !type_context.methods.iter().any(|method_definition| method_definition.identifier.identifier() == instance_name) {
+            let instance = generic_method.instantiate(&instance_name, &type_arguments);
+
+            type_context.methods.push(instance.clone());
+            type_context.monomorphized.push(instance);
+        }
+
+        self.identifier = LValue::Identifier(Identifier { name: instance_name, symbol: None });
+        self.type_arguments.clear();
+
+        // the arguments have to fit the parameters of the instance like the arguments of any other call
+        if let Some(method_definition) = conventions::method_definitions(type_context, &self.arguments, &self.identifier.identifier())?.first() {
+            return Ok(Some(method_definition.return_type.clone()));
+        }
+
+        Err(self.signature_mismatch(type_context))
+    }
+
+    /// the explicit type arguments of the call or the type arguments inferred from the arguments. Every type argument must satisfy the bound of its type parameter
+    fn resolve_type_arguments(&self, generic_method: &MethodDefinition, type_context: &StaticTypeContext) -> Result<Vec<Type>, Box<InferTypeError>> {
+        let method_name = generic_method.identifier.identifier();
+        let file_position = type_context.current_file_position.clone();
+
+        let type_arguments = if self.type_arguments.is_empty() {
+            let mut bindings = HashMap::new();
+
+            for (parameter, argument) in generic_method.arguments.iter().zip(&self.arguments) {
+                if let Some(argument_type) = argument.get_type(type_context) {
+                    bind_type_parameters(&parameter.ty, &argument_type, &generic_method.generic_parameters, &mut bindings);
+                }
+            }
+
+            generic_method.generic_parameters.iter()
+                .map(|generic_parameter| bindings.remove(&generic_parameter.name).ok_or(Box::new(InferTypeError::UninferableTypeParameter {
+                    method_name: method_name.clone(),
+                    parameter: generic_parameter.name.clone(),
+                    file_position: file_position.clone(),
+                })))
+                .collect::<Result<Vec<_>, _>>()?
+        } else if self.type_arguments.len() != generic_method.generic_parameters.len() {
+            return Err(Box::new(InferTypeError::TypeArgumentAmountMismatch {
+                method_name,
+                expected: generic_method.generic_parameters.len(),
+                actual: self.type_arguments.len(),
+                file_position,
+            }));
+        } else {
+            self.type_arguments.iter().cloned().map(|mut ty| {
+                ty.set_mutability(Mutability::Immutable);
+                ty
+            }).collect()
+        };
+
+        for (generic_parameter, ty) in generic_method.generic_parameters.iter().zip(&type_arguments) {
+            if generic_parameter.bound.is_some_and(|bound| !bound.is_satisfied_by(ty)) {
+                return Err(Box::new(InferTypeError::UnsatisfiedTypeBound {
+                    method_name,
+                    parameter: generic_parameter.clone(),
+                    ty: ty.clone(),
+                    file_position,
+                }));
+            }
+        }
+
+        Ok(type_arguments)
+    }
+}
+
+/// Binds the type parameters used in the type of a parameter to the matching part of the type of the argument.
+/// A parameter `a: *T` called with a `*i32` binds `T` to `i32`. The first binding of a type parameter wins
+fn bind_type_parameters(parameter_type: &Type, argument_type: &Type, generic_parameters: &[GenericParameter], bindings: &mut HashMap<String, Type>) {
+    match (parameter_type, argument_type) {
+        (Type::Array(parameter_element, parameter_size, _), Type::Array(argument_element, argument_size, _)) if parameter_size == argument_size => {
+            bind_type_parameters(parameter_element, argument_element, generic_parameters, bindings);
+        }
+        (Type::Custom(identifier, _), _) => {
+            let name = identifier.name.trim_start_matches('*');
+
+            if !generic_parameters.iter().any(|generic_parameter| generic_parameter.name == name) {
+                return;
+            }
+
+            let mut argument_type = argument_type.clone();
+            for _ in 0..identifier.name.len() - name.len() {
+                let Some(pointee) = argument_type.pop_pointer() else {
+                    return;
+                };
+
+                argument_type = pointee;
+            }
+
+            argument_type.set_mutability(Mutability::Immutable);
+            let _ = bindings.entry(name.to_string()).or_insert(argument_type);
+        }
+        _ => {}
+    }
+}
+
+impl MethodDefinition {
+    /// A copy of the generic method named `name`, where every type parameter is replaced by its type argument
+    pub fn instantiate(&self, name: &str, type_arguments: &[Type]) -> MethodDefinition {
+        let substitutions = self.generic_parameters.iter()
+            .map(|generic_parameter| generic_parameter.name.clone())
+            .zip(type_arguments.iter().cloned())
+            .collect::<HashMap<_, _>>();
+
+        let mut instance = MethodDefinition {
+            identifier: LValue::Identifier(Identifier { name: name.to_string(), symbol: None }),
+            generic_parameters: vec![],
+            ..self.clone()
+        };
+
+        for argument in &mut instance.arguments {
+            substitute_type(&mut argument.ty, &substitutions);
+        }
+
+        substitute_type(&mut instance.return_type, &substitutions);
+        substitute_nodes(&mut instance.stack, &substitutions);
+
+        instance
+    }
+}
+
+/// pointers like `*T` are stored as custom types, the type parameter is replaced without the pointer prefix
+fn substitute_type(ty: &mut Type, substitutions: &HashMap<String, Type>) {
+    match ty {
+        Type::Custom(identifier, mutability) => {
+            let name = identifier.name.trim_start_matches('*');
+
+            if let Some(substitution) = substitutions.get(name) {
+                let mut substitution = (0..identifier.name.len() - name.len()).fold(substitution.clone(), |ty, _| ty.push_pointer());
+                substitution.set_mutability(mutability.clone());
+                *ty = substitution;
+            }
+        }
+        Type::Array(element_type, _, _) => substitute_type(element_type, substitutions),
+        Type::Integer(_, _) | Type::Float(_, _) | Type::Bool(_) | Type::Void | Type::Statement => {}
+    }
+}
+
+fn substitute_nodes(scope: &mut [AbstractSyntaxTreeNode], substitutions: &HashMap<String, Type>) {
+    for node in scope {
+        match node {
+            AbstractSyntaxTreeNode::Variable(variable) => substitute_variable(variable, substitutions),
+            AbstractSyntaxTreeNode::MethodCall(method_call) => substitute_method_call(method_call, substitutions),
+            AbstractSyntaxTreeNode::Return(return_statement) => {
+                if let Some(assignable) = &mut return_statement.assignable {
+                    substitute_assignable(assignable, substitutions);
+                }
+            }
+            AbstractSyntaxTreeNode::If(if_statement) => {
+                substitute_assignable(&mut if_statement.condition, substitutions);
+                substitute_nodes(&mut if_statement.if_stack, substitutions);
+
+                if let Some(else_stack) = &mut if_statement.else_stack {
+                    substitute_nodes(else_stack, substitutions);
+                }
+            }
+            AbstractSyntaxTreeNode::Match(match_statement) => {
+                substitute_assignable(&mut match_statement.value, substitutions);
+
+                for arm in &mut match_statement.arms {
+                    for pattern in &mut arm.patterns {
+                        match pattern {
+                            MatchPattern::Literal(literal) => substitute_assignable(literal, substitutions),
+                            MatchPattern::Range { start, end, .. } => {
+                                substitute_assignable(start, substitutions);
+                                substitute_assignable(end, substitutions);
+                            }
+                            MatchPattern::Wildcard => {}
+                        }
+                    }
+
+                    substitute_nodes(&mut arm.stack, substitutions);
+                }
+            }
+            AbstractSyntaxTreeNode::For(for_loop) => {
+                substitute_variable(&mut for_loop.initialization, substitutions);
+                substitute_assignable(&mut for_loop.condition, substitutions);
+                substitute_variable(&mut for_loop.update, substitutions);
+                substitute_nodes(&mut for_loop.stack, substitutions);
+            }
+            AbstractSyntaxTreeNode::While(while_loop) => {
+                substitute_assignable(&mut while_loop.condition, substitutions);
+                substitute_nodes(&mut while_loop.stack, substitutions);
+            }
+            AbstractSyntaxTreeNode::MethodDefinition(_) | AbstractSyntaxTreeNode::Impl(_) | AbstractSyntaxTreeNode::StructDefinition(_) |
+            AbstractSyntaxTreeNode::EnumDefinition(_) | AbstractSyntaxTreeNode::Import(_) | AbstractSyntaxTreeNode::Break(_) |
+            AbstractSyntaxTreeNode::Continue(_) | AbstractSyntaxTreeNode::SyntaxError(_) => {}
+        }
+    }
+}
+
+fn substitute_variable<const ASSIGNMENT: char, const SEPARATOR: char>(variable: &mut Variable<ASSIGNMENT, SEPARATOR>, substitutions: &HashMap<String, Type>) {
+    if let LValue::Expression(expression) = &mut variable.l_value {
+        substitute_expression(expression, substitutions);
+    }
+
+    if let Some(ty) = &mut variable.ty {
+        substitute_type(ty, substitutions);
+    }
+
+    substitute_assignable(&mut variable.assignable, substitutions);
+}
+
+fn substitute_method_call(method_call: &mut MethodCall, substitutions: &HashMap<String, Type>) {
+    if let Some(receiver) = &mut method_call.receiver {
+        substitute_assignable(receiver, substitutions);
+    }
+
+    for ty in &mut method_call.type_arguments {
+        substitute_type(ty, substitutions);
+    }
+
+    for argument in &mut method_call.arguments {
+        substitute_assignable(argument, substitutions);
+    }
+}
+
+fn substitute_assignable(assignable: &mut Assignable, substitutions: &HashMap<String, Type>) {
+    match assignable {
+        Assignable::MethodCall(method_call) => substitute_method_call(method_call, substitutions),
+        Assignable::Object(object) => {
+            for field in &mut object.fields {
+                substitute_variable(field, substitutions);
+            }
+        }
+        Assignable::FieldAccess(field_access) => substitute_assignable(&mut field_access.value, substitutions),
+        Assignable::Array(array) => {
+            for value in &mut array.values {
+                substitute_assignable(value, substitutions);
+            }
+        }
+        Assignable::Expression(expression) => substitute_expression(expression, substitutions),
+        Assignable::Parameter(parameter) => substitute_type(&mut parameter.ty, substitutions),
+        Assignable::String(_) | Assignable::Integer(_) | Assignable::Float(_) | Assignable::Boolean(_) | Assignable::Identifier(_) |
+        Assignable::EnumPath(_) | Assignable::Uninitialized => {}
+    }
+}
+
+fn substitute_expression(expression: &mut Expression, substitutions: &HashMap<String, Type>) {
+    for side in [&mut expression.lhs, &mut expression.rhs].into_iter().flatten() {
+        substitute_expression(side, substitutions);
+    }
+
+    for assignable in [&mut expression.value, &mut expression.index_operator].into_iter().flatten() {
+        substitute_assignable(assignable, substitutions);
+    }
+
+    if let Some(PrefixArithmetic::Cast(ty)) = &mut expression.prefix_arithmetic {
+        substitute_type(ty, substitutions);
+    }
+}
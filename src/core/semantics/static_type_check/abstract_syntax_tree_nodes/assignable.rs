@@ -8,10 +8,12 @@ impl StaticTypeCheck for Assignable {
         match self {
             Assignable::Object(object) => object.static_type_check(type_context),
             Assignable::Array(array) => array.static_type_check(type_context),
+            Assignable::FieldAccess(field_access) => field_access.static_type_check(type_context),
             Assignable::String(_) | Assignable::Integer(_) | 
             Assignable::Float(_) | Assignable::Parameter(_) | 
             Assignable::Boolean(_) | Assignable::MethodCall(_) | 
-            Assignable::Identifier(_) | Assignable::Expression(_) => Ok(())
+            Assignable::Identifier(_) | Assignable::Expression(_) |
+            Assignable::EnumPath(_) | Assignable::Uninitialized => Ok(())
         }
     }
 }
\ No newline at end of file
@@ -14,7 +14,7 @@ impl StaticTypeCheck for Array {
         let all_types = self.values
             .iter()
             .map(|a| a.get_type(type_context).ok_or(InferTypeError::NoTypePresent( // we can use a dummy identifier here since the overlaying variable will provide the correct one
-                LValue::Identifier(Identifier { name: String::new() }),
+                LValue::Identifier(Identifier { name: String::new(), symbol: None }),
                 FilePosition::default(),
             )))
             .collect::<Vec<Result<Type, InferTypeError>>>();
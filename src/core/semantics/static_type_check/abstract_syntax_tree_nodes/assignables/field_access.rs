@@ -0,0 +1,22 @@
+use crate::core::model::abstract_syntax_tree_nodes::assignables::field_access::FieldAccess;
+use crate::core::parser::static_type_context::StaticTypeContext;
+use crate::core::parser::types::r#type::InferTypeError;
+use crate::core::semantics::static_type_check::static_type_checker::StaticTypeCheckError;
+use crate::core::semantics::static_type_check::StaticTypeCheck;
+
+impl StaticTypeCheck for FieldAccess {
+    fn static_type_check(&self, type_context: &mut StaticTypeContext) -> Result<(), StaticTypeCheckError> {
+        self.value.static_type_check(type_context)?;
+
+        if self.get_type(type_context).is_some() {
+            return Ok(());
+        }
+
+        let error = match self.value.get_type(type_context) {
+            Some(ty) => InferTypeError::UnknownField { ty, field: self.field.name.clone(), file_position: type_context.current_file_position.clone() },
+            None => InferTypeError::UnresolvedReference(self.value.to_string(), type_context.current_file_position.clone()),
+        };
+
+        Err(StaticTypeCheckError::InferredError(Box::new(error)))
+    }
+}
@@ -8,6 +8,11 @@ use crate::core::semantics::static_type_check::StaticTypeCheck;
 
 impl StaticTypeCheck for MethodCall {
     fn static_type_check(&self, type_context: &mut StaticTypeContext) -> Result<(), StaticTypeCheckError> {
+        // the type inference resolves the receiver into the first argument
+        if self.receiver.is_some() {
+            return Err(StaticTypeCheckError::InferredError(Box::new(InferTypeError::UnresolvedReference(self.to_string(), type_context.current_file_position.clone()))));
+        }
+
         let method_defs = type_context.methods.iter().filter(|m| m.identifier == self.identifier).collect::<Vec<_>>();
 
         'outer: for method_def in &method_defs {
@@ -30,7 +35,7 @@ impl StaticTypeCheck for MethodCall {
             for (index, (argument_def, argument_call)) in zipped.enumerate() {
                 let def_type = argument_def.ty.clone();
                 let call_type = argument_call.get_type(type_context).ok_or(Box::new(InferTypeError::NoTypePresent(
-                    LValue::Identifier(Identifier { name: argument_call.identifier().unwrap_or(self.identifier.identifier()) }),
+                    LValue::Identifier(Identifier { name: argument_call.identifier().unwrap_or(self.identifier.identifier()), symbol: None }),
                     self.file_position.clone(),
                 )))?;
 
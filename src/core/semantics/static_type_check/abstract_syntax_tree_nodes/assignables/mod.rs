@@ -1,3 +1,4 @@
 mod method_call;
 mod object;
-mod array;
\ No newline at end of file
+mod array;
+mod field_access;
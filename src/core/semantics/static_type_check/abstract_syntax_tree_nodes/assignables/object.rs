@@ -21,7 +21,7 @@ impl StaticTypeCheck for Object {
         for field in &self.fields {
             let actual_name = field.l_value.identifier();
             let actual_type = field.ty.clone().ok_or(StaticTypeCheckError::NoTypePresent {
-                name: LValue::Identifier(Identifier { name: String::new() }),
+                name: LValue::Identifier(Identifier { name: String::new(), symbol: None }),
                 file_position: type_context.current_file_position.clone(),
             })?;
 
@@ -0,0 +1,10 @@
+use crate::core::model::abstract_syntax_tree_nodes::break_::Break;
+use crate::core::parser::static_type_context::StaticTypeContext;
+use crate::core::semantics::static_type_check::static_type_checker::{static_type_check_loop_control, StaticTypeCheckError};
+use crate::core::semantics::static_type_check::StaticTypeCheck;
+
+impl StaticTypeCheck for Break {
+    fn static_type_check(&self, type_context: &mut StaticTypeContext) -> Result<(), StaticTypeCheckError> {
+        static_type_check_loop_control("break", &self.label, &self.file_position, type_context)
+    }
+}
@@ -0,0 +1,37 @@
+use std::collections::{HashMap, HashSet};
+use crate::core::model::abstract_syntax_tree_nodes::enum_::Enum;
+use crate::core::parser::static_type_context::StaticTypeContext;
+use crate::core::parser::types::r#type::InferTypeError;
+use crate::core::semantics::static_type_check::static_type_checker::StaticTypeCheckError;
+use crate::core::semantics::static_type_check::StaticTypeCheck;
+
+impl StaticTypeCheck for Enum {
+    fn static_type_check(&self, type_context: &mut StaticTypeContext) -> Result<(), StaticTypeCheckError> {
+        let mut variant_names = HashSet::new();
+
+        for variant in &self.variants {
+            if !variant_names.insert(variant.name.name.as_str()) {
+                return Err(Box::new(InferTypeError::NameCollision(format!("{}::{}", self.ty, variant.name), self.file_position.clone())).into());
+            }
+        }
+
+        // a value of the enum must map back to a single variant, e.g. in a `match`
+        let mut discriminants = HashMap::new();
+
+        for variant in &self.variants {
+            if let Some(discriminant) = self.discriminant(&variant.name.name) {
+                if let Some(other_variant) = discriminants.insert(discriminant, &variant.name.name) {
+                    return Err(StaticTypeCheckError::DuplicateDiscriminant {
+                        variant: format!("{}::{}", self.ty, variant.name),
+                        other_variant: format!("{}::{}", self.ty, other_variant),
+                        discriminant,
+                        file_position: self.file_position.clone(),
+                    });
+                }
+            }
+        }
+
+        type_context.custom_defined_enums.insert(self.ty.clone(), self.clone());
+        Ok(())
+    }
+}
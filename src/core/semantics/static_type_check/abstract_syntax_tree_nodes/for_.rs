@@ -1,4 +1,3 @@
-use crate::core::model::abstract_syntax_tree_node::AbstractSyntaxTreeNode;
 use crate::core::model::abstract_syntax_tree_nodes::for_::For;
 use crate::core::model::abstract_syntax_tree_nodes::identifier::Identifier;
 use crate::core::model::abstract_syntax_tree_nodes::l_value::LValue;
@@ -6,18 +5,18 @@ use crate::core::model::types::mutability::Mutability;
 use crate::core::model::types::ty::Type;
 use crate::core::parser::static_type_context::StaticTypeContext;
 use crate::core::parser::types::r#type::InferTypeError;
-use crate::core::semantics::static_type_check::static_type_checker::{static_type_check, static_type_check_rec, StaticTypeCheckError};
+use crate::core::semantics::static_type_check::static_type_checker::{static_type_check_loop_body, StaticTypeCheckError};
 use crate::core::semantics::static_type_check::StaticTypeCheck;
 
 impl StaticTypeCheck for For {
     fn static_type_check(&self, type_context: &mut StaticTypeContext) -> Result<(), StaticTypeCheckError> {
-        // add for header variables
-        type_context.context.push(self.initialization.clone());
+        // the variables of the header are only visible inside of the loop
+        type_context.push_scope();
+        self.initialization.static_type_check(type_context)?;
 
-        let variables_len = type_context.context.len();
         let condition_type = self.condition.get_type(type_context).ok_or(
             StaticTypeCheckError::InferredError(Box::new(InferTypeError::NoTypePresent(
-                LValue::Identifier(Identifier { name: "for loop condition".to_string() }),
+                LValue::Identifier(Identifier { name: "for loop condition".to_string(), symbol: None }),
                 self.file_position.clone(),
             )))
         )?;
@@ -30,23 +29,14 @@ impl StaticTypeCheck for For {
             })));
         }
 
-        static_type_check(&vec![
-                AbstractSyntaxTreeNode::Variable(self.initialization.clone()),
-                AbstractSyntaxTreeNode::Variable(self.update.clone()),
-            ],
-        )?;
-
         if self.update.define {
             return Err(StaticTypeCheckError::InferredError(Box::new(InferTypeError::DefineNotAllowed(self.update.clone(), self.file_position.clone()))));
         }
 
-        static_type_check_rec(&self.stack, type_context)?;
+        self.update.static_type_check(type_context)?;
+        static_type_check_loop_body(&self.label, &self.stack, type_context)?;
 
-        let amount_pop = type_context.context.len() - variables_len;
-
-        for _ in 0..amount_pop {
-            let _ = type_context.context.pop();
-        }
+        let _ = type_context.pop_scope();
 
         Ok(())
     }
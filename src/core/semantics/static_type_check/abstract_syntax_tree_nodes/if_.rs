@@ -10,10 +10,9 @@ use crate::core::semantics::static_type_check::StaticTypeCheck;
 
 impl StaticTypeCheck for If {
     fn static_type_check(&self, type_context: &mut StaticTypeContext) -> Result<(), StaticTypeCheckError> {
-        let variables_len = type_context.context.len();
         let condition_type = self.condition.get_type(type_context).ok_or(
             StaticTypeCheckError::InferredError(Box::new(InferTypeError::NoTypePresent(
-                LValue::Identifier(Identifier { name: "if condition".to_string() }),
+                LValue::Identifier(Identifier { name: "if condition".to_string(), symbol: None }),
                 self.file_position.clone(),
             )))
         )?;
@@ -28,22 +27,8 @@ impl StaticTypeCheck for If {
 
         static_type_check_rec(&self.if_stack, type_context)?;
 
-        let amount_pop = type_context.context.len() - variables_len;
-
-        for _ in 0..amount_pop {
-            let _ = type_context.context.pop();
-        }
-
         if let Some(else_stack) = &self.else_stack {
-            let variables_len = type_context.context.len();
-
             static_type_check_rec(else_stack, type_context)?;
-
-            let amount_pop = type_context.context.len() - variables_len;
-
-            for _ in 0..amount_pop {
-                let _ = type_context.context.pop();
-            }
         }
 
         Ok(())
@@ -0,0 +1,11 @@
+use crate::core::model::abstract_syntax_tree_nodes::impl_::Impl;
+use crate::core::parser::static_type_context::StaticTypeContext;
+use crate::core::semantics::static_type_check::static_type_checker::StaticTypeCheckError;
+use crate::core::semantics::static_type_check::StaticTypeCheck;
+
+impl StaticTypeCheck for Impl {
+    /// impl blocks at the top level are lowered into method definitions while parsing, so every remaining impl block is nested
+    fn static_type_check(&self, _type_context: &mut StaticTypeContext) -> Result<(), StaticTypeCheckError> {
+        Err(StaticTypeCheckError::NestedImpl { ty: self.ty.clone(), file_position: self.file_position.clone() })
+    }
+}
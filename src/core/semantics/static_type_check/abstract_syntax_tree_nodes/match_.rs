@@ -0,0 +1,91 @@
+use crate::core::lexer::token_with_span::FilePosition;
+use crate::core::model::abstract_syntax_tree_nodes::assignable::Assignable;
+use crate::core::model::abstract_syntax_tree_nodes::identifier::Identifier;
+use crate::core::model::abstract_syntax_tree_nodes::l_value::LValue;
+use crate::core::model::abstract_syntax_tree_nodes::match_::{Match, MatchPattern};
+use crate::core::model::types::mutability::Mutability;
+use crate::core::model::types::ty::Type;
+use crate::core::parser::static_type_context::StaticTypeContext;
+use crate::core::parser::types::r#type::InferTypeError;
+use crate::core::semantics::static_type_check::static_type_checker::{static_type_check_rec, StaticTypeCheckError};
+use crate::core::semantics::static_type_check::StaticTypeCheck;
+
+impl StaticTypeCheck for Match {
+    fn static_type_check(&self, type_context: &mut StaticTypeContext) -> Result<(), StaticTypeCheckError> {
+        let mut value_type = self.value.get_type(type_context).ok_or(
+            StaticTypeCheckError::InferredError(Box::new(InferTypeError::NoTypePresent(
+                LValue::Identifier(Identifier { name: "match value".to_string(), symbol: None }),
+                self.file_position.clone(),
+            )))
+        )?;
+        value_type.set_mutability(Mutability::Immutable);
+
+        let is_enum = type_context.enum_definition(&value_type).is_some();
+        if !matches!(value_type, Type::Integer(_, _) | Type::Bool(_)) && !is_enum {
+            return Err(StaticTypeCheckError::InferredError(Box::new(InferTypeError::IllegalType(value_type.to_string(), self.file_position.clone()))));
+        }
+
+        for arm in &self.arms {
+            for pattern in &arm.patterns {
+                match pattern {
+                    MatchPattern::Literal(literal) => check_pattern_literal(literal, &value_type, &arm.file_position, type_context)?,
+                    MatchPattern::Range { start, end, .. } => {
+                        if !matches!(value_type, Type::Integer(_, _)) {
+                            return Err(StaticTypeCheckError::InferredError(Box::new(InferTypeError::IllegalType(pattern.to_string(), arm.file_position.clone()))));
+                        }
+
+                        check_pattern_literal(start, &value_type, &arm.file_position, type_context)?;
+                        check_pattern_literal(end, &value_type, &arm.file_position, type_context)?;
+
+                        if pattern.interval(type_context).is_some_and(|(start, end)| start > end) {
+                            return Err(StaticTypeCheckError::EmptyRange { pattern: pattern.to_string(), file_position: arm.file_position.clone() });
+                        }
+                    }
+                    MatchPattern::Wildcard => {}
+                }
+            }
+
+            static_type_check_rec(&arm.stack, type_context)?;
+        }
+
+        if let Some((arm, pattern)) = self.unreachable_pattern(&value_type, type_context) {
+            return Err(StaticTypeCheckError::UnreachablePattern { pattern: pattern.to_string(), file_position: arm.file_position.clone() });
+        }
+
+        if !self.is_exhaustive(&value_type, type_context) {
+            return Err(StaticTypeCheckError::NonExhaustiveMatch { ty: value_type, file_position: self.file_position.clone() });
+        }
+
+        Ok(())
+    }
+}
+
+/// integer literals take the type of the matched value, as long as they fit into it
+fn check_pattern_literal(literal: &Assignable, value_type: &Type, file_position: &FilePosition, type_context: &StaticTypeContext) -> Result<(), Box<InferTypeError>> {
+    if let (Assignable::Integer(integer), Type::Integer(integer_type, _)) = (literal, value_type) {
+        return if integer_type.fits(&integer.value) {
+            Ok(())
+        } else {
+            Err(Box::new(InferTypeError::IntegerTooSmall {
+                ty: value_type.clone(),
+                literal: integer.value.to_string(),
+                file_position: file_position.clone(),
+            }))
+        };
+    }
+
+    let mut literal_type = literal.get_type(type_context).ok_or(
+        Box::new(InferTypeError::UnresolvedReference(literal.to_string(), file_position.clone()))
+    )?;
+    literal_type.set_mutability(Mutability::Immutable);
+
+    if literal_type != *value_type {
+        return Err(Box::new(InferTypeError::MismatchedTypes {
+            expected: value_type.clone(),
+            actual: literal_type,
+            file_position: file_position.clone(),
+        }));
+    }
+
+    Ok(())
+}
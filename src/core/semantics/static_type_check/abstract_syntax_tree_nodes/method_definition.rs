@@ -1,31 +1,43 @@
 use crate::core::lexer::token_with_span::FilePosition;
-use crate::core::model::abstract_syntax_tree_node::AbstractSyntaxTreeNode;
 use crate::core::model::abstract_syntax_tree_nodes::assignable::Assignable;
-use crate::core::model::abstract_syntax_tree_nodes::identifier::Identifier;
-use crate::core::model::abstract_syntax_tree_nodes::l_value::LValue;
 use crate::core::model::abstract_syntax_tree_nodes::method_definition::MethodDefinition;
 use crate::core::model::abstract_syntax_tree_nodes::variable::Variable;
-use crate::core::model::types::ty::Type;
 use crate::core::parser::static_type_context::{CurrentMethodInfo, StaticTypeContext};
-use crate::core::parser::types::r#type::{InferTypeError, MethodCallSignatureMismatchCause};
+use crate::core::parser::types::r#type::InferTypeError;
 use crate::core::semantics::static_type_check::static_type_checker::{static_type_check_rec, StaticTypeCheckError};
 use crate::core::semantics::static_type_check::StaticTypeCheck;
 
 impl StaticTypeCheck for MethodDefinition {
     fn static_type_check(&self, type_context: &mut StaticTypeContext) -> Result<(), StaticTypeCheckError> {
+        // structs follow the calling convention of monkey methods only, c functions expect them to be classified by their fields
+        if self.is_extern {
+            let by_value = self.arguments.iter().map(|a| &a.ty)
+                .chain(std::iter::once(&self.return_type))
+                .find(|ty| type_context.struct_definition(ty).is_some());
+
+            if let Some(ty) = by_value {
+                return Err(StaticTypeCheckError::StructByValueInExternMethod {
+                    method_name: self.identifier.identifier(),
+                    ty: ty.clone(),
+                    file_position: self.file_position.clone(),
+                });
+            }
+        }
+
         // add the parameters to the type information
+        type_context.push_scope();
         for argument in &self.arguments {
-            type_context.context.push(Variable {
-                l_value: LValue::Identifier(Identifier { name: argument.identifier.identifier() }),
+            type_context.declare(Variable {
+                l_value: argument.identifier.clone(),
                 mutability: argument.ty.mutable(),
                 ty: Some(argument.ty.clone()),
                 define: true,
+                compound_operator: None,
                 assignable: Assignable::default(),
                 file_position: FilePosition::default(),
             });
         }
 
-        let variables_len = type_context.context.len();
         type_context.expected_return_type = Some(CurrentMethodInfo {
             return_type: self.return_type.clone(),
             method_header_line: self.file_position.clone(),
@@ -35,38 +47,18 @@ impl StaticTypeCheck for MethodDefinition {
 
         static_type_check_rec(&self.stack, type_context)?;
 
-        if self.return_type != Type::Void {
-            if let [.., last] = &self.stack[..] {
-                let mut method_return_signature_mismatch = false;
-                let mut cause = MethodCallSignatureMismatchCause::ReturnMismatch;
-
-                if let AbstractSyntaxTreeNode::If(if_definition) = &last {
-                    method_return_signature_mismatch = !if_definition.ends_with_return_in_each_branch();
-                    if method_return_signature_mismatch {
-                        cause = MethodCallSignatureMismatchCause::IfCondition;
-                    }
-                }
-                
-                if method_return_signature_mismatch {
-                    if let Some(expected_return_type) = &type_context.expected_return_type {
-                        return Err(StaticTypeCheckError::InferredError(Box::new(InferTypeError::MethodReturnSignatureMismatch {
-                            expected: expected_return_type.return_type.clone(),
-                            method_name: expected_return_type.method_name.to_string(),
-                            file_position: expected_return_type.method_header_line.clone(),
-                            cause,
-                        })));
-                    }
-                }
-            }
+        // every path through the method must return a value
+        if let Some(cause) = self.missing_return() {
+            return Err(StaticTypeCheckError::InferredError(Box::new(InferTypeError::MethodReturnSignatureMismatch {
+                expected: self.return_type.clone(),
+                method_name: self.identifier.identifier(),
+                file_position: self.file_position.clone(),
+                cause,
+            })));
         }
 
 
-        let amount_pop = (type_context.context.len() - variables_len) + self.arguments.len();
-
-        for _ in 0..amount_pop {
-            let _ = type_context.context.pop();
-        }
-
+        let _ = type_context.pop_scope();
         type_context.expected_return_type = None;
         Ok(())
     }
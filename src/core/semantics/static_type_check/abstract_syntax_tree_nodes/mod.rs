@@ -4,7 +4,14 @@ mod method_definition;
 mod import;
 mod return_;
 mod if_;
+mod match_;
 mod for_;
 mod while_;
 mod struct_;
-mod assignable;
\ No newline at end of file
+mod enum_;
+mod assignable;
+mod syntax_error;
+mod break_;
+mod continue_;
+
+mod impl_;
\ No newline at end of file
@@ -11,7 +11,7 @@ impl StaticTypeCheck for Return {
         if let Some(expected_return_type) = &type_context.expected_return_type {
             if let Some(assignable) = &self.assignable {
                 let actual_type = assignable.get_type(type_context).ok_or(StaticTypeCheckError::NoTypePresent {
-                    name: LValue::Identifier(Identifier { name: "return".to_string() }),
+                    name: LValue::Identifier(Identifier { name: "return".to_string(), symbol: None }),
                     file_position: self.file_position.clone(),
                 })?;
 
@@ -0,0 +1,10 @@
+use crate::core::model::abstract_syntax_tree_nodes::syntax_error::SyntaxError;
+use crate::core::parser::static_type_context::StaticTypeContext;
+use crate::core::semantics::static_type_check::static_type_checker::StaticTypeCheckError;
+use crate::core::semantics::static_type_check::StaticTypeCheck;
+
+impl StaticTypeCheck for SyntaxError {
+    fn static_type_check(&self, _type_context: &mut StaticTypeContext) -> Result<(), StaticTypeCheckError> {
+        Ok(())
+    }
+}
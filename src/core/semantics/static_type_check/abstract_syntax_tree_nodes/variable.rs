@@ -1,3 +1,4 @@
+use crate::core::model::abstract_syntax_tree_nodes::assignable::Assignable;
 use crate::core::model::abstract_syntax_tree_nodes::identifier::Identifier;
 use crate::core::model::abstract_syntax_tree_nodes::l_value::LValue;
 use crate::core::model::abstract_syntax_tree_nodes::variable::Variable;
@@ -9,6 +10,12 @@ use crate::core::semantics::static_type_check::StaticTypeCheck;
 
 impl StaticTypeCheck for Variable<'=', ';'> {
     fn static_type_check(&self, type_context: &mut StaticTypeContext) -> Result<(), StaticTypeCheckError> {
+        // the value of `let mut a: i32;` is assigned later
+        if self.define && self.assignable == Assignable::Uninitialized {
+            type_context.declare(self.clone());
+            return Ok(());
+        }
+
         if self.define {
             self.assignable.static_type_check(type_context).map_err(self.map_inner_static_type_check_error())?;
             let ty = self.assignable.get_type(type_context);
@@ -19,23 +26,29 @@ impl StaticTypeCheck for Variable<'=', ';'> {
 
 
             if self.ty.is_some() {
-                type_context.context.push(self.clone());
+                type_context.declare(self.clone());
                 return Ok(());
             }
         }
 
         if !self.define {
-            if let Some(found_variable) = type_context.iter().rfind(|v| v.l_value.identifier() == self.l_value.identifier()) {
-                let inferred_type = self.assignable.get_type(type_context).ok_or(StaticTypeCheckError::NoTypePresent {
+            if let Some(found_variable) = type_context.resolve_l_value(&self.l_value) {
+                let inferred_type = match self.compound_expression() {
+                    Some(expression) => expression.get_type(type_context),
+                    None => self.assignable.get_type(type_context),
+                }.ok_or(StaticTypeCheckError::NoTypePresent {
                     name: self.l_value.clone(),
                     file_position: self.file_position.clone(),
                 })?;
-                if let Some(ty) = &found_variable.ty {
-                    if ty > &inferred_type {
+                if let Some(ty) = &self.l_value_type(type_context) {
+                    if !ty.eq_ignoring_mutability(&inferred_type) {
                         return Err(Box::new(InferTypeError::MismatchedTypes { expected: ty.clone(), actual: inferred_type.clone(), file_position: self.file_position.clone() }).into());
                     }
 
-                    if !found_variable.mutability {
+                    // an immutable variable declared without a value is assigned once. The definite assignment check rejects further assignments
+                    let deferred_initialization = found_variable.assignable == Assignable::Uninitialized && matches!(self.l_value, LValue::Identifier(_));
+
+                    if !found_variable.mutability && !deferred_initialization {
                         return Err(StaticTypeCheckError::ImmutabilityViolated {
                             name: self.l_value.clone(),
                             file_position: self.file_position.clone(),
@@ -63,7 +76,7 @@ impl Variable<'=', ';'> {
                 StaticTypeCheckError::InferredError(infer_error) => {
                     StaticTypeCheckError::InferredError(Box::new(match *infer_error {
                         InferTypeError::NoTypePresent(..) => InferTypeError::NoTypePresent(
-                            LValue::Identifier(Identifier { name: identifier }),
+                            LValue::Identifier(Identifier { name: identifier, symbol: None }),
                             self_file_position,
                         ),
                         InferTypeError::MismatchedTypes { expected, actual, file_position: _} => InferTypeError::MismatchedTypes {
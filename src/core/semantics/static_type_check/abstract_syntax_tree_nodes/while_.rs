@@ -5,15 +5,14 @@ use crate::core::model::types::mutability::Mutability;
 use crate::core::model::types::ty::Type;
 use crate::core::parser::static_type_context::StaticTypeContext;
 use crate::core::parser::types::r#type::InferTypeError;
-use crate::core::semantics::static_type_check::static_type_checker::{static_type_check_rec, StaticTypeCheckError};
+use crate::core::semantics::static_type_check::static_type_checker::{static_type_check_loop_body, StaticTypeCheckError};
 use crate::core::semantics::static_type_check::StaticTypeCheck;
 
 impl StaticTypeCheck for While {
     fn static_type_check(&self, type_context: &mut StaticTypeContext) -> Result<(), StaticTypeCheckError> {
-        let variables_len = type_context.context.len();
         let condition_type = self.condition.get_type(type_context).ok_or(
             StaticTypeCheckError::InferredError(Box::new(InferTypeError::NoTypePresent(
-                LValue::Identifier(Identifier { name: String::from("while condition") }),
+                LValue::Identifier(Identifier { name: String::from("while condition"), symbol: None }),
                 self.file_position.clone(),
             )))
         )?;
@@ -26,13 +25,7 @@ impl StaticTypeCheck for While {
             })));
         }
 
-        static_type_check_rec(&self.stack, type_context)?;
-
-        let amount_pop = type_context.context.len() - variables_len;
-
-        for _ in 0..amount_pop {
-            let _ = type_context.context.pop();
-        }
+        static_type_check_loop_body(&self.label, &self.stack, type_context)?;
 
         Ok(())
     }
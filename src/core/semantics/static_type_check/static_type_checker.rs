@@ -5,7 +5,7 @@ use crate::core::model::abstract_syntax_tree_nodes::assignable::Assignable;
 use crate::core::model::abstract_syntax_tree_nodes::l_value::LValue;
 use crate::core::model::types::ty::Type;
 use crate::core::parser::static_type_context::StaticTypeContext;
-use crate::core::parser::types::r#type::{InferTypeError, MethodCallSignatureMismatchCause};
+use crate::core::parser::types::r#type::InferTypeError;
 use crate::core::semantics::static_type_check::StaticTypeCheck;
 
 #[derive(Debug)]
@@ -16,6 +16,16 @@ pub enum StaticTypeCheckError {
     NoTypePresent { name: LValue, file_position: FilePosition },
     VoidType { assignable: Assignable, file_position: FilePosition },
     ImmutabilityViolated { name: LValue, file_position: FilePosition },
+    LoopControlOutsideOfLoop { keyword: String, file_position: FilePosition },
+    UndeclaredLoopLabel { label: String, file_position: FilePosition },
+    NonExhaustiveMatch { ty: Type, file_position: FilePosition },
+    NestedImpl { ty: Type, file_position: FilePosition },
+    PossiblyUninitialized { name: String, file_position: FilePosition },
+    UsedBeforeDeclaration { name: String, file_position: FilePosition },
+    StructByValueInExternMethod { method_name: String, ty: Type, file_position: FilePosition },
+    DuplicateDiscriminant { variant: String, other_variant: String, discriminant: i64, file_position: FilePosition },
+    EmptyRange { pattern: String, file_position: FilePosition },
+    UnreachablePattern { pattern: String, file_position: FilePosition },
     InferredError(Box<InferTypeError>),
 }
 
@@ -30,10 +40,43 @@ impl Display for StaticTypeCheckError {
             StaticTypeCheckError::ImmutabilityViolated { name, file_position } => format!("Line: {}\tThis symbol isn't declared mutable: `{name}`", file_position),
             StaticTypeCheckError::VoidType { assignable, file_position } => format!("Line: {}\tCannot assign void to a variable: `{assignable}`", file_position),
             StaticTypeCheckError::TypeDefinitionMissing { expected_type, file_position } => format!("Line: {}\tType definition missing for type: `{expected_type}`", file_position),
+            StaticTypeCheckError::LoopControlOutsideOfLoop { keyword, file_position } => format!("Line: {}\t`{keyword}` is only allowed inside of a loop", file_position),
+            StaticTypeCheckError::UndeclaredLoopLabel { label, file_position } => format!("Line: {}\tUse of undeclared loop label: `'{label}`", file_position),
+            StaticTypeCheckError::NonExhaustiveMatch { ty, file_position } => format!("Line: {}\tNon-exhaustive match over `{ty}`: add a `_` arm for the remaining values", file_position),
+            StaticTypeCheckError::NestedImpl { ty, file_position } => format!("Line: {}\tThe methods of `{ty}` must be implemented at the top level", file_position),
+            StaticTypeCheckError::PossiblyUninitialized { name, file_position } => format!("Line: {}\tThe variable `{name}` is possibly uninitialized", file_position),
+            StaticTypeCheckError::UsedBeforeDeclaration { name, file_position } => format!("Line: {}\tThe variable `{name}` is used before its declaration", file_position),
+            StaticTypeCheckError::StructByValueInExternMethod { method_name, ty, file_position } => format!("Line: {}\tThe extern method `{method_name}` can't pass the struct `{ty}` by value, because structs aren't passed like the System V and Windows x64 calling conventions require. Use a pointer instead", file_position),
+            StaticTypeCheckError::DuplicateDiscriminant { variant, other_variant, discriminant, file_position } => format!("Line: {}\tThe variant `{variant}` has the same discriminant `{discriminant}` as `{other_variant}`", file_position),
+            StaticTypeCheckError::EmptyRange { pattern, file_position } => format!("Line: {}\tThe range `{pattern}` doesn't contain any value", file_position),
+            StaticTypeCheckError::UnreachablePattern { pattern, file_position } => format!("Line: {}\tThe pattern `{pattern}` is already matched by a previous arm", file_position),
         })
     }
 }
 
+impl StaticTypeCheckError {
+    pub fn file_position(&self) -> &FilePosition {
+        match self {
+            StaticTypeCheckError::UnresolvedReference { file_position, .. } |
+            StaticTypeCheckError::TypeDefinitionMissing { file_position, .. } |
+            StaticTypeCheckError::NoTypePresent { file_position, .. } |
+            StaticTypeCheckError::VoidType { file_position, .. } |
+            StaticTypeCheckError::ImmutabilityViolated { file_position, .. } |
+            StaticTypeCheckError::LoopControlOutsideOfLoop { file_position, .. } |
+            StaticTypeCheckError::UndeclaredLoopLabel { file_position, .. } |
+            StaticTypeCheckError::NonExhaustiveMatch { file_position, .. } |
+            StaticTypeCheckError::NestedImpl { file_position, .. } |
+            StaticTypeCheckError::PossiblyUninitialized { file_position, .. } |
+            StaticTypeCheckError::UsedBeforeDeclaration { file_position, .. } |
+            StaticTypeCheckError::StructByValueInExternMethod { file_position, .. } |
+            StaticTypeCheckError::DuplicateDiscriminant { file_position, .. } |
+            StaticTypeCheckError::EmptyRange { file_position, .. } |
+            StaticTypeCheckError::UnreachablePattern { file_position, .. } => file_position,
+            StaticTypeCheckError::InferredError(error) => error.file_position(),
+        }
+    }
+}
+
 impl From<Box<InferTypeError>> for StaticTypeCheckError {
     fn from(value: Box<InferTypeError>) -> Self {
         StaticTypeCheckError::InferredError(value)
@@ -50,27 +93,45 @@ pub fn static_type_check(scope: &Vec<AbstractSyntaxTreeNode>) -> Result<StaticTy
     let mut type_context: StaticTypeContext = StaticTypeContext::new(scope);
     type_context.colliding_symbols()?;
     static_type_check_rec(scope, &mut type_context)?;
-    
+
     Ok(type_context)
 }
 
 #[allow(clippy::result_large_err)]
 pub fn static_type_check_rec(scope: &Vec<AbstractSyntaxTreeNode>, type_context: &mut StaticTypeContext) -> Result<(), StaticTypeCheckError> {
-    if let Some(ty) = &type_context.expected_return_type {
-        if scope.is_empty() && ty.return_type != Type::Void {
-            return Err(StaticTypeCheckError::InferredError(Box::new(InferTypeError::MethodReturnSignatureMismatch {
-                expected: ty.return_type.clone(),
-                method_name: ty.method_name.to_string(),
-                file_position: ty.method_header_line.clone(),
-                cause: MethodCallSignatureMismatchCause::ReturnMismatch,
-            })));
-        }
-    }
+    type_context.push_scope();
 
     for node in scope {
         node.static_type_check(type_context)?;
     }
 
+    let _ = type_context.pop_scope();
+
+    Ok(())
+}
+
+/// Checks the scope of a loop body. `break` and `continue` inside the body refer to the loop with the provided label
+#[allow(clippy::result_large_err)]
+pub fn static_type_check_loop_body(label: &Option<String>, scope: &Vec<AbstractSyntaxTreeNode>, type_context: &mut StaticTypeContext) -> Result<(), StaticTypeCheckError> {
+    type_context.loops.push(label.clone());
+    let result = static_type_check_rec(scope, type_context);
+    let _ = type_context.loops.pop();
+
+    result
+}
+
+/// `break` and `continue` need a surrounding loop. A label must belong to one of the surrounding loops
+#[allow(clippy::result_large_err)]
+pub fn static_type_check_loop_control(keyword: &str, label: &Option<String>, file_position: &FilePosition, type_context: &StaticTypeContext) -> Result<(), StaticTypeCheckError> {
+    if type_context.loops.is_empty() {
+        return Err(StaticTypeCheckError::LoopControlOutsideOfLoop { keyword: keyword.to_string(), file_position: file_position.clone() });
+    }
+
+    if let Some(label) = label {
+        if !type_context.loops.iter().any(|loop_label| loop_label.as_ref() == Some(label)) {
+            return Err(StaticTypeCheckError::UndeclaredLoopLabel { label: label.to_string(), file_position: file_position.clone() });
+        }
+    }
 
     Ok(())
 }
\ No newline at end of file
@@ -9,11 +9,20 @@ impl InferType for AbstractSyntaxTreeNode {
         let ty = match self {
             AbstractSyntaxTreeNode::Variable(variable) => variable.infer_type(type_context)?,
             AbstractSyntaxTreeNode::If(if_definition) => if_definition.infer_type(type_context)?,
+            AbstractSyntaxTreeNode::Match(match_definition) => match_definition.infer_type(type_context)?,
             AbstractSyntaxTreeNode::For(for_loop) => for_loop.infer_type(type_context)?,
             AbstractSyntaxTreeNode::While(while_loop) => while_loop.infer_type(type_context)?,
             AbstractSyntaxTreeNode::MethodCall(method_call) => method_call.infer_type(type_context)?, 
             AbstractSyntaxTreeNode::MethodDefinition(method_definition) => method_definition.infer_type(type_context)?,
-            AbstractSyntaxTreeNode::StructDefinition(_) | AbstractSyntaxTreeNode::Import(_) | AbstractSyntaxTreeNode::Return(_) => Type::Statement,
+            AbstractSyntaxTreeNode::Return(return_statement) => {
+                if let Some(assignable) = &mut return_statement.assignable {
+                    assignable.infer_type(type_context)?;
+                }
+
+                Type::Statement
+            }
+            AbstractSyntaxTreeNode::StructDefinition(_) | AbstractSyntaxTreeNode::EnumDefinition(_) | AbstractSyntaxTreeNode::Impl(_) | AbstractSyntaxTreeNode::Import(_) | AbstractSyntaxTreeNode::SyntaxError(_) |
+            AbstractSyntaxTreeNode::Break(_) | AbstractSyntaxTreeNode::Continue(_) => Type::Statement,
         };
 
         Ok(ty)
@@ -11,20 +11,37 @@ impl InferType for Assignable {
     fn infer_type(&mut self, type_context: &mut StaticTypeContext) -> Result<Type, Box<InferTypeError>> {
         match self {
             Assignable::String(_) => Ok(r#type::common::string()),
-            Assignable::Integer(a) => Ok(Type::Integer(a.ty.clone(), Mutability::Immutable)),
+            Assignable::Integer(a) => {
+                let ty = Type::Integer(a.ty.clone(), Mutability::Immutable);
+                if !a.ty.fits(&a.value) {
+                    return Err(Box::new(InferTypeError::IntegerTooSmall { ty, literal: a.value.clone(), file_position: type_context.current_file_position.clone() }));
+                }
+
+                Ok(ty)
+            },
             Assignable::Array(array) => Ok(array.infer_type(type_context)?),
-            Assignable::Float(a) => Ok(Type::Float(a.ty.clone(), Mutability::Immutable)),
+            Assignable::Float(a) => {
+                let ty = Type::Float(a.ty.clone(), Mutability::Immutable);
+                if !a.ty.fits(a.value) {
+                    return Err(Box::new(InferTypeError::FloatTooSmall { ty, float: a.value, file_position: type_context.current_file_position.clone() }));
+                }
+
+                Ok(ty)
+            },
             Assignable::Boolean(_) => Ok(Type::Bool(Mutability::Immutable)),
             Assignable::Object(object) => {
                 for field in &mut object.fields {
                     field.infer_type(type_context)?;
                 }
-                Ok(Type::Custom(Identifier { name: object.ty.to_string() }, Mutability::Immutable))
+                Ok(Type::Custom(Identifier { name: object.ty.to_string(), symbol: None }, Mutability::Immutable))
             },
+            Assignable::EnumPath(enum_path) => Ok(enum_path.infer_type(type_context)?),
             Assignable::Expression(expression) => {Ok(expression.infer_type(type_context)?)}
             Assignable::MethodCall(method_call) => { Ok(method_call.infer_type(type_context)?) }
+            Assignable::FieldAccess(field_access) => Ok(field_access.infer_type(type_context)?),
             Assignable::Identifier(var) => Ok(var.infer_type(type_context)?),
             Assignable::Parameter(r) => Ok(r.ty.clone()),
+            Assignable::Uninitialized => Ok(Type::Void),
         }
     }
 }
@@ -37,11 +54,14 @@ impl Assignable {
             Assignable::Float(node) => Some(Type::Float(node.ty.clone(), Mutability::Immutable)),
             Assignable::Boolean(_) => Some(Type::Bool(Mutability::Immutable)),
             Assignable::Object(node) => Some(node.ty.clone()),
+            Assignable::EnumPath(node) => node.get_type(type_context),
             Assignable::Array(node) => node.values[0].get_type(type_context),
             Assignable::Expression(node) => node.get_type(type_context),
             Assignable::MethodCall(node) => node.get_type(type_context),
+            Assignable::FieldAccess(node) => node.get_type(type_context),
             Assignable::Identifier(identifier) => identifier.get_type(type_context),
             Assignable::Parameter(param) => Some(param.ty.clone()),
+            Assignable::Uninitialized => Some(Type::Void),
         }
     }
 }
\ No newline at end of file
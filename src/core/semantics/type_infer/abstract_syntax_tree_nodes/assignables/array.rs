@@ -12,13 +12,13 @@ impl InferType for Array {
         let file_position = type_context.current_file_position.clone();
 
         if self.values.is_empty() {
-            return Err(Box::new(InferTypeError::NoTypePresent(LValue::Identifier(Identifier { name: "Array".to_string() }), file_position.clone())))
+            return Err(Box::new(InferTypeError::NoTypePresent(LValue::Identifier(Identifier { name: "Array".to_string(), symbol: None }), file_position.clone())))
         }
 
         if let Ok(ty) = self.values[0].infer_type(type_context) {
             return Ok(Type::Array(Box::new(ty), self.values.len(), Mutability::Immutable));
         }
 
-        Err(Box::new(InferTypeError::NoTypePresent(LValue::Identifier(Identifier { name: "Array".to_string() }), file_position)))
+        Err(Box::new(InferTypeError::NoTypePresent(LValue::Identifier(Identifier { name: "Array".to_string(), symbol: None }), file_position)))
     }
 }
\ No newline at end of file
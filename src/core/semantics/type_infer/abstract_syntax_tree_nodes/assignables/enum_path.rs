@@ -0,0 +1,25 @@
+use crate::core::model::abstract_syntax_tree_nodes::assignables::enum_path::EnumPath;
+use crate::core::model::types::mutability::Mutability;
+use crate::core::model::types::ty::Type;
+use crate::core::parser::static_type_context::StaticTypeContext;
+use crate::core::parser::types::r#type::InferTypeError;
+use crate::core::semantics::type_infer::infer_type::InferType;
+
+impl InferType for EnumPath {
+    fn infer_type(&mut self, type_context: &mut StaticTypeContext) -> Result<Type, Box<InferTypeError>> {
+        self.get_type(type_context)
+            .ok_or(Box::new(InferTypeError::UnresolvedReference(self.to_string(), type_context.current_file_position.clone())))
+    }
+}
+
+impl EnumPath {
+    /// the type of the enum, if the enum and the variant exist
+    pub fn get_type(&self, type_context: &StaticTypeContext) -> Option<Type> {
+        let enum_definition = type_context.enum_definition(&self.ty)?;
+        enum_definition.discriminant(&self.variant.name)?;
+
+        let mut ty = enum_definition.ty.clone();
+        ty.set_mutability(Mutability::Immutable);
+        Some(ty)
+    }
+}
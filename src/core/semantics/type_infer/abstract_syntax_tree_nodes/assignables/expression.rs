@@ -68,12 +68,14 @@ impl InferType for Expression {
                         PrefixArithmetic::Cast(casting_to) => {
                             value_type = Type::from_str(&casting_to.to_string(), Mutability::Immutable)?;
                         }
-                        PrefixArithmetic::Operation(_) => {}
+                        PrefixArithmetic::Operation(operator) => {
+                            value_type = Self::infer_type_after_unary_operation(*operator, value_type, type_context)?;
+                        }
                     }
                 }
 
                 if value_type.is_pointer() {
-                    Ok(Type::Custom(Identifier { name: format!("{}", value_type) }, Mutability::from(value_type.mutable())))
+                    Ok(Type::Custom(Identifier { name: format!("{}", value_type), symbol: None }, Mutability::from(value_type.mutable())))
                 } else {
                     Ok(value_type)
                 }
@@ -95,10 +97,36 @@ impl Expression {
         self_cloned.infer_type(&mut type_context_cloned).ok()
     }
 
+    /// true, if the value is cast to the type it already has, like `(i32) 5`
+    pub fn is_redundant_cast(&self, type_context: &StaticTypeContext) -> bool {
+        let Some(PrefixArithmetic::Cast(ty)) = &self.prefix_arithmetic else {
+            return false;
+        };
+
+        let value = Expression { prefix_arithmetic: None, ..self.clone() };
+        value.get_type(type_context).is_some_and(|value_type| value_type.to_string() == ty.to_string())
+    }
+
     pub fn traverse_type(&mut self, meta: &mut MetaInfo) -> Option<Type> {
         self.infer_type(&mut meta.static_type_information).ok()
     }
 
+    fn infer_type_after_unary_operation(operator: Operator, value_type: Type, context: &StaticTypeContext) -> Result<Type, Box<InferTypeError>> {
+        let allowed = match operator {
+            Operator::Sub => matches!(&value_type, Type::Integer(integer, _) if integer.signed()) || matches!(value_type, Type::Float(_, _)),
+            Operator::LogicalNot => matches!(value_type, Type::Bool(_)),
+            Operator::BitwiseNot => matches!(value_type, Type::Integer(_, _)),
+            Operator::Noop => true,
+            _ => false,
+        };
+
+        if !allowed {
+            return Err(Box::new(InferTypeError::IllegalUnaryOperation(operator, value_type, context.current_file_position.clone())));
+        }
+
+        Ok(value_type)
+    }
+
     fn infer_type_after_operation(error_message: String, lhs: &mut Option<Box<Expression>>, operator: Operator, rhs: &mut Option<Box<Expression>>, context: &mut StaticTypeContext) -> Result<Type, Box<InferTypeError>> {
             if let Some(lhs) = lhs.as_mut() {
                 if let Some(rhs) = rhs.as_mut() {
@@ -106,12 +134,16 @@ impl Expression {
                     let rhs_type = rhs.infer_type(context)?;
 
                     let mut base_type_matrix: HashMap<(Type, Operator, Type), Type> = HashMap::new();
-                    base_type_matrix.insert((Type::Custom(Identifier { name: "string".to_string() }, Mutability::Immutable), Operator::Add, Type::Custom(Identifier { name: "string".to_string() }, Mutability::Immutable)), Type::Custom(Identifier { name: "*string".to_string() }, Mutability::Immutable));
+                    base_type_matrix.insert((Type::Custom(Identifier { name: "string".to_string(), symbol: None }, Mutability::Immutable), Operator::Add, Type::Custom(Identifier { name: "string".to_string(), symbol: None }, Mutability::Immutable)), Type::Custom(Identifier { name: "*string".to_string(), symbol: None }, Mutability::Immutable));
 
                     IntegerType::operation_matrix(&mut base_type_matrix);
                     FloatType::operation_matrix(&mut base_type_matrix);
                     Boolean::operation_matrix(&mut base_type_matrix);
 
+                    for enum_definition in context.custom_defined_enums.values() {
+                        enum_definition.operation_matrix(&mut base_type_matrix);
+                    }
+
                     // I do not care, if the expression is mutable or not. The type is the relevant factor
                     let mut lhs_clone = lhs_type.clone();
                     lhs_clone.set_mutability(Mutability::Immutable);
@@ -136,7 +168,7 @@ impl Expression {
     //             let rhs_type = rhs.infer_type(context)?;
     //
     //             let mut base_type_matrix: HashMap<(Type, Operator, Type), Type> = HashMap::new();
-    //             base_type_matrix.insert((Type::Custom(Identifier { name: "string".to_string() }, Mutability::Immutable), Operator::Add, Type::Custom(Identifier { name: "string".to_string() }, Mutability::Immutable)), Type::Custom(Identifier { name: "*string".to_string() }, Mutability::Immutable));
+    //             base_type_matrix.insert((Type::Custom(Identifier { name: "string".to_string(), symbol: None }, Mutability::Immutable), Operator::Add, Type::Custom(Identifier { name: "string".to_string(), symbol: None }, Mutability::Immutable)), Type::Custom(Identifier { name: "*string".to_string(), symbol: None }, Mutability::Immutable));
     //
     //             IntegerType::operation_matrix(&mut base_type_matrix);
     //             FloatType::operation_matrix(&mut base_type_matrix);
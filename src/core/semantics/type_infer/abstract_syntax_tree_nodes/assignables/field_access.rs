@@ -0,0 +1,34 @@
+use crate::core::model::abstract_syntax_tree_nodes::assignables::field_access::FieldAccess;
+use crate::core::model::abstract_syntax_tree_nodes::struct_::Field;
+use crate::core::model::types::ty::Type;
+use crate::core::parser::static_type_context::StaticTypeContext;
+use crate::core::parser::types::r#type::InferTypeError;
+use crate::core::semantics::type_infer::infer_type::InferType;
+
+impl InferType for FieldAccess {
+    fn infer_type(&mut self, type_context: &mut StaticTypeContext) -> Result<Type, Box<InferTypeError>> {
+        let value_type = self.value.infer_type(type_context)?;
+
+        self.field_definition(&value_type, type_context)
+            .map(|field| field.ty.clone())
+            .ok_or(Box::new(InferTypeError::UnknownField { ty: value_type, field: self.field.name.clone(), file_position: type_context.current_file_position.clone() }))
+    }
+}
+
+impl FieldAccess {
+    /// the type of the field, if the value is a struct or a pointer to a struct containing the field
+    pub fn get_type(&self, type_context: &StaticTypeContext) -> Option<Type> {
+        let value_type = self.value.get_type(type_context)?;
+
+        self.field_definition(&value_type, type_context).map(|field| field.ty.clone())
+    }
+
+    fn field_definition<'a>(&self, value_type: &Type, type_context: &'a StaticTypeContext) -> Option<&'a Field> {
+        let struct_type = value_type.pop_pointer().unwrap_or(value_type.clone());
+
+        type_context.struct_definition(&struct_type)?
+            .fields
+            .iter()
+            .find(|field| field.name == self.field)
+    }
+}
@@ -1,5 +1,11 @@
 use crate::core::code_generator::conventions;
+use crate::core::model::abstract_syntax_tree_nodes::assignable::Assignable;
+use crate::core::model::abstract_syntax_tree_nodes::assignables::equation_parser::expression::Expression;
+use crate::core::model::abstract_syntax_tree_nodes::assignables::equation_parser::prefix_arithmetic::{PointerArithmetic, PrefixArithmetic};
 use crate::core::model::abstract_syntax_tree_nodes::assignables::method_call::MethodCall;
+use crate::core::model::abstract_syntax_tree_nodes::identifier::Identifier;
+use crate::core::model::abstract_syntax_tree_nodes::impl_::Impl;
+use crate::core::model::abstract_syntax_tree_nodes::l_value::LValue;
 use crate::core::model::types::ty::Type;
 use crate::core::parser::static_type_context::StaticTypeContext;
 use crate::core::parser::types::r#type::InferTypeError;
@@ -7,20 +13,102 @@ use crate::core::semantics::type_infer::infer_type::InferType;
 
 impl InferType for MethodCall {
     fn infer_type(&mut self, type_context: &mut StaticTypeContext) -> Result<Type, Box<InferTypeError>> {
-        if let Some(method_def) = conventions::method_definitions(type_context, &self.arguments, &self.identifier.identifier())?.first() {
-            return Ok(method_def.return_type.clone());
+        for argument in &mut self.arguments {
+            argument.infer_type(type_context)?;
         }
 
-        Err(Box::new(InferTypeError::UnresolvedReference(self.to_string(), type_context.current_file_position.clone())))
+        self.resolve_receiver(type_context)?;
+
+        if self.type_arguments.is_empty() {
+            if let Some(method_def) = conventions::method_definitions(type_context, &self.arguments, &self.identifier.identifier())?.first() {
+                return Ok(method_def.return_type.clone());
+            }
+        }
+
+        if let Some(return_type) = self.monomorphize(type_context)? {
+            return Ok(return_type);
+        }
+
+        Err(self.signature_mismatch(type_context))
     }
 }
 
 impl MethodCall {
     pub fn get_type(&self, type_context: &StaticTypeContext) -> Option<Type> {
-        if let Some(method_def) = conventions::method_definitions(type_context, &self.arguments, &self.identifier.identifier()).ok()?.first() {
-            return Some(method_def.return_type.clone());
+        if self.receiver.is_some() {
+            let mut method_call = self.clone();
+            method_call.resolve_receiver(&mut type_context.clone()).ok()?;
+
+            return method_call.get_type(type_context);
         }
 
-        None
+        if self.type_arguments.is_empty() {
+            if let Some(method_def) = conventions::method_definitions(type_context, &self.arguments, &self.identifier.identifier()).ok()?.first() {
+                return Some(method_def.return_type.clone());
+            }
+        }
+
+        // a generic method is instantiated, once the type of the call is inferred
+        self.clone().monomorphize(&mut type_context.clone()).ok()?
     }
-}
\ No newline at end of file
+
+    /// The error for a call, which no method accepts. If methods with the name exist, their signatures are listed
+    pub(crate) fn signature_mismatch(&self, type_context: &StaticTypeContext) -> Box<InferTypeError> {
+        let signatures = type_context.methods.iter()
+            .filter(|method_definition| method_definition.identifier == self.identifier)
+            .map(|method_definition| method_definition.arguments.iter().map(|argument| argument.ty.clone()).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+
+        if signatures.is_empty() {
+            return Box::new(InferTypeError::UnresolvedReference(self.to_string(), type_context.current_file_position.clone()));
+        }
+
+        Box::new(InferTypeError::MethodCallSignatureMismatch {
+            signatures,
+            method_name: self.identifier.clone(),
+            file_position: type_context.current_file_position.clone(),
+            provided: self.arguments.iter().map(|argument| argument.get_type(type_context).unwrap_or(Type::Void)).collect(),
+        })
+    }
+
+    /// Turns a call like `person.greet()` into `Person::greet(&person)`.
+    /// The receiver is referenced or dereferenced, depending on the first parameter of the method
+    fn resolve_receiver(&mut self, type_context: &mut StaticTypeContext) -> Result<(), Box<InferTypeError>> {
+        let Some(mut receiver) = self.receiver.take() else {
+            return Ok(());
+        };
+
+        let receiver_type = receiver.infer_type(type_context)?;
+        let receiver_is_pointer = receiver_type.is_pointer();
+        let value_type = receiver_type.pop_pointer().unwrap_or(receiver_type);
+        let method_name = Impl::mangled_name(&value_type, &self.identifier.identifier());
+
+        let self_type = type_context.methods.iter()
+            .find(|method_definition| method_definition.identifier.identifier() == method_name && method_definition.arguments.len() == self.arguments.len() + 1)
+            .map(|method_definition| method_definition.arguments[0].ty.clone());
+
+        let Some(self_type) = self_type else {
+            self.receiver = Some(receiver);
+            return Err(Box::new(InferTypeError::UnresolvedReference(self.to_string(), type_context.current_file_position.clone())));
+        };
+
+        let pointer_arithmetic = match (self_type.is_pointer(), receiver_is_pointer) {
+            (true, false) => Some(PointerArithmetic::Ampersand),
+            (false, true) => Some(PointerArithmetic::Asterics),
+            _ => None,
+        };
+
+        let receiver = match pointer_arithmetic {
+            Some(pointer_arithmetic) => Assignable::Expression(Expression {
+                prefix_arithmetic: Some(PrefixArithmetic::PointerArithmetic(pointer_arithmetic)),
+                ..Expression::from(Some(receiver))
+            }),
+            None => *receiver,
+        };
+
+        self.identifier = LValue::Identifier(Identifier { name: method_name, symbol: None });
+        self.arguments.insert(0, receiver);
+
+        Ok(())
+    }
+}
@@ -1,4 +1,6 @@
 mod array;
 mod expression;
 mod method_call;
-mod identifier;
\ No newline at end of file
+mod identifier;
+mod enum_path;
+mod field_access;
@@ -6,11 +6,21 @@ use crate::core::semantics::type_infer::infer_type::InferType;
 
 impl InferType for For {
     fn infer_type(&mut self, type_context: &mut StaticTypeContext) -> Result<Type, Box<InferTypeError>> {
+        // the variables of the header are only visible inside of the loop
+        type_context.push_scope();
+
+        // variables without a type annotation are declared while their type is inferred, typed variables beforehand like the typed variables of a scope
+        if self.initialization.ty.is_some() {
+            type_context.declare(self.initialization.clone());
+        }
+
         self.initialization.infer_type(type_context)?;
+
         self.condition.infer_type(type_context)?;
         self.update.infer_type(type_context)?;
 
         self.stack.infer_type(type_context)?;
+        let _ = type_context.pop_scope();
 
         Ok(Type::Statement)
     }
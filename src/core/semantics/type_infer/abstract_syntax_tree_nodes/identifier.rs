@@ -1,5 +1,4 @@
 use crate::core::model::abstract_syntax_tree_nodes::identifier::Identifier;
-use crate::core::model::abstract_syntax_tree_nodes::l_value::LValue;
 use crate::core::model::types::mutability::Mutability;
 use crate::core::model::types::ty::Type;
 use crate::core::parser::static_type_context::StaticTypeContext;
@@ -8,13 +7,9 @@ use crate::core::semantics::type_infer::infer_type::InferType;
 
 impl InferType for Identifier {
     fn infer_type(&mut self, type_context: &mut StaticTypeContext) -> Result<Type, Box<InferTypeError>> {
-        if let Some(v) = type_context.iter().rfind(|v| {
-            if let LValue::Identifier(n) = &v.l_value {
-                n.name == *self.name
-            } else {
-                false
-            }
-        }) {
+        self.symbol = type_context.lookup_id(&self.name);
+
+        if let Some(v) = self.symbol.and_then(|symbol| type_context.get(symbol)) {
             return if let Some(ty) = &v.ty {
                 let mut ty = ty.clone();
                 ty.set_mutability(Mutability::from(v.mutability));
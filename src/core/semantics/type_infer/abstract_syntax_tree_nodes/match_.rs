@@ -0,0 +1,28 @@
+use crate::core::model::abstract_syntax_tree_nodes::match_::{Match, MatchPattern};
+use crate::core::model::types::ty::Type;
+use crate::core::parser::static_type_context::StaticTypeContext;
+use crate::core::parser::types::r#type::InferTypeError;
+use crate::core::semantics::type_infer::infer_type::InferType;
+
+impl InferType for Match {
+    fn infer_type(&mut self, type_context: &mut StaticTypeContext) -> Result<Type, Box<InferTypeError>> {
+        self.value.infer_type(type_context)?;
+
+        for arm in &mut self.arms {
+            for pattern in &mut arm.patterns {
+                match pattern {
+                    MatchPattern::Literal(literal) => { literal.infer_type(type_context)?; },
+                    MatchPattern::Range { start, end, .. } => {
+                        start.infer_type(type_context)?;
+                        end.infer_type(type_context)?;
+                    }
+                    MatchPattern::Wildcard => {}
+                }
+            }
+
+            arm.stack.infer_type(type_context)?;
+        }
+
+        Ok(Type::Statement)
+    }
+}
@@ -3,6 +3,7 @@ pub mod variable;
 mod assignable;
 mod identifier;
 mod if_;
+mod match_;
 mod for_;
 mod while_;
 mod method_definition;
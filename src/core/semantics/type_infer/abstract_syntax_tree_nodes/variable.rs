@@ -1,3 +1,5 @@
+use crate::core::model::abstract_syntax_tree_nodes::assignable::Assignable;
+use crate::core::model::abstract_syntax_tree_nodes::l_value::LValue;
 use crate::core::model::abstract_syntax_tree_nodes::variable::Variable;
 use crate::core::model::types::ty::Type;
 use crate::core::parser::static_type_context::StaticTypeContext;
@@ -10,8 +12,28 @@ impl<const ASSIGNMENT: char, const SEPARATOR: char> InferType for Variable<ASSIG
             return Err(Box::new(InferTypeError::NameCollision(self.l_value.identifier(), self.file_position.clone())));
         }
 
+        // reassignments refer to the declaration of the variable. typed declarations were declared at the start of their scope
+        if ASSIGNMENT == '=' && (!self.define || self.ty.is_some()) {
+            self.l_value.set_symbol(type_context.lookup_id(&self.l_value.identifier()));
+        }
+
+        if self.compound_operator.is_some() {
+            let ty = self.infer_compound_type(type_context)?;
+            self.ty = Some(ty.clone());
+            return Ok(ty);
+        }
+
         if !self.define {
             let ty = self.assignable.infer_type(type_context)?;
+
+            // a = 5; casts the literal to the type of `a` like let a: i64 = 5;
+            if let Some(l_value_type) = self.l_value_type(type_context).filter(|l_value_type| !l_value_type.eq_ignoring_mutability(&ty)) {
+                if let Some(implicit_cast) = ty.implicit_cast_to(&mut self.assignable, &l_value_type, &type_context.current_file_position)? {
+                    self.ty = Some(implicit_cast.clone());
+                    return Ok(implicit_cast);
+                }
+            }
+
             self.ty = Some(ty.clone());
             return Ok(ty);
         }
@@ -22,6 +44,7 @@ impl<const ASSIGNMENT: char, const SEPARATOR: char> InferType for Variable<ASSIG
 
             // if type is present. check, if the type matches the assignment
             // else infer the type with a context
+            Some(ty) if self.assignable == Assignable::Uninitialized => Ok(ty.clone()),
             Some(ty) => {
                 let inferred_type = self.assignable.infer_type(type_context)?;
 
@@ -40,17 +63,51 @@ impl<const ASSIGNMENT: char, const SEPARATOR: char> InferType for Variable<ASSIG
             None => {
                 let ty = self.assignable.infer_type(type_context)?;
                 self.ty = Some(ty.clone());
-                type_context.push(Variable {
+                let symbol = type_context.declare(Variable {
                     l_value: self.l_value.clone(),
                     ty: Some(ty.clone()),
                     define: self.define,
+                    compound_operator: None,
                     assignable: self.assignable.clone(),
                     mutability: self.mutability,
                     file_position: self.file_position.clone(),
                 });
+                self.l_value.set_symbol(Some(symbol));
 
                 Ok(ty)
             }
         }
     }
-}
\ No newline at end of file
+}
+
+impl<const ASSIGNMENT: char, const SEPARATOR: char> Variable<ASSIGNMENT, SEPARATOR> {
+    /// the type of the memory a reassignment writes to. For `person.age = 31`, `a[1] = 2` or `*p = 3` it's the type of the field,
+    /// the element or the pointee instead of the variable. Fields of an object literal aren't reassignments
+    pub(crate) fn l_value_type(&self, type_context: &StaticTypeContext) -> Option<Type> {
+        if ASSIGNMENT != '=' {
+            return None;
+        }
+
+        match &self.l_value {
+            LValue::Identifier(identifier) => type_context.resolve(identifier).and_then(|variable| variable.ty.clone()),
+            LValue::Expression(expression) => expression.get_type(type_context),
+        }
+    }
+
+    /// a compound assignment like `a += 1` has the type of `a + 1`. literals on the right side are casted to the type of the l_value
+    fn infer_compound_type(&mut self, type_context: &mut StaticTypeContext) -> Result<Type, Box<InferTypeError>> {
+        let assignable_type = self.assignable.infer_type(type_context)?;
+
+        if let Some(mut l_value) = self.compound_expression().and_then(|expression| expression.lhs) {
+            let l_value_type = l_value.infer_type(type_context)?;
+            if matches!((&l_value_type, &assignable_type), (Type::Integer(a, _), Type::Integer(b, _)) if a != b) || matches!((&l_value_type, &assignable_type), (Type::Float(a, _), Type::Float(b, _)) if a != b) {
+                assignable_type.implicit_cast_to(&mut self.assignable, &l_value_type, &type_context.current_file_position)?;
+            }
+        }
+
+        match self.compound_expression() {
+            Some(mut expression) => expression.infer_type(type_context),
+            None => self.assignable.infer_type(type_context),
+        }
+    }
+}
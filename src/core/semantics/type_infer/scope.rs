@@ -6,21 +6,15 @@ use crate::core::semantics::type_infer::infer_type::InferType;
 
 impl InferType for Vec<AbstractSyntaxTreeNode> {
     fn infer_type(&mut self, type_context: &mut StaticTypeContext) -> Result<Type, Box<InferTypeError>> {
-        let variables_len = type_context.len();
-
-        let scoped_checker = StaticTypeContext::new(self);
-        type_context.merge(scoped_checker);
+        type_context.push_scope();
+        type_context.merge(StaticTypeContext::new(self));
 
         for node in self {
             type_context.current_file_position = node.file_position();
             node.infer_type(type_context)?;
         }
 
-        let amount_pop = type_context.len() - variables_len;
-
-        for _ in 0..amount_pop {
-            let _ = type_context.pop();
-        }
+        let _ = type_context.pop_scope();
 
         Ok(Type::Statement)
     }
@@ -6,8 +6,22 @@ use crate::core::semantics::type_infer::infer_type::InferType;
 /// Recursively infer types for all nodes in the scope
 pub fn infer_type(scope: &mut Vec<AbstractSyntaxTreeNode>) -> Result<(), Box<InferTypeError>> {
     let mut type_context: StaticTypeContext = StaticTypeContext::new(scope);
+
+    // generic methods aren't compiled, only their instances for the type arguments of the calls
+    scope.retain(|node| !matches!(node, AbstractSyntaxTreeNode::MethodDefinition(method_definition) if method_definition.is_generic()));
     infer_type_rec(scope, &mut type_context)?;
-    
+
+    // the instances are added after the whole program is inferred. Their bodies can call further generic methods
+    while !type_context.monomorphized.is_empty() {
+        let mut instances = std::mem::take(&mut type_context.monomorphized)
+            .into_iter()
+            .map(AbstractSyntaxTreeNode::MethodDefinition)
+            .collect::<Vec<_>>();
+
+        infer_type_rec(&mut instances, &mut type_context)?;
+        scope.append(&mut instances);
+    }
+
     Ok(())
 }
 
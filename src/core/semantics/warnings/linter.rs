@@ -0,0 +1,305 @@
+use std::collections::{HashMap, HashSet};
+use crate::core::lexer::token_with_span::FilePosition;
+use crate::core::model::abstract_syntax_tree_node::AbstractSyntaxTreeNode;
+use crate::core::model::abstract_syntax_tree_nodes::assignable::Assignable;
+use crate::core::model::abstract_syntax_tree_nodes::assignables::equation_parser::expression::Expression;
+use crate::core::model::abstract_syntax_tree_nodes::assignables::equation_parser::prefix_arithmetic::{PointerArithmetic, PrefixArithmetic};
+use crate::core::model::abstract_syntax_tree_nodes::assignables::method_call::MethodCall;
+use crate::core::model::abstract_syntax_tree_nodes::identifier::Identifier;
+use crate::core::model::abstract_syntax_tree_nodes::l_value::LValue;
+use crate::core::model::abstract_syntax_tree_nodes::method_definition::MethodDefinition;
+use crate::core::model::abstract_syntax_tree_nodes::variable::Variable;
+use crate::core::model::symbol_table::SymbolId;
+use crate::core::parser::static_type_context::StaticTypeContext;
+use crate::core::semantics::control_flow::ControlFlowGraph;
+use crate::core::semantics::warnings::{Lint, LintConfig, LintLevel, Warning};
+
+/// Runs every lint, which isn't allowed by the config, over the type checked program.
+/// The warnings are ordered by their position in the source code
+pub fn lint(scope: &Vec<AbstractSyntaxTreeNode>, config: &LintConfig) -> Vec<Warning> {
+    let mut type_context = StaticTypeContext::new(scope);
+    // the variables are declared while walking through the program
+    type_context.symbols.clear();
+
+    let mut linter = Linter {
+        config,
+        type_context,
+        locals: HashMap::new(),
+        called_methods: HashSet::new(),
+        inside_method: false,
+        warnings: vec![],
+    };
+
+    linter.unreachable_code(scope);
+    linter.scope(scope);
+    linter.unused_functions(scope);
+
+    linter.warnings.sort_by_key(|warning| (warning.file_position.file_id, warning.file_position.offset.start));
+    // every instance of a generic method reports the same warnings
+    linter.warnings.dedup();
+    linter.warnings
+}
+
+/// How a local variable is used between its declaration and the end of its scope
+struct LocalUsage {
+    name: String,
+    mutable: bool,
+    read: bool,
+    written: bool,
+    file_position: FilePosition,
+}
+
+struct Linter<'a> {
+    config: &'a LintConfig,
+    type_context: StaticTypeContext,
+    locals: HashMap<SymbolId, LocalUsage>,
+    called_methods: HashSet<String>,
+    /// variables outside of methods are global and might be used anywhere
+    inside_method: bool,
+    warnings: Vec<Warning>,
+}
+
+impl Linter<'_> {
+    fn warn(&mut self, lint: Lint, message: String, file_position: &FilePosition) {
+        let level = self.config.level(lint);
+
+        if level != LintLevel::Allow {
+            self.warnings.push(Warning { lint, level, message, file_position: file_position.clone() });
+        }
+    }
+
+    fn scope(&mut self, scope: &[AbstractSyntaxTreeNode]) {
+        self.type_context.push_scope();
+
+        for node in scope {
+            self.node(node);
+        }
+
+        self.pop_scope();
+    }
+
+    /// statements, which can't be reached from the start of the method or the program
+    fn unreachable_code(&mut self, stack: &[AbstractSyntaxTreeNode]) {
+        for node in ControlFlowGraph::new(stack).unreachable_statements() {
+            self.warn(Lint::UnreachableCode, "unreachable statement".to_string(), &node.file_position());
+        }
+    }
+
+    fn pop_scope(&mut self) {
+        for id in self.type_context.pop_scope() {
+            let Some(usage) = self.locals.remove(&id) else {
+                continue;
+            };
+
+            if !usage.read && !usage.name.starts_with('_') {
+                self.warn(Lint::UnusedVariables, format!("unused variable: `{}`", usage.name), &usage.file_position);
+            }
+
+            if usage.mutable && !usage.written {
+                self.warn(Lint::UnusedMut, format!("variable `{}` doesn't need to be mutable", usage.name), &usage.file_position);
+            }
+        }
+    }
+
+    fn node(&mut self, node: &AbstractSyntaxTreeNode) {
+        match node {
+            AbstractSyntaxTreeNode::Variable(variable) => self.variable(variable),
+            AbstractSyntaxTreeNode::MethodCall(method_call) => self.method_call(method_call, &method_call.file_position),
+            AbstractSyntaxTreeNode::MethodDefinition(method_definition) => self.method_definition(method_definition),
+            AbstractSyntaxTreeNode::Impl(impl_definition) => {
+                for method_definition in &impl_definition.methods {
+                    self.method_definition(method_definition);
+                }
+            }
+            AbstractSyntaxTreeNode::Return(ret) => {
+                if let Some(assignable) = &ret.assignable {
+                    self.assignable(assignable, &ret.file_position);
+                }
+            }
+            AbstractSyntaxTreeNode::If(if_definition) => {
+                self.assignable(&if_definition.condition, &if_definition.file_position);
+                self.scope(&if_definition.if_stack);
+
+                if let Some(else_stack) = &if_definition.else_stack {
+                    self.scope(else_stack);
+                }
+            }
+            AbstractSyntaxTreeNode::Match(match_definition) => {
+                self.assignable(&match_definition.value, &match_definition.file_position);
+
+                for arm in &match_definition.arms {
+                    self.scope(&arm.stack);
+                }
+            }
+            AbstractSyntaxTreeNode::While(while_definition) => {
+                self.assignable(&while_definition.condition, &while_definition.file_position);
+                self.scope(&while_definition.stack);
+            }
+            AbstractSyntaxTreeNode::For(for_definition) => {
+                self.type_context.push_scope();
+
+                self.variable(&for_definition.initialization);
+                self.assignable(&for_definition.condition, &for_definition.file_position);
+                self.scope(&for_definition.stack);
+                self.variable(&for_definition.update);
+
+                self.pop_scope();
+            }
+            AbstractSyntaxTreeNode::Import(_) | AbstractSyntaxTreeNode::StructDefinition(_) | AbstractSyntaxTreeNode::EnumDefinition(_) |
+            AbstractSyntaxTreeNode::Break(_) | AbstractSyntaxTreeNode::Continue(_) | AbstractSyntaxTreeNode::SyntaxError(_) => {}
+        }
+    }
+
+    fn method_definition(&mut self, method_definition: &MethodDefinition) {
+        if method_definition.is_extern {
+            return;
+        }
+
+        let inside_method = std::mem::replace(&mut self.inside_method, true);
+        self.type_context.push_scope();
+
+        for argument in &method_definition.arguments {
+            let name = argument.identifier.identifier();
+            let id = self.type_context.declare(Variable {
+                l_value: LValue::Identifier(Identifier { name: name.clone(), symbol: None }),
+                mutability: argument.ty.mutable(),
+                ty: Some(argument.ty.clone()),
+                define: true,
+                compound_operator: None,
+                assignable: Assignable::default(),
+                file_position: FilePosition::default(),
+            });
+
+            // the receiver of a method doesn't have to be used
+            if name != "self" {
+                let _ = self.locals.insert(id, LocalUsage {
+                    name,
+                    mutable: false,
+                    read: false,
+                    written: false,
+                    file_position: method_definition.file_position.clone(),
+                });
+            }
+        }
+
+        self.unreachable_code(&method_definition.stack);
+        self.scope(&method_definition.stack);
+
+        self.pop_scope();
+        self.inside_method = inside_method;
+    }
+
+    fn variable(&mut self, variable: &Variable<'=', ';'>) {
+        self.assignable(&variable.assignable, &variable.file_position);
+
+        if variable.define {
+            let id = self.type_context.declare(variable.clone());
+
+            if self.inside_method {
+                let _ = self.locals.insert(id, LocalUsage {
+                    name: variable.l_value.identifier(),
+                    mutable: variable.mutability,
+                    read: false,
+                    written: false,
+                    file_position: variable.file_position.clone(),
+                });
+            }
+
+            return;
+        }
+
+        match &variable.l_value {
+            // `a += 1` reads `a` first
+            LValue::Identifier(identifier) => self.usage(&identifier.name, variable.compound_operator.is_some(), true),
+            // writing an element or a field modifies the variable
+            LValue::Expression(expression) => {
+                self.expression(expression, &variable.file_position);
+
+                if let Some(name) = expression.identifier() {
+                    self.usage(&name, true, true);
+                }
+            }
+        }
+    }
+
+    fn assignable(&mut self, assignable: &Assignable, file_position: &FilePosition) {
+        match assignable {
+            Assignable::Identifier(identifier) => self.usage(&identifier.name, true, false),
+            Assignable::Expression(expression) => self.expression(expression, file_position),
+            Assignable::MethodCall(method_call) => self.method_call(method_call, file_position),
+            Assignable::Object(object) => {
+                for field in &object.fields {
+                    self.assignable(&field.assignable, file_position);
+                }
+            }
+            Assignable::Array(array) => {
+                for value in &array.values {
+                    self.assignable(value, file_position);
+                }
+            }
+            Assignable::FieldAccess(field_access) => self.assignable(&field_access.value, file_position),
+            Assignable::String(_) | Assignable::Integer(_) | Assignable::Float(_) | Assignable::Boolean(_) |
+            Assignable::Parameter(_) | Assignable::EnumPath(_) | Assignable::Uninitialized => {}
+        }
+    }
+
+    fn method_call(&mut self, method_call: &MethodCall, file_position: &FilePosition) {
+        let _ = self.called_methods.insert(method_call.identifier.identifier());
+
+        if let Some(receiver) = &method_call.receiver {
+            self.assignable(receiver, file_position);
+        }
+
+        for argument in &method_call.arguments {
+            self.assignable(argument, file_position);
+        }
+    }
+
+    fn expression(&mut self, expression: &Expression, file_position: &FilePosition) {
+        match &expression.prefix_arithmetic {
+            Some(PrefixArithmetic::Cast(ty)) if expression.is_redundant_cast(&self.type_context) => {
+                self.warn(Lint::RedundantCasts, format!("redundant cast to `{ty}`, the value already has this type"), file_position);
+            }
+            // the value behind a reference can be modified by the receiver of the reference
+            Some(PrefixArithmetic::PointerArithmetic(PointerArithmetic::Ampersand)) => {
+                if let Some(name) = expression.identifier() {
+                    self.usage(&name, true, true);
+                }
+            }
+            _ => {}
+        }
+
+        for operand in [&expression.lhs, &expression.rhs].into_iter().flatten() {
+            self.expression(operand, file_position);
+        }
+
+        for value in [&expression.value, &expression.index_operator].into_iter().flatten() {
+            self.assignable(value, file_position);
+        }
+    }
+
+    fn usage(&mut self, name: &str, read: bool, written: bool) {
+        let Some(usage) = self.type_context.lookup_id(name).and_then(|id| self.locals.get_mut(&id)) else {
+            return;
+        };
+
+        usage.read |= read;
+        usage.written |= written;
+    }
+
+    /// private methods, which are never called. Calls of a method inside its own body count as well
+    fn unused_functions(&mut self, scope: &[AbstractSyntaxTreeNode]) {
+        for node in scope {
+            let AbstractSyntaxTreeNode::MethodDefinition(method_definition) = node else {
+                continue;
+            };
+
+            let name = method_definition.identifier.identifier();
+            if method_definition.is_public || method_definition.is_extern || name == "main" || self.called_methods.contains(&name) {
+                continue;
+            }
+
+            self.warn(Lint::UnusedFunctions, format!("function `{name}` is never used"), &method_definition.file_position);
+        }
+    }
+}
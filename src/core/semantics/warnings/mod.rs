@@ -0,0 +1,103 @@
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use crate::core::lexer::token_with_span::FilePosition;
+
+pub mod linter;
+
+/// A check of the program, which doesn't prevent the program from being compiled
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Lint {
+    /// a local variable or parameter, which is never read
+    UnusedVariables,
+    /// a variable declared `mut`, which is never modified
+    UnusedMut,
+    /// a private function, which is never called
+    UnusedFunctions,
+    /// statements, which can never run, like statements after a `return` or after a `while (true)` loop without a `break`
+    UnreachableCode,
+    /// a cast to the type the value already has
+    RedundantCasts,
+}
+
+impl Lint {
+    pub const ALL: [Lint; 5] = [Lint::UnusedVariables, Lint::UnusedMut, Lint::UnusedFunctions, Lint::UnreachableCode, Lint::RedundantCasts];
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            Lint::UnusedVariables => "W0001",
+            Lint::UnusedMut => "W0002",
+            Lint::UnusedFunctions => "W0003",
+            Lint::UnreachableCode => "W0004",
+            Lint::RedundantCasts => "W0005",
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Lint::UnusedVariables => "unused_variables",
+            Lint::UnusedMut => "unused_mut",
+            Lint::UnusedFunctions => "unused_functions",
+            Lint::UnreachableCode => "unreachable_code",
+            Lint::RedundantCasts => "redundant_casts",
+        }
+    }
+}
+
+impl Display for Lint {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl FromStr for Lint {
+    type Err = String;
+
+    /// accepts the name as well as the code of a lint, like `unused_mut` or `W0002`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Lint::ALL.into_iter()
+            .find(|lint| lint.name() == s.to_lowercase().replace('-', "_") || lint.code() == s.to_uppercase())
+            .ok_or(format!("Unknown lint: {s}"))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LintLevel {
+    /// the lint isn't reported
+    Allow,
+    /// the lint is reported as a warning
+    #[default]
+    Warn,
+    /// the lint is reported as an error and the program isn't compiled
+    Deny,
+}
+
+/// The level of each lint. Lints are warnings by default
+#[derive(Debug, Clone, Default)]
+pub struct LintConfig {
+    levels: HashMap<Lint, LintLevel>,
+}
+
+impl LintConfig {
+    pub fn set(&mut self, lint: Lint, level: LintLevel) {
+        let _ = self.levels.insert(lint, level);
+    }
+
+    pub fn level(&self, lint: Lint) -> LintLevel {
+        self.levels.get(&lint).copied().unwrap_or_default()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Warning {
+    pub lint: Lint,
+    pub level: LintLevel,
+    pub message: String,
+    pub file_position: FilePosition,
+}
+
+impl Display for Warning {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Line: {}\t{} [{} {}]", self.file_position, self.message, self.lint.code(), self.lint)
+    }
+}
@@ -1,15 +1,21 @@
 use crate::cli::program_args::{OptimizationLevel, PrintOption, ProgramArgs};
-use crate::core::io::monkey_file::MonkeyFile;
 use crate::core::model::abstract_syntax_tree_node::AbstractSyntaxTreeNode;
-use crate::core::parser::ast_parser::ASTParser;
+use crate::core::parser::module_loader::ModuleLoader;
+use crate::core::semantics::definite_assignment::definite_assignment_check;
 use crate::core::semantics::static_type_check::static_type_checker::static_type_check;
 use crate::core::semantics::type_infer::type_inferer::infer_type;
+use crate::core::semantics::warnings::linter::lint;
+use crate::core::semantics::warnings::LintLevel;
 use clap::Parser;
+use std::fmt::Display;
+use std::path::PathBuf;
 use colored::Colorize;
 use crate::core::code_generator::generator::ASMGenerator;
 use crate::core::code_generator::target_creator::TargetCreator;
 use crate::core::code_generator::target_os::TargetOS;
 use crate::core::optimization::optimization_trait::OptimizationContext;
+use crate::core::io::source_map::SourceMap;
+use crate::core::lexer::token_with_span::FilePosition;
 
 mod cli;
 mod core;
@@ -20,23 +26,54 @@ fn run_compiler() -> anyhow::Result<()> {
 
     let args = ProgramArgs::parse();
     let entry_point_file = args.input.clone();
+    let module_paths = args.module_paths.iter().map(PathBuf::from).collect::<Vec<_>>();
 
-    let monkey_file: MonkeyFile = MonkeyFile::read(entry_point_file)?;
+    // 1) Build AST of the entry point file and all imported modules
+    let mut source_map = SourceMap::default();
+    let mut top_level_scope = ModuleLoader::new(module_paths)
+        .load(entry_point_file, &mut source_map)
+        .map_err(|error| anyhow::anyhow!(describe(&source_map, error.file_position(), &error)))?;
 
-    // 1) Build AST
-    let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
+    let program: &mut Vec<AbstractSyntaxTreeNode> = &mut top_level_scope.program;
 
-    let program: &mut Vec<AbstractSyntaxTreeNode> = &mut top_level_scope.result.program;
+    // 2) Static Type Checking. The valid parts of the program are checked as well, if there are syntax errors
+    let static_type_context = definite_assignment_check(program)
+        .map_err(|error| describe(&source_map, Some(error.file_position()), &error))
+        .and_then(|_| infer_type(program).map_err(|error| describe(&source_map, Some(error.file_position()), &error)))
+        .and_then(|_| static_type_check(&top_level_scope.program).map_err(|error| describe(&source_map, Some(error.file_position()), &error)));
 
-    // 2) Static Type Checking
-    infer_type(program)?;
-    let mut static_type_context = static_type_check(&top_level_scope.result.program)?;
+    let mut errors = top_level_scope.diagnostics.iter()
+        .map(|error| describe(&source_map, error.file_position(), error))
+        .collect::<Vec<_>>();
 
-    // 3) o1 Optimization
+    if !errors.is_empty() {
+        if let Err(error) = &static_type_context {
+            errors.push(error.clone());
+        }
+
+        return Err(anyhow::anyhow!(errors.join("\n\t")));
+    }
+
+    let mut static_type_context = static_type_context.map_err(anyhow::Error::msg)?;
+
+    // 3) Lints. Warnings don't stop the build, denied lints do
+    let (denied, warnings): (Vec<_>, Vec<_>) = lint(&top_level_scope.program, &args.lint_config())
+        .into_iter()
+        .partition(|warning| warning.level == LintLevel::Deny);
+
+    for warning in &warnings {
+        eprintln!("{}\n\t{}", "Warning:".yellow(), describe(&source_map, Some(&warning.file_position), warning));
+    }
+
+    if !denied.is_empty() {
+        return Err(anyhow::anyhow!(denied.iter().map(|warning| describe(&source_map, Some(&warning.file_position), warning)).collect::<Vec<_>>().join("\n\t")));
+    }
+
+    // 4) o1 Optimization
     let top_level_scope = if args.optimization_level == OptimizationLevel::O1 {
-        top_level_scope.result.o1(&mut static_type_context, OptimizationContext::default())
+        top_level_scope.o1(&mut static_type_context, OptimizationContext::default())
     } else {
-        top_level_scope.result
+        top_level_scope
     };
 
     if let Some(print_scope) = &args.print_scope {
@@ -45,7 +82,7 @@ fn run_compiler() -> anyhow::Result<()> {
             PrintOption::Debug => println!("{:#?}", top_level_scope),
         };
     }
-    // 3) Building
+    // 5) Building
     let got_main = top_level_scope.has_main_method && !top_level_scope.program.is_empty();
     let mut code_generator = ASMGenerator::from((top_level_scope.program, args.target_os.clone(), got_main));
     let target_creator = TargetCreator::try_from((args.input.as_str(), &args.target_os))?;
@@ -64,8 +101,8 @@ fn run_compiler() -> anyhow::Result<()> {
             _ => "Failed".red(),
         });
 
-        // 4) Running
-        if !args.build {
+        // 6) Running
+        if !args.build && build_status == 0 {
             let status = target_creator.execute(&args.target_os);
             println!("Process finished with exit code {}", status);
 
@@ -99,6 +136,14 @@ fn main() {
     }
 }
 
+/// The messages of the diagnostics only contain the line and the column, so the file is named in front of them
+fn describe(source_map: &SourceMap, file_position: Option<&FilePosition>, message: &dyn Display) -> String {
+    match file_position.and_then(|file_position| source_map.path_of(file_position.file_id)) {
+        Some(path) => format!("{}: {message}", path.display()),
+        None => message.to_string(),
+    }
+}
+
 fn with_path<F>(path: &str, f: F) -> anyhow::Result<()>
 where
     F: FnOnce() -> anyhow::Result<()>,
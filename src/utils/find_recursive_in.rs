@@ -114,6 +114,16 @@ impl <T: Findable<T>> FindASTNode<T> {
                         self.find_ast_node_in_stack(else_stack, static_type_context).iter().for_each(|a| { called_ast_node.insert(a.clone()); })
                     }
                 }
+                AbstractSyntaxTreeNode::Match(match_definition) => {
+                    let func = &self.assignable_match;
+                    if let Some(calls) = func(self, &match_definition.value, static_type_context) {
+                        calls.iter().for_each(|a| { called_ast_node.insert(a.clone()); });
+                    }
+
+                    for arm in &match_definition.arms {
+                        self.find_ast_node_in_stack(&arm.stack, static_type_context).iter().for_each(|a| { called_ast_node.insert(a.clone()); });
+                    }
+                }
                 AbstractSyntaxTreeNode::For(for_loop) => {
                     self.find_ast_node_in_stack(&for_loop.stack, static_type_context).iter().for_each(|a| { called_ast_node.insert(a.clone()); });
                 }
@@ -128,7 +138,8 @@ impl <T: Findable<T>> FindASTNode<T> {
                         }
                     }
                 }
-                AbstractSyntaxTreeNode::MethodDefinition(_) | AbstractSyntaxTreeNode::Import(_) | AbstractSyntaxTreeNode::StructDefinition(_) => {}
+                AbstractSyntaxTreeNode::MethodDefinition(_) | AbstractSyntaxTreeNode::Import(_) | AbstractSyntaxTreeNode::StructDefinition(_) | AbstractSyntaxTreeNode::EnumDefinition(_) | AbstractSyntaxTreeNode::Impl(_) | AbstractSyntaxTreeNode::SyntaxError(_) |
+                AbstractSyntaxTreeNode::Break(_) | AbstractSyntaxTreeNode::Continue(_) => {}
             }
         }
 
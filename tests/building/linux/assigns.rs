@@ -0,0 +1,165 @@
+use crate::building::linux::program_runner::build_and_run;
+
+#[test]
+#[ignore = "needs nasm and cc, run with `cargo test -- --ignored`"]
+fn integer_arithmetic() -> anyhow::Result<()> {
+    let code = r#"
+    extern fn printf(format: *string, value: i32): void;
+
+    let a = 5 + 3 * 8 - 9;
+    let b = (a - 4) / 4;
+    printf("%d ", a);
+    printf("%d", b);
+    "#;
+
+    let output = build_and_run(code)?;
+
+    assert_eq!("20 4", output.stdout);
+    assert_eq!(0, output.exit_code);
+    Ok(())
+}
+
+#[test]
+#[ignore = "needs nasm and cc, run with `cargo test -- --ignored`"]
+fn float_arithmetic() -> anyhow::Result<()> {
+    let code = r#"
+    extern fn printf(format: *string, value: f64): void;
+
+    let a = 1.5_f64 * 4.0_f64 - 0.5_f64;
+    printf("%.2f", a);
+    "#;
+
+    let output = build_and_run(code)?;
+
+    assert_eq!("5.50", output.stdout);
+    assert_eq!(0, output.exit_code);
+    Ok(())
+}
+
+#[test]
+#[ignore = "needs nasm and cc, run with `cargo test -- --ignored`"]
+fn casts() -> anyhow::Result<()> {
+    let code = r#"
+    extern fn printf(format: *string, value: i32): void;
+    extern fn printf(format: *string, value: f64): void;
+
+    let a: f32 = 3.25;
+    let b = (i32) a;
+    let c = (f64) b;
+    printf("%d ", b);
+    printf("%.1f", c);
+    "#;
+
+    let output = build_and_run(code)?;
+
+    assert_eq!("3 3.0", output.stdout);
+    assert_eq!(0, output.exit_code);
+    Ok(())
+}
+
+#[test]
+#[ignore = "needs nasm and cc, run with `cargo test -- --ignored`"]
+fn arrays() -> anyhow::Result<()> {
+    let code = r#"
+    extern fn printf(format: *string, value: i32): void;
+
+    let mut a: [i32, 5] = [1, 2, 3, 4, 5];
+    a[0] = 10;
+
+    for (let mut i = 0; i < 5; i = i + 1;) {
+        printf("%d,", a[i]);
+    }
+    "#;
+
+    let output = build_and_run(code)?;
+
+    assert_eq!("10,2,3,4,5,", output.stdout);
+    assert_eq!(0, output.exit_code);
+    Ok(())
+}
+
+#[test]
+#[ignore = "needs nasm and cc, run with `cargo test -- --ignored`"]
+fn bool_compares() -> anyhow::Result<()> {
+    let code = r#"
+    let a = 5;
+    let b = 8;
+    let mut result = 0;
+
+    if (a < b && b != 7) {
+        result = 1;
+    } else {
+        result = 2;
+    }
+
+    return result;
+    "#;
+
+    let output = build_and_run(code)?;
+
+    assert_eq!(1, output.exit_code);
+    Ok(())
+}
+
+#[test]
+#[ignore = "needs nasm and cc, run with `cargo test -- --ignored`"]
+fn string_escapes() -> anyhow::Result<()> {
+    let code = r#"
+    extern fn printf(format: *string): void;
+
+    printf("a\tb\n\"quoted\" \\ \x41\0never printed");
+    printf(r"raw\n");
+    "#;
+
+    let output = build_and_run(code)?;
+
+    assert_eq!("a\tb\n\"quoted\" \\ Araw\\n", output.stdout);
+    assert_eq!(0, output.exit_code);
+    Ok(())
+}
+
+#[test]
+#[ignore = "needs nasm and cc, run with `cargo test -- --ignored`"]
+fn number_literal_forms() -> anyhow::Result<()> {
+    let code = r#"
+    extern fn printf(format: *string, value: i32): void;
+    extern fn printf(format: *string, value: i64): void;
+    extern fn printf(format: *string, value: f64): void;
+
+    let a = 0xFF + 0b1010 + 0o10 + 1_000;
+    let b = 5_000_000_000i64;
+    let c = 2.5f64 * 2f64;
+    printf("%d ", a);
+    printf("%ld ", b);
+    printf("%.1f", c);
+    "#;
+
+    let output = build_and_run(code)?;
+
+    assert_eq!("1273 5000000000 5.0", output.stdout);
+    assert_eq!(0, output.exit_code);
+    Ok(())
+}
+
+#[test]
+#[ignore = "needs nasm and cc, run with `cargo test -- --ignored`"]
+fn char_literals() -> anyhow::Result<()> {
+    let code = r#"
+    extern fn printf(format: *string, value: i32): void;
+    extern fn printf(format: *string, value: u8): void;
+
+    let c: char = 'A';
+    let newline = '\n';
+    let d = (i32) c + 1;
+    printf("%c", c);
+    printf("%c", (u8) d);
+    printf("%d", '\x30');
+    printf("%c", newline);
+    "#;
+
+    let output = build_and_run(code)?;
+
+    assert_eq!("AB48\n", output.stdout);
+    assert_eq!(0, output.exit_code);
+    Ok(())
+}
@@ -0,0 +1,87 @@
+use crate::building::linux::program_runner::build_and_run;
+
+#[test]
+#[ignore = "needs nasm and cc, run with `cargo test -- --ignored`"]
+fn integer_compound_assignments() -> anyhow::Result<()> {
+    let code = r#"
+    extern fn printf(format: *string, value: i32): void;
+
+    fn main(): i32 {
+        let mut a = 10;
+        a += 5;
+        a -= 3;
+        a *= 4;
+        a /= 6;
+        a %= 5;
+        printf("%d ", a);
+
+        let mut b = 3;
+        b <<= 2;
+        b >>= 1;
+        b &= 7;
+        b |= 8;
+        b ^= 1;
+        printf("%d ", b);
+
+        let mut c: i8 = 7;
+        c *= 3;
+        c /= 2;
+        let ten: i8 = 10;
+        if (c == ten) {
+            printf("%d ", 10);
+        }
+
+        let mut d: i64 = 100;
+        d -= 1;
+        d %= 7;
+        let one: i64 = 1;
+        if (d == one) {
+            printf("%d", 1);
+        }
+
+        for (let mut i: i32 = 0; i < 4; i += 1;) {
+            a += i;
+        }
+
+        return a;
+    }
+    "#;
+
+    let output = build_and_run(code)?;
+
+    assert_eq!("3 15 10 1", output.stdout);
+    assert_eq!(9, output.exit_code);
+    Ok(())
+}
+
+#[test]
+#[ignore = "needs nasm and cc, run with `cargo test -- --ignored`"]
+fn compound_assignment_on_array_elements_and_floats() -> anyhow::Result<()> {
+    let code = r#"
+    extern fn printf(format: *string, value: i32): void;
+
+    fn main(): i32 {
+        let mut arr = [1, 2, 3];
+        let i = 1;
+        arr[i] += 40;
+        arr[2] *= arr[i];
+        printf("%d ", arr[1]);
+        printf("%d", arr[2]);
+
+        let mut f = 1.5;
+        f *= 2.0;
+        f -= 0.5;
+        if (f == 2.5) {
+            return 1;
+        }
+
+        return 0;
+    }
+    "#;
+
+    let output = build_and_run(code)?;
+
+    assert_eq!("42 126", output.stdout);
+    assert_eq!(1, output.exit_code);
+    Ok(())
+}
@@ -0,0 +1,70 @@
+use crate::building::linux::program_runner::build_and_run;
+
+#[test]
+#[ignore = "needs nasm and cc, run with `cargo test -- --ignored`"]
+fn enum_discriminants() -> anyhow::Result<()> {
+    let code = r#"
+    extern fn printf(format: *string, value: i32): void;
+
+    enum Color {
+        Red,
+        Green = 5,
+        Blue
+    }
+
+    fn pick(red: bool): Color {
+        if (red) {
+            return Color::Red;
+        }
+
+        return Color::Blue;
+    }
+
+    fn main(): i32 {
+        let c = Color::Green;
+        printf("%d ", (i32)c);
+        printf("%d ", (i32)Color::Red);
+
+        let b = pick(false);
+        if (b == Color::Blue) {
+            printf("%d ", (i32)b);
+        }
+
+        if (pick(true) != Color::Red) {
+            printf("%d ", 0);
+        }
+
+        let mut d: Color = Color::Red;
+        d = c;
+        printf("%d", (i32)(d == Color::Green));
+
+        return (i32)Color::Blue;
+    }
+    "#;
+
+    let output = build_and_run(code)?;
+
+    assert_eq!("5 0 6 1", output.stdout);
+    assert_eq!(6, output.exit_code);
+    Ok(())
+}
+
+#[test]
+#[ignore = "needs nasm and cc, run with `cargo test -- --ignored`"]
+fn enums_in_script_mode() -> anyhow::Result<()> {
+    let code = r#"
+    enum C { R, G }
+
+    fn f(c: C): i32 {
+        return (i32)c;
+    }
+
+    let x = f(C::G);
+    return x + 40;
+    "#;
+
+    let output = build_and_run(code)?;
+
+    assert_eq!(41, output.exit_code);
+    Ok(())
+}
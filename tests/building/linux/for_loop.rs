@@ -0,0 +1,44 @@
+use crate::building::linux::program_runner::build_and_run;
+
+#[test]
+#[ignore = "needs nasm and cc, run with `cargo test -- --ignored`"]
+fn single_for() -> anyhow::Result<()> {
+    let code = r#"
+    extern fn printf(format: *string, value: i32): void;
+
+    let mut a = 0;
+    for (let mut i = 0; i < 5; i = i + 1;) {
+        a = a + i;
+    }
+
+    printf("%d", a);
+    "#;
+
+    let output = build_and_run(code)?;
+
+    assert_eq!("10", output.stdout);
+    assert_eq!(0, output.exit_code);
+    Ok(())
+}
+
+#[test]
+#[ignore = "needs nasm and cc, run with `cargo test -- --ignored`"]
+fn nested_for() -> anyhow::Result<()> {
+    let code = r#"
+    extern fn printf(format: *string, value: i32): void;
+
+    for (let mut i = 0; i < 3; i = i + 1;) {
+        for (let mut j = 0; j < 2; j = j + 1;) {
+            printf("%d", i * 10 + j);
+            printf(" ");
+        }
+    }
+    extern fn printf(format: *string): void;
+    "#;
+
+    let output = build_and_run(code)?;
+
+    assert_eq!("0 1 10 11 20 21 ", output.stdout);
+    assert_eq!(0, output.exit_code);
+    Ok(())
+}
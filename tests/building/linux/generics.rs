@@ -0,0 +1,102 @@
+use crate::building::linux::program_runner::{build_and_run, build_and_run_modules};
+
+#[test]
+#[ignore = "needs nasm and cc, run with `cargo test -- --ignored`"]
+fn generic_methods() -> anyhow::Result<()> {
+    let code = r#"
+    extern fn printf(format: *string, value: i32): void;
+
+    fn max<T: Numeric>(a: T, b: T): T {
+        if (a > b) {
+            return a;
+        }
+
+        return b;
+    }
+
+    fn max(left: bool, right: bool): bool {
+        return left || right;
+    }
+
+    fn zero<T: Integer>(): T {
+        return (T) 0;
+    }
+
+    fn swap<T>(a: *mut T, b: *mut T): void {
+        let value: T = *a;
+        *a = *b;
+        *b = value;
+    }
+
+    fn main(): i32 {
+        let mut a: i32 = max(3, 7);
+        let mut b: i32 = max::<i32>(a, 2) + zero::<i32>();
+        printf("%d ", a);
+        printf("%d ", b);
+
+        let c: i64 = (i64) 40;
+        let d: i64 = max(c, (i64) 2);
+        printf("%d ", (i32) d);
+
+        a = 1;
+        swap(&a, &b);
+        printf("%d ", a);
+
+        if (max(false, true)) {
+            printf("%d", b);
+        }
+
+        return zero::<i32>();
+    }
+    "#;
+
+    let output = build_and_run(code)?;
+
+    assert_eq!("7 7 40 7 1", output.stdout);
+    assert_eq!(0, output.exit_code);
+    Ok(())
+}
+
+#[test]
+#[ignore = "needs nasm and cc, run with `cargo test -- --ignored`"]
+fn generic_methods_of_modules() -> anyhow::Result<()> {
+    let main = r#"
+    module math;
+
+    extern fn printf(format: *string, value: i32): void;
+
+    fn main(): i32 {
+        printf("%d ", math::max(4, 9));
+        printf("%d", math::min::<i32>(4, 9));
+        return math::clamp(12, 0, 10);
+    }
+    "#;
+
+    let math = r#"
+    pub fn max<T: Numeric>(a: T, b: T): T {
+        if (a > b) {
+            return a;
+        }
+
+        return b;
+    }
+
+    pub fn min<T: Numeric>(a: T, b: T): T {
+        if (a < b) {
+            return a;
+        }
+
+        return b;
+    }
+
+    pub fn clamp<T: Numeric>(value: T, low: T, high: T): T {
+        return min(max(value, low), high);
+    }
+    "#;
+
+    let output = build_and_run_modules(&[("main.monkey", main), ("math.monkey", math)])?;
+
+    assert_eq!("9 4", output.stdout);
+    assert_eq!(10, output.exit_code);
+    Ok(())
+}
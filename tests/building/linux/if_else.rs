@@ -0,0 +1,45 @@
+use crate::building::linux::program_runner::build_and_run;
+
+#[test]
+#[ignore = "needs nasm and cc, run with `cargo test -- --ignored`"]
+fn else_if_chain() -> anyhow::Result<()> {
+    let code = r#"
+    extern fn printf(format: *string, value: i32): void;
+
+    fn classify(a: i32): i32 {
+        if (a < 0) {
+            return 1;
+        } else if (a == 0) {
+            return 2;
+        } else if (a < 10) {
+            return 3;
+        } else {
+            return 4;
+        }
+    }
+
+    fn main(): i32 {
+        printf("%d", classify(-5));
+        printf("%d", classify(0));
+        printf("%d", classify(7));
+        printf("%d", classify(42));
+
+        let mut a = 0;
+        let first = false;
+        let second = true;
+        if (first) {
+            a = 1;
+        } else if (second) {
+            a = 2;
+        }
+
+        return a;
+    }
+    "#;
+
+    let output = build_and_run(code)?;
+
+    assert_eq!("1234", output.stdout);
+    assert_eq!(2, output.exit_code);
+    Ok(())
+}
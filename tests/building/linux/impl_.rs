@@ -0,0 +1,47 @@
+use crate::building::linux::program_runner::build_and_run;
+
+#[test]
+#[ignore = "needs nasm and cc, run with `cargo test -- --ignored`"]
+fn impl_methods() -> anyhow::Result<()> {
+    let code = r#"
+    extern fn printf(format: *string, value: i32): void;
+
+    enum Color { Red = 1, Green = 2, Blue = 3 }
+
+    impl Color {
+        fn code(self: Color): i32 {
+            match (self) {
+                Color::Red => { return 10; }
+                Color::Green => { return 20; }
+                _ => { return 30; }
+            }
+        }
+
+        fn shifted(self: *Color, offset: i32): i32 {
+            return self.code() + offset;
+        }
+
+        fn count(): i32 {
+            return 3;
+        }
+    }
+
+    fn main(): i32 {
+        let color: Color = Color::Green;
+        printf("%d ", color.code());
+
+        let pointer: *Color = &color;
+        printf("%d ", pointer.code());
+        printf("%d ", color.shifted(5));
+        printf("%d", Color::count());
+
+        return pointer.shifted(10);
+    }
+    "#;
+
+    let output = build_and_run(code)?;
+
+    assert_eq!("20 20 25 3", output.stdout);
+    assert_eq!(30, output.exit_code);
+    Ok(())
+}
@@ -0,0 +1,72 @@
+use crate::building::linux::program_runner::build_and_run;
+
+#[test]
+#[ignore = "needs nasm and cc, run with `cargo test -- --ignored`"]
+fn break_and_continue() -> anyhow::Result<()> {
+    let code = r#"
+    extern fn printf(format: *string, value: i32): void;
+
+    fn main(): i32 {
+        let mut a = 0;
+        while (a < 100) {
+            a = a + 1;
+            if (a == 3) {
+                break;
+            }
+        }
+        printf("%d", a);
+
+        for (let mut i: i32 = 0; i < 6; i = i + 1;) {
+            if (i < 3) {
+                continue;
+            }
+            printf("%d", i);
+        }
+
+        return a;
+    }
+    "#;
+
+    let output = build_and_run(code)?;
+
+    assert_eq!("3345", output.stdout);
+    assert_eq!(3, output.exit_code);
+    Ok(())
+}
+
+#[test]
+#[ignore = "needs nasm and cc, run with `cargo test -- --ignored`"]
+fn labeled_break_and_continue() -> anyhow::Result<()> {
+    let code = r#"
+    extern fn printf(format: *string, value: i32): void;
+
+    fn main(): i32 {
+        let mut count = 0;
+        'outer: for (let mut i: i32 = 0; i < 4; i = i + 1;) {
+            let mut j = 0;
+            'inner: while (j < 4) {
+                j = j + 1;
+                if (j == 2) {
+                    continue 'inner;
+                }
+                if (i == 1) {
+                    continue 'outer;
+                }
+                if (i == 3) {
+                    break 'outer;
+                }
+                count = count + 1;
+            }
+            printf("%d", i);
+        }
+
+        return count;
+    }
+    "#;
+
+    let output = build_and_run(code)?;
+
+    assert_eq!("02", output.stdout);
+    assert_eq!(6, output.exit_code);
+    Ok(())
+}
@@ -0,0 +1,169 @@
+use crate::building::linux::program_runner::build_and_run;
+
+#[test]
+#[ignore = "needs nasm and cc, run with `cargo test -- --ignored`"]
+fn match_jump_table() -> anyhow::Result<()> {
+    let code = r#"
+    extern fn printf(format: *string, value: i32): void;
+
+    fn main(): i32 {
+        let mut i: i32 = 0;
+
+        while (i < 8) {
+            match (i) {
+                0 => { printf("a%d ", i); }
+                1 | 2 => { printf("b%d ", i); }
+                3..=4 => { printf("c%d ", i); }
+                5 => { printf("d%d ", i); }
+                _ => { printf("e%d ", i); }
+            }
+
+            i = i + 1;
+        }
+
+        return 0;
+    }
+    "#;
+
+    let output = build_and_run(code)?;
+
+    assert_eq!("a0 b1 b2 c3 c4 d5 e6 e7 ", output.stdout);
+    Ok(())
+}
+
+#[test]
+#[ignore = "needs nasm and cc, run with `cargo test -- --ignored`"]
+fn match_compare_chain() -> anyhow::Result<()> {
+    let code = r#"
+    extern fn printf(format: *string, value: i32): void;
+
+    enum Color { Red, Green = 5, Blue }
+
+    fn describe(color: Color): i32 {
+        match (color) {
+            Color::Red => { return 1; }
+            Color::Green | Color::Blue => { return 2; }
+        }
+    }
+
+    fn grade(value: u8): i32 {
+        match (value) {
+            0..10 => { return 0; }
+            10..=99 => { return 1; }
+            100..=255 => { return 2; }
+        }
+    }
+
+    fn main(): i32 {
+        let large: i32 = 1000;
+        match (large) {
+            1..10 => { printf("%d ", 1); }
+            500..=2000 => { printf("%d ", 2); }
+            _ => { printf("%d ", 3); }
+        }
+
+        let flag: bool = false;
+        match (flag) {
+            true => { printf("%d ", 4); }
+            false => { printf("%d ", 5); }
+        }
+
+        let small: u8 = 7;
+        let big: u8 = 200;
+        printf("%d ", grade(small));
+        printf("%d ", grade(big));
+        printf("%d ", describe(Color::Red));
+        printf("%d", describe(Color::Blue));
+
+        let mut n: i32 = 0;
+        while (n < 10) {
+            n = n + 1;
+
+            match (n) {
+                3 => { continue; }
+                6 => { break; }
+                _ => { printf(" %d", n); }
+            }
+        }
+
+        return describe(Color::Green);
+    }
+    "#;
+
+    let output = build_and_run(code)?;
+
+    assert_eq!("2 5 0 2 1 2 1 2 4 5", output.stdout);
+    assert_eq!(2, output.exit_code);
+    Ok(())
+}
+
+#[test]
+#[ignore = "needs nasm and cc, run with `cargo test -- --ignored`"]
+fn match_jump_table_unsigned() -> anyhow::Result<()> {
+    let code = r#"
+    extern fn printf(format: *string, value: i32): void;
+
+    fn small(value: u8): i32 {
+        match (value) {
+            200 => { return 0; }
+            201 | 202 => { return 1; }
+            203..=205 => { return 2; }
+            _ => { return 3; }
+        }
+    }
+
+    fn medium(value: u16): i32 {
+        match (value) {
+            40000 => { return 0; }
+            40001 | 40002 => { return 1; }
+            40003..=40005 => { return 2; }
+            _ => { return 3; }
+        }
+    }
+
+    fn large(value: u32): i32 {
+        match (value) {
+            3000000000 => { return 0; }
+            3000000001 | 3000000002 => { return 1; }
+            3000000003..=3000000005 => { return 2; }
+            _ => { return 3; }
+        }
+    }
+
+    fn main(): i32 {
+        let mut a: u8 = 200;
+        printf("%d", small(a));
+        a = 202;
+        printf("%d", small(a));
+        a = 205;
+        printf("%d", small(a));
+        a = 20;
+        printf("%d ", small(a));
+
+        let mut b: u16 = 40000;
+        printf("%d", medium(b));
+        b = 40001;
+        printf("%d", medium(b));
+        b = 40004;
+        printf("%d", medium(b));
+        b = 4000;
+        printf("%d ", medium(b));
+
+        let mut c: u32 = 3000000000;
+        printf("%d", large(c));
+        c = 3000000002;
+        printf("%d", large(c));
+        c = 3000000003;
+        printf("%d", large(c));
+        c = 3;
+        printf("%d", large(c));
+
+        return 0;
+    }
+    "#;
+
+    let output = build_and_run(code)?;
+
+    assert_eq!("0123 0123 0123", output.stdout);
+    Ok(())
+}
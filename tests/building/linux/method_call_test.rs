@@ -0,0 +1,311 @@
+use crate::building::linux::program_runner::build_and_run;
+use monkey_language::core::code_generator::generator::ASMGenerator;
+use monkey_language::core::code_generator::target_os::TargetOS;
+use monkey_language::core::io::monkey_file::MonkeyFile;
+use crate::read_from_str;
+use monkey_language::core::parser::ast_parser::ASTParser;
+use monkey_language::core::semantics::static_type_check::static_type_checker::static_type_check;
+use monkey_language::core::semantics::type_infer::type_inferer::infer_type;
+
+#[test]
+fn method_build_and_call() -> anyhow::Result<()> {
+    let code = r#"
+    fn add(a: i32, b: i32): i32 {
+        return a + b;
+    }
+
+    add(5, 3);
+    "#;
+
+    let monkey_file: MonkeyFile = read_from_str(code)?;
+    let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
+    infer_type(&mut top_level_scope.result.program)?;
+    let _ = static_type_check(&top_level_scope.result.program)?;
+
+    let mut code_generator = ASMGenerator::from((top_level_scope.result.program, TargetOS::Linux));
+    let asm_result = code_generator.generate()?;
+
+    let expected = r#"
+; This assembly is targeted for the Linux Operating System
+section .text
+global main
+
+
+.add_i32_i32~i32:
+    push rbp
+    mov rbp, rsp
+    ; Reserve stack space as MS convention. Shadow stacking
+    sub rsp, 64
+    mov DWORD [rbp - 4], edi
+    mov DWORD [rbp - 8], esi
+    ; return (a + b)
+    ; (a + b)
+    mov eax, DWORD [rbp - 4]
+    add eax, DWORD [rbp - 8]
+    leave
+    ret
+main:
+    push rbp
+    mov rbp, rsp
+    ; Reserve stack space as MS convention. Shadow stacking
+    sub rsp, 64
+    mov edi, 5
+    mov esi, 3
+    ; add(5, 3)
+    call .add_i32_i32~i32
+    ; return 0
+    mov eax, 0
+    leave
+    ret
+section .note.GNU-stack noalloc noexec nowrite progbits
+    "#;
+
+    println!("{}", asm_result);
+    assert_eq!(expected.trim(), asm_result.trim());
+    Ok(())
+}
+
+#[test]
+fn method_call_stack_arguments() -> anyhow::Result<()> {
+    let code = r#"
+    fn sum(a: i32, b: f32, c: i32, d: i32, e: i32, f: i32, g: i32, h: i32): i32 {
+        return h;
+    }
+
+    let a = sum(1, 2.5, 3, 4, 5, 6, 7, 8);
+    "#;
+
+    let monkey_file: MonkeyFile = read_from_str(code)?;
+    let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
+    infer_type(&mut top_level_scope.result.program)?;
+    let _ = static_type_check(&top_level_scope.result.program)?;
+
+    let mut code_generator = ASMGenerator::from((top_level_scope.result.program, TargetOS::Linux));
+    let asm_result = code_generator.generate()?;
+
+    let expected = r#"
+; This assembly is targeted for the Linux Operating System
+section .text
+global main
+
+
+.sum_i32_f32_i32_i32_i32_i32_i32_i32~i32:
+    push rbp
+    mov rbp, rsp
+    ; Reserve stack space as MS convention. Shadow stacking
+    sub rsp, 64
+    mov DWORD [rbp - 4], edi
+    movd DWORD [rbp - 8], xmm0
+    mov DWORD [rbp - 12], esi
+    mov DWORD [rbp - 16], edx
+    mov DWORD [rbp - 20], ecx
+    mov DWORD [rbp - 24], r8d
+    mov DWORD [rbp - 28], r9d
+    mov eax, DWORD [rbp + 16]
+    mov DWORD [rbp - 32], eax
+    ; return h
+    mov eax, DWORD [rbp - 32]
+    leave
+    ret
+main:
+    push rbp
+    mov rbp, rsp
+    ; Reserve stack space as MS convention. Shadow stacking
+    sub rsp, 64
+    ; let a: i32 = sum(1, 2.5, 3, 4, 5, 6, 7, 8)
+    sub rsp, 8
+    mov eax, 8
+    push rax
+    mov eax, __?float32?__(2.5)
+    push rax
+    mov edi, 1
+    pop rax
+    movq xmm0, rax
+    mov esi, 3
+    mov edx, 4
+    mov ecx, 5
+    mov r8d, 6
+    mov r9d, 7
+    ; sum(1, 2.5, 3, 4, 5, 6, 7, 8)
+    call .sum_i32_f32_i32_i32_i32_i32_i32_i32~i32
+    add rsp, 16
+    mov DWORD [rbp - 4], eax
+    ; return 0
+    mov eax, 0
+    leave
+    ret
+section .note.GNU-stack noalloc noexec nowrite progbits
+    "#;
+
+    println!("{}", asm_result);
+    assert_eq!(expected.trim(), asm_result.trim());
+    Ok(())
+}
+
+#[test]
+fn method_call_float_return_and_variadic() -> anyhow::Result<()> {
+    let code = r#"
+    extern fn printf(format: *string, value: f64): void;
+
+    fn half(a: f64): f64 {
+        return a / 2.0_f64;
+    }
+
+    let x = half(3.0_f64);
+    printf("%f", x);
+    "#;
+
+    let monkey_file: MonkeyFile = read_from_str(code)?;
+    let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
+    infer_type(&mut top_level_scope.result.program)?;
+    let _ = static_type_check(&top_level_scope.result.program)?;
+
+    let mut code_generator = ASMGenerator::from((top_level_scope.result.program, TargetOS::Linux));
+    let asm_result = code_generator.generate()?;
+
+    let expected = r#"
+; This assembly is targeted for the Linux Operating System
+section .data
+    .label0: db "%f", 0
+
+
+
+
+section .text
+global main
+
+extern printf
+
+.half_f64~f64:
+    push rbp
+    mov rbp, rsp
+    ; Reserve stack space as MS convention. Shadow stacking
+    sub rsp, 64
+    movq QWORD [rbp - 8], xmm0
+    ; return (a / 2)
+    ; (a / 2)
+    mov rax, QWORD [rbp - 8]
+    movq xmm0, rax
+    mov rdx, __?float64?__(2.0)
+    movq xmm3, rdx
+    divsd xmm0, xmm3
+    movq rax, xmm0
+    movq xmm0, rax
+    leave
+    ret
+main:
+    push rbp
+    mov rbp, rsp
+    ; Reserve stack space as MS convention. Shadow stacking
+    sub rsp, 64
+    ; let x: f64 = half(3)
+    mov rax, __?float64?__(3.0)
+    push rax
+    pop rax
+    movq xmm0, rax
+    ; half(3)
+    call .half_f64~f64
+    movq rax, xmm0
+    mov QWORD [rbp - 8], rax
+    mov rax, .label0
+    push rax
+    pop rdi
+    movq xmm0, QWORD [rbp - 8]
+    ; printf("%f", x)
+    mov eax, 1
+    call printf
+    ; return 0
+    mov eax, 0
+    leave
+    ret
+section .note.GNU-stack noalloc noexec nowrite progbits
+    "#;
+
+    println!("{}", asm_result);
+    assert_eq!(expected.trim(), asm_result.trim());
+    Ok(())
+}
+
+#[test]
+#[ignore = "needs nasm and cc, run with `cargo test -- --ignored`"]
+fn method_call_run_stack_arguments() -> anyhow::Result<()> {
+    let code = r#"
+    extern fn printf(format: *string, value: i32): void;
+
+    fn sum(a: i32, b: i32, c: i32, d: i32, e: i32, f: i32, g: i32, h: i32, i: i32): i32 {
+        return a + b + c + d + e + f + g + h * 10 + i * 100;
+    }
+
+    printf("%d", sum(1, 2, 3, 4, 5, 6, 7, 8, 9));
+    "#;
+
+    let output = build_and_run(code)?;
+
+    assert_eq!("1008", output.stdout);
+    assert_eq!(0, output.exit_code);
+    Ok(())
+}
+
+#[test]
+#[ignore = "needs nasm and cc, run with `cargo test -- --ignored`"]
+fn method_call_run_nested_stack_arguments() -> anyhow::Result<()> {
+    let code = r#"
+    extern fn printf(format: *string, value: f64): void;
+
+    fn sum(a: i32, b: i32, c: i32, d: i32, e: i32, f: i32, g: i32): i32 {
+        printf("%.1f ", 0.5_f64);
+        return a + b + c + d + e + f + g * 10;
+    }
+
+    fn main(): i32 {
+        let total: i32 = 100 + sum(1, 2, 3, 4, 5, 6, 7);
+        let twice: i32 = sum(1, 1, 1, 1, 1, 1, 1) + sum(2, 2, 2, 2, 2, 2, 2);
+        return total - twice;
+    }
+    "#;
+
+    let output = build_and_run(code)?;
+
+    assert_eq!("0.5 0.5 0.5 ", output.stdout);
+    assert_eq!(191 - 48, output.exit_code);
+    Ok(())
+}
+
+#[test]
+#[ignore = "needs nasm and cc, run with `cargo test -- --ignored`"]
+fn method_call_run_mixed_float_arguments() -> anyhow::Result<()> {
+    let code = r#"
+    extern fn printf(format: *string, value: f64): void;
+
+    fn weighted(a: i32, x: f64, b: i32, y: f64): f64 {
+        return x * 2.0_f64 + y;
+    }
+
+    printf("%.2f", weighted(1, 1.25_f64, 2, 0.5_f64));
+    "#;
+
+    let output = build_and_run(code)?;
+
+    assert_eq!("3.00", output.stdout);
+    assert_eq!(0, output.exit_code);
+    Ok(())
+}
+
+#[test]
+#[ignore = "needs nasm and cc, run with `cargo test -- --ignored`"]
+fn method_call_run_exit_code() -> anyhow::Result<()> {
+    let code = r#"
+    fn add(a: i32, b: i32): i32 {
+        return a + b;
+    }
+
+    fn main(): i32 {
+        return add(40, 2);
+    }
+    "#;
+
+    let output = build_and_run(code)?;
+
+    assert_eq!(42, output.exit_code);
+    Ok(())
+}
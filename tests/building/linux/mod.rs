@@ -0,0 +1,16 @@
+mod program_runner;
+mod method_call_test;
+mod for_loop;
+mod while_loop;
+mod mutable_reference_test;
+mod assigns;
+mod if_else;
+mod loop_control;
+mod compound_assignment;
+mod unary_operators;
+mod enums;
+mod match_statement;
+mod modules;
+mod impl_;
+mod structs;
+mod generics;
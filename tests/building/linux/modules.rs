@@ -0,0 +1,48 @@
+use crate::building::linux::program_runner::build_and_run_modules;
+
+#[test]
+#[ignore = "needs nasm and cc, run with `cargo test -- --ignored`"]
+fn namespaced_method_calls() -> anyhow::Result<()> {
+    let main = r#"
+    module io;
+    module math::arithmetic;
+    module math::arithmetic;
+
+    fn main(): i32 {
+        io::print(math::arithmetic::area(5, 6));
+        io::print(math::arithmetic::twice(21));
+        return math::arithmetic::twice(2);
+    }
+    "#;
+
+    let io = r#"
+    extern fn printf(format: *string, value: i32): void;
+
+    pub fn print(value: i32): void {
+        printf("%d ", value);
+    }
+    "#;
+
+    let arithmetic = r#"
+    module io;
+
+    fn multiply(a: i32, b: i32): i32 {
+        return a * b;
+    }
+
+    pub fn area(width: i32, height: i32): i32 {
+        io::print(width);
+        return multiply(width, height);
+    }
+
+    pub fn twice(a: i32): i32 {
+        return multiply(a, 2);
+    }
+    "#;
+
+    let output = build_and_run_modules(&[("main.monkey", main), ("io.monkey", io), ("math/arithmetic.monkey", arithmetic)])?;
+
+    assert_eq!("5 30 42 ", output.stdout);
+    assert_eq!(4, output.exit_code);
+    Ok(())
+}
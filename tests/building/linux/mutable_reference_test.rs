@@ -0,0 +1,20 @@
+use crate::building::linux::program_runner::build_and_run;
+
+#[test]
+#[ignore = "needs nasm and cc, run with `cargo test -- --ignored`"]
+pub fn mutable_ref_test() -> anyhow::Result<()> {
+    let code = r#"
+    fn mut_ref(x: mut *i32): void {
+        *x = *x + 1;
+    }
+
+    let mut a: i32 = 5;
+    mut_ref(&a);
+    return a;
+    "#;
+
+    let output = build_and_run(code)?;
+
+    assert_eq!(6, output.exit_code);
+    Ok(())
+}
@@ -0,0 +1,111 @@
+use std::path::Path;
+use std::process::Command;
+
+use monkey_language::core::code_generator::generator::ASMGenerator;
+use monkey_language::core::code_generator::target_os::TargetOS;
+use monkey_language::core::io::monkey_file::MonkeyFile;
+use crate::read_from_str;
+use monkey_language::core::io::source_map::SourceMap;
+use monkey_language::core::parser::ast_parser::ASTParser;
+use monkey_language::core::parser::module_loader::ModuleLoader;
+use monkey_language::core::semantics::static_type_check::static_type_checker::static_type_check;
+use monkey_language::core::semantics::type_infer::type_inferer::infer_type;
+
+/// Everything a finished program reported back
+#[derive(Debug)]
+pub struct ProgramOutput {
+    pub stdout: String,
+    pub exit_code: i32,
+}
+
+/// Compiles the provided source code for linux, assembles it with nasm, links it against the c runtime and runs it.
+/// Fails, if the toolchain isn't available on this machine. The tests using it are ignored by default for that reason
+pub fn build_and_run(code: &str) -> anyhow::Result<ProgramOutput> {
+    ensure_toolchain()?;
+
+    let monkey_file: MonkeyFile = read_from_str(code)?;
+    let top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
+
+    build_and_run_program(top_level_scope.result)
+}
+
+/// Writes the provided files into a temporary project and builds it like [build_and_run]. The first file is the entry point
+pub fn build_and_run_modules(files: &[(&str, &str)]) -> anyhow::Result<ProgramOutput> {
+    ensure_toolchain()?;
+
+    let directory = std::env::temp_dir().join(format!("monkey-language-{}", uuid::Uuid::new_v4()));
+
+    for (path, code) in files {
+        let path = directory.join(path);
+        std::fs::create_dir_all(path.parent().unwrap_or(&directory))?;
+        std::fs::write(path, code)?;
+    }
+
+    let top_level_scope = ModuleLoader::new(vec![]).load(directory.join(files[0].0), &mut SourceMap::default());
+    let _ = std::fs::remove_dir_all(&directory);
+
+    build_and_run_program(top_level_scope?)
+}
+
+fn ensure_toolchain() -> anyhow::Result<()> {
+    if !cfg!(target_os = "linux") {
+        anyhow::bail!("Execution tests can only run on linux");
+    }
+
+    for program in ["nasm", "cc"] {
+        if Command::new(program).arg("--version").output().is_err() {
+            anyhow::bail!("Execution tests need `{program}`, but it isn't installed on this machine");
+        }
+    }
+
+    Ok(())
+}
+
+fn build_and_run_program(mut top_level_scope: ASTParser) -> anyhow::Result<ProgramOutput> {
+    if let Some(error) = top_level_scope.diagnostics.first() {
+        return Err(error.clone().into());
+    }
+
+    infer_type(&mut top_level_scope.program)?;
+    let _ = static_type_check(&top_level_scope.program)?;
+
+    let has_main_method = top_level_scope.has_main_method;
+    let mut code_generator = ASMGenerator::from((top_level_scope.program, TargetOS::Linux, has_main_method));
+    let asm_result = code_generator.generate()?;
+
+    let directory = std::env::temp_dir().join(format!("monkey-language-{}", uuid::Uuid::new_v4()));
+    std::fs::create_dir_all(&directory)?;
+    std::fs::write(directory.join("main.asm"), asm_result)?;
+
+    let output = run_in(&directory);
+    let _ = std::fs::remove_dir_all(&directory);
+
+    output
+}
+
+fn run_in(directory: &Path) -> anyhow::Result<ProgramOutput> {
+    run_build_step(directory, "nasm", &["-felf64", "main.asm", "-o", "main.o"])?;
+    run_build_step(directory, "cc", &["-no-pie", "main.o", "-o", "main"])?;
+
+    let output = Command::new(directory.join("main"))
+        .current_dir(directory)
+        .output()?;
+
+    Ok(ProgramOutput {
+        stdout: String::from_utf8_lossy(&output.stdout).to_string(),
+        exit_code: output.status.code().ok_or(anyhow::anyhow!("Program was terminated by a signal: {}", output.status))?,
+    })
+}
+
+fn run_build_step(directory: &Path, program: &str, args: &[&str]) -> anyhow::Result<()> {
+    let output = Command::new(program)
+        .args(args)
+        .current_dir(directory)
+        .output()?;
+
+    if !output.status.success() {
+        anyhow::bail!("`{program}` failed with {}:\n{}", output.status, String::from_utf8_lossy(&output.stderr));
+    }
+
+    Ok(())
+}
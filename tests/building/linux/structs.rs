@@ -0,0 +1,131 @@
+use crate::building::linux::program_runner::build_and_run;
+
+#[test]
+#[ignore = "needs nasm and cc, run with `cargo test -- --ignored`"]
+fn struct_fields() -> anyhow::Result<()> {
+    let code = r#"
+    extern fn printf(format: *string, value: i32): void;
+
+    struct Parent { age: i32, height: i32 }
+    struct Person { age: i32, height: i32, mother: Parent }
+
+    fn main(): i32 {
+        let mut person = Person { age: 30, height: 180, mother: Parent { age: 60, height: 170 } };
+        person.age = 31;
+        person.mother.height = 165;
+
+        let h = person.mother.height;
+        printf("%d ", h);
+        printf("%d ", person.age + person.height);
+
+        let mut pointer: *Person = &person;
+        pointer.height = 181;
+        printf("%d", person.height);
+
+        return pointer.mother.age;
+    }
+    "#;
+
+    let output = build_and_run(code)?;
+
+    assert_eq!("165 211 181", output.stdout);
+    assert_eq!(60, output.exit_code);
+    Ok(())
+}
+
+#[test]
+#[ignore = "needs nasm and cc, run with `cargo test -- --ignored`"]
+fn struct_by_value() -> anyhow::Result<()> {
+    let code = r#"
+    extern fn printf(format: *string, value: i32): void;
+
+    struct Parent { age: i32 }
+    struct Person { age: i32, height: i32, mother: Parent }
+
+    fn birthday(offset: i32, person: Person): Person {
+        let mut older = person;
+        older.age = older.age + offset;
+        older.mother.age = older.mother.age + offset;
+        return older;
+    }
+
+    fn born(height: i32): Person {
+        return Person { age: 0, height: height, mother: Parent { age: 25 } };
+    }
+
+    fn main(): i32 {
+        let child = born(50);
+        let mut copy = child;
+        copy.age = 7;
+
+        let older = birthday(2, copy);
+        printf("%d ", child.age);
+        printf("%d ", copy.age);
+        printf("%d ", older.age);
+        printf("%d ", older.height);
+        printf("%d", older.mother.age);
+
+        return child.mother.age;
+    }
+    "#;
+
+    let output = build_and_run(code)?;
+
+    assert_eq!("0 7 9 50 27", output.stdout);
+    assert_eq!(25, output.exit_code);
+    Ok(())
+}
+
+#[test]
+#[ignore = "needs nasm and cc, run with `cargo test -- --ignored`"]
+fn copy_mutable_struct() -> anyhow::Result<()> {
+    let code = r#"
+    struct Inner { a: i32, b: i32 }
+    struct Outer { x: i32, inner: Inner }
+
+    fn main(): i32 {
+        let mut o = Outer { x: 1, inner: Inner { a: 2, b: 3 } };
+        let q = o;
+        return q.inner.b;
+    }
+    "#;
+
+    let output = build_and_run(code)?;
+
+    assert_eq!(3, output.exit_code);
+    Ok(())
+}
+
+#[test]
+#[ignore = "needs nasm and cc, run with `cargo test -- --ignored`"]
+fn structs_in_script_mode() -> anyhow::Result<()> {
+    let code = r#"
+    extern fn printf(format: *string, value: i32): void;
+
+    struct P { age: i32, height: i32 }
+
+    fn born(height: i32): P {
+        return P { age: 1, height: height };
+    }
+
+    fn age_of(person: *P): i32 {
+        return person.age;
+    }
+
+    fn older(person: P): i32 {
+        return person.age + 10;
+    }
+
+    let child = born(50);
+    let pointer: *P = &child;
+    printf("%d ", age_of(pointer));
+    printf("%d", older(child));
+    return child.height;
+    "#;
+
+    let output = build_and_run(code)?;
+
+    assert_eq!("1 11", output.stdout);
+    assert_eq!(50, output.exit_code);
+    Ok(())
+}
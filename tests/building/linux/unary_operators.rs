@@ -0,0 +1,80 @@
+use crate::building::linux::program_runner::build_and_run;
+
+#[test]
+#[ignore = "needs nasm and cc, run with `cargo test -- --ignored`"]
+fn integer_unary_operators() -> anyhow::Result<()> {
+    let code = r#"
+    extern fn printf(format: *string, value: i32): void;
+
+    fn main(): i32 {
+        let a = 5;
+        let b = -a;
+        printf("%d ", b);
+        printf("%d ", -(a + 2) * 3);
+        printf("%d ", a - -a);
+
+        let mask = 12;
+        printf("%d ", ~mask & 15);
+
+        let arr = [1, 2, 3];
+        printf("%d ", -arr[1]);
+
+        let c: i8 = 5;
+        let d: i8 = ~c;
+        let minus_six: i8 = -6;
+        if (d == minus_six) {
+            printf("%d", 1);
+        }
+
+        return -b;
+    }
+    "#;
+
+    let output = build_and_run(code)?;
+
+    assert_eq!("-5 -21 10 3 -2 1", output.stdout);
+    assert_eq!(5, output.exit_code);
+    Ok(())
+}
+
+#[test]
+#[ignore = "needs nasm and cc, run with `cargo test -- --ignored`"]
+fn logical_not_and_float_negation() -> anyhow::Result<()> {
+    let code = r#"
+    extern fn printf(format: *string, value: i32): void;
+
+    fn main(): i32 {
+        let a = 1;
+        let b = 2;
+        let t = a < b;
+        if (!t) {
+            printf("%d ", 0);
+        } else {
+            printf("%d ", 1);
+        }
+
+        if (!(a > b)) {
+            printf("%d ", 2);
+        }
+
+        let f = 1.5;
+        let g = -f;
+        if (g < 0.0) {
+            printf("%d ", 3);
+        }
+
+        let d: f64 = 2.25_f64;
+        if (-d * 2.0_f64 == -4.5_f64) {
+            printf("%d", 4);
+        }
+
+        return 0;
+    }
+    "#;
+
+    let output = build_and_run(code)?;
+
+    assert_eq!("1 2 3 4", output.stdout);
+    assert_eq!(0, output.exit_code);
+    Ok(())
+}
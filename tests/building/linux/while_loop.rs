@@ -0,0 +1,51 @@
+use crate::building::linux::program_runner::build_and_run;
+
+#[test]
+#[ignore = "needs nasm and cc, run with `cargo test -- --ignored`"]
+fn single_while() -> anyhow::Result<()> {
+    let code = r#"
+    let mut a = 0;
+    while (a < 5) {
+        a = a + 1;
+    }
+
+    return a;
+    "#;
+
+    let output = build_and_run(code)?;
+
+    assert_eq!("", output.stdout);
+    assert_eq!(5, output.exit_code);
+    Ok(())
+}
+
+#[test]
+#[ignore = "needs nasm and cc, run with `cargo test -- --ignored`"]
+fn while_with_condition_in_method() -> anyhow::Result<()> {
+    let code = r#"
+    extern fn printf(format: *string, value: i32): void;
+
+    fn fibonacci(n: i32): i32 {
+        let mut a = 0;
+        let mut b = 1;
+        let mut i = 0;
+
+        while (i < n) {
+            let next = a + b;
+            a = b;
+            b = next;
+            i = i + 1;
+        }
+
+        return a;
+    }
+
+    printf("%d\n", fibonacci(10));
+    "#;
+
+    let output = build_and_run(code)?;
+
+    assert_eq!("55\n", output.stdout);
+    assert_eq!(0, output.exit_code);
+    Ok(())
+}
@@ -1 +1,2 @@
 pub mod windows;
+pub mod linux;
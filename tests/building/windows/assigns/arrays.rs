@@ -1,6 +1,7 @@
 use monkey_language::core::code_generator::generator::ASMGenerator;
 use monkey_language::core::code_generator::target_os::TargetOS;
 use monkey_language::core::io::monkey_file::MonkeyFile;
+use crate::read_from_str;
 use monkey_language::core::parser::ast_parser::ASTParser;
 use monkey_language::core::semantics::static_type_check::static_type_checker::static_type_check;
 use monkey_language::core::semantics::type_infer::type_inferer::infer_type;
@@ -12,7 +13,7 @@ fn array_test_simple() -> anyhow::Result<()> {
     let b = a[0];
     "#;
 
-    let monkey_file: MonkeyFile = MonkeyFile::read_from_str(code)?;
+    let monkey_file: MonkeyFile = read_from_str(code)?;
     let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
     infer_type(&mut top_level_scope.result.program)?;
     let _ = static_type_check(&mut top_level_scope.result.program)?;
@@ -61,7 +62,7 @@ fn array_test_variable() -> anyhow::Result<()> {
     let b = a[k];
     "#;
 
-    let monkey_file: MonkeyFile = MonkeyFile::read_from_str(code)?;
+    let monkey_file: MonkeyFile = read_from_str(code)?;
     let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
     infer_type(&mut top_level_scope.result.program)?;
     let _ = static_type_check(&mut top_level_scope.result.program)?;
@@ -114,7 +115,7 @@ fn array_test_complex() -> anyhow::Result<()> {
     let b = a[12 / 3 - 3];
     "#;
 
-    let monkey_file: MonkeyFile = MonkeyFile::read_from_str(code)?;
+    let monkey_file: MonkeyFile = read_from_str(code)?;
     let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
     infer_type(&mut top_level_scope.result.program)?;
     let _ = static_type_check(&mut top_level_scope.result.program)?;
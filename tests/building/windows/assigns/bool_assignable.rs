@@ -1,6 +1,7 @@
 use monkey_language::core::code_generator::generator::ASMGenerator;
 use monkey_language::core::code_generator::target_os::TargetOS;
 use monkey_language::core::io::monkey_file::MonkeyFile;
+use crate::read_from_str;
 use monkey_language::core::parser::ast_parser::ASTParser;
 use monkey_language::core::semantics::static_type_check::static_type_checker::static_type_check;
 use monkey_language::core::semantics::type_infer::type_inferer::infer_type;
@@ -13,7 +14,7 @@ fn expression_assign() -> anyhow::Result<()> {
     let c: bool = a | b;
     "#;
 
-    let monkey_file: MonkeyFile = MonkeyFile::read_from_str(code)?;
+    let monkey_file: MonkeyFile = read_from_str(code)?;
     let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
     infer_type(&mut top_level_scope.result.program)?;
     let _ = static_type_check(&mut top_level_scope.result.program)?;
@@ -55,7 +56,7 @@ main:
     let a: bool = true | false;
     "#;
 
-    let monkey_file: MonkeyFile = MonkeyFile::read_from_str(code)?;
+    let monkey_file: MonkeyFile = read_from_str(code)?;
     let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
     infer_type(&mut top_level_scope.result.program)?;
     let _ = static_type_check(&mut top_level_scope.result.program)?;
@@ -93,7 +94,7 @@ main:
     let a: bool = (true | true) | false;
     "#;
 
-    let monkey_file: MonkeyFile = MonkeyFile::read_from_str(code)?;
+    let monkey_file: MonkeyFile = read_from_str(code)?;
     let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
     infer_type(&mut top_level_scope.result.program)?;
     let _ = static_type_check(&mut top_level_scope.result.program)?;
@@ -133,7 +134,7 @@ main:
     let a: bool = false | (true | true);
     "#;
 
-    let monkey_file: MonkeyFile = MonkeyFile::read_from_str(code)?;
+    let monkey_file: MonkeyFile = read_from_str(code)?;
     let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
     infer_type(&mut top_level_scope.result.program)?;
     let _ = static_type_check(&mut top_level_scope.result.program)?;
@@ -174,7 +175,7 @@ main:
     let a: bool = (true | true) | (false | false);
     "#;
 
-    let monkey_file: MonkeyFile = MonkeyFile::read_from_str(code)?;
+    let monkey_file: MonkeyFile = read_from_str(code)?;
     let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
     infer_type(&mut top_level_scope.result.program)?;
     let _ = static_type_check(&mut top_level_scope.result.program)?;
@@ -219,7 +220,7 @@ main:
     let a: bool = true;
     "#;
 
-    let monkey_file: MonkeyFile = MonkeyFile::read_from_str(code)?;
+    let monkey_file: MonkeyFile = read_from_str(code)?;
     let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
     infer_type(&mut top_level_scope.result.program)?;
     let _ = static_type_check(&mut top_level_scope.result.program)?;
@@ -253,7 +254,7 @@ main:
     let a: bool = (true);
     "#;
 
-    let monkey_file: MonkeyFile = MonkeyFile::read_from_str(code)?;
+    let monkey_file: MonkeyFile = read_from_str(code)?;
     let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
     infer_type(&mut top_level_scope.result.program)?;
     let _ = static_type_check(&mut top_level_scope.result.program)?;
@@ -292,7 +293,7 @@ fn pointer_assign_test() -> anyhow::Result<()> {
     let b: *bool = &a;
     "#;
 
-    let monkey_file: MonkeyFile = MonkeyFile::read_from_str(code)?;
+    let monkey_file: MonkeyFile = read_from_str(code)?;
     let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
     infer_type(&mut top_level_scope.result.program)?;
     let _ = static_type_check(&mut top_level_scope.result.program)?;
@@ -340,7 +341,7 @@ let f: bool = *d;
 let g: bool = **c;
     "#;
 
-    let monkey_file: MonkeyFile = MonkeyFile::read_from_str(code)?;
+    let monkey_file: MonkeyFile = read_from_str(code)?;
     let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
     infer_type(&mut top_level_scope.result.program)?;
     let _ = static_type_check(&mut top_level_scope.result.program)?;
@@ -402,7 +403,7 @@ let b: *bool = &a;
 let and = *b & false;
     "#;
 
-    let monkey_file: MonkeyFile = MonkeyFile::read_from_str(code)?;
+    let monkey_file: MonkeyFile = read_from_str(code)?;
     let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
     infer_type(&mut top_level_scope.result.program)?;
     let _ = static_type_check(&mut top_level_scope.result.program)?;
@@ -451,7 +452,7 @@ let b: *bool = &a;
 let addition = true | *b;
     "#;
 
-    let monkey_file: MonkeyFile = MonkeyFile::read_from_str(code)?;
+    let monkey_file: MonkeyFile = read_from_str(code)?;
     let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
     infer_type(&mut top_level_scope.result.program)?;
     let _ = static_type_check(&mut top_level_scope.result.program)?;
@@ -503,7 +504,7 @@ let b: *bool = &a;
 let addition = *b | *b;
     "#;
 
-    let monkey_file: MonkeyFile = MonkeyFile::read_from_str(code)?;
+    let monkey_file: MonkeyFile = read_from_str(code)?;
     let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
     infer_type(&mut top_level_scope.result.program)?;
     let _ = static_type_check(&mut top_level_scope.result.program)?;
@@ -555,7 +556,7 @@ let b: *bool = &a;
 let addition = *b | (false | true);
     "#;
 
-    let monkey_file: MonkeyFile = MonkeyFile::read_from_str(code)?;
+    let monkey_file: MonkeyFile = read_from_str(code)?;
     let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
     infer_type(&mut top_level_scope.result.program)?;
     let _ = static_type_check(&mut top_level_scope.result.program)?;
@@ -607,7 +608,7 @@ let b: *bool = &a;
 let addition = (false | true) | *b;
     "#;
 
-    let monkey_file: MonkeyFile = MonkeyFile::read_from_str(code)?;
+    let monkey_file: MonkeyFile = read_from_str(code)?;
     let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
     infer_type(&mut top_level_scope.result.program)?;
     let _ = static_type_check(&mut top_level_scope.result.program)?;
@@ -660,7 +661,7 @@ let b: *bool = &a;
 let addition = (*b | *b) & (*b | *b);
     "#;
 
-    let monkey_file: MonkeyFile = MonkeyFile::read_from_str(code)?;
+    let monkey_file: MonkeyFile = read_from_str(code)?;
     let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
     infer_type(&mut top_level_scope.result.program)?;
     let _ = static_type_check(&mut top_level_scope.result.program)?;
@@ -726,7 +727,7 @@ let d: *bool = &c;
 let addition = (((*d | *b) | (*b | *d)) | (*b | *b)) | ((*b | (*b | *b)) | (*b | (*d | *b)));
     "#;
 
-    let monkey_file: MonkeyFile = MonkeyFile::read_from_str(code)?;
+    let monkey_file: MonkeyFile = read_from_str(code)?;
     let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
     infer_type(&mut top_level_scope.result.program)?;
     let _ = static_type_check(&mut top_level_scope.result.program)?;
@@ -848,7 +849,7 @@ fn single_expression_test() -> anyhow::Result<()> {
     let b: bool = a;
     "#;
 
-    let monkey_file: MonkeyFile = MonkeyFile::read_from_str(code)?;
+    let monkey_file: MonkeyFile = read_from_str(code)?;
     let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
     infer_type(&mut top_level_scope.result.program)?;
     let _ = static_type_check(&mut top_level_scope.result.program)?;
@@ -887,7 +888,7 @@ main:
     let b: bool = (a);
     "#;
 
-    let monkey_file: MonkeyFile = MonkeyFile::read_from_str(code)?;
+    let monkey_file: MonkeyFile = read_from_str(code)?;
     let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
     infer_type(&mut top_level_scope.result.program)?;
     let _ = static_type_check(&mut top_level_scope.result.program)?;
@@ -908,7 +909,7 @@ fn bool_assign() -> anyhow::Result<()> {
     let a: bool = false;
     "#;
 
-    let monkey_file: MonkeyFile = MonkeyFile::read_from_str(code)?;
+    let monkey_file: MonkeyFile = read_from_str(code)?;
     let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
     infer_type(&mut top_level_scope.result.program)?;
     let _ = static_type_check(&mut top_level_scope.result.program)?;
@@ -1,6 +1,7 @@
 use monkey_language::core::code_generator::generator::ASMGenerator;
 use monkey_language::core::code_generator::target_os::TargetOS;
 use monkey_language::core::io::monkey_file::MonkeyFile;
+use crate::read_from_str;
 use monkey_language::core::parser::ast_parser::ASTParser;
 use monkey_language::core::semantics::static_type_check::static_type_checker::static_type_check;
 use monkey_language::core::semantics::type_infer::type_inferer::infer_type;
@@ -15,7 +16,7 @@ fn bool_to_i32() -> anyhow::Result<()> {
     let d: i32 = (i32) b;
     "#;
 
-    let monkey_file: MonkeyFile = MonkeyFile::read_from_str(code)?;
+    let monkey_file: MonkeyFile = read_from_str(code)?;
     let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
     infer_type(&mut top_level_scope.result.program)?;
     let _ = static_type_check(&mut top_level_scope.result.program)?;
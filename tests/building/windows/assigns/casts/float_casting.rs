@@ -1,6 +1,7 @@
 use monkey_language::core::code_generator::generator::ASMGenerator;
 use monkey_language::core::code_generator::target_os::TargetOS;
 use monkey_language::core::io::monkey_file::MonkeyFile;
+use crate::read_from_str;
 use monkey_language::core::parser::ast_parser::ASTParser;
 use monkey_language::core::semantics::static_type_check::static_type_checker::static_type_check;
 use monkey_language::core::semantics::type_infer::type_inferer::infer_type;
@@ -12,7 +13,7 @@ let a: f64 = 5.0_f64;
 let b: f32 = (f32) a;
     "#;
 
-    let monkey_file: MonkeyFile = MonkeyFile::read_from_str(code)?;
+    let monkey_file: MonkeyFile = read_from_str(code)?;
     let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
     infer_type(&mut top_level_scope.result.program)?;
     let _ = static_type_check(&mut top_level_scope.result.program)?;
@@ -62,7 +63,7 @@ let a: f32 = 5.0;
 let s = (f64)a;
     "#;
 
-    let monkey_file: MonkeyFile = MonkeyFile::read_from_str(code)?;
+    let monkey_file: MonkeyFile = read_from_str(code)?;
     let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
     infer_type(&mut top_level_scope.result.program)?;
     let _ = static_type_check(&mut top_level_scope.result.program)?;
@@ -112,7 +113,7 @@ let a: f64 = 5.0_f64;
 let s = (f64)(f32)a;
     "#;
 
-    let monkey_file: MonkeyFile = MonkeyFile::read_from_str(code)?;
+    let monkey_file: MonkeyFile = read_from_str(code)?;
     let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
     infer_type(&mut top_level_scope.result.program)?;
     let _ = static_type_check(&mut top_level_scope.result.program)?;
@@ -168,7 +169,7 @@ let b: f32 = (f32)(a + 1.0_f64);
 let s = (f64) b;
     "#;
 
-    let monkey_file: MonkeyFile = MonkeyFile::read_from_str(code)?;
+    let monkey_file: MonkeyFile = read_from_str(code)?;
     let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
     infer_type(&mut top_level_scope.result.program)?;
     let _ = static_type_check(&mut top_level_scope.result.program)?;
@@ -233,7 +234,7 @@ let b: f32 = ((f32)a + 1.0_f32);
 let s = (f64) b;
     "#;
 
-    let monkey_file: MonkeyFile = MonkeyFile::read_from_str(code)?;
+    let monkey_file: MonkeyFile = read_from_str(code)?;
     let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
     infer_type(&mut top_level_scope.result.program)?;
     let _ = static_type_check(&mut top_level_scope.result.program)?;
@@ -298,7 +299,7 @@ let c: f32 = ((f32) a + (f32)b);
 let s = (f64) c;
     "#;
 
-    let monkey_file: MonkeyFile = MonkeyFile::read_from_str(code)?;
+    let monkey_file: MonkeyFile = read_from_str(code)?;
     let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
     infer_type(&mut top_level_scope.result.program)?;
     let _ = static_type_check(&mut top_level_scope.result.program)?;
@@ -367,7 +368,7 @@ let b: f32 = (1.0_f32 + (f32)a);
 let s = (f64) b;
     "#;
 
-    let monkey_file: MonkeyFile = MonkeyFile::read_from_str(code)?;
+    let monkey_file: MonkeyFile = read_from_str(code)?;
     let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
     infer_type(&mut top_level_scope.result.program)?;
     let _ = static_type_check(&mut top_level_scope.result.program)?;
@@ -430,7 +431,7 @@ let b: f32 = (f32)a + (1.0_f32 + 5.1_f32);
 let s = (f64) b;
     "#;
 
-    let monkey_file: MonkeyFile = MonkeyFile::read_from_str(code)?;
+    let monkey_file: MonkeyFile = read_from_str(code)?;
     let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
     infer_type(&mut top_level_scope.result.program)?;
     let _ = static_type_check(&mut top_level_scope.result.program)?;
@@ -498,7 +499,7 @@ let b: f32 = (1.0_f32 + 5.1_f32) + (f32)a;
 let s = (f64) b;
     "#;
 
-    let monkey_file: MonkeyFile = MonkeyFile::read_from_str(code)?;
+    let monkey_file: MonkeyFile = read_from_str(code)?;
     let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
     infer_type(&mut top_level_scope.result.program)?;
     let _ = static_type_check(&mut top_level_scope.result.program)?;
@@ -565,7 +566,7 @@ let b: f32 = ((f32)a + (f32)a) + ((f32)a + (f32)a);
 let s = (f64) b;
     "#;
 
-    let monkey_file: MonkeyFile = MonkeyFile::read_from_str(code)?;
+    let monkey_file: MonkeyFile = read_from_str(code)?;
     let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
     infer_type(&mut top_level_scope.result.program)?;
     let _ = static_type_check(&mut top_level_scope.result.program)?;
@@ -648,7 +649,7 @@ let addition: f32 = ((((f32)d + (f32)b) + ((f32)b + (f32)d)) + ((f32)b + (f32)b)
 let s = (f64) addition;
     "#;
 
-    let monkey_file: MonkeyFile = MonkeyFile::read_from_str(code)?;
+    let monkey_file: MonkeyFile = read_from_str(code)?;
     let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
     infer_type(&mut top_level_scope.result.program)?;
     let _ = static_type_check(&mut top_level_scope.result.program)?;
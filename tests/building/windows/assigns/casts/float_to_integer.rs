@@ -1,6 +1,7 @@
 use monkey_language::core::code_generator::generator::ASMGenerator;
 use monkey_language::core::code_generator::target_os::TargetOS;
 use monkey_language::core::io::monkey_file::MonkeyFile;
+use crate::read_from_str;
 use monkey_language::core::parser::ast_parser::ASTParser;
 use monkey_language::core::semantics::static_type_check::static_type_checker::static_type_check;
 use monkey_language::core::semantics::type_infer::type_inferer::infer_type;
@@ -21,7 +22,7 @@ fn f32_to_u() -> anyhow::Result<()> {
     let h: u64 = (u64)g;
     "#;
 
-    let monkey_file: MonkeyFile = MonkeyFile::read_from_str(code)?;
+    let monkey_file: MonkeyFile = read_from_str(code)?;
     let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
     infer_type(&mut top_level_scope.result.program)?;
     let _ = static_type_check(&mut top_level_scope.result.program)?;
@@ -112,7 +113,7 @@ fn f32_to_i() -> anyhow::Result<()> {
     let h: i64 = (i64)g;
     "#;
 
-    let monkey_file: MonkeyFile = MonkeyFile::read_from_str(code)?;
+    let monkey_file: MonkeyFile = read_from_str(code)?;
     let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
     infer_type(&mut top_level_scope.result.program)?;
     let _ = static_type_check(&mut top_level_scope.result.program)?;
@@ -202,7 +203,7 @@ fn f64_to_u() -> anyhow::Result<()> {
     let h: u64 = (u64)g;
     "#;
 
-    let monkey_file: MonkeyFile = MonkeyFile::read_from_str(code)?;
+    let monkey_file: MonkeyFile = read_from_str(code)?;
     let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
     infer_type(&mut top_level_scope.result.program)?;
     let _ = static_type_check(&mut top_level_scope.result.program)?;
@@ -290,7 +291,7 @@ fn f64_to_i() -> anyhow::Result<()> {
     let h: i64 = (i64)g;
     "#;
 
-    let monkey_file: MonkeyFile = MonkeyFile::read_from_str(code)?;
+    let monkey_file: MonkeyFile = read_from_str(code)?;
     let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
     infer_type(&mut top_level_scope.result.program)?;
     let _ = static_type_check(&mut top_level_scope.result.program)?;
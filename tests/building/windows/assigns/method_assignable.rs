@@ -1,6 +1,7 @@
 use monkey_language::core::code_generator::generator::{ASMGenerator};
 use monkey_language::core::code_generator::target_os::TargetOS;
 use monkey_language::core::io::monkey_file::MonkeyFile;
+use crate::read_from_str;
 use monkey_language::core::parser::ast_parser::ASTParser;
 use monkey_language::core::semantics::static_type_check::static_type_checker::static_type_check;
 use monkey_language::core::semantics::type_infer::type_inferer::infer_type;
@@ -17,7 +18,7 @@ fn expression_assign() -> anyhow::Result<()> {
     let c: i32 = integer_return() + (i32) b;
     "#;
 
-    let monkey_file: MonkeyFile = MonkeyFile::read_from_str(code)?;
+    let monkey_file: MonkeyFile = read_from_str(code)?;
     let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
     infer_type(&mut top_level_scope.result.program)?;
     let _ = static_type_check(&mut top_level_scope.result.program)?;
@@ -78,8 +79,10 @@ main:
     push rcx
     push rdi
     push rdx
+    sub rsp, 8
     ; integer_return()
     call .integer_return_void~i32
+    add rsp, 8
     ; PopQ
     pop rdx
     pop rdi
@@ -114,7 +117,7 @@ main:
     let b: f64 = float_f64_5() + float_f64_3();
     "#;
 
-    let monkey_file: MonkeyFile = MonkeyFile::read_from_str(code)?;
+    let monkey_file: MonkeyFile = read_from_str(code)?;
     let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
     infer_type(&mut top_level_scope.result.program)?;
     let _ = static_type_check(&mut top_level_scope.result.program)?;
@@ -193,8 +196,10 @@ main:
     push rcx
     push rdi
     push rdx
+    sub rsp, 8
     ; int_5()
     call .int_5_void~i32
+    add rsp, 8
     ; PopQ
     pop rdx
     pop rdi
@@ -203,8 +208,10 @@ main:
     push rax
     push rcx
     push rdi
+    sub rsp, 8
     ; int_3()
     call .int_3_void~i32
+    add rsp, 8
     mov edx, eax
     ; PopQ
     pop rdi
@@ -218,8 +225,10 @@ main:
     push rcx
     push rdi
     push rdx
+    sub rsp, 8
     ; float_5()
     call .float_5_void~f32
+    add rsp, 8
     ; PopQ
     pop rdx
     pop rdi
@@ -229,8 +238,10 @@ main:
     push rax
     push rcx
     push rdi
+    sub rsp, 8
     ; float_3()
     call .float_3_void~f32
+    add rsp, 8
     mov edx, eax
     ; PopQ
     pop rdi
@@ -246,8 +257,10 @@ main:
     push rcx
     push rdi
     push rdx
+    sub rsp, 8
     ; float_f64_5()
     call .float_f64_5_void~f64
+    add rsp, 8
     ; PopQ
     pop rdx
     pop rdi
@@ -257,8 +270,10 @@ main:
     push rax
     push rcx
     push rdi
+    sub rsp, 8
     ; float_f64_3()
     call .float_f64_3_void~f64
+    add rsp, 8
     mov rdx, rax
     ; PopQ
     pop rdi
@@ -287,7 +302,7 @@ fn pointer_assign_test() -> anyhow::Result<()> {
     let b: *f64 = &a;
     "#;
 
-    let monkey_file: MonkeyFile = MonkeyFile::read_from_str(code)?;
+    let monkey_file: MonkeyFile = read_from_str(code)?;
     let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
     infer_type(&mut top_level_scope.result.program)?;
     let _ = static_type_check(&mut top_level_scope.result.program)?;
@@ -349,7 +364,7 @@ let g: f64 = **c;
 let h: *f64 = &r();
 "#;
 
-    let monkey_file: MonkeyFile = MonkeyFile::read_from_str(code)?;
+    let monkey_file: MonkeyFile = read_from_str(code)?;
     let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
     infer_type(&mut top_level_scope.result.program)?;
     let _ = static_type_check(&mut top_level_scope.result.program)?;
@@ -410,8 +425,10 @@ main:
     push rcx
     push rdi
     push rdx
+    sub rsp, 8
     ; r()
     call .r_void~f64
+    add rsp, 8
     ; PopQ
     pop rdx
     pop rdi
@@ -439,7 +456,7 @@ let b: *f64 = &a;
 let addition = *b + f2();
     "#;
 
-    let monkey_file: MonkeyFile = MonkeyFile::read_from_str(code)?;
+    let monkey_file: MonkeyFile = read_from_str(code)?;
     let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
     infer_type(&mut top_level_scope.result.program)?;
     let _ = static_type_check(&mut top_level_scope.result.program)?;
@@ -492,8 +509,10 @@ main:
     push rax
     push rcx
     push rdi
+    sub rsp, 8
     ; f2()
     call .f2_void~f64
+    add rsp, 8
     mov rdx, rax
     ; PopQ
     pop rdi
@@ -525,7 +544,7 @@ let b: *f64 = &a;
 let addition = f2() + *b;
     "#;
 
-    let monkey_file: MonkeyFile = MonkeyFile::read_from_str(code)?;
+    let monkey_file: MonkeyFile = read_from_str(code)?;
     let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
     infer_type(&mut top_level_scope.result.program)?;
     let _ = static_type_check(&mut top_level_scope.result.program)?;
@@ -578,8 +597,10 @@ main:
     push rcx
     push rdi
     push rdx
+    sub rsp, 8
     ; f2()
     call .f2_void~f64
+    add rsp, 8
     ; PopQ
     pop rdx
     pop rdi
@@ -609,7 +630,7 @@ fn f1(): f64 { return 5.0_f64; }
 let addition = f1() + f1();
     "#;
 
-    let monkey_file: MonkeyFile = MonkeyFile::read_from_str(code)?;
+    let monkey_file: MonkeyFile = read_from_str(code)?;
     let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
     infer_type(&mut top_level_scope.result.program)?;
     let _ = static_type_check(&mut top_level_scope.result.program)?;
@@ -646,8 +667,10 @@ main:
     push rcx
     push rdi
     push rdx
+    sub rsp, 8
     ; f1()
     call .f1_void~f64
+    add rsp, 8
     ; PopQ
     pop rdx
     pop rdi
@@ -657,8 +680,10 @@ main:
     push rax
     push rcx
     push rdi
+    sub rsp, 8
     ; f1()
     call .f1_void~f64
+    add rsp, 8
     mov rdx, rax
     ; PopQ
     pop rdi
@@ -686,7 +711,7 @@ let a: f64 = f1();
 let addition = f1() + (a + a);
     "#;
 
-    let monkey_file: MonkeyFile = MonkeyFile::read_from_str(code)?;
+    let monkey_file: MonkeyFile = read_from_str(code)?;
     let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
     infer_type(&mut top_level_scope.result.program)?;
     let _ = static_type_check(&mut top_level_scope.result.program)?;
@@ -731,8 +756,10 @@ main:
     push rcx
     push rdi
     push rdx
+    sub rsp, 8
     ; f1()
     call .f1_void~f64
+    add rsp, 8
     ; PopQ
     pop rdx
     pop rdi
@@ -760,7 +787,7 @@ let a: f64 = f1();
 let addition = (a + a) + f1();
     "#;
 
-    let monkey_file: MonkeyFile = MonkeyFile::read_from_str(code)?;
+    let monkey_file: MonkeyFile = read_from_str(code)?;
     let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
     infer_type(&mut top_level_scope.result.program)?;
     let _ = static_type_check(&mut top_level_scope.result.program)?;
@@ -807,8 +834,10 @@ main:
     push rax
     push rcx
     push rdi
+    sub rsp, 8
     ; f1()
     call .f1_void~f64
+    add rsp, 8
     mov rdx, rax
     ; PopQ
     pop rdi
@@ -837,7 +866,7 @@ let a2 = f1() + (f1() + f1());
 let a3 = (f1() + f1()) + (f1() + f1());
     "#;
 
-    let monkey_file: MonkeyFile = MonkeyFile::read_from_str(code)?;
+    let monkey_file: MonkeyFile = read_from_str(code)?;
     let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
     infer_type(&mut top_level_scope.result.program)?;
     let _ = static_type_check(&mut top_level_scope.result.program)?;
@@ -875,8 +904,10 @@ main:
     push rcx
     push rdi
     push rdx
+    sub rsp, 8
     ; f1()
     call .f1_void~f64
+    add rsp, 8
     ; PopQ
     pop rdx
     pop rdi
@@ -886,8 +917,10 @@ main:
     push rax
     push rcx
     push rdi
+    sub rsp, 8
     ; f1()
     call .f1_void~f64
+    add rsp, 8
     mov rdx, rax
     ; PopQ
     pop rdi
@@ -899,8 +932,10 @@ main:
     push rax
     push rcx
     push rdi
+    sub rsp, 8
     ; f1()
     call .f1_void~f64
+    add rsp, 8
     mov rdx, rax
     ; PopQ
     pop rdi
@@ -917,8 +952,10 @@ main:
     push rcx
     push rdi
     push rdx
+    sub rsp, 8
     ; f1()
     call .f1_void~f64
+    add rsp, 8
     ; PopQ
     pop rdx
     pop rdi
@@ -928,8 +965,10 @@ main:
     push rax
     push rcx
     push rdi
+    sub rsp, 8
     ; f1()
     call .f1_void~f64
+    add rsp, 8
     mov rdx, rax
     ; PopQ
     pop rdi
@@ -942,8 +981,10 @@ main:
     push rcx
     push rdi
     push rdx
+    sub rsp, 8
     ; f1()
     call .f1_void~f64
+    add rsp, 8
     ; PopQ
     pop rdx
     pop rdi
@@ -959,8 +1000,10 @@ main:
     push rcx
     push rdi
     push rdx
+    sub rsp, 8
     ; f1()
     call .f1_void~f64
+    add rsp, 8
     ; PopQ
     pop rdx
     pop rdi
@@ -970,8 +1013,10 @@ main:
     push rax
     push rcx
     push rdi
+    sub rsp, 8
     ; f1()
     call .f1_void~f64
+    add rsp, 8
     mov rdx, rax
     ; PopQ
     pop rdi
@@ -985,8 +1030,10 @@ main:
     push rcx
     push rdi
     push rdx
+    sub rsp, 8
     ; f1()
     call .f1_void~f64
+    add rsp, 8
     ; PopQ
     pop rdx
     pop rdi
@@ -996,8 +1043,10 @@ main:
     push rax
     push rcx
     push rdi
+    sub rsp, 8
     ; f1()
     call .f1_void~f64
+    add rsp, 8
     mov rdx, rax
     ; PopQ
     pop rdi
@@ -1028,7 +1077,7 @@ fn f2(): f64 { return 5.0_f64; }
 let addition = (((f1() + f2()) + (f2() + f1())) + (f2() + f2())) + ((f2() + (f2() + f2())) + (f2() + (f1() + f2())));
     "#;
 
-    let monkey_file: MonkeyFile = MonkeyFile::read_from_str(code)?;
+    let monkey_file: MonkeyFile = read_from_str(code)?;
     let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
     infer_type(&mut top_level_scope.result.program)?;
     let _ = static_type_check(&mut top_level_scope.result.program)?;
@@ -1077,8 +1126,10 @@ main:
     push rcx
     push rdi
     push rdx
+    sub rsp, 8
     ; f1()
     call .f1_void~f64
+    add rsp, 8
     ; PopQ
     pop rdx
     pop rdi
@@ -1088,8 +1139,10 @@ main:
     push rax
     push rcx
     push rdi
+    sub rsp, 8
     ; f2()
     call .f2_void~f64
+    add rsp, 8
     mov rdx, rax
     ; PopQ
     pop rdi
@@ -1103,8 +1156,10 @@ main:
     push rcx
     push rdi
     push rdx
+    sub rsp, 8
     ; f2()
     call .f2_void~f64
+    add rsp, 8
     ; PopQ
     pop rdx
     pop rdi
@@ -1114,8 +1169,10 @@ main:
     push rax
     push rcx
     push rdi
+    sub rsp, 8
     ; f1()
     call .f1_void~f64
+    add rsp, 8
     mov rdx, rax
     ; PopQ
     pop rdi
@@ -1216,8 +1273,10 @@ main:
     push rcx
     push rdi
     push rdx
+    sub rsp, 8
     ; f1()
     call .f1_void~f64
+    add rsp, 8
     ; PopQ
     pop rdx
     pop rdi
@@ -1227,8 +1286,10 @@ main:
     push rax
     push rcx
     push rdi
+    sub rsp, 8
     ; f2()
     call .f2_void~f64
+    add rsp, 8
     mov rdx, rax
     ; PopQ
     pop rdi
@@ -1241,8 +1302,10 @@ main:
     push rcx
     push rdi
     push rdx
+    sub rsp, 8
     ; f2()
     call .f2_void~f64
+    add rsp, 8
     ; PopQ
     pop rdx
     pop rdi
@@ -1286,7 +1349,7 @@ fn f1(): f64 { return 5.0_f64; }
     let b: f64 = a;
     "#;
 
-    let monkey_file: MonkeyFile = MonkeyFile::read_from_str(code)?;
+    let monkey_file: MonkeyFile = read_from_str(code)?;
     let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
     infer_type(&mut top_level_scope.result.program)?;
     let _ = static_type_check(&mut top_level_scope.result.program)?;
@@ -1337,7 +1400,7 @@ fn f1(): f64 { return 5.0_f64; }
     let b: f64 = (a);
     "#;
 
-    let monkey_file: MonkeyFile = MonkeyFile::read_from_str(code)?;
+    let monkey_file: MonkeyFile = read_from_str(code)?;
     let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
     infer_type(&mut top_level_scope.result.program)?;
     let _ = static_type_check(&mut top_level_scope.result.program)?;
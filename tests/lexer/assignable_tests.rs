@@ -1,11 +1,14 @@
 use std::str::FromStr;
 use monkey_language::core::io::monkey_file::MonkeyFile;
+use crate::read_from_str;
+use monkey_language::core::lexer::error::Error;
+use monkey_language::core::lexer::token_with_span::FilePosition;
 use monkey_language::core::lexer::parse::{Parse, ParseOptions};
 use monkey_language::core::model::abstract_syntax_tree_nodes::assignables::equation_parser::expression::Expression;
 use monkey_language::core::model::abstract_syntax_tree_nodes::assignables::method_call::MethodCall;
 use monkey_language::core::model::types::boolean::Boolean;
-use monkey_language::core::model::types::float::FloatAST;
-use monkey_language::core::model::types::integer::{IntegerAST};
+use monkey_language::core::model::types::float::{FloatAST, FloatType};
+use monkey_language::core::model::types::integer::{IntegerAST, IntegerType};
 use monkey_language::core::model::types::static_string::StaticString;
 
 #[test]
@@ -25,7 +28,7 @@ fn assignable_string() -> anyhow::Result<()> {
     ];
 
     for (expected_result, value) in &values {
-        let monkey_file: MonkeyFile = MonkeyFile::read_from_str(value)?;
+        let monkey_file: MonkeyFile = read_from_str(value)?;
         let integer = StaticString::parse(&monkey_file.tokens, ParseOptions::default());
         if !*expected_result {
             println!("{}", integer.err().unwrap());
@@ -37,6 +40,50 @@ fn assignable_string() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn assignable_string_escapes() -> anyhow::Result<()> {
+    let values = vec![
+        (r#""tab\tnew line\n""#, "tab\tnew line\n"),
+        (r#""\"quoted\" \\ \0""#, "\"quoted\" \\ \0"),
+        (r#""\x41\x7f""#, "A\x7f"),
+        (r#"r"raw\n\x41""#, "raw\\n\\x41"),
+    ];
+
+    for (value, expected) in values {
+        let monkey_file: MonkeyFile = read_from_str(value)?;
+        let string = StaticString::parse(&monkey_file.tokens, ParseOptions::default())?;
+
+        assert_eq!(1, monkey_file.tokens.len());
+        assert_eq!(expected, string.result.content()?);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn assignable_string_invalid_escapes() -> anyhow::Result<()> {
+    let values = vec![
+        (r#""\q""#, "\\q", 2..=3, 1..3),
+        (r#""ok \x4""#, "\\x4", 5..=7, 4..7),
+        (r#""\x80""#, "\\x80", 2..=5, 1..5),
+    ];
+
+    for (value, expected_sequence, expected_column, expected_offset) in values {
+        let monkey_file: MonkeyFile = read_from_str(value)?;
+        let error = StaticString::parse(&monkey_file.tokens, ParseOptions::default());
+
+        match error {
+            Err(Error::InvalidEscapeSequence(sequence, file_position)) => {
+                assert_eq!(expected_sequence, sequence);
+                assert_eq!(FilePosition { line: 1..=1, column: expected_column, offset: expected_offset, ..Default::default() }, file_position);
+            }
+            other => panic!("Expected an invalid escape sequence, got: {:?}", other.map(|r| r.result)),
+        }
+    }
+
+    Ok(())
+}
+
 #[test]
 fn assignable_integer() -> anyhow::Result<()> {
     let values: Vec<(bool, String)> = vec![
@@ -55,7 +102,7 @@ fn assignable_integer() -> anyhow::Result<()> {
     ];
 
     for (expected_result, value) in &values {
-        let monkey_file: MonkeyFile = MonkeyFile::read_from_str(value)?;
+        let monkey_file: MonkeyFile = read_from_str(value)?;
         let integer = IntegerAST::parse(&monkey_file.tokens, ParseOptions::default());
 
         if !*expected_result {
@@ -68,6 +115,97 @@ fn assignable_integer() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn assignable_integer_literal_forms() -> anyhow::Result<()> {
+    let values = vec![
+        ("0xFF", "255", IntegerType::I32),
+        ("0b1010", "10", IntegerType::I32),
+        ("0o755", "493", IntegerType::I32),
+        ("1_000_000", "1000000", IntegerType::I32),
+        ("10u8", "10", IntegerType::U8),
+        ("5i64", "5", IntegerType::I64),
+        ("5_i64", "5", IntegerType::I64),
+        ("0xFF_u16", "255", IntegerType::U16),
+        ("-0x10", "-16", IntegerType::I32),
+        ("0xFFFF_FFFF", "4294967295", IntegerType::I64),
+        ("18446744073709551615u64", "18446744073709551615", IntegerType::U64),
+    ];
+
+    for (value, expected_value, expected_type) in values {
+        let monkey_file: MonkeyFile = read_from_str(value)?;
+        let integer = IntegerAST::parse(&monkey_file.tokens, ParseOptions::default())?;
+
+        assert_eq!(IntegerAST { value: expected_value.to_string(), ty: expected_type }, integer.result, "{value}");
+    }
+
+    for value in ["0x", "0b102", "0o8", "10u7", "18446744073709551615"] {
+        assert!(IntegerAST::from_str(value).is_err(), "{value}");
+    }
+
+    Ok(())
+}
+
+#[test]
+fn assignable_float_literal_forms() -> anyhow::Result<()> {
+    let values = vec![
+        ("2.5f64", 2.5, FloatType::Float64),
+        ("2.5_f32", 2.5, FloatType::Float32),
+        ("2f64", 2.0, FloatType::Float64),
+        ("2_f32", 2.0, FloatType::Float32),
+        ("1_000.25", 1000.25, FloatType::Float32),
+        (".5f64", 0.5, FloatType::Float64),
+        ("-1.5_f64", -1.5, FloatType::Float64),
+    ];
+
+    for (value, expected_value, expected_type) in values {
+        let monkey_file: MonkeyFile = read_from_str(value)?;
+        let float = FloatAST::parse(&monkey_file.tokens, ParseOptions::default())?;
+
+        assert_eq!(FloatAST { value: expected_value, ty: expected_type }, float.result, "{value}");
+        assert_eq!(monkey_file.tokens.len(), float.consumed, "{value}");
+    }
+
+    for value in ["0x1.5", "2i64.5", "f64"] {
+        let monkey_file: MonkeyFile = read_from_str(value)?;
+        assert!(FloatAST::parse(&monkey_file.tokens, ParseOptions::default()).is_err(), "{value}");
+    }
+
+    Ok(())
+}
+
+#[test]
+fn assignable_char() -> anyhow::Result<()> {
+    let values = vec![
+        ("'a'", "97"),
+        ("'\\n'", "10"),
+        ("'\\''", "39"),
+        ("'\"'", "34"),
+        ("' '", "32"),
+        ("'\\x41'", "65"),
+    ];
+
+    for (value, expected_value) in values {
+        let monkey_file: MonkeyFile = read_from_str(value)?;
+        let integer = IntegerAST::parse(&monkey_file.tokens, ParseOptions::default())?;
+
+        assert_eq!(1, monkey_file.tokens.len(), "{value}");
+        assert_eq!(IntegerAST { value: expected_value.to_string(), ty: IntegerType::U8 }, integer.result, "{value}");
+    }
+
+    for value in ["''", "'ab'", "'ä'"] {
+        let monkey_file: MonkeyFile = read_from_str(value)?;
+        let integer = IntegerAST::parse(&monkey_file.tokens, ParseOptions::default());
+
+        assert!(matches!(integer, Err(Error::InvalidCharLiteral(..))), "{value}");
+    }
+
+    let monkey_file: MonkeyFile = read_from_str("'\\q'")?;
+    let integer = IntegerAST::parse(&monkey_file.tokens, ParseOptions::default());
+    assert!(matches!(integer, Err(Error::InvalidEscapeSequence(..))));
+
+    Ok(())
+}
+
 #[test]
 fn assignable_double() -> anyhow::Result<()> {
     let values: Vec<(bool, String)> = vec![
@@ -82,7 +220,7 @@ fn assignable_double() -> anyhow::Result<()> {
     ];
 
     for (expected_result, value) in &values {
-        let monkey_file: MonkeyFile = MonkeyFile::read_from_str(value)?;
+        let monkey_file: MonkeyFile = read_from_str(value)?;
         let float = FloatAST::parse(&monkey_file.tokens, ParseOptions::default());
         if !*expected_result {
             println!("{}", float.err().unwrap());
@@ -160,7 +298,7 @@ fn assignable_imaginary_fn_calls() -> anyhow::Result<()> {
     ];
 
     for (expected_result, value) in &values {
-        let monkey_file: MonkeyFile = MonkeyFile::read_from_str(value)?;
+        let monkey_file: MonkeyFile = read_from_str(value)?;
         let node = MethodCall::parse(&monkey_file.tokens, ParseOptions::default());
 
         match *expected_result {
@@ -215,7 +353,7 @@ fn assignable_arithmetic_equation() -> anyhow::Result<()> {
     ];
 
     for (value, expected_result) in &expressions {
-        let monkey_file: MonkeyFile = MonkeyFile::read_from_str(value)?;
+        let monkey_file: MonkeyFile = read_from_str(value)?;
         let top_level_scope = Expression::parse(&monkey_file.tokens, ParseOptions::default());
 
         assert_eq!(top_level_scope.is_ok(), *expected_result);
@@ -242,7 +380,7 @@ fn assignable_boolean_equation() -> anyhow::Result<()> {
     ];
 
     for (expected_result, value) in &values {
-        let monkey_file: MonkeyFile = MonkeyFile::read_from_str(value)?;
+        let monkey_file: MonkeyFile = read_from_str(value)?;
         let node = Expression::parse(&monkey_file.tokens, ParseOptions::default());
         
         assert_eq!(node.is_ok(), *expected_result, "{:?}", value);
@@ -0,0 +1,61 @@
+use monkey_language::core::lexer::error::Error;
+use monkey_language::core::lexer::token::Token;
+use monkey_language::core::lexer::token_with_span::FilePosition;
+use crate::tokenize;
+
+#[test]
+fn line_comments() -> Result<(), Error> {
+    let program = "let a = 10; // a comment with / and * inside\n// a whole line\nlet b = a//trailing\n;";
+    let tokens = tokenize(program)?;
+
+    assert_eq!(tokens.iter().map(|token| token.token.clone()).collect::<Vec<_>>(), vec![
+        Token::Let, Token::Literal("a".to_string()), Token::Equals, Token::Numbers("10".to_string()), Token::SemiColon,
+        Token::Let, Token::Literal("b".to_string()), Token::Equals, Token::Literal("a".to_string()),
+        Token::SemiColon,
+    ]);
+
+    assert_eq!(tokens[5].span, FilePosition { line: 3..=3, column: 1..=3, offset: 61..64, ..Default::default() });
+    assert_eq!(tokens[8].span, FilePosition { line: 3..=3, column: 9..=9, offset: 69..70, ..Default::default() });
+    assert_eq!(tokens[9].span, FilePosition { line: 4..=4, column: 1..=1, offset: 81..82, ..Default::default() });
+
+    Ok(())
+}
+
+#[test]
+fn block_comments() -> Result<(), Error> {
+    let program = "let /* inline */ a = /* spanning\n multiple\n lines */ 10;";
+    let tokens = tokenize(program)?;
+
+    assert_eq!(tokens.iter().map(|token| token.token.clone()).collect::<Vec<_>>(), vec![
+        Token::Let, Token::Literal("a".to_string()), Token::Equals, Token::Numbers("10".to_string()), Token::SemiColon,
+    ]);
+
+    assert_eq!(tokens[1].span, FilePosition { line: 1..=1, column: 18..=18, offset: 17..18, ..Default::default() });
+    assert_eq!(tokens[3].span, FilePosition { line: 3..=3, column: 11..=12, offset: 53..55, ..Default::default() });
+
+    Ok(())
+}
+
+#[test]
+fn nested_block_comments() -> Result<(), Error> {
+    let program = "/* outer /* inner */ still a comment */ let a = 1 / 2;";
+    let tokens = tokenize(program)?;
+
+    assert_eq!(tokens.iter().map(|token| token.token.clone()).collect::<Vec<_>>(), vec![
+        Token::Let, Token::Literal("a".to_string()), Token::Equals, Token::Numbers("1".to_string()), Token::Divide, Token::Numbers("2".to_string()), Token::SemiColon,
+    ]);
+
+    assert_eq!(tokens[0].span, FilePosition { line: 1..=1, column: 41..=43, offset: 40..43, ..Default::default() });
+
+    Ok(())
+}
+
+#[test]
+fn unterminated_block_comment() {
+    let program = "let a = 1;\n/* outer /* inner */ never closed";
+
+    assert!(matches!(
+        tokenize(program),
+        Err(Error::UnterminatedComment(FilePosition { line, column, .. })) if line == (2..=2) && column == (1..=2)
+    ));
+}
@@ -0,0 +1,65 @@
+use monkey_language::core::io::monkey_file::MonkeyFile;
+use crate::read_from_str;
+use monkey_language::core::lexer::error::Error;
+use monkey_language::core::lexer::token::Token;
+use crate::tokenize;
+use monkey_language::core::model::abstract_syntax_tree_node::AbstractSyntaxTreeNode;
+use monkey_language::core::model::abstract_syntax_tree_nodes::assignables::equation_parser::operator::Operator;
+use monkey_language::core::model::abstract_syntax_tree_nodes::l_value::LValue;
+use monkey_language::core::parser::ast_parser::ASTParser;
+
+#[test]
+fn compound_assignment_tokens() -> Result<(), Error> {
+    let tokens = tokenize("+= -= *= /= %= <<= >>= &= |= ^= << >> < = &&")?
+        .into_iter()
+        .map(|token| token.token)
+        .collect::<Vec<_>>();
+
+    assert_eq!(tokens, vec![
+        Token::PlusEquals, Token::MinusEquals, Token::MultiplyEquals, Token::DivideEquals, Token::ModuloEquals,
+        Token::LeftShiftEquals, Token::RightShiftEquals, Token::AmpersandEquals, Token::PipeEquals, Token::XorEquals,
+        Token::LeftShift, Token::RightShift, Token::LessThan, Token::Equals, Token::LogicalAnd,
+    ]);
+
+    Ok(())
+}
+
+#[test]
+fn compound_assignments() -> anyhow::Result<()> {
+    let program = r#"
+    fn main(): void {
+        let mut a = 1;
+        a += 2;
+        a <<= a * 2;
+        let mut arr = [1, 2, 3];
+        arr[a] ^= 5;
+    }
+    "#;
+
+    let monkey_file: MonkeyFile = read_from_str(program)?;
+    let top_level_scope = ASTParser::parse(&monkey_file.tokens)?.result;
+
+    let AbstractSyntaxTreeNode::MethodDefinition(main) = &top_level_scope.program[0] else { panic!("expected a method definition") };
+    let variables = main.stack.iter().filter_map(|node| match node {
+        AbstractSyntaxTreeNode::Variable(variable) => Some(variable),
+        _ => None
+    }).collect::<Vec<_>>();
+
+    assert_eq!(variables.len(), 5);
+    assert_eq!(variables[0].compound_operator, None);
+    assert_eq!(variables[1].compound_operator, Some(Operator::Add));
+    assert!(!variables[1].define);
+    assert_eq!(variables[2].compound_operator, Some(Operator::LeftShift));
+    assert_eq!(variables[4].compound_operator, Some(Operator::BitwiseXor));
+    assert!(matches!(variables[4].l_value, LValue::Expression(_)));
+
+    assert_eq!(*variables[2].file_position.line.start(), 5);
+    assert_eq!(variables[1].to_string(), "a += 2");
+    assert_eq!(variables[2].to_string(), "a <<= (a * 2)");
+
+    let compound_expression = variables[1].compound_expression().expect("compound expression");
+    assert_eq!(compound_expression.operator, Operator::Add);
+    assert_eq!(compound_expression.to_string(), "(a + 2)");
+
+    Ok(())
+}
@@ -0,0 +1,102 @@
+use monkey_language::core::io::monkey_file::MonkeyFile;
+use crate::read_from_str;
+use monkey_language::core::lexer::error::Error;
+use monkey_language::core::lexer::token::Token;
+use crate::tokenize;
+use monkey_language::core::model::abstract_syntax_tree_node::AbstractSyntaxTreeNode;
+use monkey_language::core::model::abstract_syntax_tree_nodes::assignable::Assignable;
+use monkey_language::core::model::abstract_syntax_tree_nodes::assignables::equation_parser::operator::Operator;
+use monkey_language::core::model::abstract_syntax_tree_nodes::assignables::equation_parser::prefix_arithmetic::PrefixArithmetic;
+use monkey_language::core::parser::ast_parser::ASTParser;
+
+#[test]
+fn enum_tokens() -> Result<(), Error> {
+    let tokens = tokenize("enum Color::Red : a")?
+        .into_iter()
+        .map(|token| token.token)
+        .collect::<Vec<_>>();
+
+    assert_eq!(tokens, vec![
+        Token::Enum,
+        Token::Literal("Color".to_string()), Token::DoubleColon, Token::Literal("Red".to_string()),
+        Token::Colon, Token::Literal("a".to_string()),
+    ]);
+
+    Ok(())
+}
+
+#[test]
+fn enum_definition() -> anyhow::Result<()> {
+    let program = r#"
+    enum Color {
+        Red,
+        Green = 5,
+        Blue
+    }
+    "#;
+
+    let monkey_file: MonkeyFile = read_from_str(program)?;
+    let top_level_scope = ASTParser::parse(&monkey_file.tokens)?.result;
+
+    let AbstractSyntaxTreeNode::EnumDefinition(enum_definition) = &top_level_scope.program[0] else { panic!("expected an enum definition") };
+
+    assert_eq!(enum_definition.ty.to_string(), "Color");
+    assert_eq!(enum_definition.variants.len(), 3);
+    assert_eq!(enum_definition.discriminant("Red"), Some(0));
+    assert_eq!(enum_definition.discriminant("Green"), Some(5));
+    assert_eq!(enum_definition.discriminant("Blue"), Some(6));
+    assert_eq!(enum_definition.discriminant("Purple"), None);
+    assert_eq!(enum_definition.to_string(), "enum Color {\n    Red,\n    Green = 5,\n    Blue\n}");
+
+    Ok(())
+}
+
+#[test]
+fn enum_paths() -> anyhow::Result<()> {
+    let program = r#"
+    enum Color { Red, Green }
+
+    fn main(): void {
+        let a = Color::Red;
+        let b = a == Color::Green;
+        let c = (i32)Color::Green;
+    }
+    "#;
+
+    let monkey_file: MonkeyFile = read_from_str(program)?;
+    let top_level_scope = ASTParser::parse(&monkey_file.tokens)?.result;
+
+    let AbstractSyntaxTreeNode::MethodDefinition(main) = &top_level_scope.program[1] else { panic!("expected a method definition") };
+    let assignables = main.stack.iter().filter_map(|node| match node {
+        AbstractSyntaxTreeNode::Variable(variable) => Some(&variable.assignable),
+        _ => None
+    }).collect::<Vec<_>>();
+
+    let Assignable::EnumPath(enum_path) = assignables[0] else { panic!("expected an enum path, found {:?}", assignables[0]) };
+    assert_eq!(enum_path.ty.to_string(), "Color");
+    assert_eq!(enum_path.variant.name, "Red");
+
+    let Assignable::Expression(comparison) = assignables[1] else { panic!("expected an expression") };
+    assert_eq!(comparison.operator, Operator::Equal);
+    assert_eq!(assignables[1].to_string(), "(a == Color::Green)");
+
+    let Assignable::Expression(cast) = assignables[2] else { panic!("expected an expression") };
+    assert!(matches!(cast.prefix_arithmetic, Some(PrefixArithmetic::Cast(_))), "{:?}", cast.prefix_arithmetic);
+    assert_eq!(cast.value.as_ref().map(|value| value.to_string()), Some("Color::Green".to_string()));
+
+    Ok(())
+}
+
+#[test]
+fn enum_missing_variant() -> anyhow::Result<()> {
+    let program = r#"
+    enum Color { Red,, Green }
+    "#;
+
+    let monkey_file: MonkeyFile = read_from_str(program)?;
+    let top_level_scope = ASTParser::parse(&monkey_file.tokens)?.result;
+
+    assert!(matches!(top_level_scope.program[0], AbstractSyntaxTreeNode::SyntaxError(_)), "{:?}", top_level_scope.program[0]);
+
+    Ok(())
+}
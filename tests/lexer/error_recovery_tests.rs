@@ -0,0 +1,45 @@
+use monkey_language::core::io::source_map::FileId;
+use monkey_language::core::lexer::error::Error;
+use monkey_language::core::lexer::token::Token;
+use monkey_language::core::lexer::token_with_span::FilePosition;
+use monkey_language::core::lexer::tokenizer::{collect_greedy_recovering, tokenize_lossless};
+use crate::tokenize;
+
+#[test]
+fn reports_every_invalid_character() {
+    let program = "let a = 5 # 3;\nlet b = @;";
+    let (tokens, errors) = collect_greedy_recovering(program, FileId::default());
+
+    assert_eq!(errors, vec![
+        Error::InvalidCharacter('#', FilePosition { line: 1..=1, column: 11..=11, offset: 10..11, ..Default::default() }),
+        Error::InvalidCharacter('@', FilePosition { line: 2..=2, column: 9..=9, offset: 23..24, ..Default::default() }),
+    ]);
+
+    assert_eq!(tokens.iter().map(|token| token.token.clone()).collect::<Vec<_>>(), vec![
+        Token::Let, Token::Literal("a".to_string()), Token::Equals, Token::Numbers("5".to_string()), Token::Error("#".to_string()), Token::Numbers("3".to_string()), Token::SemiColon,
+        Token::Let, Token::Literal("b".to_string()), Token::Equals, Token::Error("@".to_string()), Token::SemiColon,
+    ]);
+
+    assert_eq!(tokens[4].span.offset, 10..11);
+    assert_eq!(tokens[10].span.offset, 23..24);
+}
+
+#[test]
+fn first_error_without_recovery() {
+    let program = "let a = #;\nlet b = @;";
+
+    assert_eq!(
+        tokenize(program).err(),
+        Some(Error::InvalidCharacter('#', FilePosition { line: 1..=1, column: 9..=9, offset: 8..9, ..Default::default() }))
+    );
+}
+
+#[test]
+fn unterminated_comment_ends_lexing() {
+    let (tokens, errors) = tokenize_lossless("let a = 1 ? 2;\n/* let b = 2;", FileId::default());
+
+    assert_eq!(errors.len(), 2);
+    assert!(matches!(errors[0], Error::InvalidCharacter('?', _)));
+    assert!(matches!(errors[1], Error::UnterminatedComment(FilePosition { ref line, .. }) if *line == (2..=2)));
+    assert_eq!(tokens.len(), 7);
+}
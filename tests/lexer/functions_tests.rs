@@ -1,4 +1,5 @@
 use monkey_language::core::io::monkey_file::MonkeyFile;
+use crate::read_from_str;
 use monkey_language::core::lexer::token_with_span::FilePosition;
 use monkey_language::core::model::abstract_syntax_tree_node::AbstractSyntaxTreeNode;
 use monkey_language::core::model::abstract_syntax_tree_nodes::assignable::Assignable;
@@ -23,43 +24,47 @@ fn function_test() -> anyhow::Result<()> {
     "#;
 
 
-    let monkey_file: MonkeyFile = MonkeyFile::read_from_str(function)?;
+    let monkey_file: MonkeyFile = read_from_str(function)?;
     let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
     infer_type(&mut top_level_scope.result.program)?;
 
     let expected = vec![
         AbstractSyntaxTreeNode::MethodDefinition(MethodDefinition {
-            identifier: LValue::Identifier(Identifier { name: "method_name".to_string() }),
+            identifier: LValue::Identifier(Identifier { name: "method_name".to_string(), symbol: None }),
+            generic_parameters: vec![],
             return_type: Type::Void,
             arguments: vec![
                 MethodArgument {
-                    identifier: LValue::Identifier(Identifier { name: "variable".to_string() }),
+                    identifier: LValue::Identifier(Identifier { name: "variable".to_string(), symbol: None }),
                     ty: Type::Integer(IntegerType::I32, Mutability::Immutable),
                 },
                 MethodArgument {
-                    identifier: LValue::Identifier(Identifier { name: "variable".to_string() }),
+                    identifier: LValue::Identifier(Identifier { name: "variable".to_string(), symbol: None }),
                     ty: Type::Integer(IntegerType::I32, Mutability::Immutable),
                 },
             ],
             stack: vec![
                 AbstractSyntaxTreeNode::Variable(Variable {
-                    l_value: LValue::Identifier(Identifier { name: "function_variable_one".to_string() }),
+                    l_value: LValue::Identifier(Identifier { name: "function_variable_one".to_string(), symbol: None }),
                     mutability: false,
                     ty: Some(Type::Integer(IntegerType::I32, Mutability::Immutable)),
                     define: true,
+                    compound_operator: None,
                     assignable: Assignable::Integer(IntegerAST { value: "10".to_string(), ty: IntegerType::I32 }),
-                    file_position: FilePosition { line: 3..=3, column: 9..=39 },
+                    file_position: FilePosition { line: 3..=3, column: 9..=39, offset: 66..97, ..Default::default() },
                 }),
                 AbstractSyntaxTreeNode::Variable(Variable {
-                    l_value: LValue::Identifier(Identifier { name: "function_variable_two".to_string() }),
+                    l_value: LValue::Identifier(Identifier { name: "function_variable_two".to_string(), symbol: None }),
                     mutability: false,
                     ty: Some(Type::Integer(IntegerType::I32, Mutability::Immutable)),
                     define: true,
+                    compound_operator: None,
                     assignable: Assignable::Integer(IntegerAST { value: "2".to_string(), ty: IntegerType::I32 }),
-                    file_position: FilePosition { line: 4..=4, column: 9..=38 },
+                    file_position: FilePosition { line: 4..=4, column: 9..=38, offset: 106..136, ..Default::default() },
                 })],
             is_extern: false,
-            file_position: FilePosition { line: 2..=5, column: 5..=5 }
+            is_public: false,
+            file_position: FilePosition { line: 2..=5, column: 5..=5, offset: 5..142, ..Default::default() }
         }),
     ];
 
@@ -87,75 +92,84 @@ fn multiple_functions_test() -> anyhow::Result<()> {
     "#;
 
 
-    let monkey_file: MonkeyFile = MonkeyFile::read_from_str(function)?;
+    let monkey_file: MonkeyFile = read_from_str(function)?;
     let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
     infer_type(&mut top_level_scope.result.program)?;
 
     let expected = vec![
         AbstractSyntaxTreeNode::MethodDefinition(MethodDefinition {
-            identifier: LValue::Identifier(Identifier { name: "f".to_string() }),
+            identifier: LValue::Identifier(Identifier { name: "f".to_string(), symbol: None }),
+            generic_parameters: vec![],
             return_type: Type::Void,
             arguments: vec![
                 MethodArgument {
-                    identifier: LValue::Identifier(Identifier { name: "variable1".to_string() }),
+                    identifier: LValue::Identifier(Identifier { name: "variable1".to_string(), symbol: None }),
                     ty: Type::Integer(IntegerType::I32, Mutability::Immutable),
                 },
                 MethodArgument {
-                    identifier: LValue::Identifier(Identifier { name: "variable2".to_string() }),
-                    ty: Type::Custom(Identifier { name: "Data".to_string() }, Mutability::Immutable),
+                    identifier: LValue::Identifier(Identifier { name: "variable2".to_string(), symbol: None }),
+                    ty: Type::Custom(Identifier { name: "Data".to_string(), symbol: None }, Mutability::Immutable),
                 },
             ],
             stack: vec![AbstractSyntaxTreeNode::Variable(Variable {
-                l_value: LValue::Identifier(Identifier { name: "function_variable_one".to_string() }),
+                l_value: LValue::Identifier(Identifier { name: "function_variable_one".to_string(), symbol: None }),
                 mutability: false,
                 ty: Some(Type::Integer(IntegerType::I32, Mutability::Immutable)),
                 define: true,
+                compound_operator: None,
                 assignable: Assignable::Integer(IntegerAST { value: "10".to_string(), ty: IntegerType::I32 }),
-                file_position: FilePosition { line: 4..=4, column: 9..=39 }
+                file_position: FilePosition { line: 4..=4, column: 9..=39, offset: 63..94, ..Default::default() }
             })],
             is_extern: false,
-            file_position: FilePosition { line: 2..=5, column: 5..=5 },
+            is_public: false,
+            file_position: FilePosition { line: 2..=5, column: 5..=5, offset: 5..100, ..Default::default() },
         }),
         AbstractSyntaxTreeNode::MethodDefinition(MethodDefinition {
-            identifier: LValue::Identifier(Identifier { name: "method_name".to_string() }),
+            identifier: LValue::Identifier(Identifier { name: "method_name".to_string(), symbol: None }),
+            generic_parameters: vec![],
             return_type: Type::Void,
             arguments: vec![
                 MethodArgument {
-                    identifier: LValue::Identifier(Identifier { name: "variable1".to_string() }),
+                    identifier: LValue::Identifier(Identifier { name: "variable1".to_string(), symbol: None }),
                     ty: Type::Bool(Mutability::Immutable),
                 },
                 MethodArgument {
-                    identifier: LValue::Identifier(Identifier { name: "variable2".to_string() }),
-                    ty: Type::Custom(Identifier { name: "*string".to_string() }, Mutability::Immutable),
+                    identifier: LValue::Identifier(Identifier { name: "variable2".to_string(), symbol: None }),
+                    ty: Type::Custom(Identifier { name: "*string".to_string(), symbol: None }, Mutability::Immutable),
                 },
             ],
             stack: vec![
                 AbstractSyntaxTreeNode::Variable(Variable {
-                    l_value: LValue::Identifier(Identifier { name: "function_variable_one".to_string() }),
+                    l_value: LValue::Identifier(Identifier { name: "function_variable_one".to_string(), symbol: None }),
                     mutability: false,
                     ty: Some(Type::Integer(IntegerType::I32, Mutability::Immutable)),
                     define: true,
+                    compound_operator: None,
                     assignable: Assignable::Integer(IntegerAST { value: "10".to_string(), ty: IntegerType::I32 }),
-                    file_position: FilePosition { line: 8..=8, column: 9..=39 }
+                    file_position: FilePosition { line: 8..=8, column: 9..=39, offset: 174..205, ..Default::default() }
                 }),
                 AbstractSyntaxTreeNode::Variable(Variable {
-                    l_value: LValue::Identifier(Identifier { name: "function_variable_two".to_string() }),
+                    l_value: LValue::Identifier(Identifier { name: "function_variable_two".to_string(), symbol: None }),
                     mutability: false,
                     ty: Some(Type::Integer(IntegerType::I32, Mutability::Immutable)),
                     define: true,
+                    compound_operator: None,
                     assignable: Assignable::Integer(IntegerAST { value: "2".to_string(), ty: IntegerType::I32 }),
-                    file_position: FilePosition { line: 9..=9, column: 9..=38 }
+                    file_position: FilePosition { line: 9..=9, column: 9..=38, offset: 214..244, ..Default::default() }
                 })],
             is_extern: false,
-            file_position: FilePosition { line: 7..=10, column: 5..=5 },
+            is_public: false,
+            file_position: FilePosition { line: 7..=10, column: 5..=5, offset: 106..250, ..Default::default() },
         }),
         AbstractSyntaxTreeNode::MethodDefinition(MethodDefinition {
-            identifier: LValue::Identifier(Identifier { name: "method_without_parameters".to_string() }),
+            identifier: LValue::Identifier(Identifier { name: "method_without_parameters".to_string(), symbol: None }),
+            generic_parameters: vec![],
             return_type: Type::Void,
             arguments: vec![],
             stack: vec![],
             is_extern: false,
-            file_position: FilePosition { line: 13..=15, column: 5..=5 }
+            is_public: false,
+            file_position: FilePosition { line: 13..=15, column: 5..=5, offset: 257..302, ..Default::default() }
         }),
     ];
 
@@ -173,7 +187,7 @@ fn function_different_return_type_test() -> anyhow::Result<()> {
     "#;
 
 
-    let monkey_file: MonkeyFile = MonkeyFile::read_from_str(function)?;
+    let monkey_file: MonkeyFile = read_from_str(function)?;
     let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
     infer_type(&mut top_level_scope.result.program)?;
 
@@ -181,30 +195,33 @@ fn function_different_return_type_test() -> anyhow::Result<()> {
 
     let expected = vec![
         AbstractSyntaxTreeNode::MethodDefinition(MethodDefinition {
-            identifier: LValue::Identifier(Identifier { name: "f".to_string() }),
+            identifier: LValue::Identifier(Identifier { name: "f".to_string(), symbol: None }),
+            generic_parameters: vec![],
             return_type: Type::Void,
             arguments: vec![
                 MethodArgument {
-                    identifier: LValue::Identifier(Identifier { name: "variable1".to_string() }),
+                    identifier: LValue::Identifier(Identifier { name: "variable1".to_string(), symbol: None }),
                     ty: Type::Integer(IntegerType::I32, Mutability::Immutable),
                 },
                 MethodArgument {
-                    identifier: LValue::Identifier(Identifier { name: "variable2".to_string() }),
+                    identifier: LValue::Identifier(Identifier { name: "variable2".to_string(), symbol: None }),
                     ty: Type::Integer(IntegerType::I32, Mutability::Immutable),
                 },
             ],
             stack: vec![
                 AbstractSyntaxTreeNode::Variable(Variable {
-                    l_value: LValue::Identifier(Identifier { name: "function_variable_zero".to_string() }),
+                    l_value: LValue::Identifier(Identifier { name: "function_variable_zero".to_string(), symbol: None }),
                     mutability: false,
-                    ty: Some(Type::Custom(Identifier { name: String::from("*string") }, Mutability::Immutable)),
+                    ty: Some(Type::Custom(Identifier { name: String::from("*string"), symbol: None }, Mutability::Immutable)),
                     define: true,
+                    compound_operator: None,
                     assignable: Assignable::String(StaticString { value: "\"Hallo\"".to_string() }),
-                    file_position: FilePosition { line: 4..=4, column: 9..=45 }
+                    file_position: FilePosition { line: 4..=4, column: 9..=45, offset: 62..99, ..Default::default() }
                 }),
             ],
             is_extern: false,
-            file_position: FilePosition { line: 2..=5, column: 5..=5 },
+            is_public: false,
+            file_position: FilePosition { line: 2..=5, column: 5..=5, offset: 5..105, ..Default::default() },
         }),
     ];
 
@@ -226,55 +243,60 @@ fn function_in_function_test() -> anyhow::Result<()> {
     "#;
 
 
-    let monkey_file: MonkeyFile = MonkeyFile::read_from_str(function)?;
+    let monkey_file: MonkeyFile = read_from_str(function)?;
     let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
     infer_type(&mut top_level_scope.result.program)?;
 
     let expected = vec![
         AbstractSyntaxTreeNode::MethodDefinition(MethodDefinition {
-            identifier: LValue::Identifier(Identifier { name: "f".to_string() }),
+            identifier: LValue::Identifier(Identifier { name: "f".to_string(), symbol: None }),
+            generic_parameters: vec![],
             return_type: Type::Void,
             arguments: vec![
                 MethodArgument {
-                    identifier: LValue::Identifier(Identifier { name: "variable1".to_string() }),
+                    identifier: LValue::Identifier(Identifier { name: "variable1".to_string(), symbol: None }),
                     ty: Type::Integer(IntegerType::I32, Mutability::Immutable),
                 },
                 MethodArgument {
-                    identifier: LValue::Identifier(Identifier { name: "variable2".to_string() }),
+                    identifier: LValue::Identifier(Identifier { name: "variable2".to_string(), symbol: None }),
                     ty: Type::Integer(IntegerType::I32, Mutability::Immutable),
                 }
             ],
             stack: vec![
                 AbstractSyntaxTreeNode::Variable(Variable {
-                    l_value: LValue::Identifier(Identifier { name: "function_variable_zero".to_string() }),
+                    l_value: LValue::Identifier(Identifier { name: "function_variable_zero".to_string(), symbol: None }),
                     mutability: false,
-                    ty: Some(Type::Custom(Identifier { name: String::from("*string") }, Mutability::Immutable)),
+                    ty: Some(Type::Custom(Identifier { name: String::from("*string"), symbol: None }, Mutability::Immutable)),
                     define: true,
+                    compound_operator: None,
                     assignable: Assignable::String(StaticString { value: "\"Hallo\"".to_string() }),
-                    file_position: FilePosition { line: 4..=4, column: 9..=45 }
+                    file_position: FilePosition { line: 4..=4, column: 9..=45, offset: 62..99, ..Default::default() }
                 }),
                 AbstractSyntaxTreeNode::MethodDefinition(MethodDefinition {
-                    identifier: LValue::Identifier(Identifier { name: "method_name".to_string() }),
+                    identifier: LValue::Identifier(Identifier { name: "method_name".to_string(), symbol: None }),
+                    generic_parameters: vec![],
                     return_type: Type::Void,
                     arguments: vec![
                         MethodArgument {
-                            identifier: LValue::Identifier(Identifier { name: "variable1".to_string() }),
+                            identifier: LValue::Identifier(Identifier { name: "variable1".to_string(), symbol: None }),
                             ty: Type::Integer(IntegerType::I32, Mutability::Immutable),
                         }, MethodArgument {
-                            identifier: LValue::Identifier(Identifier { name: "variable2".to_string() }),
+                            identifier: LValue::Identifier(Identifier { name: "variable2".to_string(), symbol: None }),
                             ty: Type::Integer(IntegerType::I32, Mutability::Immutable),
                         }
                     ],
                     stack: vec![
-                        AbstractSyntaxTreeNode::Variable(Variable { l_value: LValue::Identifier(Identifier { name: "function_variable_one".to_string() }), mutability: false, ty: Some(Type::Integer(IntegerType::I32, Mutability::Immutable)), define: true, assignable: Assignable::Integer(IntegerAST { value: "10".to_string(), ty: IntegerType::I32 }), file_position: FilePosition { line: 6..=6, column: 13..=43 } }),
-                        AbstractSyntaxTreeNode::Variable(Variable { l_value: LValue::Identifier(Identifier { name: "function_variable_two".to_string() }), mutability: false, ty: Some(Type::Integer(IntegerType::I32, Mutability::Immutable)), define: true, assignable: Assignable::Integer(IntegerAST { value: "2".to_string(), ty: IntegerType::I32 }), file_position: FilePosition { line: 7..=7, column: 13..=42 } }),
+                        AbstractSyntaxTreeNode::Variable(Variable { l_value: LValue::Identifier(Identifier { name: "function_variable_one".to_string(), symbol: None }), mutability: false, ty: Some(Type::Integer(IntegerType::I32, Mutability::Immutable)), define: true, compound_operator: None, assignable: Assignable::Integer(IntegerAST { value: "10".to_string(), ty: IntegerType::I32 }), file_position: FilePosition { line: 6..=6, column: 13..=43, offset: 175..206, ..Default::default() } }),
+                        AbstractSyntaxTreeNode::Variable(Variable { l_value: LValue::Identifier(Identifier { name: "function_variable_two".to_string(), symbol: None }), mutability: false, ty: Some(Type::Integer(IntegerType::I32, Mutability::Immutable)), define: true, compound_operator: None, assignable: Assignable::Integer(IntegerAST { value: "2".to_string(), ty: IntegerType::I32 }), file_position: FilePosition { line: 7..=7, column: 13..=42, offset: 219..249, ..Default::default() } }),
                     ],
                     is_extern: false,
-                    file_position: FilePosition { line: 5..=8, column: 9..=9 },
+                    is_public: false,
+                    file_position: FilePosition { line: 5..=8, column: 9..=9, offset: 108..259, ..Default::default() },
                 }),
             ],
             is_extern: false,
-            file_position: FilePosition { line: 2..=9, column: 5..=5 },
+            is_public: false,
+            file_position: FilePosition { line: 2..=9, column: 5..=5, offset: 5..265, ..Default::default() },
         }),
     ];
 
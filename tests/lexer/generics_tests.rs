@@ -0,0 +1,82 @@
+use monkey_language::core::io::monkey_file::MonkeyFile;
+use crate::read_from_str;
+use monkey_language::core::model::abstract_syntax_tree_node::AbstractSyntaxTreeNode;
+use monkey_language::core::model::abstract_syntax_tree_nodes::assignable::Assignable;
+use monkey_language::core::model::abstract_syntax_tree_nodes::method_definition::{GenericParameter, TypeBound};
+use monkey_language::core::parser::ast_parser::ASTParser;
+
+#[test]
+fn generic_method_definition() -> anyhow::Result<()> {
+    let program = r#"
+    fn max<T: Numeric>(a: T, b: T): T {
+        return a;
+    }
+
+    pub fn pair<T, U: Integer>(a: T, b: U) {
+    }
+    "#;
+
+    let monkey_file: MonkeyFile = read_from_str(program)?;
+    let top_level_scope = ASTParser::parse(&monkey_file.tokens)?.result;
+
+    let [AbstractSyntaxTreeNode::MethodDefinition(max), AbstractSyntaxTreeNode::MethodDefinition(pair)] = top_level_scope.program.as_slice() else {
+        panic!("expected two method definitions: {:?}", top_level_scope.program);
+    };
+
+    assert_eq!("max", max.identifier.identifier());
+    assert_eq!(vec![GenericParameter { name: "T".to_string(), bound: Some(TypeBound::Numeric) }], max.generic_parameters);
+    assert_eq!("T", max.return_type.to_string());
+    assert_eq!(1, max.stack.len());
+
+    assert!(pair.is_public);
+    assert_eq!(vec![
+        GenericParameter { name: "T".to_string(), bound: None },
+        GenericParameter { name: "U".to_string(), bound: Some(TypeBound::Integer) },
+    ], pair.generic_parameters);
+    assert_eq!("pub fn pair<T, U: Integer>(a: T, b: U): void {\n}", pair.to_string());
+
+    Ok(())
+}
+
+#[test]
+fn generic_method_call() -> anyhow::Result<()> {
+    let program = r#"
+    let a: i32 = max::<i32>(1, 2);
+    let b: f64 = std::max::<f64, [i32, 2]>(c, d) + 1;
+    max(a, b);
+    "#;
+
+    let monkey_file: MonkeyFile = read_from_str(program)?;
+    let top_level_scope = ASTParser::parse(&monkey_file.tokens)?.result;
+
+    assert!(top_level_scope.diagnostics.is_empty(), "{:?}", top_level_scope.diagnostics);
+
+    let expected = ["let a: i32 = max::<i32>(1, 2)", "let b: f64 = (std::max::<f64, [i32; 2]>(c, d) + 1)", "max(a, b)"];
+    for (node, expected) in top_level_scope.program.iter().zip(expected) {
+        assert_eq!(expected, node.to_string().trim_end_matches(';'));
+    }
+
+    let AbstractSyntaxTreeNode::Variable(variable) = &top_level_scope.program[0] else {
+        panic!("expected a variable");
+    };
+    let Assignable::MethodCall(method_call) = &variable.assignable else {
+        panic!("expected a method call: {}", variable.assignable);
+    };
+
+    assert_eq!("max", method_call.identifier.identifier());
+    assert_eq!(vec!["i32".to_string()], method_call.type_arguments.iter().map(|ty| ty.to_string()).collect::<Vec<_>>());
+
+    Ok(())
+}
+
+#[test]
+fn invalid_generic_parameters() -> anyhow::Result<()> {
+    for program in ["fn f<T: Unknown>(a: T) { }", "fn f<T(a: T) { }", "fn f<>(a: i32) { }"] {
+        let monkey_file: MonkeyFile = read_from_str(program)?;
+        let result = ASTParser::parse(&monkey_file.tokens);
+
+        assert!(result.is_err() || !result?.result.diagnostics.is_empty(), "{program}");
+    }
+
+    Ok(())
+}
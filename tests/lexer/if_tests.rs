@@ -1,4 +1,5 @@
 use monkey_language::core::io::monkey_file::MonkeyFile;
+use crate::read_from_str;
 use monkey_language::core::lexer::token_with_span::FilePosition;
 use monkey_language::core::model::abstract_syntax_tree_node::AbstractSyntaxTreeNode;
 use monkey_language::core::model::abstract_syntax_tree_nodes::assignable::Assignable;
@@ -25,7 +26,7 @@ fn if_test() -> anyhow::Result<()> {
     }
     "#;
 
-    let monkey_file: MonkeyFile = MonkeyFile::read_from_str(program)?;
+    let monkey_file: MonkeyFile = read_from_str(program)?;
     let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
     infer_type(&mut top_level_scope.result.program)?;
 
@@ -33,10 +34,12 @@ fn if_test() -> anyhow::Result<()> {
         AbstractSyntaxTreeNode::Variable(Variable {
             l_value: LValue::Identifier(Identifier {
                 name: String::from("variable"),
+                symbol: None,
             }),
             mutability: false,
             ty: Some(Type::Integer(IntegerType::I32, Mutability::Immutable)),
             define: true,
+            compound_operator: None,
             assignable: Assignable::Integer(IntegerAST {
                 value: "5".to_string(),
                 ty: IntegerType::I32,
@@ -44,20 +47,25 @@ fn if_test() -> anyhow::Result<()> {
             file_position: FilePosition {
                 line: 2..=2,
                 column: 5..=21,
+                offset: 5..22,
+                ..Default::default()
             },
         }),
         AbstractSyntaxTreeNode::If(If {
             condition: Assignable::Identifier(Identifier {
                 name: String::from("variable"),
+                symbol: None,
             }),
             if_stack: vec![
                 AbstractSyntaxTreeNode::Variable(Variable {
                     l_value: LValue::Identifier(Identifier {
                         name: "if_variable_one".to_string(),
+                        symbol: None,
                     }),
                     mutability: true,
                     ty: Some(Type::Integer(IntegerType::I32, Mutability::Immutable)),
                     define: true,
+                    compound_operator: None,
                     assignable: Assignable::Integer(IntegerAST {
                         value: "10".to_string(),
                         ty: IntegerType::I32,
@@ -65,15 +73,19 @@ fn if_test() -> anyhow::Result<()> {
                     file_position: FilePosition {
                         line: 4..=4,
                         column: 9..=37,
+                        offset: 51..80,
+                        ..Default::default()
                     },
                 }),
                 AbstractSyntaxTreeNode::Variable(Variable {
                     l_value: LValue::Identifier(Identifier {
                         name: "if_variable_two".to_string(),
+                        symbol: None,
                     }),
                     mutability: false,
                     ty: Some(Type::Integer(IntegerType::I32, Mutability::Immutable)),
                     define: true,
+                    compound_operator: None,
                     assignable: Assignable::Integer(IntegerAST {
                         value: "2".to_string(),
                         ty: IntegerType::I32,
@@ -81,6 +93,8 @@ fn if_test() -> anyhow::Result<()> {
                     file_position: FilePosition {
                         line: 5..=5,
                         column: 9..=32,
+                        offset: 89..113,
+                        ..Default::default()
                     },
                 }),
             ],
@@ -88,6 +102,8 @@ fn if_test() -> anyhow::Result<()> {
             file_position: FilePosition {
                 line: 3..=6,
                 column: 5..=5,
+                offset: 27..119,
+                ..Default::default()
             },
         }),
     ];
@@ -122,7 +138,7 @@ fn multiple_if_test() -> anyhow::Result<()> {
     }
     "#;
 
-    let monkey_file: MonkeyFile = MonkeyFile::read_from_str(program)?;
+    let monkey_file: MonkeyFile = read_from_str(program)?;
     let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
     infer_type(&mut top_level_scope.result.program)?;
 
@@ -130,10 +146,12 @@ fn multiple_if_test() -> anyhow::Result<()> {
         AbstractSyntaxTreeNode::Variable(Variable {
             l_value: LValue::Identifier(Identifier {
                 name: String::from("variable1"),
+                symbol: None,
             }),
             mutability: false,
             ty: Some(Type::Integer(IntegerType::I32, Mutability::Immutable)),
             define: true,
+            compound_operator: None,
             assignable: Assignable::Integer(IntegerAST {
                 value: "5".to_string(),
                 ty: IntegerType::I32,
@@ -141,15 +159,19 @@ fn multiple_if_test() -> anyhow::Result<()> {
             file_position: FilePosition {
                 line: 2..=2,
                 column: 5..=22,
+                offset: 5..23,
+                ..Default::default()
             },
         }),
         AbstractSyntaxTreeNode::Variable(Variable {
             l_value: LValue::Identifier(Identifier {
                 name: String::from("variable2"),
+                symbol: None,
             }),
             mutability: false,
             ty: Some(Type::Integer(IntegerType::I32, Mutability::Immutable)),
             define: true,
+            compound_operator: None,
             assignable: Assignable::Integer(IntegerAST {
                 value: "5".to_string(),
                 ty: IntegerType::I32,
@@ -157,15 +179,19 @@ fn multiple_if_test() -> anyhow::Result<()> {
             file_position: FilePosition {
                 line: 3..=3,
                 column: 5..=22,
+                offset: 28..46,
+                ..Default::default()
             },
         }),
         AbstractSyntaxTreeNode::Variable(Variable {
             l_value: LValue::Identifier(Identifier {
                 name: String::from("variable3"),
+                symbol: None,
             }),
             mutability: false,
             ty: Some(Type::Integer(IntegerType::I32, Mutability::Immutable)),
             define: true,
+            compound_operator: None,
             assignable: Assignable::Integer(IntegerAST {
                 value: "5".to_string(),
                 ty: IntegerType::I32,
@@ -173,20 +199,25 @@ fn multiple_if_test() -> anyhow::Result<()> {
             file_position: FilePosition {
                 line: 4..=4,
                 column: 5..=22,
+                offset: 51..69,
+                ..Default::default()
             },
         }),
         AbstractSyntaxTreeNode::If(If {
             condition: Assignable::Identifier(Identifier {
                 name: String::from("variable1"),
+                symbol: None,
             }),
             if_stack: vec![
                 AbstractSyntaxTreeNode::Variable(Variable {
                     l_value: LValue::Identifier(Identifier {
                         name: "if_variable_one".to_string(),
+                        symbol: None,
                     }),
                     mutability: false,
                     ty: Some(Type::Integer(IntegerType::I32, Mutability::Immutable)),
                     define: true,
+                    compound_operator: None,
                     assignable: Assignable::Integer(IntegerAST {
                         value: "10".to_string(),
                         ty: IntegerType::I32,
@@ -194,15 +225,19 @@ fn multiple_if_test() -> anyhow::Result<()> {
                     file_position: FilePosition {
                         line: 6..=6,
                         column: 9..=33,
+                        offset: 99..124,
+                        ..Default::default()
                     },
                 }),
                 AbstractSyntaxTreeNode::Variable(Variable {
                     l_value: LValue::Identifier(Identifier {
                         name: "if_variable_two".to_string(),
+                        symbol: None,
                     }),
                     mutability: false,
                     ty: Some(Type::Integer(IntegerType::I32, Mutability::Immutable)),
                     define: true,
+                    compound_operator: None,
                     assignable: Assignable::Integer(IntegerAST {
                         value: "2".to_string(),
                         ty: IntegerType::I32,
@@ -210,6 +245,8 @@ fn multiple_if_test() -> anyhow::Result<()> {
                     file_position: FilePosition {
                         line: 7..=7,
                         column: 9..=32,
+                        offset: 133..157,
+                        ..Default::default()
                     },
                 }),
             ],
@@ -217,20 +254,25 @@ fn multiple_if_test() -> anyhow::Result<()> {
             file_position: FilePosition {
                 line: 5..=8,
                 column: 5..=5,
+                offset: 74..163,
+                ..Default::default()
             },
         }),
         AbstractSyntaxTreeNode::If(If {
             condition: Assignable::Identifier(Identifier {
                 name: String::from("variable2"),
+                symbol: None,
             }),
             if_stack: vec![
                 AbstractSyntaxTreeNode::Variable(Variable {
                     l_value: LValue::Identifier(Identifier {
                         name: "if_variable_one".to_string(),
+                        symbol: None,
                     }),
                     mutability: false,
                     ty: Some(Type::Integer(IntegerType::I32, Mutability::Immutable)),
                     define: true,
+                    compound_operator: None,
                     assignable: Assignable::Integer(IntegerAST {
                         value: "10".to_string(),
                         ty: IntegerType::I32,
@@ -238,15 +280,19 @@ fn multiple_if_test() -> anyhow::Result<()> {
                     file_position: FilePosition {
                         line: 11..=11,
                         column: 9..=33,
+                        offset: 194..219,
+                        ..Default::default()
                     },
                 }),
                 AbstractSyntaxTreeNode::Variable(Variable {
                     l_value: LValue::Identifier(Identifier {
                         name: "if_variable_two".to_string(),
+                        symbol: None,
                     }),
                     mutability: false,
                     ty: Some(Type::Integer(IntegerType::I32, Mutability::Immutable)),
                     define: true,
+                    compound_operator: None,
                     assignable: Assignable::Integer(IntegerAST {
                         value: "2".to_string(),
                         ty: IntegerType::I32,
@@ -254,6 +300,8 @@ fn multiple_if_test() -> anyhow::Result<()> {
                     file_position: FilePosition {
                         line: 12..=12,
                         column: 9..=32,
+                        offset: 228..252,
+                        ..Default::default()
                     },
                 }),
             ],
@@ -261,17 +309,22 @@ fn multiple_if_test() -> anyhow::Result<()> {
             file_position: FilePosition {
                 line: 10..=13,
                 column: 5..=5,
+                offset: 169..258,
+                ..Default::default()
             },
         }),
         AbstractSyntaxTreeNode::If(If {
             condition: Assignable::Identifier(Identifier {
                 name: String::from("variable3"),
+                symbol: None,
             }),
             if_stack: vec![],
             else_stack: None,
             file_position: FilePosition {
                 line: 16..=18,
                 column: 5..=5,
+                offset: 265..288,
+                ..Default::default()
             },
         }),
     ];
@@ -292,7 +345,7 @@ fn if_else_test() -> anyhow::Result<()> {
     }
     "#;
 
-    let monkey_file: MonkeyFile = MonkeyFile::read_from_str(program)?;
+    let monkey_file: MonkeyFile = read_from_str(program)?;
     let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
     infer_type(&mut top_level_scope.result.program)?;
 
@@ -300,77 +353,88 @@ fn if_else_test() -> anyhow::Result<()> {
         AbstractSyntaxTreeNode::Variable(Variable {
             l_value: LValue::Identifier(Identifier {
                 name: String::from("variable"),
+                symbol: None,
             }),
             mutability: false,
             ty: Some(Type::Integer(IntegerType::I32, Mutability::Immutable)),
             define: true,
+            compound_operator: None,
             assignable: Assignable::Integer(IntegerAST {
                 value: "1".to_string(),
                 ty: IntegerType::I32,
             }),
-            file_position: FilePosition { line: 1..=1, column: 1..=17 },
+            file_position: FilePosition { line: 1..=1, column: 1..=17, offset: 0..17, ..Default::default() },
         }),
         AbstractSyntaxTreeNode::If(If {
         condition: Assignable::Identifier(Identifier {
             name: String::from("variable"),
+            symbol: None,
         }),
         if_stack: vec![
             AbstractSyntaxTreeNode::Variable(Variable {
                 l_value: LValue::Identifier(Identifier {
                     name: "if_variable_one".to_string(),
+                    symbol: None,
                 }),
                 mutability: true,
                 ty: Some(Type::Integer(IntegerType::I32, Mutability::Immutable)),
                 define: true,
+                compound_operator: None,
                 assignable: Assignable::Integer(IntegerAST {
                     value: "10".to_string(),
                     ty: IntegerType::I32,
                 }),
-                file_position: FilePosition { line: 3..=3, column: 9..=39 },
+                file_position: FilePosition { line: 3..=3, column: 9..=39, offset: 46..77, ..Default::default() },
             }),
             AbstractSyntaxTreeNode::Variable(Variable {
                 l_value: LValue::Identifier(Identifier {
                     name: "if_variable_two".to_string(),
+                    symbol: None,
                 }),
                 mutability: false,
                 ty: Some(Type::Integer(IntegerType::I32, Mutability::Immutable)),
                 define: true,
+                compound_operator: None,
                 assignable: Assignable::Integer(IntegerAST {
                     value: "2".to_string(),
                     ty: IntegerType::I32,
                 }),
-                file_position: FilePosition { line: 4..=4, column: 9..=32 },
+                file_position: FilePosition { line: 4..=4, column: 9..=32, offset: 86..110, ..Default::default() },
             }),
         ],
         else_stack: Some(vec![
             AbstractSyntaxTreeNode::Variable(Variable {
                 l_value: LValue::Identifier(Identifier {
                     name: "else_variable_one".to_string(),
+                    symbol: None,
                 }),
                 mutability: false,
                 ty: Some(Type::Integer(IntegerType::I32, Mutability::Immutable)),
                 define: true,
+                compound_operator: None,
                 assignable: Assignable::Integer(IntegerAST {
                     value: "10".to_string(),
                     ty: IntegerType::I32,
                 }),
-                file_position: FilePosition { line: 6..=6, column: 9..=35 },
+                file_position: FilePosition { line: 6..=6, column: 9..=35, offset: 132..159, ..Default::default() },
             }),
             AbstractSyntaxTreeNode::Variable(Variable {
                 l_value: LValue::Identifier(Identifier {
                     name: "else_variable_two".to_string(),
+                    symbol: None,
                 }),
                 mutability: true,
                 ty: Some(Type::Integer(IntegerType::I32, Mutability::Immutable)),
                 define: true,
+                compound_operator: None,
                 assignable: Assignable::Integer(IntegerAST {
                     value: "2".to_string(),
                     ty: IntegerType::I32,
                 }),
-                file_position: FilePosition { line: 7..=7, column: 9..=38 },
+                file_position: FilePosition { line: 7..=7, column: 9..=38, offset: 168..198, ..Default::default() },
             }),
         ]),
-        file_position: FilePosition { line: 2..=8, column: 5..=5 },
+        file_position: FilePosition { line: 2..=8, column: 5..=5, offset: 22..204, ..Default::default() },
     })];
 
     assert_eq!(expected, top_level_scope.result.program);
@@ -392,7 +456,7 @@ fn function_in_function_test() -> anyhow::Result<()> {
     }
     "#;
 
-    let monkey_file: MonkeyFile = MonkeyFile::read_from_str(program)?;
+    let monkey_file: MonkeyFile = read_from_str(program)?;
     let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
     infer_type(&mut top_level_scope.result.program)?;
 
@@ -400,28 +464,33 @@ fn function_in_function_test() -> anyhow::Result<()> {
         AbstractSyntaxTreeNode::Variable(Variable {
             l_value: LValue::Identifier(Identifier {
                 name: String::from("hallo"),
+                symbol: None,
             }),
             mutability: false,
             ty: Some(Type::Integer(IntegerType::I32, Mutability::Immutable)),
             define: true,
+            compound_operator: None,
             assignable: Assignable::Integer(IntegerAST {
                 value: "5".to_string(),
                 ty: IntegerType::I32,
             }),
-            file_position: FilePosition { line: 2..=2, column: 5..=18 },
+            file_position: FilePosition { line: 2..=2, column: 5..=18, offset: 5..19, ..Default::default() },
         }),
         AbstractSyntaxTreeNode::If(If {
         condition: Assignable::Identifier(Identifier {
             name: "hallo".to_string(),
+            symbol: None,
         }),
         if_stack: vec![
             AbstractSyntaxTreeNode::Variable(Variable {
                 l_value: LValue::Identifier(Identifier {
                     name: "if_stack_variable".to_string(),
+                    symbol: None,
                 }),
                 mutability: false,
                 ty: Some(Type::Integer(IntegerType::I32, Mutability::Immutable)),
                 define: true,
+                compound_operator: None,
                 assignable: Assignable::Expression(Expression {
                     lhs: Some(Box::new(Expression {
                         lhs: None,
@@ -453,65 +522,109 @@ fn function_in_function_test() -> anyhow::Result<()> {
                     prefix_arithmetic: None,
                     index_operator: None,
                 }),
-                file_position: FilePosition { line: 4..=4, column: 9..=38 },
+                file_position: FilePosition { line: 4..=4, column: 9..=38, offset: 45..75, ..Default::default() },
             }),
             AbstractSyntaxTreeNode::If(If {
                 condition: Assignable::Identifier(Identifier {
                     name: "if_stack_variable".to_string(),
+                    symbol: None,
                 }),
                 if_stack: vec![AbstractSyntaxTreeNode::Variable(Variable {
                     l_value: LValue::Identifier(Identifier {
                         name: "nested_if_stack_variable".to_string(),
+                        symbol: None,
                     }),
                     mutability: false,
                     ty: Some(Type::Integer(IntegerType::I32, Mutability::Immutable)),
                     define: true,
+                    compound_operator: None,
                     assignable: Assignable::Integer(IntegerAST {
                         value: "13".to_string(),
                         ty: IntegerType::I32,
                     }),
-                    file_position: FilePosition { line: 7..=7, column: 13..=46 },
+                    file_position: FilePosition { line: 7..=7, column: 13..=46, offset: 121..155, ..Default::default() },
                 })],
                 else_stack: Some(vec![AbstractSyntaxTreeNode::Variable(Variable {
                     l_value: LValue::Identifier(Identifier {
                         name: "nested_else_stack_variable".to_string(),
+                        symbol: None,
                     }),
                     mutability: false,
                     ty: Some(Type::Custom(
                         Identifier {
                             name: String::from("*string"),
+                            symbol: None,
                         },
                         Mutability::Immutable,
                     )),
                     define: true,
+                    compound_operator: None,
                     assignable: Assignable::String(StaticString {
                         value: "\"nice\"".to_string(),
                     }),
-                    file_position: FilePosition { line: 8..=8, column: 17..=56 },
+                    file_position: FilePosition { line: 8..=8, column: 17..=56, offset: 172..212, ..Default::default() },
                 })]),
-                file_position: FilePosition { line: 6..=8, column: 9..=57 },
+                file_position: FilePosition { line: 6..=8, column: 9..=57, offset: 85..213, ..Default::default() },
             }),
         ],
         else_stack: Some(vec![AbstractSyntaxTreeNode::Variable(Variable {
             l_value: LValue::Identifier(Identifier {
                 name: "else_stack_variable".to_string(),
+                symbol: None,
             }),
             mutability: false,
             ty: Some(Type::Custom(
                 Identifier {
                     name: String::from("*string"),
+                    symbol: None,
                 },
                 Mutability::Immutable,
             )),
             define: true,
+            compound_operator: None,
             assignable: Assignable::String(StaticString {
                 value: "\"hallo\"".to_string(),
             }),
-            file_position: FilePosition { line: 10..=10, column: 9..=42 },
+            file_position: FilePosition { line: 10..=10, column: 9..=42, offset: 235..269, ..Default::default() },
         })]),
-        file_position: FilePosition { line: 3..=11, column: 5..=5 },
+        file_position: FilePosition { line: 3..=11, column: 5..=5, offset: 24..275, ..Default::default() },
     })];
 
     assert_eq!(expected, top_level_scope.result.program);
     Ok(())
 }
+
+#[test]
+fn else_if_chain_test() -> anyhow::Result<()> {
+    let program = r#"
+    let a = 2;
+    if (a == 1) {
+        let b = 1;
+    } else if (a == 2) {
+        let b = 2;
+    } else if (a == 3) {
+        let b = 3;
+    } else {
+        let b = 4;
+    }
+    "#;
+
+    let monkey_file: MonkeyFile = read_from_str(program)?;
+    let top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
+
+    assert!(top_level_scope.result.diagnostics.is_empty());
+    assert_eq!(top_level_scope.result.program.len(), 2);
+
+    let AbstractSyntaxTreeNode::If(first) = &top_level_scope.result.program[1] else { panic!("Expected an if statement") };
+    assert_eq!(first.file_position.line, 3..=11);
+
+    let Some([AbstractSyntaxTreeNode::If(second)]) = first.else_stack.as_deref() else { panic!("Expected an else if") };
+    assert_eq!(second.file_position.line, 5..=11);
+    assert_eq!(second.condition.to_string(), "(a == 2)");
+
+    let Some([AbstractSyntaxTreeNode::If(third)]) = second.else_stack.as_deref() else { panic!("Expected an else if") };
+    assert_eq!(third.condition.to_string(), "(a == 3)");
+    assert!(matches!(third.else_stack.as_deref(), Some([AbstractSyntaxTreeNode::Variable(_)])));
+
+    Ok(())
+}
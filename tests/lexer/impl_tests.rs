@@ -0,0 +1,85 @@
+use monkey_language::core::io::monkey_file::MonkeyFile;
+use crate::read_from_str;
+use monkey_language::core::lexer::error::Error;
+use monkey_language::core::lexer::token::Token;
+use crate::tokenize;
+use monkey_language::core::model::abstract_syntax_tree_node::AbstractSyntaxTreeNode;
+use monkey_language::core::model::abstract_syntax_tree_nodes::assignable::Assignable;
+use monkey_language::core::parser::ast_parser::ASTParser;
+
+#[test]
+fn impl_tokens() -> Result<(), Error> {
+    let tokens = tokenize("impl Person { } person.greet()")?
+        .into_iter()
+        .map(|token| token.token)
+        .collect::<Vec<_>>();
+
+    assert_eq!(tokens, vec![
+        Token::Impl, Token::Literal("Person".to_string()), Token::CurlyBraceOpen, Token::CurlyBraceClose,
+        Token::Literal("person".to_string()), Token::Dot, Token::Literal("greet".to_string()), Token::ParenthesisOpen, Token::ParenthesisClose,
+    ]);
+
+    Ok(())
+}
+
+#[test]
+fn impl_methods_are_lowered() -> anyhow::Result<()> {
+    let program = r#"
+    struct Person { age: i32 }
+
+    impl Person {
+        fn age(self: *Person): i32 {
+            return 42;
+        }
+
+        fn new(): i32 {
+            return 0;
+        }
+    }
+    "#;
+
+    let monkey_file: MonkeyFile = read_from_str(program)?;
+    let top_level_scope = ASTParser::parse(&monkey_file.tokens)?.result;
+
+    let method_names = top_level_scope.program.iter()
+        .filter_map(|node| match node {
+            AbstractSyntaxTreeNode::MethodDefinition(method_definition) => Some(method_definition.identifier.identifier()),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(method_names, vec!["Person::age", "Person::new"]);
+    assert!(!top_level_scope.program.iter().any(|node| matches!(node, AbstractSyntaxTreeNode::Impl(_))));
+
+    Ok(())
+}
+
+#[test]
+fn receiver_calls() -> anyhow::Result<()> {
+    let program = r#"
+    fn main(): void {
+        person.greet(1, 2);
+        let a = person.age() + 1;
+        let b = Person::new();
+    }
+    "#;
+
+    let monkey_file: MonkeyFile = read_from_str(program)?;
+    let top_level_scope = ASTParser::parse(&monkey_file.tokens)?.result;
+
+    let AbstractSyntaxTreeNode::MethodDefinition(main) = &top_level_scope.program[0] else { panic!("expected a method definition") };
+
+    let AbstractSyntaxTreeNode::MethodCall(greet) = &main.stack[0] else { panic!("expected a method call") };
+    assert!(matches!(greet.receiver.as_deref(), Some(Assignable::Identifier(receiver)) if receiver.name == "person"));
+    assert_eq!(greet.identifier.identifier(), "greet");
+    assert_eq!(greet.arguments.len(), 2);
+    assert_eq!(greet.to_string(), "person.greet(1, 2)");
+
+    let AbstractSyntaxTreeNode::Variable(a) = &main.stack[1] else { panic!("expected a variable") };
+    assert_eq!(a.assignable.to_string(), "(person.age() + 1)");
+
+    let AbstractSyntaxTreeNode::Variable(b) = &main.stack[2] else { panic!("expected a variable") };
+    assert!(matches!(&b.assignable, Assignable::MethodCall(new) if new.receiver.is_none() && new.identifier.identifier() == "Person::new"));
+
+    Ok(())
+}
@@ -0,0 +1,64 @@
+use monkey_language::core::io::monkey_file::MonkeyFile;
+use crate::read_from_str;
+use monkey_language::core::lexer::error::Error;
+use monkey_language::core::lexer::token::Token;
+use crate::tokenize;
+use monkey_language::core::model::abstract_syntax_tree_node::AbstractSyntaxTreeNode;
+use monkey_language::core::model::abstract_syntax_tree_nodes::break_::Break;
+use monkey_language::core::model::abstract_syntax_tree_nodes::continue_::Continue;
+use monkey_language::core::parser::ast_parser::ASTParser;
+
+#[test]
+fn labels_and_char_literals() -> Result<(), Error> {
+    let tokens = tokenize("'outer: while (true) { let c = 'a'; break 'outer; continue; }")?
+        .into_iter()
+        .map(|token| token.token)
+        .collect::<Vec<_>>();
+
+    assert_eq!(tokens[0], Token::Label("outer".to_string()));
+    assert_eq!(tokens[1], Token::Colon);
+    assert_eq!(tokens[10], Token::Literal("'a'".to_string()));
+    assert_eq!(&tokens[12..], &[
+        Token::Break, Token::Label("outer".to_string()), Token::SemiColon,
+        Token::Continue, Token::SemiColon,
+        Token::CurlyBraceClose,
+    ]);
+
+    Ok(())
+}
+
+#[test]
+fn labeled_loops() -> anyhow::Result<()> {
+    let program = r#"
+    fn main(): void {
+        'outer: while (true) {
+            'inner: for (let mut i: i32 = 0; i < 10; i = i + 1;) {
+                continue 'outer;
+                break 'inner;
+            }
+            break;
+        }
+    }
+    "#;
+
+    let monkey_file: MonkeyFile = read_from_str(program)?;
+    let top_level_scope = ASTParser::parse(&monkey_file.tokens)?.result;
+
+    let AbstractSyntaxTreeNode::MethodDefinition(main) = &top_level_scope.program[0] else { panic!("expected a method definition") };
+    let AbstractSyntaxTreeNode::While(outer) = &main.stack[0] else { panic!("expected a while loop") };
+    let AbstractSyntaxTreeNode::For(inner) = &outer.stack[0] else { panic!("expected a for loop") };
+
+    assert_eq!(outer.label, Some("outer".to_string()));
+    assert_eq!(*outer.file_position.line.start(), 3);
+    assert_eq!(*outer.file_position.column.start(), 9);
+    assert_eq!(inner.label, Some("inner".to_string()));
+    assert!(matches!(&inner.stack[0], AbstractSyntaxTreeNode::Continue(Continue { label: Some(label), .. }) if label == "outer"));
+    assert!(matches!(&inner.stack[1], AbstractSyntaxTreeNode::Break(Break { label: Some(label), .. }) if label == "inner"));
+    assert!(matches!(&outer.stack[1], AbstractSyntaxTreeNode::Break(Break { label: None, .. })));
+
+    assert_eq!(outer.stack[1].to_string(), "break");
+    assert_eq!(inner.stack[0].to_string(), "continue 'outer");
+    assert!(outer.to_string().starts_with("'outer: while (true) {"));
+
+    Ok(())
+}
@@ -0,0 +1,83 @@
+use monkey_language::core::io::monkey_file::MonkeyFile;
+use crate::read_from_str;
+use monkey_language::core::lexer::error::Error;
+use monkey_language::core::lexer::token::Token;
+use crate::tokenize;
+use monkey_language::core::model::abstract_syntax_tree_node::AbstractSyntaxTreeNode;
+use monkey_language::core::model::abstract_syntax_tree_nodes::match_::MatchPattern;
+use monkey_language::core::parser::ast_parser::ASTParser;
+
+#[test]
+fn match_tokens() -> Result<(), Error> {
+    let tokens = tokenize("match (a) { 1..2 | 3..=4 => {} _ => {} }")?
+        .into_iter()
+        .map(|token| token.token)
+        .collect::<Vec<_>>();
+
+    assert_eq!(tokens, vec![
+        Token::Match, Token::ParenthesisOpen, Token::Literal("a".to_string()), Token::ParenthesisClose, Token::CurlyBraceOpen,
+        Token::Numbers("1".to_string()), Token::DotDot, Token::Numbers("2".to_string()), Token::Pipe,
+        Token::Numbers("3".to_string()), Token::DotDotEquals, Token::Numbers("4".to_string()),
+        Token::FatArrow, Token::CurlyBraceOpen, Token::CurlyBraceClose,
+        Token::Underscore, Token::FatArrow, Token::CurlyBraceOpen, Token::CurlyBraceClose,
+        Token::CurlyBraceClose,
+    ]);
+
+    Ok(())
+}
+
+#[test]
+fn match_statement() -> anyhow::Result<()> {
+    let program = r#"
+    fn main(): void {
+        let a: i32 = 5;
+        match (a) {
+            1 => { let b = 1; }
+            2 | 3 => { let b = 2; },
+            4..10 | 20..=30 => { let b = 3; }
+            _ => { let b = 4; }
+        }
+    }
+    "#;
+
+    let monkey_file: MonkeyFile = read_from_str(program)?;
+    let top_level_scope = ASTParser::parse(&monkey_file.tokens)?.result;
+
+    let AbstractSyntaxTreeNode::MethodDefinition(main) = &top_level_scope.program[0] else { panic!("expected a method definition") };
+    let AbstractSyntaxTreeNode::Match(match_statement) = &main.stack[1] else { panic!("expected a match statement, found {:?}", main.stack[1]) };
+
+    assert_eq!(match_statement.value.to_string(), "a");
+    assert_eq!(match_statement.arms.len(), 4);
+    assert!(match_statement.arms.iter().all(|arm| arm.stack.len() == 1));
+
+    let patterns = match_statement.arms.iter()
+        .map(|arm| arm.patterns.iter().map(|pattern| pattern.to_string()).collect::<Vec<_>>().join(" | "))
+        .collect::<Vec<_>>();
+    assert_eq!(patterns, vec!["1", "2 | 3", "4..10 | 20..=30", "_"]);
+
+    assert!(matches!(match_statement.arms[2].patterns[0], MatchPattern::Range { inclusive: false, .. }));
+    assert!(matches!(match_statement.arms[2].patterns[1], MatchPattern::Range { inclusive: true, .. }));
+    assert_eq!(match_statement.arms[3].patterns, vec![MatchPattern::Wildcard]);
+
+    Ok(())
+}
+
+#[test]
+fn match_illegal_pattern() -> anyhow::Result<()> {
+    let program = r#"
+    fn main(): void {
+        let a: i32 = 5;
+        match (a) {
+            a + 1 => { let b = 1; }
+        }
+    }
+    "#;
+
+    let monkey_file: MonkeyFile = read_from_str(program)?;
+    let top_level_scope = ASTParser::parse(&monkey_file.tokens)?.result;
+
+    let AbstractSyntaxTreeNode::MethodDefinition(main) = &top_level_scope.program[0] else { panic!("expected a method definition") };
+    assert!(matches!(main.stack[1], AbstractSyntaxTreeNode::SyntaxError(_)), "{:?}", main.stack[1]);
+
+    Ok(())
+}
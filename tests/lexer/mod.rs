@@ -2,4 +2,17 @@ pub mod assignable_tests;
 pub mod variable_tests;
 pub mod functions_tests;
 pub mod if_tests;
-mod semantic_token_merge_tests;
+pub mod comment_tests;
+pub mod span_tests;
+pub mod error_recovery_tests;
+pub mod trivia_tests;
+pub mod syntax_error_tests;
+pub mod loop_control_tests;
+pub mod compound_assignment_tests;
+pub mod unary_operator_tests;
+pub mod enum_tests;
+pub mod match_tests;
+pub mod module_tests;
+pub mod impl_tests;
+mod struct_tests;
+pub mod generics_tests;
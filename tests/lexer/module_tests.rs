@@ -0,0 +1,239 @@
+use std::path::{Path, PathBuf};
+use monkey_language::core::io::monkey_file::MonkeyFile;
+use crate::read_from_str;
+use monkey_language::core::io::source_map::{FileId, SourceMap};
+use monkey_language::core::lexer::error::Error;
+use monkey_language::core::lexer::token::Token;
+use crate::tokenize;
+use monkey_language::core::model::abstract_syntax_tree_node::AbstractSyntaxTreeNode;
+use monkey_language::core::model::abstract_syntax_tree_nodes::assignable::Assignable;
+use monkey_language::core::parser::abstract_syntax_tree_nodes::import::ImportError;
+use monkey_language::core::parser::ast_parser::ASTParser;
+use monkey_language::core::parser::module_loader::ModuleLoader;
+
+/// Writes the files into a new temporary directory and returns the directory
+fn write_project(files: &[(&str, &str)]) -> anyhow::Result<PathBuf> {
+    let directory = std::env::temp_dir().join(format!("monkey-language-{}", uuid::Uuid::new_v4()));
+
+    for (path, code) in files {
+        let path = directory.join(path);
+        std::fs::create_dir_all(path.parent().unwrap_or(&directory))?;
+        std::fs::write(path, code)?;
+    }
+
+    Ok(directory)
+}
+
+fn load_project(files: &[(&str, &str)], search_paths: Vec<PathBuf>) -> anyhow::Result<Result<ASTParser, ImportError>> {
+    let directory = write_project(files)?;
+    let result = ModuleLoader::new(search_paths).load(directory.join(files[0].0), &mut SourceMap::default());
+    let _ = std::fs::remove_dir_all(&directory);
+
+    Ok(result)
+}
+
+fn method_names(top_level_scope: &ASTParser) -> Vec<String> {
+    top_level_scope.program.iter().filter_map(|node| match node {
+        AbstractSyntaxTreeNode::MethodDefinition(method_definition) => Some(method_definition.identifier.identifier()),
+        _ => None,
+    }).collect()
+}
+
+#[test]
+fn module_tokens() -> Result<(), Error> {
+    let tokens = tokenize("module std::io; pub fn")?
+        .into_iter()
+        .map(|token| token.token)
+        .collect::<Vec<_>>();
+
+    assert_eq!(tokens, vec![
+        Token::Module, Token::Literal("std".to_string()), Token::DoubleColon, Token::Literal("io".to_string()), Token::SemiColon,
+        Token::Pub, Token::Fn,
+    ]);
+
+    Ok(())
+}
+
+#[test]
+fn module_import() -> anyhow::Result<()> {
+    let program = r#"
+    module std;
+    module collections::list;
+    "#;
+
+    let monkey_file: MonkeyFile = read_from_str(program)?;
+    let top_level_scope = ASTParser::parse(&monkey_file.tokens)?.result;
+
+    let AbstractSyntaxTreeNode::Import(std) = &top_level_scope.program[0] else { panic!("expected an import") };
+    let AbstractSyntaxTreeNode::Import(list) = &top_level_scope.program[1] else { panic!("expected an import") };
+
+    assert_eq!(std.namespace(), "std");
+    assert_eq!(list.namespace(), "collections::list");
+    assert_eq!(list.relative_path(), Path::new("collections").join("list.monkey"));
+    assert_eq!(list.to_string(), "module collections::list;");
+
+    // file paths aren't modules
+    let monkey_file: MonkeyFile = read_from_str("module std/io.monkey;")?;
+    assert!(!matches!(ASTParser::parse(&monkey_file.tokens), Ok(parsed) if parsed.result.diagnostics.is_empty()));
+
+    Ok(())
+}
+
+#[test]
+fn public_definitions() -> anyhow::Result<()> {
+    let program = r#"
+    pub fn print(value: i32): void { }
+    fn helper(): void { }
+    pub extern fn printf(format: *string, value: i32): void;
+    pub struct Point { x: i32, y: i32 }
+    pub enum Color { Red, Green }
+    "#;
+
+    let monkey_file: MonkeyFile = read_from_str(program)?;
+    let top_level_scope = ASTParser::parse(&monkey_file.tokens)?.result;
+
+    assert!(top_level_scope.diagnostics.is_empty(), "{:?}", top_level_scope.diagnostics);
+
+    let visibility = top_level_scope.program.iter().map(|node| match node {
+        AbstractSyntaxTreeNode::MethodDefinition(method_definition) => method_definition.is_public,
+        AbstractSyntaxTreeNode::StructDefinition(struct_definition) => struct_definition.is_public,
+        AbstractSyntaxTreeNode::EnumDefinition(enum_definition) => enum_definition.is_public,
+        node => panic!("unexpected node: {node:?}"),
+    }).collect::<Vec<_>>();
+
+    assert_eq!(visibility, vec![true, false, true, true, true]);
+    assert!(top_level_scope.program[2].to_string().starts_with("pub extern fn printf"));
+
+    Ok(())
+}
+
+#[test]
+fn qualified_names() -> anyhow::Result<()> {
+    let program = r#"
+    fn main(): void {
+        let point: geometry::Point = geometry::Point { x: 1, y: 2 };
+        let color = graphics::Color::Red;
+        std::print(1);
+    }
+    "#;
+
+    let monkey_file: MonkeyFile = read_from_str(program)?;
+    let top_level_scope = ASTParser::parse(&monkey_file.tokens)?.result;
+
+    assert!(top_level_scope.diagnostics.is_empty(), "{:?}", top_level_scope.diagnostics);
+
+    let AbstractSyntaxTreeNode::MethodDefinition(main) = &top_level_scope.program[0] else { panic!("expected a method definition") };
+
+    let AbstractSyntaxTreeNode::Variable(point) = &main.stack[0] else { panic!("expected a variable") };
+    assert_eq!(point.ty.as_ref().map(|ty| ty.to_string()), Some("geometry::Point".to_string()));
+    let Assignable::Object(object) = &point.assignable else { panic!("expected an object") };
+    assert_eq!(object.ty.to_string(), "geometry::Point");
+
+    let AbstractSyntaxTreeNode::Variable(color) = &main.stack[1] else { panic!("expected a variable") };
+    let Assignable::EnumPath(enum_path) = &color.assignable else { panic!("expected an enum path") };
+    assert_eq!(enum_path.ty.to_string(), "graphics::Color");
+    assert_eq!(enum_path.variant.name, "Red");
+
+    let AbstractSyntaxTreeNode::MethodCall(print) = &main.stack[2] else { panic!("expected a method call") };
+    assert_eq!(print.identifier.identifier(), "std::print");
+
+    Ok(())
+}
+
+#[test]
+fn load_modules() -> anyhow::Result<()> {
+    let top_level_scope = load_project(&[
+        ("main.monkey", "module io;\nmodule geometry::shapes;\nmodule geometry::shapes;\nfn main(): i32 { io::print(geometry::shapes::area(2, 3)); return 0; }"),
+        ("io.monkey", "extern fn printf(format: *string, value: i32): void;\npub fn print(value: i32): void { printf(\"%d\", value); }"),
+        ("geometry/shapes.monkey", "module io;\npub struct Point { x: i32, y: i32 }\nfn multiply(a: i32, b: i32): i32 { return a * b; }\npub fn area(a: i32, b: i32): i32 { io::print(a); return multiply(a, b); }"),
+    ], vec![])??;
+
+    // `io` is imported twice, but only loaded once
+    assert_eq!(method_names(&top_level_scope), vec!["io::printf", "io::print", "geometry::shapes::multiply", "geometry::shapes::area", "main"]);
+
+    let AbstractSyntaxTreeNode::StructDefinition(point) = &top_level_scope.program[2] else { panic!("expected a struct definition") };
+    assert_eq!(point.ty.to_string(), "geometry::shapes::Point");
+
+    let AbstractSyntaxTreeNode::MethodDefinition(area) = &top_level_scope.program[4] else { panic!("expected a method definition") };
+    assert_eq!(area.stack[1].to_string(), "return geometry::shapes::multiply(a, b)");
+
+    Ok(())
+}
+
+#[test]
+fn load_module_with_relative_name() -> anyhow::Result<()> {
+    let top_level_scope = load_project(&[
+        ("main.monkey", "module geometry::shapes;\nmodule geometry::util;\nfn main(): i32 { return geometry::shapes::area(2) + geometry::util::twice(1); }"),
+        ("geometry/shapes.monkey", "module util;\npub fn area(a: i32): i32 { return util::twice(a); }"),
+        ("geometry/util.monkey", "pub fn twice(a: i32): i32 { return a * 2; }"),
+    ], vec![])??;
+
+    // `module util;` next to the file and `module geometry::util;` import the same module
+    assert_eq!(method_names(&top_level_scope), vec!["geometry::util::twice", "geometry::shapes::area", "main"]);
+
+    let AbstractSyntaxTreeNode::MethodDefinition(area) = &top_level_scope.program[1] else { panic!("expected a method definition") };
+    assert_eq!(area.stack[0].to_string(), "return geometry::util::twice(a)");
+
+    Ok(())
+}
+
+#[test]
+fn load_module_from_search_path() -> anyhow::Result<()> {
+    let library = write_project(&[("math.monkey", "pub fn twice(a: i32): i32 { return a * 2; }")])?;
+    let result = load_project(&[("main.monkey", "module math;\nfn main(): i32 { return math::twice(2); }")], vec![library.clone()])?;
+    let _ = std::fs::remove_dir_all(&library);
+
+    assert_eq!(method_names(&result?), vec!["math::twice", "main"]);
+
+    let result = load_project(&[("main.monkey", "module math;\nfn main(): i32 { return math::twice(2); }")], vec![])?;
+    assert!(matches!(result, Err(ImportError::ModuleNotFound { namespace, .. }) if namespace == "math"));
+
+    Ok(())
+}
+
+#[test]
+fn source_map_of_modules() -> anyhow::Result<()> {
+    let directory = write_project(&[
+        ("main.monkey", "module a;\nfn main(): i32 { return a::f(); }"),
+        ("a.monkey", "pub fn f(): i32 { return 1; }"),
+    ])?;
+
+    // every compilation has its own source map, so the ids of the files don't depend on earlier compilations
+    for _ in 0..2 {
+        let mut source_map = SourceMap::default();
+        let top_level_scope = ModuleLoader::new(vec![]).load(directory.join("main.monkey"), &mut source_map)?;
+        let file_ids = top_level_scope.program.iter().map(|node| node.file_position().file_id).collect::<Vec<_>>();
+
+        assert_eq!(file_ids, vec![FileId(2), FileId(1), FileId(1)]);
+        assert_eq!(source_map.path_of(FileId(1)), Some(directory.join("main.monkey").as_path()));
+        assert_eq!(source_map.path_of(FileId(2)), Some(directory.join("a.monkey").as_path()));
+        assert_eq!(source_map.path_of(FileId(3)), None);
+    }
+
+    let _ = std::fs::remove_dir_all(&directory);
+    Ok(())
+}
+
+#[test]
+fn module_errors() -> anyhow::Result<()> {
+    let result = load_project(&[
+        ("main.monkey", "module a;\nfn main(): i32 { a::hidden(); return 0; }"),
+        ("a.monkey", "fn hidden(): void { }"),
+    ], vec![])?;
+    assert!(matches!(result, Err(ImportError::PrivateAccess { name, .. }) if name == "a::hidden"));
+
+    let result = load_project(&[
+        ("main.monkey", "module a;\nfn main(): i32 { return 0; }"),
+        ("a.monkey", "module b;\npub fn f(): void { }"),
+        ("b.monkey", "module a;\npub fn g(): void { }"),
+    ], vec![])?;
+    assert!(matches!(result, Err(ImportError::CyclicImport { cycle, .. }) if cycle == vec!["a", "b", "a"]));
+
+    let result = load_project(&[
+        ("main.monkey", "module a;\nfn main(): i32 { return 0; }"),
+        ("a.monkey", "let b: i32 = 5;"),
+    ], vec![])?;
+    assert!(matches!(result, Err(ImportError::StatementInModule { namespace, .. }) if namespace == "a"));
+
+    Ok(())
+}
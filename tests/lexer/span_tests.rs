@@ -0,0 +1,57 @@
+use monkey_language::core::io::monkey_file::MonkeyFile;
+use monkey_language::core::io::source_map::{FileId, SourceMap};
+use monkey_language::core::lexer::error::Error;
+use monkey_language::core::lexer::token::Token;
+use crate::tokenize;
+
+#[test]
+fn byte_offsets() -> Result<(), Error> {
+    let program = "let a = \"äöü\";\nlet b = a;";
+    let tokens = tokenize(program)?;
+
+    for token in &tokens {
+        assert_eq!(&program[token.span.offset.clone()], token.token.to_string(), "{:?}", token);
+    }
+
+    assert_eq!(tokens[3].span.offset, 8..16);
+    assert_eq!(tokens[6].span.offset, 22..23);
+    assert_eq!(tokens[6].span.line, 2..=2);
+    assert_eq!(tokens[6].span.column, 5..=5);
+
+    Ok(())
+}
+
+#[test]
+fn multi_line_spans() -> Result<(), Error> {
+    let program = "let a = \"first\nsecond\";\nlet b = 1;";
+    let tokens = tokenize(program)?;
+
+    assert_eq!(tokens[3].token, Token::Literal("\"first\nsecond\"".to_string()));
+    assert_eq!(tokens[3].span.line, 1..=2);
+    assert_eq!(tokens[4].span.line, 2..=2);
+    assert_eq!(tokens[4].span.column, 8..=8);
+    assert_eq!(tokens[5].span.line, 3..=3);
+    assert_eq!(tokens[5].span.column, 1..=3);
+
+    Ok(())
+}
+
+#[test]
+fn spans_name_their_file() -> anyhow::Result<()> {
+    let path = std::env::temp_dir().join("monkey_language_spans_name_their_file.monkey");
+    std::fs::write(&path, "let a = 1;\nlet b = 2;")?;
+
+    let mut source_map = SourceMap::default();
+    let monkey_file = MonkeyFile::read(&path, &mut source_map)?;
+    let _ = std::fs::remove_file(&path);
+
+    assert_ne!(monkey_file.file_id, FileId::default());
+    assert!(monkey_file.tokens.iter().all(|token| token.span.file_id == monkey_file.file_id));
+
+    let span = &monkey_file.tokens[5].span;
+    assert_eq!(span.to_string(), "2:1 (3 chars)");
+    assert_eq!(source_map.path_of(span.file_id), Some(path.as_path()));
+    assert_eq!(source_map.get(span.file_id).map(|file| file.source[span.offset.clone()].to_string()), Some("let".to_string()));
+
+    Ok(())
+}
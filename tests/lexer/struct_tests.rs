@@ -0,0 +1,41 @@
+use monkey_language::core::io::monkey_file::MonkeyFile;
+use crate::read_from_str;
+use monkey_language::core::model::abstract_syntax_tree_node::AbstractSyntaxTreeNode;
+use monkey_language::core::model::abstract_syntax_tree_nodes::assignable::Assignable;
+use monkey_language::core::model::abstract_syntax_tree_nodes::l_value::LValue;
+use monkey_language::core::parser::ast_parser::ASTParser;
+
+#[test]
+fn field_access() -> anyhow::Result<()> {
+    let program = r#"
+    fn main(): void {
+        let h = person.mother.height;
+        person.age = 31;
+        let a = person.age + 1;
+        person.mother.greet();
+    }
+    "#;
+
+    let monkey_file: MonkeyFile = read_from_str(program)?;
+    let top_level_scope = ASTParser::parse(&monkey_file.tokens)?.result;
+
+    let AbstractSyntaxTreeNode::MethodDefinition(main) = &top_level_scope.program[0] else { panic!("expected a method definition") };
+
+    let AbstractSyntaxTreeNode::Variable(h) = &main.stack[0] else { panic!("expected a variable") };
+    assert_eq!(h.assignable.to_string(), "person.mother.height");
+
+    let AbstractSyntaxTreeNode::Variable(age) = &main.stack[1] else { panic!("expected a variable") };
+    assert!(!age.define);
+    assert!(matches!(&age.l_value, LValue::Expression(expression) if matches!(expression.value.as_deref(), Some(Assignable::FieldAccess(_)))));
+    assert_eq!(age.l_value.to_string(), "person.age");
+    assert_eq!(age.l_value.identifier(), "person");
+
+    let AbstractSyntaxTreeNode::Variable(a) = &main.stack[2] else { panic!("expected a variable") };
+    assert_eq!(a.assignable.to_string(), "(person.age + 1)");
+
+    let AbstractSyntaxTreeNode::MethodCall(greet) = &main.stack[3] else { panic!("expected a method call") };
+    assert!(matches!(greet.receiver.as_deref(), Some(Assignable::FieldAccess(receiver)) if receiver.to_string() == "person.mother"));
+    assert_eq!(greet.identifier.identifier(), "greet");
+
+    Ok(())
+}
@@ -0,0 +1,137 @@
+use monkey_language::core::io::monkey_file::MonkeyFile;
+use crate::read_from_str;
+use monkey_language::core::lexer::error::Error;
+use monkey_language::core::lexer::token::Token;
+use monkey_language::core::model::abstract_syntax_tree_node::AbstractSyntaxTreeNode;
+use monkey_language::core::parser::ast_parser::ASTParser;
+use monkey_language::core::parser::types::r#type::InferTypeError;
+use monkey_language::core::semantics::static_type_check::static_type_checker::static_type_check;
+use monkey_language::core::semantics::type_infer::type_inferer::infer_type;
+
+#[test]
+fn every_syntax_error_is_reported() -> anyhow::Result<()> {
+    let code = r#"fn add(a: i32, b: i32): i32 {
+    let c = a + ;
+    return a + b;
+}
+
+let y = (1 + ;
+struct Foo { a: i32 }
+fn main(): i32 {
+    let x: i32 = add(1, 2);
+    return 0;
+}
+"#;
+
+    let monkey_file: MonkeyFile = read_from_str(code)?;
+    let top_level_scope = ASTParser::parse(&monkey_file.tokens)?.result;
+
+    let diagnostics = top_level_scope.diagnostics.iter()
+        .map(|error| match error {
+            Error::UnexpectedToken(token) => (token.token.clone(), *token.span.line.start()),
+            other => panic!("Expected an unexpected token, got: {other:?}"),
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(diagnostics, vec![(Token::Let, 2), (Token::Let, 6)]);
+    assert!(top_level_scope.has_main_method);
+
+    let AbstractSyntaxTreeNode::MethodDefinition(add) = &top_level_scope.program[0] else { panic!("Expected a method definition") };
+    assert!(matches!(add.stack[0], AbstractSyntaxTreeNode::SyntaxError(_)));
+    assert!(matches!(add.stack[1], AbstractSyntaxTreeNode::Return(_)));
+
+    assert!(matches!(top_level_scope.program[1], AbstractSyntaxTreeNode::SyntaxError(_)));
+    assert!(matches!(top_level_scope.program[2], AbstractSyntaxTreeNode::StructDefinition(_)));
+    assert!(matches!(top_level_scope.program[3], AbstractSyntaxTreeNode::MethodDefinition(_)));
+
+    Ok(())
+}
+
+#[test]
+fn synchronize_on_blocks() -> anyhow::Result<()> {
+    let code = r#"fn main(): i32 {
+    if (1 == ) { let a = 1; } else { let a = 2; }
+    let b: i32 = 5
+    let c: i32 = 6;
+    return b;
+}
+"#;
+
+    let monkey_file: MonkeyFile = read_from_str(code)?;
+    let top_level_scope = ASTParser::parse(&monkey_file.tokens)?.result;
+
+    assert_eq!(top_level_scope.diagnostics.len(), 2);
+
+    let AbstractSyntaxTreeNode::MethodDefinition(main) = &top_level_scope.program[0] else { panic!("Expected a method definition") };
+    assert_eq!(main.stack.len(), 4);
+    assert!(matches!(&main.stack[0], AbstractSyntaxTreeNode::SyntaxError(error) if *error.file_position.line.start() == 2));
+    assert!(matches!(&main.stack[1], AbstractSyntaxTreeNode::SyntaxError(error) if *error.file_position.line.start() == 3));
+    assert!(matches!(main.stack[2], AbstractSyntaxTreeNode::Variable(_)));
+    assert!(matches!(main.stack[3], AbstractSyntaxTreeNode::Return(_)));
+
+    Ok(())
+}
+
+#[test]
+fn synchronize_on_parentheses() -> anyhow::Result<()> {
+    let code = r#"fn main(): i32 {
+    for (let mut i: i32 = 0; i < ; i = i + 1;) {
+        let a: i32 = i;
+    }
+    let b: i32 = add(1, ; 2);
+    let c: i32 = 6;
+    return c;
+}
+let y: i32 = 1 +
+pub fn add(a: i32, b: i32): i32 {
+    return a + b;
+}
+"#;
+
+    let monkey_file: MonkeyFile = read_from_str(code)?;
+    let top_level_scope = ASTParser::parse(&monkey_file.tokens)?.result;
+
+    assert_eq!(top_level_scope.diagnostics.len(), 3);
+
+    let AbstractSyntaxTreeNode::MethodDefinition(main) = &top_level_scope.program[0] else { panic!("Expected a method definition") };
+    assert_eq!(main.stack.len(), 4);
+    assert!(matches!(&main.stack[0], AbstractSyntaxTreeNode::SyntaxError(error) if *error.file_position.line.end() == 4));
+    assert!(matches!(&main.stack[1], AbstractSyntaxTreeNode::SyntaxError(error) if *error.file_position.line.start() == 5));
+    assert!(matches!(main.stack[2], AbstractSyntaxTreeNode::Variable(_)));
+    assert!(matches!(main.stack[3], AbstractSyntaxTreeNode::Return(_)));
+    assert!(matches!(top_level_scope.program[1], AbstractSyntaxTreeNode::SyntaxError(_)));
+    assert!(matches!(&top_level_scope.program[2], AbstractSyntaxTreeNode::MethodDefinition(add) if add.is_public));
+
+    Ok(())
+}
+
+#[test]
+fn type_checking_continues_after_syntax_errors() -> anyhow::Result<()> {
+    let code = r#"
+    let a: i32 = 5;
+    let b = ;
+    let c: i32 = a * 2;
+    "#;
+
+    let monkey_file: MonkeyFile = read_from_str(code)?;
+    let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?.result;
+
+    assert_eq!(top_level_scope.diagnostics.len(), 1);
+    infer_type(&mut top_level_scope.program)?;
+    static_type_check(&top_level_scope.program)?;
+
+    let code = r#"
+    let a: i32 = 5;
+    let b = ;
+    let c: bool = a * 2;
+    "#;
+
+    let monkey_file: MonkeyFile = read_from_str(code)?;
+    let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?.result;
+
+    assert_eq!(top_level_scope.diagnostics.len(), 1);
+    let infer_result = infer_type(&mut top_level_scope.program);
+    assert!(matches!(infer_result.map_err(|e| *e), Err(InferTypeError::MismatchedTypes { .. })));
+
+    Ok(())
+}
@@ -0,0 +1,53 @@
+use monkey_language::core::io::monkey_file::MonkeyFile;
+use monkey_language::core::io::source_map::{FileId, SourceMap};
+use monkey_language::core::lexer::token::Token;
+use monkey_language::core::lexer::token_with_span::Trivia;
+use monkey_language::core::lexer::tokenizer::tokenize_lossless;
+
+fn source_code(program: &str) -> String {
+    let (tokens, _) = tokenize_lossless(program, FileId::default());
+    tokens.iter().map(|token| token.to_source()).collect::<String>()
+}
+
+#[test]
+fn lossless_round_trip() {
+    let programs = [
+        "let a = 10;",
+        "  let a: i32 = 10;  // trailing\n\n\t// leading\nlet b = a /* inline */ + 2;\n",
+        "fn main(): i32 {\r\n    /* a\n  multi line /* nested */ comment */\n    return 0;\r\n}\n\n",
+        "let s = \"text with  spaces\\n\";\nlet c = 'a'; let ü = r\"raw\";",
+        "module std/io.monkey;\nlet a = 5 # 3; /* unterminated",
+        "let a = 1 ? 2;",
+    ];
+
+    for program in programs {
+        assert_eq!(source_code(program), program);
+    }
+}
+
+#[test]
+fn trivia_placement() {
+    let program = "let a = 1; // one\n/* two\n*/ let b = 2; /* three */\n";
+    let (tokens, errors) = tokenize_lossless(program, FileId::default());
+
+    assert!(errors.is_empty());
+    assert_eq!(tokens[4].token, Token::SemiColon);
+    assert_eq!(tokens[4].trivia.as_deref(), Some(&Trivia { leading: String::new(), trailing: " // one".to_string() }));
+    assert_eq!(tokens[5].token, Token::Let);
+    assert_eq!(tokens[5].trivia.as_deref(), Some(&Trivia { leading: "\n/* two\n*/ ".to_string(), trailing: " ".to_string() }));
+    assert_eq!(tokens[9].trivia.as_deref(), Some(&Trivia { leading: String::new(), trailing: " /* three */\n".to_string() }));
+}
+
+#[test]
+fn read_files_keep_their_trivia() -> anyhow::Result<()> {
+    let program = "fn main(): i32 {\n    // answer\n    return 42; /* done */\n}\n";
+    let path = std::env::temp_dir().join("monkey_language_read_files_keep_their_trivia.monkey");
+    std::fs::write(&path, program)?;
+
+    let monkey_file = MonkeyFile::read(&path, &mut SourceMap::default())?;
+    let _ = std::fs::remove_file(&path);
+
+    assert_eq!(monkey_file.tokens.iter().map(|token| token.to_source()).collect::<String>(), program);
+
+    Ok(())
+}
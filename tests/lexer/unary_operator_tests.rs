@@ -0,0 +1,67 @@
+use monkey_language::core::io::monkey_file::MonkeyFile;
+use crate::read_from_str;
+use monkey_language::core::lexer::error::Error;
+use monkey_language::core::lexer::token::Token;
+use crate::tokenize;
+use monkey_language::core::model::abstract_syntax_tree_node::AbstractSyntaxTreeNode;
+use monkey_language::core::model::abstract_syntax_tree_nodes::assignable::Assignable;
+use monkey_language::core::model::abstract_syntax_tree_nodes::assignables::equation_parser::operator::Operator;
+use monkey_language::core::model::abstract_syntax_tree_nodes::assignables::equation_parser::prefix_arithmetic::PrefixArithmetic;
+use monkey_language::core::parser::ast_parser::ASTParser;
+
+#[test]
+fn unary_operator_tokens() -> Result<(), Error> {
+    let tokens = tokenize("-a !b ~c != ~=")?
+        .into_iter()
+        .map(|token| token.token)
+        .collect::<Vec<_>>();
+
+    assert_eq!(tokens, vec![
+        Token::Minus, Token::Literal("a".to_string()),
+        Token::LogicalNot, Token::Literal("b".to_string()),
+        Token::Tilde, Token::Literal("c".to_string()),
+        Token::NotEquals, Token::Tilde, Token::Equals,
+    ]);
+
+    Ok(())
+}
+
+#[test]
+fn unary_operators() -> anyhow::Result<()> {
+    let program = r#"
+    fn main(): void {
+        let a = 5;
+        let b = -a;
+        let c = !(a < 3);
+        let d = ~a & 7;
+        let e = -5;
+        let f = a - -a;
+    }
+    "#;
+
+    let monkey_file: MonkeyFile = read_from_str(program)?;
+    let top_level_scope = ASTParser::parse(&monkey_file.tokens)?.result;
+
+    let AbstractSyntaxTreeNode::MethodDefinition(main) = &top_level_scope.program[0] else { panic!("expected a method definition") };
+    let assignables = main.stack.iter().filter_map(|node| match node {
+        AbstractSyntaxTreeNode::Variable(variable) => Some(&variable.assignable),
+        _ => None
+    }).collect::<Vec<_>>();
+
+    let Assignable::Expression(negation) = assignables[1] else { panic!("expected an expression") };
+    assert_eq!(negation.prefix_arithmetic, Some(PrefixArithmetic::Operation(Operator::Sub)));
+
+    let Assignable::Expression(logical_not) = assignables[2] else { panic!("expected an expression") };
+    assert_eq!(logical_not.prefix_arithmetic, Some(PrefixArithmetic::Operation(Operator::LogicalNot)));
+
+    let Assignable::Expression(bitwise_and) = assignables[3] else { panic!("expected an expression") };
+    assert_eq!(bitwise_and.operator, Operator::BitwiseAnd);
+    assert_eq!(bitwise_and.lhs.as_ref().map(|lhs| lhs.prefix_arithmetic.clone()), Some(Some(PrefixArithmetic::Operation(Operator::BitwiseNot))));
+
+    assert!(matches!(assignables[4], Assignable::Integer(integer) if integer.value == "-5"), "{:?}", assignables[4]);
+
+    assert_eq!(assignables[1].to_string(), "-a");
+    assert_eq!(assignables[5].to_string(), "(a - -a)");
+
+    Ok(())
+}
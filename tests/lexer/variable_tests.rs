@@ -1,4 +1,5 @@
 use monkey_language::core::io::monkey_file::MonkeyFile;
+use crate::read_from_str;
 use monkey_language::core::lexer::token_with_span::FilePosition;
 use monkey_language::core::model::abstract_syntax_tree_node::AbstractSyntaxTreeNode;
 use monkey_language::core::model::abstract_syntax_tree_nodes::assignable::Assignable;
@@ -44,67 +45,73 @@ fn variable_test() -> anyhow::Result<()> {
     let pointer_arithmetic = *ref_value + 1;
     "#;
 
-    let monkey_file: MonkeyFile = MonkeyFile::read_from_str(variables)?;
+    let monkey_file: MonkeyFile = read_from_str(variables)?;
     let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
     infer_type(&mut top_level_scope.result.program)?;
 
     let expected = vec![
         AbstractSyntaxTreeNode::Variable(
             Variable {
-                l_value: LValue::Identifier(Identifier { name: "fisch".to_string() }),
+                l_value: LValue::Identifier(Identifier { name: "fisch".to_string(), symbol: None }),
                 mutability: false,
-                ty: Some(Type::Custom(Identifier { name: String::from("*string") }, Mutability::Immutable)),
+                ty: Some(Type::Custom(Identifier { name: String::from("*string"), symbol: None }, Mutability::Immutable)),
                 define: true,
+                compound_operator: None,
                 assignable: Assignable::String(StaticString { value: "\"Fische sind wirklich wirklich toll\"".to_string() }),
-                file_position: FilePosition { line: 2..=2, column: 5..=53 },
+                file_position: FilePosition { line: 2..=2, column: 5..=53, offset: 5..54, ..Default::default() },
             }
         ),
         AbstractSyntaxTreeNode::Variable(
             Variable {
-                l_value: LValue::Identifier(Identifier { name: "hallo".to_string() }),
+                l_value: LValue::Identifier(Identifier { name: "hallo".to_string(), symbol: None }),
                 mutability: false,
-                ty: Some(Type::Custom(Identifier { name: String::from("*string") }, Mutability::Immutable)),
+                ty: Some(Type::Custom(Identifier { name: String::from("*string"), symbol: None }, Mutability::Immutable)),
                 define: true,
+                compound_operator: None,
                 assignable: Assignable::String(StaticString { value: "\"Thomas\"".to_string() }),
-                file_position: FilePosition { line: 3..=3, column: 5..=25 },
+                file_position: FilePosition { line: 3..=3, column: 5..=25, offset: 59..80, ..Default::default() },
             }
         ),
         AbstractSyntaxTreeNode::Variable(
             Variable {
-                l_value: LValue::Identifier(Identifier { name: "tschuess".to_string() }),
+                l_value: LValue::Identifier(Identifier { name: "tschuess".to_string(), symbol: None }),
                 mutability: false,
                 ty: Some(Type::Integer(IntegerType::I32, Mutability::Immutable)),
                 define: true,
+                compound_operator: None,
                 assignable: Assignable::Integer(IntegerAST { value: "5".to_string(), ty: IntegerType::I32 }),
-                file_position: FilePosition { line: 3..=3, column: 27..=43 },
+                file_position: FilePosition { line: 3..=3, column: 27..=43, offset: 81..98, ..Default::default() },
             }
         ),
         AbstractSyntaxTreeNode::Variable(
             Variable {
-                l_value: LValue::Identifier(Identifier { name: "mallo".to_string() }),
+                l_value: LValue::Identifier(Identifier { name: "mallo".to_string(), symbol: None }),
                 mutability: false,
-                ty: Some(Type::Custom(Identifier { name: String::from("*string") }, Mutability::Immutable)),
+                ty: Some(Type::Custom(Identifier { name: String::from("*string"), symbol: None }, Mutability::Immutable)),
                 define: true,
+                compound_operator: None,
                 assignable: Assignable::String(StaticString { value: "\"\"".to_string() }),
-                file_position: FilePosition { line: 4..=4, column: 5..=19 },
+                file_position: FilePosition { line: 4..=4, column: 5..=19, offset: 103..118, ..Default::default() },
             }
         ),
         AbstractSyntaxTreeNode::Variable(
             Variable {
-                l_value: LValue::Identifier(Identifier { name: "value".to_string() }),
+                l_value: LValue::Identifier(Identifier { name: "value".to_string(), symbol: None }),
                 mutability: false,
                 ty: Some(Type::Integer(IntegerType::I32, Mutability::Immutable)),
                 define: true,
+                compound_operator: None,
                 assignable: Assignable::Integer(IntegerAST { value: "9".to_string(), ty: IntegerType::I32 }),
-                file_position: FilePosition { line: 5..=5, column: 5..=18 },
+                file_position: FilePosition { line: 5..=5, column: 5..=18, offset: 123..137, ..Default::default() },
             }
         ),
         AbstractSyntaxTreeNode::Variable(
             Variable {
-                l_value: LValue::Identifier(Identifier { name: "ref_value".to_string() }),
+                l_value: LValue::Identifier(Identifier { name: "ref_value".to_string(), symbol: None }),
                 mutability: false,
-                ty: Some(Type::Custom(Identifier { name: "*i32".to_string() }, Mutability::Immutable)),
+                ty: Some(Type::Custom(Identifier { name: "*i32".to_string(), symbol: None }, Mutability::Immutable)),
                 define: true,
+                compound_operator: None,
                 assignable: Assignable::Expression(Expression {
                     lhs: None,
                     rhs: None,
@@ -115,23 +122,24 @@ fn variable_test() -> anyhow::Result<()> {
                         rhs: None,
                         operator: Operator::Noop,
                         prefix_arithmetic: None,
-                        value: Some(Box::new(Assignable::Identifier(Identifier { name: "value".to_string() }))),
+                        value: Some(Box::new(Assignable::Identifier(Identifier { name: "value".to_string(), symbol: None }))),
                         index_operator: None,
                         positive: true,
                     }))),
                     index_operator: None,
                     positive: true,
                 }),
-                file_position: FilePosition { line: 6..=6, column: 5..=27 },
+                file_position: FilePosition { line: 6..=6, column: 5..=27, offset: 142..165, ..Default::default() },
             }
         ),
         // let pointer_arithmetic = *ref_value + 1;
         AbstractSyntaxTreeNode::Variable(
             Variable {
-                l_value: LValue::Identifier(Identifier { name: "pointer_arithmetic".to_string() }),
+                l_value: LValue::Identifier(Identifier { name: "pointer_arithmetic".to_string(), symbol: None }),
                 mutability: false,
                 ty: Some(Type::Integer(IntegerType::I32, Mutability::Immutable)),
                 define: true,
+                compound_operator: None,
                 assignable: Assignable::Expression(Expression {
                     lhs: Some(Box::new(Expression {
                         value: Some(Box::new(Assignable::Expression(Expression {
@@ -139,7 +147,7 @@ fn variable_test() -> anyhow::Result<()> {
                             rhs: None,
                             operator: Operator::Noop,
                             prefix_arithmetic: None,
-                            value: Some(Box::new(Assignable::Identifier(Identifier { name: "ref_value".to_string() }))),
+                            value: Some(Box::new(Assignable::Identifier(Identifier { name: "ref_value".to_string(), symbol: None }))),
                             index_operator: None,
                             positive: true,
                         }))),
@@ -158,7 +166,7 @@ fn variable_test() -> anyhow::Result<()> {
                     index_operator: None,
                     positive: true,
                 }),
-                file_position: FilePosition { line: 7..=7, column: 5..=44 },
+                file_position: FilePosition { line: 7..=7, column: 5..=44, offset: 170..210, ..Default::default() },
             }
         ),
     ];
@@ -185,7 +193,7 @@ fn variable_test_types() -> anyhow::Result<()> {
     let pointer_arithmetic = *ref_value + 1;
     "#;
 
-    let monkey_file: MonkeyFile = MonkeyFile::read_from_str(variables)?;
+    let monkey_file: MonkeyFile = read_from_str(variables)?;
     let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
     infer_type(&mut top_level_scope.result.program)?;
 
@@ -194,9 +202,9 @@ fn variable_test_types() -> anyhow::Result<()> {
         r#type::common::string(),
         Type::Integer(IntegerType::I32, Mutability::Immutable),
         r#type::common::string(),
-        Type::Custom(Identifier { name: "Data".to_string() }, Mutability::Immutable),
+        Type::Custom(Identifier { name: "Data".to_string(), symbol: None }, Mutability::Immutable),
         Type::Integer(IntegerType::I32, Mutability::Immutable),
-        Type::Custom(Identifier { name: "*i32".to_string() }, Mutability::Immutable),
+        Type::Custom(Identifier { name: "*i32".to_string(), symbol: None }, Mutability::Immutable),
         Type::Integer(IntegerType::I32, Mutability::Immutable),
     ];
 
@@ -227,7 +235,7 @@ fn variable_test_casting() -> anyhow::Result<()> {
     let d = 5.0 + 1.0;
     "#;
 
-    let monkey_file: MonkeyFile = MonkeyFile::read_from_str(variables)?;
+    let monkey_file: MonkeyFile = read_from_str(variables)?;
     let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
     infer_type(&mut top_level_scope.result.program)?;
     println!("{:?}", top_level_scope);
@@ -261,7 +269,7 @@ fn variable_test_casting() -> anyhow::Result<()> {
 fn variable_test_double_casting() -> anyhow::Result<()> {
     let variables = r#"let b: f32 = (f32)(i32) 5;"#;
 
-    let monkey_file: MonkeyFile = MonkeyFile::read_from_str(variables)?;
+    let monkey_file: MonkeyFile = read_from_str(variables)?;
     let top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
 
     let expected = vec![
@@ -317,15 +325,15 @@ fn variable_test_casting_complex_expression() -> anyhow::Result<()> {
     let addition4 = (((i32)((f32)*d + (f32)*b) + (*b + *d)) + (*b + *b)) + ((*b + (*b + *b)) + (*b + (*d + *b)));
     "#;
 
-    let monkey_file: MonkeyFile = MonkeyFile::read_from_str(variables)?;
+    let monkey_file: MonkeyFile = read_from_str(variables)?;
     let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
     infer_type(&mut top_level_scope.result.program)?;
 
     let expected = vec![
         Type::Integer(IntegerType::I32, Mutability::Immutable),
-        Type::Custom(Identifier { name: "*i32".to_string() }, Mutability::Immutable),
+        Type::Custom(Identifier { name: "*i32".to_string(), symbol: None }, Mutability::Immutable),
         Type::Integer(IntegerType::I32, Mutability::Immutable),
-        Type::Custom(Identifier { name: "*i32".to_string() }, Mutability::Immutable),
+        Type::Custom(Identifier { name: "*i32".to_string(), symbol: None }, Mutability::Immutable),
         Type::Float(FloatType::Float32, Mutability::Immutable),
         Type::Float(FloatType::Float32, Mutability::Immutable),
         Type::Float(FloatType::Float32, Mutability::Immutable),
@@ -358,7 +366,7 @@ fn variable_test_casting_complex() -> anyhow::Result<()> {
     let k: i32 = (i32)(f32)(((i32)(f32)*r) + 2);
     "#;
 
-    let monkey_file: MonkeyFile = MonkeyFile::read_from_str(variables)?;
+    let monkey_file: MonkeyFile = read_from_str(variables)?;
     let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
     infer_type(&mut top_level_scope.result.program)?;
 
@@ -366,7 +374,7 @@ fn variable_test_casting_complex() -> anyhow::Result<()> {
 
     let expected = vec![
         Type::Integer(IntegerType::I32, Mutability::Immutable),
-        Type::Custom(Identifier { name: "*i32".to_string() }, Mutability::Immutable),
+        Type::Custom(Identifier { name: "*i32".to_string(), symbol: None }, Mutability::Immutable),
         Type::Integer(IntegerType::I32, Mutability::Immutable),
         Type::Integer(IntegerType::I32, Mutability::Immutable),
         Type::Integer(IntegerType::I32, Mutability::Immutable),
@@ -410,7 +418,7 @@ fn variable_test_integers() -> anyhow::Result<()> {
     let j = (u64)f + i;
     "#;
 
-    let monkey_file: MonkeyFile = MonkeyFile::read_from_str(variables)?;
+    let monkey_file: MonkeyFile = read_from_str(variables)?;
     let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
     infer_type(&mut top_level_scope.result.program)?;
 
@@ -463,7 +471,7 @@ fn variable_test_integers_assignable() -> anyhow::Result<()> {
     let i: u64 = 5;
     "#;
 
-    let monkey_file: MonkeyFile = MonkeyFile::read_from_str(variables)?;
+    let monkey_file: MonkeyFile = read_from_str(variables)?;
     let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
     infer_type(&mut top_level_scope.result.program)?;
 
@@ -499,4 +507,40 @@ fn variable_test_integers_assignable() -> anyhow::Result<()> {
     }
 
     Ok(())
-}
\ No newline at end of file
+}
+#[test]
+fn variable_declaration_without_value() -> anyhow::Result<()> {
+    let program = r#"
+    let mut a: i32;
+    let b: *string;
+    "#;
+
+    let monkey_file: MonkeyFile = read_from_str(program)?;
+    let top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
+
+    let expected = vec![
+        AbstractSyntaxTreeNode::Variable(Variable {
+            l_value: LValue::Identifier(Identifier { name: "a".to_string(), symbol: None }),
+            mutability: true,
+            ty: Some(Type::Integer(IntegerType::I32, Mutability::Immutable)),
+            define: true,
+            compound_operator: None,
+            assignable: Assignable::Uninitialized,
+            file_position: FilePosition { line: 2..=2, column: 5..=19, offset: 5..20, ..Default::default() },
+        }),
+        AbstractSyntaxTreeNode::Variable(Variable {
+            l_value: LValue::Identifier(Identifier { name: "b".to_string(), symbol: None }),
+            mutability: false,
+            ty: Some(r#type::common::string()),
+            define: true,
+            compound_operator: None,
+            assignable: Assignable::Uninitialized,
+            file_position: FilePosition { line: 3..=3, column: 5..=19, offset: 25..40, ..Default::default() },
+        }),
+    ];
+
+    assert_eq!(expected, top_level_scope.result.program);
+    assert_eq!("let mut a: i32", top_level_scope.result.program[0].to_string());
+
+    Ok(())
+}
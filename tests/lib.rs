@@ -1,4 +1,33 @@
+use std::path::PathBuf;
+
+use monkey_language::core::io::monkey_file::MonkeyFile;
+use monkey_language::core::io::source_map::FileId;
+use monkey_language::core::lexer::error::Error;
+use monkey_language::core::lexer::token_with_span::TokenWithSpan;
+use monkey_language::core::lexer::tokenizer::collect_greedy_recovering;
+
 pub mod lexer;
 pub mod semantics;
 pub mod building;
-pub mod optimization;
\ No newline at end of file
+pub mod optimization;
+
+/// Tokenizes source code, which isn't backed by a file. Fails at the first lexical error
+pub fn tokenize(code: &str) -> Result<Vec<TokenWithSpan>, Error> {
+    let (tokens, errors) = collect_greedy_recovering(code, FileId::default());
+
+    match errors.into_iter().next() {
+        Some(error) => Err(error),
+        None => Ok(tokens),
+    }
+}
+
+/// Reads source code, which isn't backed by a file. Fails at the first lexical error
+pub fn read_from_str(code: &str) -> anyhow::Result<MonkeyFile> {
+    Ok(MonkeyFile {
+        path: PathBuf::new(),
+        tokens: tokenize(code)?,
+        size: code.chars().count(),
+        file_id: FileId::default(),
+        errors: vec![],
+    })
+}
@@ -1,4 +1,5 @@
 use monkey_language::core::io::monkey_file::MonkeyFile;
+use crate::read_from_str;
 use monkey_language::core::model::abstract_syntax_tree_node::AbstractSyntaxTreeNode;
 use monkey_language::core::model::abstract_syntax_tree_nodes::assignable::Assignable;
 use monkey_language::core::model::abstract_syntax_tree_nodes::variable::Variable;
@@ -17,7 +18,7 @@ fn fold_expression_test() -> anyhow::Result<()> {
     "#;
 
 
-    let monkey_file: MonkeyFile = MonkeyFile::read_from_str(function)?;
+    let monkey_file: MonkeyFile = read_from_str(function)?;
     let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
     let _ = infer_type(&mut top_level_scope.result.program)?;
     let mut static_type_context = static_type_check(&top_level_scope.result.program)?;
@@ -46,7 +47,7 @@ fn fold_expression_complex() -> anyhow::Result<()> {
     "#;
 
 
-    let monkey_file: MonkeyFile = MonkeyFile::read_from_str(function)?;
+    let monkey_file: MonkeyFile = read_from_str(function)?;
     let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
     let _ = infer_type(&mut top_level_scope.result.program)?;
     let mut static_type_context = static_type_check(&top_level_scope.result.program)?;
@@ -76,7 +77,7 @@ fn fold_expression_with_variable_complex() -> anyhow::Result<()> {
     "#;
 
 
-    let monkey_file: MonkeyFile = MonkeyFile::read_from_str(function)?;
+    let monkey_file: MonkeyFile = read_from_str(function)?;
     let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
     let _ = infer_type(&mut top_level_scope.result.program)?;
     let mut static_type_context = static_type_check(&top_level_scope.result.program)?;
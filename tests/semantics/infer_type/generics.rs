@@ -0,0 +1,100 @@
+use monkey_language::core::io::monkey_file::MonkeyFile;
+use crate::read_from_str;
+use monkey_language::core::model::abstract_syntax_tree_node::AbstractSyntaxTreeNode;
+use monkey_language::core::parser::ast_parser::ASTParser;
+use monkey_language::core::parser::types::r#type::InferTypeError;
+use monkey_language::core::semantics::static_type_check::static_type_checker::static_type_check;
+use monkey_language::core::semantics::type_infer::type_inferer::infer_type;
+
+const GENERIC_METHODS: &str = r#"
+    fn max<T: Numeric>(a: T, b: T): T {
+        if (a > b) {
+            return a;
+        }
+
+        return b;
+    }
+
+    fn zero<T: Integer>(): T {
+        return (T) 0;
+    }
+
+    fn max3<T: Numeric>(a: T, b: T, c: T): T {
+        let ab: T = max(a, b);
+        return max::<T>(ab, c);
+    }
+
+    fn read<T>(value: *T): T {
+        return *value;
+    }
+"#;
+
+fn monomorphize(main: &str) -> anyhow::Result<Result<Vec<AbstractSyntaxTreeNode>, Box<InferTypeError>>> {
+    let program = format!("{GENERIC_METHODS}\nfn main(): i32 {{\n{main}\nreturn 0;\n}}");
+    let monkey_file: MonkeyFile = read_from_str(&program)?;
+    let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?.result;
+
+    if let Err(err) = infer_type(&mut top_level_scope.program) {
+        return Ok(Err(err));
+    }
+
+    static_type_check(&top_level_scope.program)?;
+    Ok(Ok(top_level_scope.program))
+}
+
+fn method_names(program: &[AbstractSyntaxTreeNode]) -> Vec<String> {
+    program.iter()
+        .filter_map(|node| match node {
+            AbstractSyntaxTreeNode::MethodDefinition(method_definition) => Some(method_definition.identifier.identifier()),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn instances() -> anyhow::Result<()> {
+    let program = monomorphize(r#"
+        let a: i32 = max(1, 2);
+        let b: i32 = max::<i32>(a, 3);
+        let c: f64 = 1.5;
+        let d: f64 = max(c, c);
+        let e: u8 = zero::<u8>();
+        let f: i64 = (i64) 5;
+        let g: i64 = max3(f, f, f);
+        let h: i32 = read(&a);
+    "#)??;
+
+    // the generic methods are replaced by one instance for every combination of type arguments
+    assert_eq!(method_names(&program), vec!["main", "max<i32>", "max<f64>", "zero<u8>", "max3<i64>", "read<i32>", "max<i64>"]);
+
+    let Some(AbstractSyntaxTreeNode::MethodDefinition(read)) = program.iter().find(|node| matches!(node, AbstractSyntaxTreeNode::MethodDefinition(method_definition) if method_definition.identifier.identifier() == "read<i32>")) else {
+        panic!("expected the instance `read<i32>`");
+    };
+
+    assert!(!read.is_generic());
+    assert_eq!("fn read<i32>(value: *i32): i32", read.to_string().lines().next().unwrap_or_default().trim_end_matches(" {"));
+
+    Ok(())
+}
+
+#[test]
+fn instance_errors() -> anyhow::Result<()> {
+    let programs = [
+        ("let a: bool = max(true, false);", "The type `bool` doesn't satisfy the bound `T: Numeric` of the method `max`"),
+        ("let a: f64 = zero::<f64>();", "The type `f64` doesn't satisfy the bound `T: Integer` of the method `zero`"),
+        ("let a: i32 = zero();", "The type parameter `T` of the method `zero` cannot be inferred"),
+        ("let a: i32 = max::<i32, i32>(1, 2);", "The method `max` expects 1 type arguments, but 2 are provided"),
+        ("let a: i32 = max(1, true);", "Arguments `(i32, bool)` to the function `max<i32>` are incorrect"),
+    ];
+
+    for (main, expected) in programs {
+        let result = monomorphize(main)?;
+
+        match &result {
+            Err(err) => assert!(err.to_string().contains(expected), "{main}: {err}"),
+            Ok(_) => panic!("{main}: expected an error"),
+        }
+    }
+
+    Ok(())
+}
@@ -1,4 +1,5 @@
 use monkey_language::core::io::monkey_file::MonkeyFile;
+use crate::read_from_str;
 use monkey_language::core::lexer::token_with_span::FilePosition;
 use monkey_language::core::model::abstract_syntax_tree_node::AbstractSyntaxTreeNode;
 use monkey_language::core::model::abstract_syntax_tree_nodes::assignable::Assignable;
@@ -25,42 +26,46 @@ fn infer_type_test() -> anyhow::Result<()> {
     "#;
 
 
-    let monkey_file: MonkeyFile = MonkeyFile::read_from_str(function)?;
+    let monkey_file: MonkeyFile = read_from_str(function)?;
     let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
     infer_type(&mut top_level_scope.result.program)?;
 
     let expected: Vec<AbstractSyntaxTreeNode> = vec![
         AbstractSyntaxTreeNode::Variable(Variable {
-            l_value: LValue::Identifier(Identifier { name: "a".to_string() }),
+            l_value: LValue::Identifier(Identifier { name: "a".to_string(), symbol: None }),
             mutability: false,
             ty: Some(Type::Integer(IntegerType::I32, Mutability::Immutable)),
             define: true,
+            compound_operator: None,
             assignable: Assignable::Integer(IntegerAST { value: "1".to_string(), ty: IntegerType::I32 }),
-            file_position: FilePosition { line: 2..=2, column: 9..=18 },
+            file_position: FilePosition { line: 2..=2, column: 9..=18, offset: 9..19, ..Default::default() },
         }),
         AbstractSyntaxTreeNode::Variable(Variable {
-            l_value: LValue::Identifier(Identifier { name: "b".to_string() }),
+            l_value: LValue::Identifier(Identifier { name: "b".to_string(), symbol: None }),
             mutability: false,
             ty: Some(Type::Float(FloatType::Float32, Mutability::Immutable)),
             define: true,
+            compound_operator: None,
             assignable: Assignable::Float(FloatAST { value: 2.0, ty: FloatType::Float32 }),
-            file_position: FilePosition { line: 3..=3, column: 9..=20 },
+            file_position: FilePosition { line: 3..=3, column: 9..=20, offset: 28..40, ..Default::default() },
         }),
         AbstractSyntaxTreeNode::Variable(Variable {
-            l_value: LValue::Identifier(Identifier { name: "c".to_string() }),
+            l_value: LValue::Identifier(Identifier { name: "c".to_string(), symbol: None }),
             mutability: false,
             ty: Some(Type::Bool(Mutability::Immutable)),
             define: true,
+            compound_operator: None,
             assignable: Assignable::Boolean(Boolean { value: true }),
-            file_position: FilePosition { line: 4..=4, column: 9..=21 },
+            file_position: FilePosition { line: 4..=4, column: 9..=21, offset: 49..62, ..Default::default() },
         }),
         AbstractSyntaxTreeNode::Variable(Variable {
-            l_value: LValue::Identifier(Identifier { name: "d".to_string() }),
+            l_value: LValue::Identifier(Identifier { name: "d".to_string(), symbol: None }),
             mutability: false,
-            ty: Some(Type::Custom(Identifier { name: String::from("*string") }, Mutability::Immutable)),
+            ty: Some(Type::Custom(Identifier { name: String::from("*string"), symbol: None }, Mutability::Immutable)),
             define: true,
+            compound_operator: None,
             assignable: Assignable::String(StaticString { value: "\"KEKW\"".to_string() }),
-            file_position: FilePosition { line: 5..=5, column: 9..=23 },
+            file_position: FilePosition { line: 5..=5, column: 9..=23, offset: 71..86, ..Default::default() },
         }),
     ];
 
@@ -80,7 +85,7 @@ fn infer_type_in_scope() -> anyhow::Result<()> {
     "#;
 
 
-    let monkey_file: MonkeyFile = MonkeyFile::read_from_str(function)?;
+    let monkey_file: MonkeyFile = read_from_str(function)?;
     let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
     infer_type(&mut top_level_scope.result.program)?;
 
@@ -89,40 +94,44 @@ fn infer_type_in_scope() -> anyhow::Result<()> {
             condition: Assignable::Boolean(Boolean { value: true }),
             if_stack: vec![
                 AbstractSyntaxTreeNode::Variable(Variable {
-                    l_value: LValue::Identifier(Identifier { name: "a".to_string() }),
+                    l_value: LValue::Identifier(Identifier { name: "a".to_string(), symbol: None }),
                     mutability: false,
                     ty: Some(Type::Integer(IntegerType::I32, Mutability::Immutable)),
                     define: true,
+                    compound_operator: None,
                     assignable: Assignable::Integer(IntegerAST { value: "1".to_string(), ty: IntegerType::I32 }),
-                    file_position: FilePosition { line: 3..=3, column: 13..=22 },
+                    file_position: FilePosition { line: 3..=3, column: 13..=22, offset: 33..43, ..Default::default() },
                 }),
                 AbstractSyntaxTreeNode::Variable(Variable {
-                    l_value: LValue::Identifier(Identifier { name: "b".to_string() }),
+                    l_value: LValue::Identifier(Identifier { name: "b".to_string(), symbol: None }),
                     mutability: false,
                     ty: Some(Type::Float(FloatType::Float32, Mutability::Immutable)),
                     define: true,
+                    compound_operator: None,
                     assignable: Assignable::Float(FloatAST { value: 2.0, ty: FloatType::Float32 }),
-                    file_position: FilePosition { line: 4..=4, column: 13..=24 },
+                    file_position: FilePosition { line: 4..=4, column: 13..=24, offset: 56..68, ..Default::default() },
                 }),
                 AbstractSyntaxTreeNode::Variable(Variable {
-                    l_value: LValue::Identifier(Identifier { name: "c".to_string() }),
+                    l_value: LValue::Identifier(Identifier { name: "c".to_string(), symbol: None }),
                     mutability: false,
                     ty: Some(Type::Bool(Mutability::Immutable)),
                     define: true,
+                    compound_operator: None,
                     assignable: Assignable::Boolean(Boolean { value: true }),
-                    file_position: FilePosition { line: 5..=5, column: 13..=25 },
+                    file_position: FilePosition { line: 5..=5, column: 13..=25, offset: 81..94, ..Default::default() },
                 }),
                 AbstractSyntaxTreeNode::Variable(Variable {
-                    l_value: LValue::Identifier(Identifier { name: "d".to_string() }),
+                    l_value: LValue::Identifier(Identifier { name: "d".to_string(), symbol: None }),
                     mutability: false,
-                    ty: Some(Type::Custom(Identifier { name: String::from("*string") }, Mutability::Immutable)),
+                    ty: Some(Type::Custom(Identifier { name: String::from("*string"), symbol: None }, Mutability::Immutable)),
                     define: true,
+                    compound_operator: None,
                     assignable: Assignable::String(StaticString { value: "\"KEKW\"".to_string() }),
-                    file_position: FilePosition { line: 6..=6, column: 13..=27 },
+                    file_position: FilePosition { line: 6..=6, column: 13..=27, offset: 107..122, ..Default::default() },
                 }),
             ],
             else_stack: None,
-            file_position: FilePosition { line: 2..=7, column: 9..=9 },
+            file_position: FilePosition { line: 2..=7, column: 9..=9, offset: 9..132, ..Default::default() },
         })
     ];
 
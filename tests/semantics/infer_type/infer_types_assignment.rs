@@ -1,4 +1,5 @@
 use monkey_language::core::io::monkey_file::MonkeyFile;
+use crate::read_from_str;
 use monkey_language::core::lexer::token_with_span::FilePosition;
 use monkey_language::core::model::abstract_syntax_tree_node::AbstractSyntaxTreeNode;
 use monkey_language::core::model::abstract_syntax_tree_nodes::assignable::Assignable;
@@ -26,26 +27,28 @@ fn infer_type_assignment() -> anyhow::Result<()> {
     "#;
 
 
-    let monkey_file: MonkeyFile = MonkeyFile::read_from_str(function)?;
+    let monkey_file: MonkeyFile = read_from_str(function)?;
     let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
     infer_type(&mut top_level_scope.result.program)?;
 
     let expected: Vec<AbstractSyntaxTreeNode> = vec![
         AbstractSyntaxTreeNode::Variable(Variable {
-            l_value: LValue::Identifier(Identifier { name: "a".to_string() }),
+            l_value: LValue::Identifier(Identifier { name: "a".to_string(), symbol: None }),
             mutability: false,
             ty: Some(Type::Integer(IntegerType::I32, Mutability::Immutable)),
             define: true,
+            compound_operator: None,
             assignable: Assignable::Integer(IntegerAST { value: "1".to_string(), ty: IntegerType::I32 }),
-            file_position: FilePosition { line: 2..=2, column: 9..=18 },
+            file_position: FilePosition { line: 2..=2, column: 9..=18, offset: 9..19, ..Default::default() },
         }),
         AbstractSyntaxTreeNode::Variable(Variable {
-            l_value: LValue::Identifier(Identifier { name: "c".to_string() }),
+            l_value: LValue::Identifier(Identifier { name: "c".to_string(), symbol: None }),
             mutability: false,
             ty: Some(Type::Integer(IntegerType::I32, Mutability::Immutable)),
             define: true,
-            assignable: Assignable::Identifier(Identifier { name: "a".to_string() }),
-            file_position: FilePosition { line: 3..=3, column: 9..=18 },
+            compound_operator: None,
+            assignable: Assignable::Identifier(Identifier { name: "a".to_string(), symbol: None }),
+            file_position: FilePosition { line: 3..=3, column: 9..=18, offset: 28..38, ..Default::default() },
         }),
     ];
 
@@ -62,7 +65,7 @@ fn infer_type_assignment_in_scope() -> anyhow::Result<()> {
         }
     "#;
 
-    let monkey_file: MonkeyFile = MonkeyFile::read_from_str(function)?;
+    let monkey_file: MonkeyFile = read_from_str(function)?;
     let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
     infer_type(&mut top_level_scope.result.program)?;
 
@@ -72,24 +75,26 @@ fn infer_type_assignment_in_scope() -> anyhow::Result<()> {
             condition: Assignable::Boolean(Boolean { value: true }),
             if_stack: vec![
                 AbstractSyntaxTreeNode::Variable(Variable {
-                    l_value: LValue::Identifier(Identifier { name: "a".to_string() }),
+                    l_value: LValue::Identifier(Identifier { name: "a".to_string(), symbol: None }),
                     mutability: false,
                     ty: Some(Type::Integer(IntegerType::I32, Mutability::Immutable)),
                     define: true,
+                    compound_operator: None,
                     assignable: Assignable::Integer(IntegerAST { value: "1".to_string(), ty: IntegerType::I32 }),
-                    file_position: FilePosition { line: 3..=3, column: 13..=22 },
+                    file_position: FilePosition { line: 3..=3, column: 13..=22, offset: 33..43, ..Default::default() },
                 }),
                 AbstractSyntaxTreeNode::Variable(Variable {
-                    l_value: LValue::Identifier(Identifier { name: "c".to_string() }),
+                    l_value: LValue::Identifier(Identifier { name: "c".to_string(), symbol: None }),
                     mutability: false,
                     ty: Some(Type::Integer(IntegerType::I32, Mutability::Immutable)),
                     define: true,
-                    assignable: Assignable::Identifier(Identifier { name: "a".to_string() }),
-                    file_position: FilePosition { line: 4..=4, column: 13..=22 },
+                    compound_operator: None,
+                    assignable: Assignable::Identifier(Identifier { name: "a".to_string(), symbol: None }),
+                    file_position: FilePosition { line: 4..=4, column: 13..=22, offset: 56..66, ..Default::default() },
                 }),
             ],
             else_stack: None,
-            file_position: FilePosition { line: 2..=5, column: 9..=9 },
+            file_position: FilePosition { line: 2..=5, column: 9..=9, offset: 9..76, ..Default::default() },
         })
     ];
 
@@ -108,46 +113,50 @@ fn infer_type_assignment_in_scope_complex() -> anyhow::Result<()> {
     }
     "#;
 
-    let monkey_file: MonkeyFile = MonkeyFile::read_from_str(function)?;
+    let monkey_file: MonkeyFile = read_from_str(function)?;
     let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
     infer_type(&mut top_level_scope.result.program)?;
 
     let expected: Vec<AbstractSyntaxTreeNode> = vec![
         AbstractSyntaxTreeNode::MethodDefinition(MethodDefinition {
-            identifier: LValue::Identifier(Identifier { name: "constant_1".to_string() }),
+            identifier: LValue::Identifier(Identifier { name: "constant_1".to_string(), symbol: None }),
+            generic_parameters: vec![],
             return_type: Type::Integer(IntegerType::I32, Mutability::Immutable),
             arguments: vec![],
             stack: vec![AbstractSyntaxTreeNode::Return(Return {
                 assignable: Some(Assignable::Integer(IntegerAST { value: "5".to_string(), ty: IntegerType::I32 })),
-                file_position: FilePosition { line: 2..=2, column: 28..=36 },
+                file_position: FilePosition { line: 2..=2, column: 28..=36, offset: 28..37, ..Default::default() },
             })],
             is_extern: false,
-            file_position: FilePosition { line: 2..=2, column: 5..=38 },
+            is_public: false,
+            file_position: FilePosition { line: 2..=2, column: 5..=38, offset: 5..39, ..Default::default() },
         }),
         AbstractSyntaxTreeNode::Variable(Variable {
-            l_value: LValue::Identifier(Identifier { name: "a".to_string() }),
+            l_value: LValue::Identifier(Identifier { name: "a".to_string(), symbol: None }),
             mutability: false,
             ty: Some(Type::Integer(IntegerType::I32, Mutability::Immutable)),
             define: true,
+            compound_operator: None,
             assignable: Assignable::Integer(IntegerAST { value: "5".to_string(), ty: IntegerType::I32 }),
-            file_position: FilePosition { line: 3..=3, column: 5..=19 },
+            file_position: FilePosition { line: 3..=3, column: 5..=19, offset: 44..59, ..Default::default() },
         }),
         AbstractSyntaxTreeNode::If(If {
             condition: Assignable::Boolean(Boolean { value: true }),
             if_stack: vec![
                 AbstractSyntaxTreeNode::Variable(Variable::<'=', ';'> {
-                    l_value: LValue::Identifier(Identifier { name: "a".to_string() }),
+                    l_value: LValue::Identifier(Identifier { name: "a".to_string(), symbol: None }),
                     mutability: false,
                     ty: Some(Type::Integer(IntegerType::I32, Mutability::Immutable)),
                     define: true,
-                    file_position: FilePosition { line: 5..=5, column: 9..=33 },
+                    compound_operator: None,
+                    file_position: FilePosition { line: 5..=5, column: 9..=33, offset: 84..109, ..Default::default() },
                     assignable: Assignable::Expression(Expression {
                         lhs: Some(Box::new(Expression {
                             lhs: None,
                             rhs: None,
                             operator: Operator::Noop,
                             prefix_arithmetic: None,
-                            value: Some(Box::new(Assignable::Identifier(Identifier { name: "a".to_string() }))),
+                            value: Some(Box::new(Assignable::Identifier(Identifier { name: "a".to_string(), symbol: None }))),
                             index_operator: None,
                             positive: true,
                         })),
@@ -157,9 +166,11 @@ fn infer_type_assignment_in_scope_complex() -> anyhow::Result<()> {
                             operator: Operator::Noop,
                             prefix_arithmetic: None,
                             value: Some(Box::new(Assignable::MethodCall(MethodCall {
-                                identifier: LValue::Identifier(Identifier { name: "constant_1".to_string() }),
+                                receiver: None,
+                                identifier: LValue::Identifier(Identifier { name: "constant_1".to_string(), symbol: None }),
+                                type_arguments: vec![],
                                 arguments: vec![],
-                                file_position: FilePosition { line: 5..=5, column: 21..=32 },
+                                file_position: FilePosition { line: 5..=5, column: 21..=32, offset: 96..108, ..Default::default() },
                             }))),
                             index_operator: None,
                             positive: true,
@@ -172,16 +183,17 @@ fn infer_type_assignment_in_scope_complex() -> anyhow::Result<()> {
                     }),
                 }),
                 AbstractSyntaxTreeNode::Variable(Variable {
-                    l_value: LValue::Identifier(Identifier { name: "c".to_string() }),
+                    l_value: LValue::Identifier(Identifier { name: "c".to_string(), symbol: None }),
                     mutability: false,
                     ty: Some(Type::Integer(IntegerType::I32, Mutability::Immutable)),
                     define: true,
-                    assignable: Assignable::Identifier(Identifier { name: "a".to_string() }),
-                    file_position: FilePosition { line: 6..=6, column: 9..=18 },
+                    compound_operator: None,
+                    assignable: Assignable::Identifier(Identifier { name: "a".to_string(), symbol: None }),
+                    file_position: FilePosition { line: 6..=6, column: 9..=18, offset: 118..128, ..Default::default() },
                 }),
             ],
             else_stack: None,
-            file_position: FilePosition { line: 4..=7, column: 5..=5 },
+            file_position: FilePosition { line: 4..=7, column: 5..=5, offset: 64..134, ..Default::default() },
         })
     ];
 
@@ -206,7 +218,7 @@ fn infer_type_assignment_in_scope_complex_in_method() -> anyhow::Result<()> {
     "#;
 
 
-    let monkey_file: MonkeyFile = MonkeyFile::read_from_str(function)?;
+    let monkey_file: MonkeyFile = read_from_str(function)?;
     let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
     infer_type(&mut top_level_scope.result.program)?;
 
@@ -214,18 +226,21 @@ fn infer_type_assignment_in_scope_complex_in_method() -> anyhow::Result<()> {
 
     let expected: Vec<AbstractSyntaxTreeNode> = vec![
         AbstractSyntaxTreeNode::MethodDefinition(MethodDefinition {
-            identifier: LValue::Identifier(Identifier { name: "constant_1".to_string() }),
+            identifier: LValue::Identifier(Identifier { name: "constant_1".to_string(), symbol: None }),
+            generic_parameters: vec![],
             return_type: Type::Integer(IntegerType::I32, Mutability::Immutable),
             arguments: vec![],
             stack: vec![AbstractSyntaxTreeNode::Return(Return {
                 assignable: Some(Assignable::Integer(IntegerAST { value: "5".to_string(), ty: IntegerType::I32 })),
-                file_position: FilePosition { line: 2..=2, column: 28..=36 },
+                file_position: FilePosition { line: 2..=2, column: 28..=36, offset: 28..37, ..Default::default() },
             })],
             is_extern: false,
-            file_position: FilePosition { line: 2..=2, column: 5..=38 },
+            is_public: false,
+            file_position: FilePosition { line: 2..=2, column: 5..=38, offset: 5..39, ..Default::default() },
         }),
         AbstractSyntaxTreeNode::MethodDefinition(MethodDefinition {
-            identifier: LValue::Identifier(Identifier { name: "test".to_string() }),
+            identifier: LValue::Identifier(Identifier { name: "test".to_string(), symbol: None }),
+            generic_parameters: vec![],
             return_type: Type::Integer(IntegerType::I32, Mutability::Immutable),
             arguments: vec![],
             stack: vec![
@@ -233,18 +248,19 @@ fn infer_type_assignment_in_scope_complex_in_method() -> anyhow::Result<()> {
                     condition: Assignable::Boolean(Boolean { value: true }),
                     if_stack: vec![
                         AbstractSyntaxTreeNode::Variable(Variable::<'=', ';'> {
-                            l_value: LValue::Identifier(Identifier { name: "a".to_string() }),
+                            l_value: LValue::Identifier(Identifier { name: "a".to_string(), symbol: None }),
                             mutability: false,
                             ty: Some(Type::Integer(IntegerType::I32, Mutability::Immutable)),
                             define: true,
-                            file_position: FilePosition { line: 5..=5, column: 13..=37 },
+                            compound_operator: None,
+                            file_position: FilePosition { line: 5..=5, column: 13..=37, offset: 93..118, ..Default::default() },
                             assignable: Assignable::Expression(Expression {
                                 lhs: Some(Box::new(Expression {
                                     lhs: None,
                                     rhs: None,
                                     operator: Operator::Noop,
                                     prefix_arithmetic: None,
-                                    value: Some(Box::new(Assignable::Identifier(Identifier { name: "a".to_string() }))),
+                                    value: Some(Box::new(Assignable::Identifier(Identifier { name: "a".to_string(), symbol: None }))),
                                     index_operator: None,
                                     positive: true,
                                 })),
@@ -254,9 +270,11 @@ fn infer_type_assignment_in_scope_complex_in_method() -> anyhow::Result<()> {
                                     operator: Operator::Noop,
                                     prefix_arithmetic: None,
                                     value: Some(Box::new(Assignable::MethodCall(MethodCall {
-                                        identifier: LValue::Identifier(Identifier { name: "constant_1".to_string() }),
+                                        receiver: None,
+                                        identifier: LValue::Identifier(Identifier { name: "constant_1".to_string(), symbol: None }),
+                                        type_arguments: vec![],
                                         arguments: vec![],
-                                        file_position: FilePosition { line: 5..=5, column: 25..=36 },
+                                        file_position: FilePosition { line: 5..=5, column: 25..=36, offset: 105..117, ..Default::default() },
                                     }))),
                                     index_operator: None,
                                     positive: true,
@@ -269,32 +287,35 @@ fn infer_type_assignment_in_scope_complex_in_method() -> anyhow::Result<()> {
                             }),
                         }),
                         AbstractSyntaxTreeNode::Variable(Variable {
-                            l_value: LValue::Identifier(Identifier { name: "c".to_string() }),
+                            l_value: LValue::Identifier(Identifier { name: "c".to_string(), symbol: None }),
                             mutability: false,
                             ty: Some(Type::Integer(IntegerType::I32, Mutability::Immutable)),
                             define: true,
-                            assignable: Assignable::Identifier(Identifier { name: "a".to_string() }),
-                            file_position: FilePosition { line: 6..=6, column: 13..=22 },
+                            compound_operator: None,
+                            assignable: Assignable::Identifier(Identifier { name: "a".to_string(), symbol: None }),
+                            file_position: FilePosition { line: 6..=6, column: 13..=22, offset: 131..141, ..Default::default() },
                         }),
                     ],
                     else_stack: None,
-                    file_position: FilePosition { line: 4..=7, column: 9..=9 },
+                    file_position: FilePosition { line: 4..=7, column: 9..=9, offset: 69..151, ..Default::default() },
                 }),
                 AbstractSyntaxTreeNode::Return(Return {
                     assignable: Some(Assignable::Integer(IntegerAST { value: "0".to_string(), ty: IntegerType::I32 })),
-                    file_position: FilePosition { line: 9..=9, column: 9..=17 },
+                    file_position: FilePosition { line: 9..=9, column: 9..=17, offset: 161..170, ..Default::default() },
                 })
             ],
             is_extern: false,
-            file_position: FilePosition { line: 3..=10, column: 5..=5 },
+            is_public: false,
+            file_position: FilePosition { line: 3..=10, column: 5..=5, offset: 44..176, ..Default::default() },
         }),
         AbstractSyntaxTreeNode::Variable(Variable {
-            l_value: LValue::Identifier(Identifier { name: "a".to_string() }),
+            l_value: LValue::Identifier(Identifier { name: "a".to_string(), symbol: None }),
             mutability: false,
             ty: Some(Type::Integer(IntegerType::I32, Mutability::Immutable)),
             define: true,
+            compound_operator: None,
             assignable: Assignable::Integer(IntegerAST { value: "5".to_string(), ty: IntegerType::I32 }),
-            file_position: FilePosition { line: 12..=12, column: 5..=19 },
+            file_position: FilePosition { line: 12..=12, column: 5..=19, offset: 182..197, ..Default::default() },
         }),
     ];
 
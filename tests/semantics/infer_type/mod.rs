@@ -1,3 +1,5 @@
 pub mod infer_types;
 pub mod infer_types_assignment;
-pub mod type_check_mismatch;
\ No newline at end of file
+pub mod type_check_mismatch;
+pub mod generics;
+pub mod symbols;
\ No newline at end of file
@@ -0,0 +1,51 @@
+use monkey_language::core::io::monkey_file::MonkeyFile;
+use crate::read_from_str;
+use monkey_language::core::model::abstract_syntax_tree_node::AbstractSyntaxTreeNode;
+use monkey_language::core::model::abstract_syntax_tree_nodes::assignable::Assignable;
+use monkey_language::core::model::symbol_table::SymbolId;
+use monkey_language::core::parser::ast_parser::ASTParser;
+use monkey_language::core::semantics::type_infer::type_inferer::infer_type;
+
+/// the symbol of the declared or assigned variable and the symbol of the assigned identifier
+fn symbols(node: &AbstractSyntaxTreeNode) -> (Option<SymbolId>, Option<SymbolId>) {
+    match node {
+        AbstractSyntaxTreeNode::Variable(variable) => (variable.l_value.symbol(), match &variable.assignable {
+            Assignable::Identifier(identifier) => identifier.symbol,
+            _ => None,
+        }),
+        _ => panic!("Expected a variable, found: {node:?}"),
+    }
+}
+
+#[test]
+fn infer_type_records_symbols() -> anyhow::Result<()> {
+    let program = r#"
+    let mut x: i32 = 1;
+    let condition: bool = true;
+    if (condition) {
+        let x = false;
+        let y: bool = x;
+    }
+    x = 2;
+    let z: i32 = x;
+    "#;
+
+    let monkey_file: MonkeyFile = read_from_str(program)?;
+    let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
+    infer_type(&mut top_level_scope.result.program)?;
+    let program = &top_level_scope.result.program;
+
+    let (outer_x, _) = symbols(&program[0]);
+    let AbstractSyntaxTreeNode::If(if_definition) = &program[2] else { panic!("Expected an if, found: {:?}", program[2]) };
+    let (inner_x, _) = symbols(&if_definition.if_stack[0]);
+    let (_, inner_read) = symbols(&if_definition.if_stack[1]);
+    let (reassigned, _) = symbols(&program[3]);
+    let (_, outer_read) = symbols(&program[4]);
+
+    assert!(outer_x.is_some() && inner_x.is_some());
+    assert_ne!(outer_x, inner_x);
+    assert_eq!(inner_x, inner_read);
+    assert_eq!(outer_x, reassigned);
+    assert_eq!(outer_x, outer_read);
+    Ok(())
+}
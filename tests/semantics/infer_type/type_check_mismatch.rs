@@ -1,4 +1,5 @@
 use monkey_language::core::io::monkey_file::MonkeyFile;
+use crate::read_from_str;
 use monkey_language::core::parser::ast_parser::ASTParser;
 use monkey_language::core::parser::types::r#type::InferTypeError;
 use monkey_language::core::semantics::type_infer::type_inferer::infer_type;
@@ -10,7 +11,7 @@ fn wrong_index_type() -> anyhow::Result<()> {
     let b = a["0"];
     "#;
 
-    let monkey_file: MonkeyFile = MonkeyFile::read_from_str(code)?;
+    let monkey_file: MonkeyFile = read_from_str(code)?;
     let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
     let infer_result = infer_type(&mut top_level_scope.result.program);
 
@@ -20,4 +21,28 @@ fn wrong_index_type() -> anyhow::Result<()> {
         assert!(matches!(s, InferTypeError::IllegalIndexOperation(_, _)));
     }
     Ok(())
-}
\ No newline at end of file
+}
+
+#[test]
+fn suffixed_literal_out_of_range() -> anyhow::Result<()> {
+    let values = [
+        "let a = 256u8;",
+        "let a = -1_u32;",
+        "let a = 0x8000i16;",
+        "let a = 0b1_0000_0000u8;",
+    ];
+
+    for code in values {
+        let monkey_file: MonkeyFile = read_from_str(code)?;
+        let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
+        let infer_result = infer_type(&mut top_level_scope.result.program);
+
+        assert!(matches!(infer_result.map_err(|e| *e), Err(InferTypeError::IntegerTooSmall { .. })), "{code}");
+    }
+
+    let monkey_file: MonkeyFile = read_from_str("let a = 255u8; let b = -128_i8; let c = 0xFFFF_FFFF_FFFF_FFFFu64;")?;
+    let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
+    infer_type(&mut top_level_scope.result.program)?;
+
+    Ok(())
+}
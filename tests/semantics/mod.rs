@@ -1,2 +1,3 @@
 mod infer_type;
-mod static_type_check;
\ No newline at end of file
+mod static_type_check;
+mod warnings;
@@ -0,0 +1,78 @@
+use monkey_language::core::io::monkey_file::MonkeyFile;
+use crate::read_from_str;
+use monkey_language::core::parser::ast_parser::ASTParser;
+use monkey_language::core::parser::types::r#type::InferTypeError;
+use monkey_language::core::semantics::static_type_check::static_type_checker::StaticTypeCheckError;
+use monkey_language::core::semantics::type_infer::type_inferer::infer_type;
+use crate::semantics::static_type_check::check;
+
+#[test]
+fn static_type_check_compound_assignment() -> anyhow::Result<()> {
+    let program = r#"
+    fn main(): void {
+        let mut a = 5;
+        a += 1;
+        a %= a;
+        let mut b: i64 = 5;
+        b <<= 2;
+        let mut c = 1.5;
+        c /= 2.0;
+        let mut arr = [1, 2, 3];
+        arr[a] -= 2;
+    }
+    "#;
+
+    let result = check(program)?;
+    assert!(result.is_ok(), "{result:?}");
+    Ok(())
+}
+
+#[test]
+fn static_type_check_reassign_literals() -> anyhow::Result<()> {
+    // the literals are casted to the type of the variable like in their definitions
+    let program = r#"
+    fn main(): void {
+        let mut a: i64 = 0;
+        a = 5;
+        let mut b: u8 = 0;
+        b = 200;
+        let mut arr: [i64, 2] = [1_i64, 2_i64];
+        arr[1] = 7;
+    }
+    "#;
+
+    let result = check(program)?;
+    assert!(result.is_ok(), "{result:?}");
+    Ok(())
+}
+
+#[test]
+fn static_type_check_compound_assignment_immutable() -> anyhow::Result<()> {
+    let program = r#"
+    fn main(): void {
+        let a = 5;
+        a += 1;
+    }
+    "#;
+
+    let result = check(program)?;
+    assert!(matches!(&result, Err(StaticTypeCheckError::ImmutabilityViolated { name, .. }) if name.identifier() == "a"), "{result:?}");
+    Ok(())
+}
+
+#[test]
+fn infer_type_compound_assignment_mismatched_types() -> anyhow::Result<()> {
+    let program = r#"
+    fn main(): void {
+        let mut a = 5;
+        a *= 1.5;
+    }
+    "#;
+
+    let monkey_file: MonkeyFile = read_from_str(program)?;
+    let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
+
+    let result = infer_type(&mut top_level_scope.result.program);
+    assert!(matches!(result.as_ref().map_err(|e| e.as_ref()), Err(InferTypeError::TypesNotCalculable(..))), "{result:?}");
+    Ok(())
+}
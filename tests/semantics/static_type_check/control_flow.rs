@@ -0,0 +1,122 @@
+use monkey_language::core::io::monkey_file::MonkeyFile;
+use crate::read_from_str;
+use monkey_language::core::model::abstract_syntax_tree_node::AbstractSyntaxTreeNode;
+use monkey_language::core::parser::ast_parser::ASTParser;
+use monkey_language::core::parser::types::r#type::{InferTypeError, MethodCallSignatureMismatchCause};
+use monkey_language::core::semantics::control_flow::ControlFlowGraph;
+use monkey_language::core::semantics::static_type_check::static_type_checker::StaticTypeCheckError;
+use crate::semantics::static_type_check::check;
+
+#[test]
+fn every_path_returns() -> anyhow::Result<()> {
+    let program = r#"
+    enum Color { Red, Green }
+
+    fn endless(a: i32): i32 {
+        while (true) {
+            if (a > 5) {
+                return a;
+            }
+        }
+    }
+
+    fn nested(a: i32): i32 {
+        if (a < 0) {
+            if (a < -5) {
+                return 1;
+            } else {
+                return 2;
+            }
+        } else {
+            let color: Color = Color::Red;
+            match (color) {
+                Color::Red => { return 3; }
+                Color::Green => { return 4; }
+            }
+        }
+    }
+
+    fn after_loop(a: i32): i32 {
+        for (let mut i: i32 = 0; i < a; i = i + 1;) {
+            if (i == 3) {
+                return i;
+            }
+        }
+
+        return 0;
+    }
+    "#;
+
+    check(program)??;
+
+    Ok(())
+}
+
+#[test]
+fn missing_return() -> anyhow::Result<()> {
+    let programs = [
+        ("while (a > 0) { return 1; }", MethodCallSignatureMismatchCause::LoopCondition),
+        ("while (true) { break; }", MethodCallSignatureMismatchCause::LoopCondition),
+        ("for (let mut i: i32 = 0; i < a; i = i + 1;) { return i; }", MethodCallSignatureMismatchCause::LoopCondition),
+        ("if (a > 0) { if (a > 5) { return 1; } } else { return 2; }", MethodCallSignatureMismatchCause::IfCondition),
+        ("let b: i32 = a;", MethodCallSignatureMismatchCause::ReturnMismatch),
+    ];
+
+    for (body, expected_cause) in programs {
+        let program = format!("fn f(a: i32): i32 {{ {body} }}");
+        let result = check(&program)?;
+
+        let cause = match &result {
+            Err(StaticTypeCheckError::InferredError(err)) => match err.as_ref() {
+                InferTypeError::MethodReturnSignatureMismatch { cause, .. } => Some(cause),
+                _ => None,
+            },
+            _ => None,
+        };
+
+        assert!(cause.is_some_and(|cause| std::mem::discriminant(cause) == std::mem::discriminant(&expected_cause)), "{body}: {result:?}");
+    }
+
+    Ok(())
+}
+
+#[test]
+fn unreachable_statements() -> anyhow::Result<()> {
+    let programs = [
+        ("return; let b: i32 = 1; let c: i32 = 2;", "let b: i32 = 1"),
+        ("if (a > 0) { return; } else { return; } let b: i32 = 1;", "let b: i32 = 1"),
+        ("while (true) { let b: i32 = 1; } let c: i32 = 2;", "let c: i32 = 2"),
+        ("while (a > 0) { break; let b: i32 = 1; }", "let b: i32 = 1"),
+        ("if (true) { return; } else { let b: i32 = 1; }", "let b: i32 = 1"),
+        ("return; if (a > 0) { let b: i32 = 1; } let c: i32 = 2;", "if ("),
+    ];
+
+    for (body, expected_statement) in programs {
+        let program = format!("fn f(a: i32): void {{ {body} }}");
+        let monkey_file: MonkeyFile = read_from_str(&program)?;
+        let top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
+
+        let Some(AbstractSyntaxTreeNode::MethodDefinition(method_definition)) = top_level_scope.result.program.first() else {
+            panic!("{body}: expected a method definition");
+        };
+
+        let unreachable_statements = ControlFlowGraph::new(&method_definition.stack).unreachable_statements()
+            .iter()
+            .map(|node| node.to_string())
+            .collect::<Vec<_>>();
+
+        assert_eq!(1, unreachable_statements.len(), "{body}: {unreachable_statements:?}");
+        assert!(unreachable_statements[0].trim().starts_with(expected_statement), "{body}: {unreachable_statements:?}");
+    }
+
+    let reachable = "if (a > 0) { return; } let b: i32 = 1; while (a > 0) { if (a > 1) { break; } continue; }";
+    let program = format!("fn f(a: i32): void {{ {reachable} }}");
+    let monkey_file: MonkeyFile = read_from_str(&program)?;
+    let top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
+
+    if let Some(AbstractSyntaxTreeNode::MethodDefinition(method_definition)) = top_level_scope.result.program.first() {
+        assert!(ControlFlowGraph::new(&method_definition.stack).unreachable_statements().is_empty());
+    }
+
+    Ok(())
+}
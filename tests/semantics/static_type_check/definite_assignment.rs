@@ -0,0 +1,325 @@
+use monkey_language::core::semantics::static_type_check::static_type_checker::StaticTypeCheckError;
+use crate::semantics::static_type_check::check;
+
+#[test]
+fn definitely_assigned() -> anyhow::Result<()> {
+    let program = r#"
+    enum Color { Red, Green }
+
+    fn set(value: mut *i32): void {
+        *value = 42;
+    }
+
+    fn main(): i32 {
+        let condition: bool = true;
+
+        let mut a: i32;
+        if (condition) {
+            a = 1;
+        } else {
+            a = 2;
+        }
+        let b: i32 = a;
+
+        let mut c: i32;
+        if (condition) {
+            return 1;
+        } else {
+            c = 3;
+        }
+        let d: i32 = c;
+
+        let color: Color = Color::Red;
+        let mut e: i32;
+        match (color) {
+            Color::Red => { e = 1; }
+            Color::Green => { e = 2; }
+        }
+        let f: i32 = e;
+
+        let mut g: i32;
+        set(&g);
+        let h: i32 = g;
+
+        let mut k: i32;
+        for (let mut i: i32 = 0; i < 10; i = i + k;) {
+            k = 1;
+            if (condition) {
+                continue;
+            }
+        }
+
+        return 0;
+    }
+    "#;
+
+    check(program)??;
+
+    Ok(())
+}
+
+#[test]
+fn possibly_uninitialized() -> anyhow::Result<()> {
+    let programs = [
+        r#"
+        fn main(): void {
+            let mut a: i32;
+            let b: i32 = a;
+        }
+        "#,
+        r#"
+        fn main(): void {
+            let condition: bool = true;
+            let mut a: i32;
+            if (condition) {
+                a = 1;
+            }
+            let b: i32 = a;
+        }
+        "#,
+        r#"
+        fn main(): void {
+            let mut i: i32 = 0;
+            let mut a: i32;
+            while (i < 10) {
+                a = i;
+                i = i + 1;
+            }
+            let b: i32 = a;
+        }
+        "#,
+        r#"
+        fn main(): void {
+            let mut a: i32;
+            for (let mut i: i32 = 0; i < 10; i = i + 1;) {
+                a = i;
+            }
+            let b: i32 = a;
+        }
+        "#,
+        r#"
+        fn main(): void {
+            let mut a: i32;
+            a += 1;
+        }
+        "#,
+        r#"
+        fn main(): void {
+            let condition: bool = true;
+            let mut a: i32;
+            for (let mut i: i32 = 0; i < 10; i = i + a;) {
+                if (condition) {
+                    continue;
+                }
+                a = 1;
+            }
+        }
+        "#,
+        r#"
+        fn main(): void {
+            let condition: bool = true;
+            let mut a: i32;
+            while (true) {
+                if (condition) {
+                    break;
+                }
+                a = 1;
+                break;
+            }
+            let b: i32 = a;
+        }
+        "#,
+        r#"
+        fn main(): void {
+            let mut a: i32;
+            while (false) {
+                a = 1;
+            }
+            let b: i32 = a;
+        }
+        "#,
+    ];
+
+    for program in programs {
+        let result = check(program)?;
+        assert!(matches!(&result, Err(StaticTypeCheckError::PossiblyUninitialized { name, .. }) if name == "a"), "{program}: {result:?}");
+    }
+
+    Ok(())
+}
+
+#[test]
+fn break_and_constant_conditions() -> anyhow::Result<()> {
+    let program = r#"
+    fn main(): i32 {
+        let condition: bool = true;
+
+        let x: i32;
+        while (true) {
+            x = 1;
+            break;
+        }
+        let y: i32 = x;
+
+        let mut a: i32;
+        'outer: while (true) {
+            for (let mut i: i32 = 0; i < 10; i = i + 1;) {
+                a = i;
+                break 'outer;
+            }
+        }
+        let b: i32 = a;
+
+        let mut c: i32;
+        while (true) {
+            if (condition) {
+                c = 1;
+                break;
+            }
+        }
+        let d: i32 = c;
+
+        let mut e: i32;
+        while (false) {
+            let f: i32 = e;
+        }
+
+        while (true) {
+        }
+        let g: i32 = e;
+
+        return x;
+    }
+    "#;
+
+    check(program)??;
+
+    Ok(())
+}
+
+#[test]
+fn used_before_declaration() -> anyhow::Result<()> {
+    let programs = [
+        r#"
+        fn main(): void {
+            let b: i32 = a;
+            let a: i32 = 1;
+        }
+        "#,
+        r#"
+        fn main(): void {
+            let condition: bool = true;
+            if (condition) {
+                let b: i32 = a;
+            }
+            let a: i32 = 1;
+        }
+        "#,
+        r#"
+        fn main(): void {
+            let b = a;
+            let a = 1;
+        }
+        "#,
+        r#"
+        fn main(): void {
+            let mut b = 0;
+            b = a + 1;
+            let a = 1;
+        }
+        "#,
+    ];
+
+    for program in programs {
+        let result = check(program)?;
+        assert!(matches!(&result, Err(StaticTypeCheckError::UsedBeforeDeclaration { name, .. }) if name == "a"), "{program}: {result:?}");
+    }
+
+    Ok(())
+}
+
+#[test]
+fn deferred_initialization() -> anyhow::Result<()> {
+    let program = r#"
+    fn main(): i32 {
+        let condition: bool = true;
+
+        let a: i32;
+        a = 1;
+
+        let b: i32;
+        if (condition) {
+            b = 2;
+        } else {
+            b = 3;
+        }
+
+        for (let mut i: i32 = 0; i < 10; i = i + 1;) {
+            let c: i32;
+            c = i;
+        }
+
+        return a + b;
+    }
+    "#;
+
+    check(program)??;
+
+    Ok(())
+}
+
+#[test]
+fn deferred_initialization_assigned_twice() -> anyhow::Result<()> {
+    let programs = [
+        r#"
+        fn main(): void {
+            let a: i32;
+            a = 1;
+            a = 2;
+        }
+        "#,
+        r#"
+        fn main(): void {
+            let condition: bool = true;
+            let a: i32;
+            if (condition) {
+                a = 1;
+            }
+            a = 2;
+        }
+        "#,
+        r#"
+        fn main(): void {
+            let condition: bool = true;
+            let a: i32;
+            while (condition) {
+                a = 1;
+            }
+        }
+        "#,
+        r#"
+        fn main(): void {
+            let a: i32 = 1;
+            a = 2;
+        }
+        "#,
+        r#"
+        fn main(): void {
+            let condition: bool = true;
+            let a: i32;
+            while (condition) {
+                a = 1;
+                break;
+            }
+            a = 2;
+        }
+        "#,
+    ];
+
+    for program in programs {
+        let result = check(program)?;
+        assert!(matches!(&result, Err(StaticTypeCheckError::ImmutabilityViolated { name, .. }) if name.identifier() == "a"), "{program}: {result:?}");
+    }
+
+    Ok(())
+}
@@ -0,0 +1,186 @@
+use monkey_language::core::model::abstract_syntax_tree_nodes::assignables::equation_parser::operator::Operator;
+use monkey_language::core::model::abstract_syntax_tree_nodes::identifier::Identifier;
+use monkey_language::core::model::types::mutability::Mutability;
+use monkey_language::core::model::types::ty::Type;
+use monkey_language::core::parser::types::r#type::InferTypeError;
+use monkey_language::core::semantics::static_type_check::static_type_checker::StaticTypeCheckError;
+use crate::semantics::static_type_check::check;
+
+#[test]
+fn static_type_check_enums() -> anyhow::Result<()> {
+    let program = r#"
+    enum Color { Red, Green = 5, Blue }
+
+    fn is_red(color: Color): bool {
+        return color == Color::Red;
+    }
+
+    fn main(): void {
+        let a = Color::Green;
+        let mut b: Color = Color::Blue;
+        b = a;
+        let c: bool = a != b;
+        let d: i32 = (i32)a;
+        let e: u8 = (u8)Color::Blue;
+        let f: bool = is_red(Color::Red);
+    }
+    "#;
+
+    let result = check(program)?;
+    assert!(result.is_ok(), "{result:?}");
+    Ok(())
+}
+
+#[test]
+fn static_type_check_compare_different_enums() -> anyhow::Result<()> {
+    let program = r#"
+    enum Color { Red, Green }
+    enum Shape { Circle, Square }
+
+    fn main(): void {
+        let a = Color::Red == Shape::Circle;
+    }
+    "#;
+
+    let result = check(program)?;
+    assert!(matches!(
+        &result,
+        Err(StaticTypeCheckError::InferredError(err)) if matches!(err.as_ref(), InferTypeError::TypesNotCalculable(Type::Custom(lhs, _), Operator::Equal, Type::Custom(rhs, _), _) if lhs.name == "Color" && rhs.name == "Shape")
+    ), "{result:?}");
+    Ok(())
+}
+
+#[test]
+fn static_type_check_assign_different_enum() -> anyhow::Result<()> {
+    let program = r#"
+    enum Color { Red, Green }
+    enum Shape { Circle, Square }
+
+    fn main(): void {
+        let a: Color = Shape::Circle;
+    }
+    "#;
+
+    let result = check(program)?;
+    assert!(matches!(
+        &result,
+        Err(StaticTypeCheckError::InferredError(err)) if matches!(err.as_ref(), InferTypeError::MismatchedTypes { .. })
+    ), "{result:?}");
+    Ok(())
+}
+
+#[test]
+fn static_type_check_reassign_different_enum() -> anyhow::Result<()> {
+    let programs = [
+        "let mut c = Color::Red; c = Shape::Circle;",
+        "let mut c: Color = Color::Red; c = Shape::Square;",
+        "let mut b: i32 = 1; b = true;",
+    ];
+
+    for program in programs {
+        let program = format!("enum Color {{ Red, Green }}\nenum Shape {{ Circle, Square }}\nfn main(): void {{ {program} }}");
+
+        let result = check(&program)?;
+        assert!(matches!(
+            &result,
+            Err(StaticTypeCheckError::InferredError(err)) if matches!(err.as_ref(), InferTypeError::MismatchedTypes { .. })
+        ), "{program}: {result:?}");
+    }
+
+    Ok(())
+}
+
+#[test]
+fn static_type_check_pass_different_enum() -> anyhow::Result<()> {
+    let program = r#"
+    enum Color { Red, Green }
+    enum Shape { Circle, Square }
+
+    fn take(color: Color): void { }
+
+    fn main(): void {
+        take(Shape::Circle);
+    }
+    "#;
+
+    let result = check(program)?;
+    assert!(matches!(
+        &result,
+        Err(StaticTypeCheckError::InferredError(err)) if matches!(err.as_ref(), InferTypeError::MethodCallSignatureMismatch { signatures, provided, .. }
+            if *signatures == vec![vec![Type::Custom(Identifier { name: "Color".to_string(), symbol: None }, Mutability::Immutable)]]
+            && *provided == vec![Type::Custom(Identifier { name: "Shape".to_string(), symbol: None }, Mutability::Immutable)])
+    ), "{result:?}");
+    Ok(())
+}
+
+#[test]
+fn static_type_check_enum_arithmetic() -> anyhow::Result<()> {
+    let program = r#"
+    enum Color { Red, Green }
+
+    fn main(): void {
+        let a = Color::Red + Color::Green;
+    }
+    "#;
+
+    let result = check(program)?;
+    assert!(matches!(
+        &result,
+        Err(StaticTypeCheckError::InferredError(err)) if matches!(err.as_ref(), InferTypeError::TypesNotCalculable(_, Operator::Add, _, _))
+    ), "{result:?}");
+    Ok(())
+}
+
+#[test]
+fn static_type_check_unknown_variant() -> anyhow::Result<()> {
+    let program = r#"
+    enum Color { Red, Green }
+
+    fn main(): void {
+        let a = Color::Purple;
+    }
+    "#;
+
+    let result = check(program)?;
+    assert!(matches!(
+        &result,
+        Err(StaticTypeCheckError::InferredError(err)) if matches!(err.as_ref(), InferTypeError::UnresolvedReference(name, _) if name == "Color::Purple")
+    ), "{result:?}");
+    Ok(())
+}
+
+#[test]
+fn static_type_check_duplicate_variant() -> anyhow::Result<()> {
+    let program = r#"
+    enum Color { Red, Green, Red = 4 }
+    "#;
+
+    let result = check(program)?;
+    assert!(matches!(
+        &result,
+        Err(StaticTypeCheckError::InferredError(err)) if matches!(err.as_ref(), InferTypeError::NameCollision(name, _) if name == "Color::Red")
+    ), "{result:?}");
+    Ok(())
+}
+
+#[test]
+fn static_type_check_duplicate_discriminant() -> anyhow::Result<()> {
+    let programs = [
+        ("enum E { A = 1, B = 1 }", "E::B", "E::A"),
+        ("enum E { A = 1, B = 0, C }", "E::C", "E::A"),
+    ];
+
+    for (enum_definition, duplicate, original) in programs {
+        let program = format!("{enum_definition}\nfn main(): void {{ }}");
+
+        let result = check(&program)?;
+        assert!(matches!(
+            &result,
+            Err(StaticTypeCheckError::DuplicateDiscriminant { variant, other_variant, discriminant: 1, .. }) if variant == duplicate && other_variant == original
+        ), "{program}: {result:?}");
+    }
+
+    let result = check("enum E { A = 2, B = 0, C }\nfn main(): void { }")?;
+    assert!(result.is_ok(), "{result:?}");
+    Ok(())
+}
@@ -1,4 +1,5 @@
 use monkey_language::core::io::monkey_file::MonkeyFile;
+use crate::read_from_str;
 use monkey_language::core::lexer::token_with_span::FilePosition;
 use monkey_language::core::model::abstract_syntax_tree_node::AbstractSyntaxTreeNode;
 use monkey_language::core::model::abstract_syntax_tree_nodes::assignable::Assignable;
@@ -23,7 +24,7 @@ fn static_type_check_for_loop() -> anyhow::Result<()> {
     }
     "#;
 
-    let monkey_file: MonkeyFile = MonkeyFile::read_from_str(program)?;
+    let monkey_file: MonkeyFile = read_from_str(program)?;
     let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
 
     infer_type(&mut top_level_scope.result.program)?;
@@ -33,13 +34,15 @@ fn static_type_check_for_loop() -> anyhow::Result<()> {
 
     let expected: Vec<AbstractSyntaxTreeNode> = vec![
         AbstractSyntaxTreeNode::For(For {
+            label: None,
             initialization: Variable {
-                l_value: LValue::Identifier(Identifier { name: "i".to_string() }),
+                l_value: LValue::Identifier(Identifier { name: "i".to_string(), symbol: None }),
                 mutability: true,
                 ty: Some(Type::Integer(IntegerType::I32, Mutability::Immutable)),
                 define: true,
+                compound_operator: None,
                 assignable: Assignable::Integer(IntegerAST { value: "0".to_string(), ty: IntegerType::I32 }),
-                file_position: FilePosition { line: 2..=2, column: 10..=28 },
+                file_position: FilePosition { line: 2..=2, column: 10..=28, offset: 10..29, ..Default::default() },
             },
             condition: Assignable::Expression(Expression {
                 lhs: Some(Box::new(Expression {
@@ -47,7 +50,7 @@ fn static_type_check_for_loop() -> anyhow::Result<()> {
                     rhs: None,
                     operator: Operator::Noop,
                     prefix_arithmetic: None,
-                    value: Some(Box::new(Assignable::Identifier(Identifier { name: "i".to_string() }))),
+                    value: Some(Box::new(Assignable::Identifier(Identifier { name: "i".to_string(), symbol: None }))),
                     index_operator: None,
                     positive: true,
                 })),
@@ -67,17 +70,18 @@ fn static_type_check_for_loop() -> anyhow::Result<()> {
                 positive: true,
             }),
             update: Variable {
-                l_value: LValue::Identifier(Identifier { name: "i".to_string() }),
+                l_value: LValue::Identifier(Identifier { name: "i".to_string(), symbol: None }),
                 mutability: false,
                 ty: Some(Type::Integer(IntegerType::I32, Mutability::Immutable)),
                 define: false,
+                compound_operator: None,
                 assignable: Assignable::Expression(Expression {
                     lhs: Some(Box::new(Expression {
                         lhs: None,
                         rhs: None,
                         operator: Operator::Noop,
                         prefix_arithmetic: None,
-                        value: Some(Box::new(Assignable::Identifier(Identifier { name: "i".to_string() }))),
+                        value: Some(Box::new(Assignable::Identifier(Identifier { name: "i".to_string(), symbol: None }))),
                         index_operator: None,
                         positive: true,
                     })),
@@ -96,19 +100,20 @@ fn static_type_check_for_loop() -> anyhow::Result<()> {
                     index_operator: None,
                     positive: true,
                 }),
-                file_position: FilePosition { line: 2..=2, column: 38..=47 },
+                file_position: FilePosition { line: 2..=2, column: 38..=47, offset: 38..48, ..Default::default() },
             },
             stack: vec![
                 AbstractSyntaxTreeNode::Variable(Variable {
-                    l_value: LValue::Identifier(Identifier { name: "a".to_string() }),
+                    l_value: LValue::Identifier(Identifier { name: "a".to_string(), symbol: None }),
                     mutability: false,
                     ty: Some(Type::Integer(IntegerType::I32, Mutability::Immutable)),
                     define: true,
-                    assignable: Assignable::Identifier(Identifier { name: "i".to_string() }),
-                    file_position: FilePosition { line: 3..=3, column: 9..=23 },
+                    compound_operator: None,
+                    assignable: Assignable::Identifier(Identifier { name: "i".to_string(), symbol: None }),
+                    file_position: FilePosition { line: 3..=3, column: 9..=23, offset: 60..75, ..Default::default() },
                 })
             ],
-            file_position: FilePosition { line: 2..=4, column: 5..=5 },
+            file_position: FilePosition { line: 2..=4, column: 5..=5, offset: 5..81, ..Default::default() },
         })
     ];
 
@@ -125,7 +130,7 @@ fn static_type_check_for_loop_should_fail_missing_mutability() -> anyhow::Result
     }
     "#;
 
-    let monkey_file: MonkeyFile = MonkeyFile::read_from_str(program)?;
+    let monkey_file: MonkeyFile = read_from_str(program)?;
     let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
 
     infer_type(&mut top_level_scope.result.program)?;
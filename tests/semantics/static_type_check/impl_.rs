@@ -0,0 +1,80 @@
+use monkey_language::core::parser::types::r#type::InferTypeError;
+use monkey_language::core::semantics::static_type_check::static_type_checker::StaticTypeCheckError;
+use crate::semantics::static_type_check::check;
+
+#[test]
+fn static_type_check_impl() -> anyhow::Result<()> {
+    let program = r#"
+    enum Color { Red, Green }
+
+    impl Color {
+        fn code(self: Color): i32 {
+            return (i32)self;
+        }
+
+        fn shifted(self: *Color, offset: i32): i32 {
+            return self.code() + offset;
+        }
+
+        fn default(): Color {
+            return Color::Green;
+        }
+    }
+
+    fn main(): void {
+        let color: Color = Color::default();
+        let a: i32 = color.code();
+        let b: i32 = color.shifted(a);
+        let pointer: *Color = &color;
+        let c: i32 = pointer.code();
+    }
+    "#;
+
+    let result = check(program)?;
+    assert!(result.is_ok(), "{result:?}");
+    Ok(())
+}
+
+#[test]
+fn static_type_check_unknown_receiver_method() -> anyhow::Result<()> {
+    let program = r#"
+    enum Color { Red, Green }
+
+    impl Color {
+        fn code(self: Color): i32 {
+            return (i32)self;
+        }
+    }
+
+    fn main(): void {
+        let color = Color::Red;
+        let a: i32 = color.name();
+    }
+    "#;
+
+    let result = check(program)?;
+    assert!(matches!(
+        &result,
+        Err(StaticTypeCheckError::InferredError(err)) if matches!(err.as_ref(), InferTypeError::UnresolvedReference(call, _) if call == "color.name()")
+    ), "{result:?}");
+    Ok(())
+}
+
+#[test]
+fn static_type_check_nested_impl() -> anyhow::Result<()> {
+    let program = r#"
+    enum Color { Red, Green }
+
+    fn main(): void {
+        impl Color {
+            fn code(self: Color): i32 {
+                return (i32)self;
+            }
+        }
+    }
+    "#;
+
+    let result = check(program)?;
+    assert!(matches!(&result, Err(StaticTypeCheckError::NestedImpl { ty, .. }) if ty.to_string() == "Color"), "{result:?}");
+    Ok(())
+}
@@ -0,0 +1,54 @@
+use monkey_language::core::semantics::static_type_check::static_type_checker::StaticTypeCheckError;
+use crate::semantics::static_type_check::check;
+
+#[test]
+fn static_type_check_loop_control() -> anyhow::Result<()> {
+    let program = r#"
+    fn main(): void {
+        'outer: while (true) {
+            for (let mut i: i32 = 0; i < 10; i = i + 1;) {
+                if (i == 5) {
+                    continue 'outer;
+                }
+                break;
+            }
+            break 'outer;
+        }
+    }
+    "#;
+
+    assert!(check(program)?.is_ok());
+    Ok(())
+}
+
+#[test]
+fn static_type_check_loop_control_outside_of_loop() -> anyhow::Result<()> {
+    let programs = [
+        ("break", "fn main(): void { break; }"),
+        ("continue", "fn main(): void { let a = true; if (a) { continue; } }"),
+    ];
+
+    for (keyword, program) in programs {
+        let result = check(program)?;
+        assert!(matches!(&result, Err(StaticTypeCheckError::LoopControlOutsideOfLoop { keyword: k, .. }) if k == keyword), "{program}: {result:?}");
+    }
+
+    Ok(())
+}
+
+#[test]
+fn static_type_check_undeclared_loop_label() -> anyhow::Result<()> {
+    let program = r#"
+    fn main(): void {
+        'outer: while (true) {
+            'inner: while (true) {
+                break 'other;
+            }
+        }
+    }
+    "#;
+
+    let result = check(program)?;
+    assert!(matches!(&result, Err(StaticTypeCheckError::UndeclaredLoopLabel { label, .. }) if label == "other"), "{result:?}");
+    Ok(())
+}
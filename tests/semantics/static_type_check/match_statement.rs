@@ -0,0 +1,240 @@
+use monkey_language::core::model::types::integer::IntegerType;
+use monkey_language::core::model::types::ty::Type;
+use monkey_language::core::parser::types::r#type::InferTypeError;
+use monkey_language::core::semantics::static_type_check::static_type_checker::StaticTypeCheckError;
+use crate::semantics::static_type_check::check;
+
+#[test]
+fn static_type_check_match() -> anyhow::Result<()> {
+    let program = r#"
+    enum Color { Red, Green = 5, Blue }
+
+    fn describe(color: Color): i32 {
+        match (color) {
+            Color::Red => { return 1; }
+            Color::Green | Color::Blue => { return 2; }
+        }
+    }
+
+    fn negate(value: bool): bool {
+        match (value) {
+            true => { return false; }
+            false => { return true; }
+        }
+    }
+
+    fn grade(value: u8): i32 {
+        match (value) {
+            0..10 => { return 0; }
+            10..=255 => { return 1; }
+        }
+    }
+
+    fn main(): void {
+        let a: i32 = 5;
+        match (a) {
+            1 | 2 => { let b: i32 = a; }
+            3..=10 => { let b: bool = true; }
+            _ => { let b: i32 = describe(Color::Red); }
+        }
+    }
+    "#;
+
+    let result = check(program)?;
+    assert!(result.is_ok(), "{result:?}");
+    Ok(())
+}
+
+#[test]
+fn static_type_check_match_non_exhaustive() -> anyhow::Result<()> {
+    let programs = [
+        (r#"
+        fn main(): void {
+            let a: i32 = 5;
+            match (a) {
+                1..=10 => { let b = 1; }
+            }
+        }
+        "#, "i32"),
+        (r#"
+        fn main(): void {
+            let a: u8 = 5;
+            match (a) {
+                0..=100 => { let b = 1; }
+                102..=255 => { let b = 1; }
+            }
+        }
+        "#, "u8"),
+        (r#"
+        fn main(): void {
+            match (true) {
+                true => { let b = 1; }
+            }
+        }
+        "#, "bool"),
+        (r#"
+        enum Color { Red, Green, Blue }
+
+        fn main(): void {
+            match (Color::Red) {
+                Color::Red | Color::Blue => { let b = 1; }
+            }
+        }
+        "#, "Color"),
+    ];
+
+    for (program, ty) in programs {
+        let result = check(program)?;
+        assert!(matches!(&result, Err(StaticTypeCheckError::NonExhaustiveMatch { ty: actual, .. }) if actual.to_string() == ty), "{result:?}");
+    }
+
+    Ok(())
+}
+
+#[test]
+fn static_type_check_match_mismatched_pattern() -> anyhow::Result<()> {
+    let program = r#"
+    fn main(): void {
+        let a: i32 = 5;
+        match (a) {
+            true => { let b = 1; }
+            _ => { let b = 2; }
+        }
+    }
+    "#;
+
+    let result = check(program)?;
+    assert!(matches!(
+        &result,
+        Err(StaticTypeCheckError::InferredError(err)) if matches!(err.as_ref(), InferTypeError::MismatchedTypes { expected: Type::Integer(IntegerType::I32, _), actual: Type::Bool(_), .. })
+    ), "{result:?}");
+    Ok(())
+}
+
+#[test]
+fn static_type_check_match_pattern_out_of_range() -> anyhow::Result<()> {
+    let program = r#"
+    fn main(): void {
+        let a: u8 = 5;
+        match (a) {
+            0..=300 => { let b = 1; }
+        }
+    }
+    "#;
+
+    let result = check(program)?;
+    assert!(matches!(
+        &result,
+        Err(StaticTypeCheckError::InferredError(err)) if matches!(err.as_ref(), InferTypeError::IntegerTooSmall { literal, .. } if literal == "300")
+    ), "{result:?}");
+    Ok(())
+}
+
+#[test]
+fn static_type_check_match_missing_return() -> anyhow::Result<()> {
+    let program = r#"
+    fn sign(value: i32): i32 {
+        match (value) {
+            0 => { return 0; }
+            _ => { let b = 1; }
+        }
+    }
+    "#;
+
+    let result = check(program)?;
+    assert!(result.is_err(), "{result:?}");
+    Ok(())
+}
+
+#[test]
+fn static_type_check_match_empty_range() -> anyhow::Result<()> {
+    let programs = [
+        (r#"
+        fn main(): void {
+            let a: i32 = 5;
+            match (a) {
+                5..2 => { let b = 1; }
+                _ => { let b = 2; }
+            }
+        }
+        "#, "5..2"),
+        (r#"
+        fn main(): void {
+            let a: i32 = 5;
+            match (a) {
+                1..1 => { let b = 1; }
+                _ => { let b = 2; }
+            }
+        }
+        "#, "1..1"),
+    ];
+
+    for (program, expected) in programs {
+        let result = check(program)?;
+        assert!(matches!(&result, Err(StaticTypeCheckError::EmptyRange { pattern, .. }) if pattern == expected), "{result:?}");
+    }
+
+    Ok(())
+}
+
+#[test]
+fn static_type_check_match_unreachable_pattern() -> anyhow::Result<()> {
+    let programs = [
+        (r#"
+        fn main(): void {
+            let a: i32 = 5;
+            match (a) {
+                1 => { let b = 1; }
+                1 => { let b = 2; }
+                _ => { let b = 3; }
+            }
+        }
+        "#, "1"),
+        (r#"
+        fn main(): void {
+            let a: i32 = 5;
+            match (a) {
+                1 | 1 => { let b = 1; }
+                _ => { let b = 2; }
+            }
+        }
+        "#, "1"),
+        (r#"
+        fn main(): void {
+            let a: u8 = 5;
+            match (a) {
+                0..10 => { let b = 1; }
+                10..=20 => { let b = 2; }
+                5..=15 => { let b = 3; }
+                _ => { let b = 4; }
+            }
+        }
+        "#, "5..=15"),
+        (r#"
+        enum Color { Red, Green, Blue }
+
+        fn main(): void {
+            match (Color::Red) {
+                _ => { let b = 1; }
+                Color::Red => { let b = 2; }
+            }
+        }
+        "#, "Color::Red"),
+        (r#"
+        fn main(): void {
+            match (true) {
+                true => { let b = 1; }
+                false => { let b = 2; }
+                _ => { let b = 3; }
+            }
+        }
+        "#, "_"),
+    ];
+
+    for (program, expected) in programs {
+        let result = check(program)?;
+        assert!(matches!(&result, Err(StaticTypeCheckError::UnreachablePattern { pattern, .. }) if pattern == expected), "{result:?}");
+    }
+
+    Ok(())
+}
@@ -1,4 +1,5 @@
 use monkey_language::core::io::monkey_file::MonkeyFile;
+use crate::read_from_str;
 use monkey_language::core::lexer::token_with_span::FilePosition;
 use monkey_language::core::model::abstract_syntax_tree_node::AbstractSyntaxTreeNode;
 use monkey_language::core::model::abstract_syntax_tree_nodes::assignable::Assignable;
@@ -15,7 +16,7 @@ use monkey_language::core::model::types::mutability::Mutability;
 use monkey_language::core::model::types::static_string::StaticString;
 use monkey_language::core::model::types::ty::Type;
 use monkey_language::core::parser::ast_parser::ASTParser;
-use monkey_language::core::parser::types::r#type::InferTypeError;
+use monkey_language::core::parser::types::r#type::{InferTypeError, MethodCallSignatureMismatchCause};
 use monkey_language::core::semantics::static_type_check::static_type_checker::{static_type_check, StaticTypeCheckError};
 use monkey_language::core::semantics::type_infer::type_inferer::infer_type;
 
@@ -30,7 +31,7 @@ fn static_type_check_method_definition() -> anyhow::Result<()> {
     let result: i32 = test("test");
     "#;
 
-    let monkey_file: MonkeyFile = MonkeyFile::read_from_str(program)?;
+    let monkey_file: MonkeyFile = read_from_str(program)?;
     let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
 
     infer_type(&mut top_level_scope.result.program)?;
@@ -42,15 +43,19 @@ fn static_type_check_method_definition() -> anyhow::Result<()> {
         AbstractSyntaxTreeNode::MethodDefinition(MethodDefinition {
             identifier: LValue::Identifier(Identifier {
                 name: "test".to_string(),
+                symbol: None,
             }),
+            generic_parameters: vec![],
             return_type: Type::Integer(IntegerType::I32, Mutability::Immutable),
             arguments: vec![MethodArgument {
                 identifier: LValue::Identifier(Identifier {
                     name: "test".to_string(),
+                    symbol: None,
                 }),
                 ty: Type::Custom(
                     Identifier {
                         name: "*string".to_string(),
+                        symbol: None,
                     },
                     Mutability::Immutable,
                 ),
@@ -59,21 +64,27 @@ fn static_type_check_method_definition() -> anyhow::Result<()> {
                 AbstractSyntaxTreeNode::Variable(Variable {
                     l_value: LValue::Identifier(Identifier {
                         name: "a".to_string(),
+                        symbol: None,
                     }),
                     mutability: false,
                     ty: Some(Type::Custom(
                         Identifier {
                             name: "*string".to_string(),
+                            symbol: None,
                         },
                         Mutability::Immutable,
                     )),
                     define: true,
+                    compound_operator: None,
                     assignable: Assignable::Identifier(Identifier {
                         name: "test".to_string(),
+                        symbol: None,
                     }),
                     file_position: FilePosition {
                         line: 3..=3,
                         column: 9..=21,
+                        offset: 43..56,
+                        ..Default::default()
                     },
                 }),
                 AbstractSyntaxTreeNode::Return(Return {
@@ -84,37 +95,51 @@ fn static_type_check_method_definition() -> anyhow::Result<()> {
                     file_position: FilePosition {
                         line: 4..=4,
                         column: 9..=17,
+                        offset: 65..74,
+                        ..Default::default()
                     },
                 }),
             ],
             is_extern: false,
+            is_public: false,
             file_position: FilePosition {
                 line: 2..=5,
                 column: 5..=5,
+                offset: 5..80,
+                ..Default::default()
             },
         }),
         AbstractSyntaxTreeNode::Variable(Variable {
             l_value: LValue::Identifier(Identifier {
                 name: "result".to_string(),
+                symbol: None,
             }),
             mutability: false,
             ty: Some(Type::Integer(IntegerType::I32, Mutability::Immutable)),
             define: true,
+            compound_operator: None,
             assignable: Assignable::MethodCall(MethodCall {
+                receiver: None,
                 identifier: LValue::Identifier(Identifier {
                     name: "test".to_string(),
+                    symbol: None,
                 }),
+                type_arguments: vec![],
                 arguments: vec![Assignable::String(StaticString {
                     value: "\"test\"".to_string(),
                 })],
                 file_position: FilePosition {
                     line: 7..=7,
                     column: 23..=34,
+                    offset: 104..116,
+                    ..Default::default()
                 },
             }),
             file_position: FilePosition {
                 line: 7..=7,
                 column: 5..=35,
+                offset: 86..117,
+                ..Default::default()
             },
         }),
     ];
@@ -135,7 +160,7 @@ fn static_type_check_return_mismatch() -> anyhow::Result<()> {
     let result: i32 = test("test");
     "#;
 
-    let monkey_file: MonkeyFile = MonkeyFile::read_from_str(program)?;
+    let monkey_file: MonkeyFile = read_from_str(program)?;
     let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
 
     infer_type(&mut top_level_scope.result.program)?;
@@ -155,3 +180,43 @@ fn static_type_check_return_mismatch() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn static_type_check_else_if_returns() -> anyhow::Result<()> {
+    let program = r#"
+    fn sign(a: i32): i32 {
+        if (a < 0) {
+            return -1;
+        } else if (a == 0) {
+            return 0;
+        } else {
+            return 1;
+        }
+    }
+
+    let result: i32 = sign(5);
+    "#;
+
+    let monkey_file: MonkeyFile = read_from_str(program)?;
+    let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
+
+    infer_type(&mut top_level_scope.result.program)?;
+    static_type_check(&top_level_scope.result.program)?;
+
+    let missing_returns = [
+        "if (a < 0) { return -1; } else if (a == 0) { let b = 0; } else { return 1; }",
+        "if (a < 0) { return -1; } else if (a == 0) { return 0; }",
+        "if (a < 0) { if (a < 5) { return 1; } else { return 2; } } else { let b = 0; }",
+    ];
+
+    for body in missing_returns {
+        let program = format!("fn sign(a: i32): i32 {{ {body} }}");
+        let monkey_file: MonkeyFile = read_from_str(&program)?;
+        let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
+
+        let result = infer_type(&mut top_level_scope.result.program);
+        assert!(matches!(result.map_err(|e| *e), Err(InferTypeError::MethodReturnSignatureMismatch { cause: MethodCallSignatureMismatchCause::IfCondition, .. })), "{body}");
+    }
+
+    Ok(())
+}
@@ -1,2 +1,35 @@
+use monkey_language::core::io::monkey_file::MonkeyFile;
+use crate::read_from_str;
+use monkey_language::core::parser::ast_parser::ASTParser;
+use monkey_language::core::semantics::definite_assignment::definite_assignment_check;
+use monkey_language::core::semantics::static_type_check::static_type_checker::{static_type_check, StaticTypeCheckError};
+use monkey_language::core::semantics::type_infer::type_inferer::infer_type;
+
 pub mod method_definition;
-mod for_;
\ No newline at end of file
+mod for_;
+mod loop_control;
+mod compound_assignment;
+mod unary_operators;
+mod enums;
+mod match_statement;
+mod impl_;
+mod structs;
+mod scopes;
+mod definite_assignment;
+mod control_flow;
+
+/// Checks the program like the compiler does. Errors of the type inference are returned as [`StaticTypeCheckError::InferredError`]
+fn check(program: &str) -> anyhow::Result<Result<(), StaticTypeCheckError>> {
+    let monkey_file: MonkeyFile = read_from_str(program)?;
+    let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
+
+    if let Err(err) = definite_assignment_check(&top_level_scope.result.program) {
+        return Ok(Err(err));
+    }
+
+    if let Err(err) = infer_type(&mut top_level_scope.result.program) {
+        return Ok(Err(StaticTypeCheckError::InferredError(err)));
+    }
+
+    Ok(static_type_check(&top_level_scope.result.program).map(|_| ()))
+}
@@ -0,0 +1,83 @@
+use monkey_language::core::parser::types::r#type::InferTypeError;
+use monkey_language::core::semantics::static_type_check::static_type_checker::StaticTypeCheckError;
+use crate::semantics::static_type_check::check;
+
+fn is_unresolved(result: &Result<(), StaticTypeCheckError>, expected_name: &str) -> bool {
+    match result {
+        Err(StaticTypeCheckError::InferredError(err)) => matches!(err.as_ref(), InferTypeError::UnresolvedReference(name, _) if name == expected_name),
+        Err(StaticTypeCheckError::UnresolvedReference { name, .. }) => name.identifier() == expected_name,
+        _ => false,
+    }
+}
+
+#[test]
+fn static_type_check_loop_variable_out_of_scope() -> anyhow::Result<()> {
+    let programs = [
+        r#"
+        fn main(): void {
+            for (let mut i: i32 = 0; i < 10; i = i + 1;) { let a: i32 = i; }
+            let b: i32 = i;
+        }
+        "#,
+        r#"
+        fn main(): void {
+            for (let mut i = 0; i < 10; i = i + 1;) {
+                let a = i;
+            }
+            let b = i;
+        }
+        "#,
+    ];
+
+    for program in programs {
+        let result = check(program)?;
+        assert!(is_unresolved(&result, "i"), "{program}: {result:?}");
+    }
+
+    Ok(())
+}
+
+#[test]
+fn static_type_check_block_variable_out_of_scope() -> anyhow::Result<()> {
+    let program = r#"
+    fn main(): void {
+        let condition: bool = true;
+        if (condition) {
+            let inner: i32 = 5;
+        }
+        let outer: i32 = inner;
+    }
+    "#;
+
+    let result = check(program)?;
+    assert!(is_unresolved(&result, "inner"), "{result:?}");
+
+    Ok(())
+}
+
+#[test]
+fn static_type_check_shadowing() -> anyhow::Result<()> {
+    let program = r#"
+    fn main(): void {
+        let x: i32 = 1;
+        let condition: bool = true;
+        if (condition) {
+            let x: bool = false;
+            let y: bool = x;
+        }
+        let z: i32 = x;
+
+        for (let mut i: i32 = 0; i < 10; i = i + 1;) {
+            let a = i;
+        }
+
+        for (let mut i: i32 = 0; i < 10; i = i + 1;) {
+            let a = i;
+        }
+    }
+    "#;
+
+    check(program)??;
+
+    Ok(())
+}
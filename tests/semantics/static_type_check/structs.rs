@@ -0,0 +1,99 @@
+use monkey_language::core::parser::types::r#type::InferTypeError;
+use monkey_language::core::semantics::static_type_check::static_type_checker::StaticTypeCheckError;
+use crate::semantics::static_type_check::check;
+
+#[test]
+fn static_type_check_field_access() -> anyhow::Result<()> {
+    let program = r#"
+    struct Parent { height: i32 }
+    struct Person { age: i32, mother: Parent }
+
+    fn birthday(person: Person): Person {
+        let mut older = person;
+        older.age = older.age + 1;
+        return older;
+    }
+
+    fn main(): void {
+        let mut person = Person { age: 30, mother: Parent { height: 170 } };
+        person.age = 31;
+        let h: i32 = person.mother.height;
+        let older: Person = birthday(person);
+        let pointer: *Person = &person;
+        let a: i32 = pointer.age;
+    }
+    "#;
+
+    let result = check(program)?;
+    assert!(result.is_ok(), "{result:?}");
+    Ok(())
+}
+
+#[test]
+fn static_type_check_unknown_field() -> anyhow::Result<()> {
+    let program = r#"
+    struct Person { age: i32 }
+
+    fn main(): void {
+        let person = Person { age: 30 };
+        let h = person.height;
+    }
+    "#;
+
+    let result = check(program)?;
+    assert!(matches!(result, Err(StaticTypeCheckError::InferredError(ref err)) if matches!(**err, InferTypeError::UnknownField { ref field, .. } if field == "height")), "{result:?}");
+    Ok(())
+}
+
+#[test]
+fn static_type_check_immutable_field() -> anyhow::Result<()> {
+    let program = r#"
+    struct Person { age: i32 }
+
+    fn main(): void {
+        let person = Person { age: 30 };
+        person.age = 31;
+    }
+    "#;
+
+    let result = check(program)?;
+    assert!(matches!(result, Err(StaticTypeCheckError::ImmutabilityViolated { .. })), "{result:?}");
+    Ok(())
+}
+
+#[test]
+fn static_type_check_struct_by_value_in_extern_method() -> anyhow::Result<()> {
+    let argument = r#"
+    struct Point { x: i32, y: i32 }
+    extern fn draw(point: Point): void;
+
+    fn main(): void {
+        draw(Point { x: 1, y: 2 });
+    }
+    "#;
+
+    let return_type = r#"
+    struct Point { x: i32, y: i32 }
+    extern fn origin(): Point;
+
+    fn main(): void { }
+    "#;
+
+    let pointer = r#"
+    struct Point { x: i32, y: i32 }
+    extern fn draw(point: *Point): void;
+
+    fn main(): void { }
+    "#;
+
+    let result = check(argument)?;
+    assert!(matches!(result, Err(StaticTypeCheckError::StructByValueInExternMethod { ref method_name, .. }) if method_name == "draw"), "{result:?}");
+    assert!(result.is_err_and(|error| error.to_string().contains("System V")));
+
+    let result = check(return_type)?;
+    assert!(matches!(result, Err(StaticTypeCheckError::StructByValueInExternMethod { ref method_name, .. }) if method_name == "origin"), "{result:?}");
+
+    let result = check(pointer)?;
+    assert!(result.is_ok(), "{result:?}");
+    Ok(())
+}
@@ -0,0 +1,87 @@
+use monkey_language::core::io::monkey_file::MonkeyFile;
+use crate::read_from_str;
+use monkey_language::core::model::abstract_syntax_tree_nodes::assignables::equation_parser::operator::Operator;
+use monkey_language::core::model::types::integer::IntegerType;
+use monkey_language::core::model::types::ty::Type;
+use monkey_language::core::parser::ast_parser::ASTParser;
+use monkey_language::core::parser::types::r#type::InferTypeError;
+use monkey_language::core::semantics::static_type_check::static_type_checker::static_type_check;
+use monkey_language::core::semantics::type_infer::type_inferer::infer_type;
+
+fn infer(program: &str) -> anyhow::Result<Result<(), Box<InferTypeError>>> {
+    let monkey_file: MonkeyFile = read_from_str(program)?;
+    let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
+
+    Ok(infer_type(&mut top_level_scope.result.program).map(|_| ()))
+}
+
+#[test]
+fn static_type_check_unary_operators() -> anyhow::Result<()> {
+    let program = r#"
+    fn main(): void {
+        let a = 5;
+        let b: i32 = -a;
+        let c: bool = !(a < 3);
+        let d: i32 = ~a;
+        let e: u8 = ~1_u8;
+        let f: f64 = -2.5_f64;
+        let g: f32 = -(1.5 * 2.0);
+    }
+    "#;
+
+    let monkey_file: MonkeyFile = read_from_str(program)?;
+    let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
+
+    infer_type(&mut top_level_scope.result.program)?;
+    static_type_check(&top_level_scope.result.program)?;
+    Ok(())
+}
+
+#[test]
+fn infer_type_negate_unsigned() -> anyhow::Result<()> {
+    let program = r#"
+    fn main(): void {
+        let a: u32 = 5;
+        let b = -a;
+    }
+    "#;
+
+    let result = infer(program)?;
+    assert!(matches!(
+        result.as_ref().map_err(|e| e.as_ref()),
+        Err(InferTypeError::IllegalUnaryOperation(Operator::Sub, Type::Integer(_, _), _))
+    ), "{result:?}");
+    Ok(())
+}
+
+#[test]
+fn infer_type_logical_not_integer() -> anyhow::Result<()> {
+    let program = r#"
+    fn main(): void {
+        let a = !5;
+    }
+    "#;
+
+    let result = infer(program)?;
+    assert!(matches!(
+        result.as_ref().map_err(|e| e.as_ref()),
+        Err(InferTypeError::IllegalUnaryOperation(Operator::LogicalNot, Type::Integer(IntegerType::I32, _), _))
+    ), "{result:?}");
+    Ok(())
+}
+
+#[test]
+fn infer_type_bitwise_not_float() -> anyhow::Result<()> {
+    let program = r#"
+    fn main(): void {
+        let a = ~1.5;
+    }
+    "#;
+
+    let result = infer(program)?;
+    assert!(matches!(
+        result.as_ref().map_err(|e| e.as_ref()),
+        Err(InferTypeError::IllegalUnaryOperation(Operator::BitwiseNot, Type::Float(_, _), _))
+    ), "{result:?}");
+    Ok(())
+}
@@ -0,0 +1,92 @@
+use monkey_language::core::io::monkey_file::MonkeyFile;
+use crate::read_from_str;
+use monkey_language::core::parser::ast_parser::ASTParser;
+use monkey_language::core::semantics::static_type_check::static_type_checker::static_type_check;
+use monkey_language::core::semantics::type_infer::type_inferer::infer_type;
+use monkey_language::core::semantics::warnings::linter::lint;
+use monkey_language::core::semantics::warnings::{Lint, LintConfig, LintLevel};
+
+fn warnings(program: &str, lint_config: &LintConfig) -> anyhow::Result<Vec<(Lint, LintLevel, String)>> {
+    let monkey_file: MonkeyFile = read_from_str(program)?;
+    let mut top_level_scope = ASTParser::parse(&monkey_file.tokens)?;
+
+    infer_type(&mut top_level_scope.result.program)?;
+    static_type_check(&top_level_scope.result.program)?;
+
+    Ok(lint(&top_level_scope.result.program, lint_config)
+        .into_iter()
+        .map(|warning| (warning.lint, warning.level, warning.message))
+        .collect())
+}
+
+#[test]
+fn lints() -> anyhow::Result<()> {
+    let program = r#"
+    extern fn printf(format: *string, value: i32): void;
+
+    fn unused(value: i32, other: i32): void {
+        return;
+        printf("%d", value);
+    }
+
+    fn set(value: mut *i32): void {
+        *value = 42;
+    }
+
+    fn main(): i32 {
+        let a: i32 = 1;
+        let mut b: i32 = 2;
+        let mut c: i32 = 3;
+        c += b;
+        let mut d: i32 = 4;
+        set(&d);
+        let _e: i32 = 5;
+        let f: i32 = (i32) c;
+
+        return d;
+    }
+    "#;
+
+    let warnings = warnings(program, &LintConfig::default())?;
+    let expected = [
+        (Lint::UnusedVariables, "unused variable: `other`"),
+        (Lint::UnusedFunctions, "function `unused` is never used"),
+        (Lint::UnreachableCode, "unreachable statement"),
+        (Lint::UnusedVariables, "unused variable: `a`"),
+        (Lint::UnusedMut, "variable `b` doesn't need to be mutable"),
+        (Lint::RedundantCasts, "redundant cast to `i32`, the value already has this type"),
+        (Lint::UnusedVariables, "unused variable: `f`"),
+    ];
+
+    assert_eq!(expected.len(), warnings.len(), "{warnings:?}");
+    for ((expected_lint, expected_message), (lint, level, message)) in expected.iter().zip(&warnings) {
+        assert_eq!(expected_lint, lint);
+        assert_eq!(LintLevel::Warn, *level);
+        assert_eq!(expected_message, message);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn lint_levels() -> anyhow::Result<()> {
+    let program = r#"
+    fn main(): i32 {
+        let mut a: i32 = 1;
+        let b: i32 = 2;
+        return a;
+    }
+    "#;
+
+    let mut lint_config = LintConfig::default();
+    lint_config.set(Lint::UnusedVariables, LintLevel::Allow);
+    lint_config.set("W0002".parse().map_err(anyhow::Error::msg)?, LintLevel::Deny);
+
+    let warnings = warnings(program, &lint_config)?;
+    assert_eq!(vec![(Lint::UnusedMut, LintLevel::Deny, "variable `a` doesn't need to be mutable".to_string())], warnings);
+
+    assert_eq!(Lint::RedundantCasts, "redundant-casts".parse::<Lint>().map_err(anyhow::Error::msg)?);
+    assert!("unknown_lint".parse::<Lint>().is_err());
+
+    Ok(())
+}